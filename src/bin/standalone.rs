@@ -1,91 +1,187 @@
 use async_graphql::{
     http::{playground_source, GraphQLPlaygroundConfig},
-    EmptyMutation, EmptySubscription, Schema,
+    Data, Schema,
 };
-use async_graphql_warp::{GraphQLBadRequest, GraphQLResponse};
-use dataloader::non_cached::Loader;
+use async_graphql_warp::{graphql_subscription_with_data, GraphQLBadRequest, GraphQLResponse};
+use clap::{Parser, Subcommand};
 use http::StatusCode;
 use relation_server::{
+    client::RelationClient,
     config::{self, C},
-    controller::graphql::Query,
+    controller::graphql::{
+        insert_loaders, ErrorCode, FieldMetrics, Mutation, Provenance, Query, Subscription,
+    },
+    controller::{self, request_id},
     error::Result,
-    graph::arangopool::new_connection_pool,
-    graph::vertex::contract::ContractLoadFn,
-    graph::vertex::FromToLoadFn,
-    graph::vertex::IdentityLoadFn,
+    graph::{export::export, migrations, shared_pool},
+    metrics,
+    upstream::Platform,
+    worker,
 };
 // use aragog::{AuthMode, DatabaseConnection, OperationOptions};
 use std::{convert::Infallible, net::SocketAddr};
-use tracing::{info, warn};
+use tracing::{info, warn, Instrument};
 use tracing_subscriber::filter::{EnvFilter, LevelFilter};
 use warp::{http::Response as HttpResponse, Filter, Rejection};
 
+/// RelationService: GraphQL server of identity relations, and tools to operate it.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// `serve` if omitted.
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the GraphQL server, with background refresh workers.
+    Serve,
+    /// Fetch an identity from all upstreams, save what's found, and print its cluster as JSON.
+    Fetch {
+        /// Platform of identity, e.g. `twitter`.
+        platform: Platform,
+        /// Identity on platform.
+        identity: String,
+        /// Depth of the cluster printed.
+        #[arg(long, default_value_t = 1)]
+        depth: u16,
+    },
+    /// Scan stale proofs once: re-fetch them, and invalidate those gone in upstream.
+    RefreshStale,
+    /// Apply pending database migrations.
+    Migrate,
+    /// Print documents in DB as JSON lines.
+    Export {
+        /// Collections to export, e.g. `Identities`. All of them if omitted.
+        #[arg(long)]
+        collection: Vec<String>,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    let log_subscriber = tracing_subscriber::FmtSubscriber::builder()
-        .with_env_filter(
-            EnvFilter::builder()
-                .with_default_directive(LevelFilter::INFO.into())
-                .from_env_lossy()
-                .add_directive("hyper=info".parse().unwrap())
-                .add_directive("tokio=info".parse().unwrap()),
+    let cli = Cli::parse();
+
+    let env_filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .from_env_lossy()
+        .add_directive("hyper=info".parse().unwrap())
+        .add_directive("tokio=info".parse().unwrap());
+    // Logs go to stderr, so stdout of `fetch` and `export` can be piped as is.
+    let log_builder = tracing_subscriber::FmtSubscriber::builder()
+        .with_env_filter(env_filter)
+        .with_writer(std::io::stderr);
+    // Fields of all spans an event is in (e.g. `request_id`) are logged with it.
+    let result = if C.log.json {
+        tracing::subscriber::set_global_default(
+            log_builder
+                .json()
+                .flatten_event(true)
+                .with_current_span(false)
+                .with_span_list(true)
+                .finish(),
         )
-        .finish();
+    } else {
+        tracing::subscriber::set_global_default(log_builder.finish())
+    };
+    result.expect("Setting default subscriber failed");
+
+    // Create collections, indexes and views if missing.
+    migrations::bootstrap().await?;
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve().await,
+        Command::Fetch {
+            platform,
+            identity,
+            depth,
+        } => {
+            let client = RelationClient::new();
+            for err in client.fetch(&platform, &identity).await? {
+                warn!(
+                    "{} failed to fetch {}: {}",
+                    err.upstream, err.target, err.error
+                );
+            }
+            let graph = client.query_cluster(&platform, &identity, depth).await?;
+            println!("{}", serde_json::to_string_pretty(&graph)?);
+            Ok(())
+        }
+        Command::RefreshStale => worker::scheduler::scan().await,
+        Command::Migrate => {
+            let applied = migrations::migrate().await?;
+            info!("{} migration(s) applied: {:?}", applied.len(), applied);
+            Ok(())
+        }
+        Command::Export { collection } => {
+            let written = export(&mut std::io::stdout().lock(), &collection).await?;
+            info!("{} document(s) exported", written);
+            Ok(())
+        }
+    }
+}
 
-    tracing::subscriber::set_global_default(log_subscriber)
-        .expect("Setting default subscriber failed");
+/// Run the GraphQL server until shut down.
+async fn serve() -> Result<()> {
+    let pending = migrations::pending().await?;
+    if !pending.is_empty() {
+        warn!(
+            "{} migration(s) pending, run `standalone migrate` to apply",
+            pending.len()
+        );
+    }
 
     let middleware_cors = warp::cors()
         .allow_any_origin() // : maybe more strict CORS in production?
         .allow_methods(vec!["GET", "POST"])
-        .allow_headers(vec!["Accept", "Content-Type", "Length"]);
-
-    // Performing DB migration
-    let _db = aragog::DatabaseConnection::builder()
-        .with_credentials(&C.db.host, &C.db.db, &C.db.username, &C.db.password)
-        .with_auth_mode(aragog::AuthMode::Basic)
-        .with_operation_options(aragog::OperationOptions::default())
-        .with_schema_path(&C.db.schema_path)
-        .apply_schema() // Only apply database migration here.
-        .build()
-        .await?;
+        .allow_headers(vec!["Accept", "Content-Type", "Length", request_id::HEADER])
+        .expose_headers(vec![request_id::HEADER]);
 
     // Runtime::Tokio1
-    let pool = new_connection_pool().await?;
-    let contract_loader_fn = ContractLoadFn {
-        pool: pool.to_owned(),
-    };
-    let identity_loader_fn = IdentityLoadFn {
-        pool: pool.to_owned(),
-    };
-    let from_to_loader_fn = FromToLoadFn {
-        pool: pool.to_owned(),
-    };
-    // HOLD ON: Specify the batch size number
-    let contract_loader = Loader::new(contract_loader_fn)
-        .with_max_batch_size(100)
-        .with_yield_count(10);
-    let identity_loader = Loader::new(identity_loader_fn)
-        .with_max_batch_size(100)
-        .with_yield_count(10);
-    let from_to_loader = Loader::new(from_to_loader_fn)
-        .with_max_batch_size(100)
-        .with_yield_count(10);
-
-    let schema = Schema::build(Query::default(), EmptyMutation, EmptySubscription)
-        .data(pool)
-        .data(contract_loader)
-        .data(identity_loader)
-        .data(from_to_loader)
-        .finish();
+    let pool = shared_pool();
+    // Background refresh of outdated records.
+    worker::start();
+    worker::scheduler::start();
+    let schema = Schema::build(
+        Query::default(),
+        Mutation::default(),
+        Subscription::default(),
+    )
+    .data(pool.clone())
+    .limit_depth(C.web.max_query_depth)
+    .limit_complexity(C.web.max_query_complexity)
+    .extension(Provenance)
+    .extension(ErrorCode)
+    .extension(FieldMetrics)
+    .finish();
 
+    // DataLoaders live in one request (or one WebSocket connection), so is their cache.
+    let ws_pool = pool.clone();
+    let graphql_ws = graphql_subscription_with_data(schema.clone(), move |_| async move {
+        let mut data = Data::default();
+        insert_loaders(&mut data, &ws_pool);
+        Ok(data)
+    });
     let graphql_post = async_graphql_warp::graphql(schema)
+        .and(warp::header::optional::<String>(request_id::HEADER))
         .and_then(
-            |(schema, request): (
-                Schema<Query, EmptyMutation, EmptySubscription>,
+            move |(schema, mut request): (
+                Schema<Query, Mutation, Subscription>,
                 async_graphql::Request,
-            )| async move {
-                Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
+            ),
+                  given_id: Option<String>| {
+                insert_loaders(&mut request.data, &pool);
+                let id = request_id::from_header(given_id);
+                let span = request_id::span(&id);
+                async move {
+                    let response = schema.execute(request).instrument(span).await;
+                    Ok::<_, Infallible>(warp::reply::with_header(
+                        GraphQLResponse::from(response),
+                        request_id::HEADER,
+                        id,
+                    ))
+                }
             },
         )
         .with(middleware_cors);
@@ -93,10 +189,24 @@ async fn main() -> Result<()> {
     let playground = warp::path::end().and(warp::get()).map(|| {
         HttpResponse::builder()
             .header("content-type", "text/html")
-            .body(playground_source(GraphQLPlaygroundConfig::new("/")))
+            .body(playground_source(
+                GraphQLPlaygroundConfig::new("/").subscription_endpoint("/"),
+            ))
     });
 
-    let routes = playground
+    let metrics = warp::path("metrics").and(warp::get()).map(|| {
+        HttpResponse::builder()
+            .header("content-type", prometheus::TEXT_FORMAT)
+            .body(metrics::render())
+    });
+
+    // `/api/*` and `/v1/*`, as served by the Lambda entrypoint.
+    let rest = controller::filter();
+
+    let routes = graphql_ws
+        .or(playground)
+        .or(metrics)
+        .or(rest)
         .or(graphql_post)
         .recover(|err: Rejection| async move {
             if let Some(GraphQLBadRequest(err)) = err.find() {
@@ -125,6 +235,6 @@ async fn main() -> Result<()> {
 
     warp::serve(routes).run(address).await;
 
-    println!("Shutting down...");
+    info!("Shutting down...");
     Ok(())
 }
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    error::Error,
+    graph::{
+        edge::ProofLevel,
+        shared_pool,
+        vertex::{Identity, IdentityGraph},
+        ConnectionPool,
+    },
+    upstream::{fetch_all, import::import, Connection, FetchError, Platform, Target},
+    util::canonical::canonicalize,
+};
+
+/// Relation graph for other Rust services, without running GraphQL.
+/// Reads the same config as the server (`config/main.toml` and `KV__` environment variables),
+/// and saves to / queries the same ArangoDB.
+///
+/// ```no_run
+/// use relation_server::{client::RelationClient, upstream::Platform};
+///
+/// # async fn example() -> Result<(), relation_server::error::Error> {
+/// let client = RelationClient::new();
+/// // Crawl upstreams for everything connected to this identity, and save it.
+/// client.fetch(&Platform::Twitter, "alice").await?;
+/// if let Some(cluster) = client.query_cluster(&Platform::Twitter, "alice", 2).await? {
+///     for vertex in cluster.vertices {
+///         println!("{} {}", vertex.identity.platform, vertex.identity.identity);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RelationClient {
+    pool: ConnectionPool,
+}
+
+impl Default for RelationClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelationClient {
+    /// Client sharing connections with everything else in this process (see `shared_pool`).
+    pub fn new() -> Self {
+        Self::with_pool(shared_pool())
+    }
+
+    /// Client using connections of `pool`.
+    pub fn with_pool(pool: ConnectionPool) -> Self {
+        Self { pool }
+    }
+
+    /// Save one connection, the same way connections found by upstreams are saved.
+    pub async fn upsert_connection(&self, connection: &Connection) -> Result<(), Error> {
+        self.upsert_connections(std::slice::from_ref(connection))
+            .await
+    }
+
+    /// Save connections, the same way as `upstream::import`: all of them in one transaction.
+    pub async fn upsert_connections(&self, connections: &[Connection]) -> Result<(), Error> {
+        let db = self
+            .pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        import(&db, connections).await?;
+        Ok(())
+    }
+
+    /// Connected component ("identity cluster") around an identity, up to `depth`
+    /// (capped by `traversal.max_depth` in config). Invalidated connections are left out.
+    /// `None` if the identity is not saved, see `fetch`.
+    pub async fn query_cluster(
+        &self,
+        platform: &Platform,
+        identity: &str,
+        depth: u16,
+    ) -> Result<Option<IdentityGraph>, Error> {
+        let identity = canonicalize(platform, identity)?;
+        let db = self
+            .pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        match Identity::find_by_platform_identity(&db, platform, &identity).await? {
+            None => Ok(None),
+            Some(found) => Ok(Some(
+                found
+                    .identity_graph(&self.pool, depth, false, ProofLevel::default())
+                    .await?,
+            )),
+        }
+    }
+
+    /// Crawl upstreams from an identity, and save everything found.
+    /// Returns errors of failed upstreams, what's found by others is saved anyway.
+    pub async fn fetch(
+        &self,
+        platform: &Platform,
+        identity: &str,
+    ) -> Result<Vec<FetchError>, Error> {
+        let identity = canonicalize(platform, identity)?;
+        let crawled = fetch_all(Target::Identity(*platform, identity)).await?;
+        Ok(crawled.errors)
+    }
+}
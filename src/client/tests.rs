@@ -0,0 +1,37 @@
+use crate::{
+    client::RelationClient,
+    graph::{edge::Proof, vertex::Identity},
+    upstream::Connection,
+};
+use fake::{Fake, Faker};
+
+#[tokio::test]
+async fn test_upsert_and_query_cluster() {
+    let client = RelationClient::new();
+    let from: Identity = Faker.fake();
+    let to: Identity = Faker.fake();
+    let proof: Proof = Faker.fake();
+    client
+        .upsert_connection(&Connection::Proof(from.clone(), to.clone(), proof))
+        .await
+        .unwrap();
+
+    let cluster = client
+        .query_cluster(&from.platform, &from.identity, 1)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cluster.vertices[0].identity.identity, from.identity);
+    assert!(cluster
+        .vertices
+        .iter()
+        .any(|vertex| vertex.identity.identity == to.identity));
+    assert_eq!(cluster.edges.len(), 1);
+
+    let missing: Identity = Faker.fake();
+    assert!(client
+        .query_cluster(&missing.platform, &missing.identity, 1)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -1,8 +1,11 @@
 mod env;
+#[cfg(test)]
+mod tests;
 
 use crate::error::Error;
 use config::Config;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 use self::env::ENV;
 
@@ -24,12 +27,32 @@ lazy_static! {
 
 #[derive(Clone, Deserialize, Default)]
 pub struct KVConfig {
+    #[serde(default)]
     pub db: ConfigDB,
+    #[serde(default)]
     pub web: ConfigWeb,
+    #[serde(default)]
+    pub crawler: ConfigCrawler,
+    #[serde(default)]
+    pub http: ConfigHttp,
+    #[serde(default)]
+    pub worker: ConfigWorker,
+    #[serde(default)]
+    pub scheduler: ConfigScheduler,
+    #[serde(default)]
+    pub traversal: ConfigTraversal,
+    #[serde(default)]
+    pub breaker: ConfigBreaker,
+    #[serde(default)]
+    pub log: ConfigLog,
+    #[serde(default)]
+    pub cache: ConfigCache,
+    #[serde(default)]
     pub upstream: Upstream,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct Upstream {
     pub proof_service: ConfigProofService,
     pub aggregation_service: ConfigAggregationService,
@@ -44,9 +67,56 @@ pub struct Upstream {
     pub unstoppable_api: ConfigUnstoppableDomainsAPI,
     pub datamgr_api: ConfigDataMgrAPI,
     pub spaceid_api: ConfigSpaceIdAPI,
+    pub farcaster_hub_api: ConfigFarcasterHubAPI,
+    pub gitcoin_passport_api: ConfigGitcoinPassportAPI,
+    pub proof_of_humanity: ConfigProofOfHumanity,
+    pub brightid: ConfigBrightID,
+    pub twitter_api: ConfigTwitterAPI,
+    pub github_api: ConfigGithubAPI,
+    pub dns: ConfigDNS,
+    pub poap_api: ConfigPoapAPI,
+    pub snapshot: ConfigSnapshot,
+    pub opensea_api: ConfigOpenSeaAPI,
+    pub sns_api: ConfigSNSAPI,
+    pub crossbell_api: ConfigCrossbellAPI,
+    pub cyberconnect: ConfigCyberConnect,
+    pub yat_api: ConfigYatAPI,
+    pub telegram_bot: ConfigTelegramBot,
+    pub discord_attestation: ConfigDiscordAttestation,
+    pub openpgp_keyserver: ConfigOpenPGPKeyserver,
+    pub orcid_api: ConfigOrcidAPI,
+    pub mirror_api: ConfigMirrorAPI,
+    pub galxe_api: ConfigGalxeAPI,
+    pub link3: ConfigLink3,
+    pub world_id: ConfigWorldID,
+    pub civic_api: ConfigCivicAPI,
+    pub polygon_id: ConfigPolygonID,
+    pub vc: ConfigVC,
+    pub siwe: ConfigSIWE,
+    pub ethereum_rpc: ConfigEthereumRPC,
+    pub aptos_names_api: ConfigAptosNamesAPI,
+    pub avvy_api: ConfigAvvyAPI,
+    pub icns: ConfigICNS,
+    pub stargaze_names: ConfigStargazeNames,
+    pub debank_api: ConfigDeBankAPI,
+    pub etherscan_api: ConfigEtherscanAPI,
+    pub rarible_api: ConfigRaribleAPI,
+    pub zora_api: ConfigZoraAPI,
+    pub website: ConfigWebsite,
+    pub matrix: ConfigMatrix,
+    pub hacker_news_api: ConfigHackerNewsAPI,
+    pub sbt: ConfigSBT,
+    pub eas: ConfigEAS,
+    /// Switches of upstreams, keyed by their module name in `upstream`,
+    /// e.g. `keybase = false` or `KV__UPSTREAM__ENABLED__KEYBASE=false`.
+    /// Upstreams not listed here are enabled.
+    pub enabled: HashMap<String, bool>,
+    /// Upstreams defined by config only, see `upstream::generic`.
+    pub generic: Vec<ConfigGenericUpstream>,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigDB {
     pub host: String,
     pub username: String,
@@ -55,78 +125,640 @@ pub struct ConfigDB {
     pub schema_path: String,
 }
 
-#[derive(Clone, Deserialize, Default)]
+#[derive(Clone, Deserialize)]
+#[serde(default)]
 pub struct ConfigWeb {
     pub listen: String,
     pub port: u16,
+    /// Fetch from upstreams when a queried identity is not found in DB.
+    pub fetch_on_miss: bool,
+    /// Max nesting depth of a GraphQL query.
+    pub max_query_depth: usize,
+    /// Max complexity of a GraphQL query. Traversal fields (e.g. `neighbor`)
+    /// are weighted by their `depth`.
+    pub max_query_complexity: usize,
+}
+
+impl Default for ConfigWeb {
+    fn default() -> Self {
+        Self {
+            listen: "127.0.0.1".into(),
+            port: 3722,
+            fetch_on_miss: true,
+            max_query_depth: 10,
+            max_query_complexity: 1000,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigCrawler {
+    /// Rounds of feeding found identities back into upstreams.
+    pub max_depth: u16,
+    /// Max amount of targets fetched in one crawl.
+    pub budget: usize,
+    /// Milliseconds to wait between two pages of one upstream API.
+    pub page_interval: u64,
+    /// Max pages fetched from one upstream API for one target.
+    pub max_pages: usize,
+    /// Seconds for all upstreams to fetch one target.
+    /// Upstreams still running by then are reported as failed.
+    pub fetch_timeout: u64,
+}
+
+impl Default for ConfigCrawler {
+    fn default() -> Self {
+        Self {
+            max_depth: 5,
+            budget: 200,
+            page_interval: 200,
+            max_pages: 50,
+            fetch_timeout: 30,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigHttp {
+    /// Seconds to wait for one attempt of a request.
+    pub timeout: u64,
+    /// Retries after the first attempt, on timeout, network error, 429 or 5xx.
+    pub retries: u32,
+    /// Milliseconds to wait before the first retry, doubled on every retry.
+    pub backoff: u64,
+    /// Max milliseconds to wait between two retries.
+    pub max_backoff: u64,
+    /// Max requests sent to one host at the same time.
+    pub max_per_host: usize,
+}
+
+impl Default for ConfigHttp {
+    fn default() -> Self {
+        Self {
+            timeout: 5,
+            retries: 2,
+            backoff: 200,
+            max_backoff: 5000,
+            max_per_host: 8,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigWorker {
+    /// Background refresh jobs running at the same time.
+    pub concurrency: usize,
+}
+
+impl Default for ConfigWorker {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigScheduler {
+    /// Seconds between two scans of stale proofs. `0` disables the scheduler.
+    pub interval: u64,
+    /// Max proofs of one data source re-fetched in one scan.
+    pub batch: usize,
+    /// Seconds since last fetched for a proof to become stale.
+    pub staleness: u64,
+    /// `staleness` of specific data sources, e.g. `keybase = 3600`. `0` skips that source.
+    pub sources: HashMap<String, u64>,
+}
+
+impl Default for ConfigScheduler {
+    fn default() -> Self {
+        Self {
+            interval: 3600,
+            batch: 100,
+            staleness: 86400,
+            sources: HashMap::new(),
+        }
+    }
+}
+
+/// Which direction of an edge collection is followed in graph traversal.
+#[derive(Clone, Copy, Deserialize, Default, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TraversalDirection {
+    #[default]
+    Any,
+    Outbound,
+    Inbound,
+}
+
+impl TraversalDirection {
+    /// Direction keyword in AQL.
+    pub fn aql(&self) -> &'static str {
+        match self {
+            TraversalDirection::Any => "ANY",
+            TraversalDirection::Outbound => "OUTBOUND",
+            TraversalDirection::Inbound => "INBOUND",
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigTraversal {
+    /// Direction of `Proofs` followed by `neighbors`. Some upstreams only save
+    /// one-way proofs (e.g. `keybase => twitter`), so `any` by default.
+    pub proofs: TraversalDirection,
+    /// Direction of `Holds` followed by `neighbors`.
+    pub holds: TraversalDirection,
+    /// Max depth of an identity graph query.
+    pub max_depth: u16,
+}
+
+impl Default for ConfigTraversal {
+    fn default() -> Self {
+        Self {
+            proofs: Default::default(),
+            holds: Default::default(),
+            max_depth: 5,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigBreaker {
+    /// Consecutive failures of an upstream to skip it, 0 to never skip.
+    pub failures: u32,
+    /// Seconds to skip a failing upstream, before trying it again.
+    pub cooldown: u64,
+}
+
+impl Default for ConfigBreaker {
+    fn default() -> Self {
+        Self {
+            failures: 5,
+            cooldown: 60,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigLog {
+    /// One JSON object per line (for log collectors), or human-readable text if `false`.
+    pub json: bool,
+}
+
+impl Default for ConfigLog {
+    fn default() -> Self {
+        Self { json: true }
+    }
+}
+
+/// Seconds before a saved record is outdated, and refetched when queried.
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfigCache {
+    pub identity: u64,
+    pub contract: u64,
+    pub crypto_key: u64,
+    pub proof: u64,
+    pub hold: u64,
+    pub resolve: u64,
+}
+
+impl Default for ConfigCache {
+    fn default() -> Self {
+        Self {
+            identity: 3600,
+            contract: 3600,
+            crypto_key: 86400,
+            proof: 86400,
+            hold: 28800,
+            resolve: 86400,
+        }
+    }
+}
+
+impl ConfigScheduler {
+    pub fn staleness_of(&self, source: &str) -> u64 {
+        self.sources.get(source).copied().unwrap_or(self.staleness)
+    }
+}
+
+impl Upstream {
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.enabled.get(name).copied().unwrap_or(true)
+    }
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigProofService {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigKeybaseService {
     pub url: String,
+    pub sigchain_url: String,
+    /// Download sigchain and verify signatures of proofs before saving them.
+    pub verify_sigchain: bool,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigAggregationService {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigSybilService {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigKnn3Service {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigRss3Service {
     pub url: String,
+    pub profile_url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigUpstreamTheGraph {
     pub ens: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigENSReverse {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigDotbitService {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigLensAPI {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigUnstoppableDomainsAPI {
     pub url: String,
     pub token: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigDataMgrAPI {
     pub url: String,
 }
 
 #[derive(Clone, Deserialize, Default)]
+#[serde(default)]
 pub struct ConfigSpaceIdAPI {
     pub url: String,
 }
 
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigFarcasterHubAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigGitcoinPassportAPI {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigProofOfHumanity {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigBrightID {
+    pub url: String,
+    pub context: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigTwitterAPI {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigGithubAPI {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigDNS {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigPoapAPI {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigSnapshot {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigOpenSeaAPI {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigSNSAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigCrossbellAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigCyberConnect {
+    pub url: String,
+    pub social_url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigYatAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigTelegramBot {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigDiscordAttestation {
+    pub url: String,
+    pub token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigOpenPGPKeyserver {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigOrcidAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigMirrorAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigGalxeAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigLink3 {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigWorldID {
+    pub url: String,
+    pub app_id: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigCivicAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigPolygonID {
+    pub trusted_issuers: Vec<String>,
+    /// RPC of the chain where the iden3 State contract is, e.g. Polygon.
+    pub rpc_url: String,
+    /// Address of the iden3 State contract, where issuers publish their states.
+    pub state_contract: String,
+    /// Hosts of trusted issuers, where revocation statuses (`credentialStatus.id`) are fetched from.
+    pub status_hosts: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigVC {
+    pub trusted_issuers: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigSIWE {
+    pub domain: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigEthereumRPC {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigAptosNamesAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigAvvyAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigICNS {
+    pub lcd_url: String,
+    pub resolver: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigStargazeNames {
+    pub lcd_url: String,
+    pub contract: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigDeBankAPI {
+    pub url: String,
+    pub access_key: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigEtherscanAPI {
+    pub url: String,
+    pub api_key: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigRaribleAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigZoraAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigWebsite {
+    pub onion_gateway: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigMatrix {
+    pub homeserver: String,
+    pub identity_server: String,
+    pub access_token: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigHackerNewsAPI {
+    pub url: String,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigSBT {
+    pub contracts: Vec<ConfigSBTContract>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+pub struct ConfigSBTContract {
+    /// Human-readable name, e.g. `sismo`.
+    pub name: String,
+    /// JSON-RPC of the chain this contract is deployed on.
+    pub rpc_url: String,
+    pub address: String,
+    /// `erc721` or `erc1155`.
+    pub standard: String,
+    /// Token IDs (badges) to scan for an `erc1155` contract.
+    /// Each one is an attestation schema, ignored for `erc721`.
+    #[serde(default)]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct ConfigEAS {
+    /// GraphQL endpoint of an EAS indexer.
+    pub url: String,
+    pub schemas: Vec<ConfigEASSchema>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+pub struct ConfigEASSchema {
+    /// Schema UID.
+    pub uid: String,
+    /// `Platform` of the identity attested to the recipient.
+    pub platform: String,
+    /// Field of the attestation data holding the identity.
+    pub field: String,
+    /// Only attestations made by these attesters are accepted, saved as `PlatformVerified`.
+    /// Empty means anyone's, saved as `SelfClaimed`.
+    #[serde(default)]
+    pub attesters: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+pub struct ConfigGenericUpstream {
+    /// Name of this upstream, used as prefix of `record_id`.
+    pub name: String,
+    /// URL template. `{identity}` is replaced by the identity being fetched.
+    pub url: String,
+    /// Platforms of identities this upstream accepts.
+    pub platforms: Vec<String>,
+    /// Extra request headers, e.g. API key.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Whether extracted identities prove the fetched one as well.
+    #[serde(default)]
+    pub two_way: bool,
+    pub identities: Vec<ConfigGenericIdentity>,
+}
+
+#[derive(Clone, Deserialize, Default)]
+pub struct ConfigGenericIdentity {
+    /// `Platform` of extracted identities.
+    pub platform: String,
+    /// JSONPath of identities in response, e.g. `$.data.accounts[*].handle`.
+    pub path: String,
+    /// JSONPath of record ID, relative to the response root.
+    #[serde(default)]
+    pub record_id: Option<String>,
+}
+
 #[derive(Clone, Deserialize)]
 pub enum ConfigCategory {
     File,
@@ -150,6 +782,7 @@ pub fn app_env() -> ENV {
 }
 
 /// Parse config from local file or ENV.
+/// Sections missing in all layers fall back to their `Default`.
 pub fn parse() -> Result<KVConfig, Error> {
     let s = Config::builder()
         // Default
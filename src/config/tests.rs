@@ -0,0 +1,44 @@
+use crate::config::KVConfig;
+use config::{Config, File, FileFormat};
+
+fn from_toml(toml: &str) -> KVConfig {
+    Config::builder()
+        .add_source(File::from_str(toml, FileFormat::Toml))
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap()
+}
+
+#[test]
+fn test_parse_minimal() {
+    let config = from_toml(
+        r#"
+        [db]
+        host = "http://127.0.0.1:8529"
+
+        [upstream.keybase_service]
+        url = "https://keybase.io/_/api/1.0"
+        "#,
+    );
+    assert_eq!(config.db.host, "http://127.0.0.1:8529");
+    assert!(config.db.username.is_empty());
+    assert!(config.web.fetch_on_miss);
+    assert_eq!(config.web.max_query_depth, 10);
+    assert_eq!(
+        config.upstream.keybase_service.url,
+        "https://keybase.io/_/api/1.0"
+    );
+    assert!(config.upstream.keybase_service.sigchain_url.is_empty());
+    assert!(config.upstream.proof_service.url.is_empty());
+    assert!(config.upstream.eas.schemas.is_empty());
+    assert!(config.upstream.is_enabled("keybase"));
+}
+
+#[test]
+fn test_parse_empty() {
+    let config = from_toml("");
+    assert_eq!(config.web.port, 3722);
+    assert_eq!(config.crawler.max_depth, 5);
+    assert!(config.upstream.generic.is_empty());
+}
@@ -0,0 +1,51 @@
+use crate::{
+    graph::edge::AnnotationRecord,
+    upstream::{DataFetcher, DataSource},
+};
+use async_graphql::Object;
+use uuid::Uuid;
+
+#[Object]
+impl AnnotationRecord {
+    /// UUID of this record.
+    async fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Data source (upstream) which provides this label.
+    async fn source(&self) -> DataSource {
+        self.source
+    }
+
+    /// Label given by upstream (e.g. `Binance 14`).
+    async fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Categories given by upstream (e.g. `["Exchange", "Binance"]`).
+    async fn tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+
+    /// How much this label could be trusted, from `0.0` to `1.0`.
+    /// Annotations are never cryptographically proved, so this is always below `1.0`.
+    async fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// When this label is recorded in upstream platform (if platform gives such data).
+    async fn created_at(&self) -> Option<i64> {
+        self.created_at.map(|dt| dt.timestamp())
+    }
+
+    /// When this label is fetched by us RelationService.
+    async fn updated_at(&self) -> i64 {
+        self.updated_at.timestamp()
+    }
+
+    /// Who collects this data.
+    /// It works as a "data cleansing" or "proxy" between `source`s and us.
+    async fn fetcher(&self) -> DataFetcher {
+        self.fetcher
+    }
+}
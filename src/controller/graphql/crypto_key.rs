@@ -0,0 +1,79 @@
+use crate::{
+    error::{Error, Result},
+    graph::{
+        vertex::{contract::Chain, CryptoKey, CryptoKeyRecord, IdentityRecord, KeyAlgorithm},
+        ConnectionPool,
+    },
+};
+use async_graphql::{Context, Object};
+use deadpool::managed::Object;
+use tracing::debug;
+use uuid::Uuid;
+
+#[Object]
+impl CryptoKeyRecord {
+    /// UUID of this record.
+    async fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Algorithm family of this key.
+    async fn algorithm(&self) -> KeyAlgorithm {
+        self.algorithm
+    }
+
+    /// Fingerprint of this key, lowercase hex string without spaces.
+    async fn fingerprint(&self) -> String {
+        self.fingerprint.clone()
+    }
+
+    /// Public key itself (e.g. ASCII-armored PGP key) if provided.
+    async fn public_key(&self) -> Option<String> {
+        self.public_key.clone()
+    }
+
+    /// On which chain this key is used. `null` if it is not a chain key (e.g. PGP).
+    async fn chain(&self) -> Option<Chain> {
+        self.chain
+    }
+
+    /// When this key is created. `null` if upstream doesn't provide it.
+    async fn created_at(&self) -> Option<i64> {
+        self.created_at.map(|dt| dt.timestamp())
+    }
+
+    /// When this data is fetched by RelationService.
+    async fn updated_at(&self) -> i64 {
+        self.updated_at.timestamp()
+    }
+
+    /// Identities holding this key.
+    async fn holders(&self, ctx: &Context<'_>) -> Result<Vec<IdentityRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+        self.holders(pool).await
+    }
+}
+
+#[derive(Default)]
+pub struct CryptoKeyQuery;
+
+#[Object]
+impl CryptoKeyQuery {
+    /// Query a public key by its fingerprint.
+    async fn crypto_key(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Fingerprint of the key. Spaces and case are ignored.")]
+        fingerprint: String,
+    ) -> Result<Option<CryptoKeyRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = Object::take(conn);
+        CryptoKey::find_by_fingerprint(&db, &fingerprint).await
+    }
+}
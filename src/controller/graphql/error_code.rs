@@ -0,0 +1,43 @@
+use crate::error::Error;
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo},
+    ServerResult, Value,
+};
+use std::sync::Arc;
+
+/// Adds `extensions.code` (see `Error::code`) to GraphQL errors caused by `Error`,
+/// so clients can tell what's wrong without parsing messages.
+pub struct ErrorCode;
+
+impl ExtensionFactory for ErrorCode {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ErrorCodeExtension)
+    }
+}
+
+struct ErrorCodeExtension;
+
+#[async_trait::async_trait]
+impl Extension for ErrorCodeExtension {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        next.run(ctx, info).await.map_err(|mut err| {
+            let has_code = err
+                .extensions
+                .as_ref()
+                .map_or(false, |extensions| extensions.get("code").is_some());
+            if let Some(code) = err.source::<Error>().map(Error::code) {
+                if !has_code {
+                    err.extensions
+                        .get_or_insert_with(Default::default)
+                        .set("code", code);
+                }
+            }
+            err
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use super::check_identity;
+use crate::{
+    error::Result,
+    upstream::{fetch_all, FetchError, Platform, Target},
+};
+use async_graphql::{Object, SimpleObject};
+
+/// What a fetch brings into RelationService.
+#[derive(SimpleObject, Default)]
+pub struct FetchResult {
+    /// Amount of identities created.
+    identities: u64,
+    /// Amount of connections created.
+    edges: u64,
+    /// Upstreams failed in this fetch. What's found by others is saved anyway.
+    errors: Vec<FetchError>,
+}
+
+#[derive(Default)]
+pub struct FetchMutation;
+
+#[Object]
+impl FetchMutation {
+    /// Fetch an `identity` from all upstreams right now, even if it's cached,
+    /// and returns what's newly found.
+    /// What's found is queried by `identity` or `identityGraph` afterwards.
+    async fn fetch(
+        &self,
+        #[graphql(desc = "Platform to fetch")] platform: String,
+        #[graphql(desc = "Identity on target Platform")] identity: String,
+    ) -> Result<FetchResult> {
+        let platform: Platform = platform.parse()?;
+        let identity = check_identity(&platform, &identity)?;
+        let crawled = fetch_all(Target::Identity(platform, identity)).await?;
+        Ok(FetchResult {
+            identities: crawled.created.identities,
+            edges: crawled.created.edges,
+            errors: crawled.errors,
+        })
+    }
+}
@@ -9,10 +9,11 @@ use crate::{
         ConnectionPool,
     },
     upstream::{fetch_all, DataFetcher, DataSource, Target},
+    worker,
 };
 use async_graphql::{Context, Object};
 // use dataloader::cached::Loader;
-use dataloader::non_cached::Loader;
+use dataloader::cached::Loader;
 use strum::IntoEnumIterator;
 use tracing::debug;
 use uuid::Uuid;
@@ -160,8 +161,7 @@ impl HoldQuery {
         match Hold::find_by_id_chain_address_merge(pool, &id, &chain, &contract_address).await? {
             Some(hold) => {
                 if hold.is_outdated() {
-                    // Refetch in the background
-                    tokio::spawn(fetch_all(target));
+                    worker::enqueue(target);
                 }
                 Ok(Some(hold))
             }
@@ -1,14 +1,37 @@
-use crate::controller::vec_string_to_vec_platform;
+use super::pagination::{page_size, paginate, parse_cursor};
+use super::{check_identity, traversal_complexity};
+use crate::config::C;
+use crate::controller::{find_identity, vec_string_to_vec_platform};
 use crate::error::{Error, Result};
-use crate::graph::edge::{HoldRecord, IdentityFromToRecord};
+use crate::graph::edge::{AnnotationRecord, HoldRecord, IdentityFromToRecord, ProofLevel};
 use crate::graph::vertex::contract::ContractCategory;
-use crate::graph::vertex::{Identity, IdentityRecord, IdentityWithSource, Vertex};
+use crate::graph::vertex::{
+    CryptoKeyRecord, Identity, IdentityCandidate, IdentityGraph, IdentityRecord,
+    IdentityWithSource, Vertex,
+};
 use crate::graph::ConnectionPool;
-use crate::upstream::{fetch_all, DataSource, Platform, Target};
-use async_graphql::{Context, Object};
+use crate::upstream::{breaker::CircuitState, fetch_all, DataSource, Platform, Target, UPSTREAMS};
+use crate::worker;
+use async_graphql::connection::Connection;
+use async_graphql::{Context, Object, SimpleObject};
 use deadpool::managed::Object;
 use strum::IntoEnumIterator;
-use tracing::{debug, Level, event};
+use tracing::debug;
+
+/// A fetcher (upstream module) of RelationService.
+#[derive(SimpleObject)]
+struct FetcherStatus {
+    /// Name of this fetcher, same as its key in `[upstream.enabled]` of config.
+    name: String,
+    /// Whether this fetcher is enabled in config.
+    enabled: bool,
+    /// Platforms of identities this fetcher can fetch.
+    platforms: Vec<Platform>,
+    /// State of its circuit breaker: it's skipped while `Open`, after failing repeatedly.
+    circuit: CircuitState,
+    /// How many times its circuit breaker is opened since started.
+    trips: u64,
+}
 
 /// Status for a record in RelationService DB
 #[derive(Default, Copy, Clone, PartialEq, Eq, async_graphql::Enum)]
@@ -40,6 +63,19 @@ impl IdentityWithSource {
     }
 }
 
+#[Object]
+impl IdentityGraph {
+    /// All identities in this cluster, the queried one first.
+    async fn vertices(&self) -> Vec<IdentityWithSource> {
+        self.vertices.clone()
+    }
+
+    /// All connections between identities in this cluster.
+    async fn edges(&self) -> Vec<IdentityFromToRecord> {
+        self.edges.clone()
+    }
+}
+
 #[Object]
 impl IdentityRecord {
     /// Status for this record in RelationService.
@@ -119,14 +155,24 @@ impl IdentityRecord {
 
     /// Neighbor identity from current. Flattened.
     // FIXME: <2023-04-23 SUN> broken of high CPU / bandwidth consumption. Maybe something is wrong with SQL.
+    #[graphql(
+        deprecation = "Use `neighborConnection` for paginated results.",
+        complexity = "traversal_complexity(depth, child_complexity)"
+    )]
     async fn neighbor(
         &self,
         ctx: &Context<'_>,
-        // #[graphql(
-        //     desc = "Upstream source of this connection. Will search all upstreams if omitted."
-        // )]
-        // upstream: Option<String>,
         #[graphql(desc = "Depth of traversal. 1 if omitted")] depth: Option<u16>,
+        #[graphql(
+            desc = "Only traverse connections from these upstreams. All upstreams if omitted or empty"
+        )]
+        sources: Option<Vec<DataSource>>,
+        #[graphql(desc = "Also traverse invalidated connections. false if omitted")]
+        include_invalid: Option<bool>,
+        #[graphql(
+            desc = "Only traverse connections of this level or higher. All levels if omitted"
+        )]
+        min_level: Option<ProofLevel>,
     ) -> Result<Vec<IdentityWithSource>> {
         let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
         debug!("Connection pool status: {:?}", pool.status());
@@ -134,21 +180,69 @@ impl IdentityRecord {
         self.neighbors(
             pool,
             depth.unwrap_or(1),
-            // upstream.map(|u| DataSource::from_str(&u).unwrap_or(DataSource::Unknown))
-            None,
+            &sources.unwrap_or_default(),
+            include_invalid.unwrap_or(false),
+            min_level.unwrap_or_default(),
         )
         .await
     }
 
+    /// Neighbor identity from current, paginated by cursor.
+    #[graphql(complexity = "traversal_complexity(depth, child_complexity)")]
+    async fn neighbor_connection(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Depth of traversal. 1 if omitted")] depth: Option<u16>,
+        #[graphql(
+            desc = "Only traverse connections from these upstreams. All upstreams if omitted or empty"
+        )]
+        sources: Option<Vec<DataSource>>,
+        #[graphql(desc = "Also traverse invalidated connections. false if omitted")]
+        include_invalid: Option<bool>,
+        #[graphql(
+            desc = "Only traverse connections of this level or higher. All levels if omitted"
+        )]
+        min_level: Option<ProofLevel>,
+        #[graphql(desc = "Page size, 20 if omitted, 100 at most")] first: Option<usize>,
+        #[graphql(desc = "Cursor to start after, `endCursor` of last page")] after: Option<String>,
+    ) -> Result<Connection<usize, IdentityWithSource>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+
+        let mut neighbors = self
+            .neighbors(
+                pool,
+                depth.unwrap_or(1),
+                &sources.unwrap_or_default(),
+                include_invalid.unwrap_or(false),
+                min_level.unwrap_or_default(),
+            )
+            .await?;
+        neighbors.sort_by(|a, b| a.identity.id().cmp(b.identity.id()));
+        Ok(paginate(neighbors, first, parse_cursor(after)?))
+    }
+
+    #[graphql(complexity = "traversal_complexity(depth, child_complexity)")]
     async fn neighbor_with_traversal(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "Depth of traversal. 1 if omitted")] depth: Option<u16>,
+        #[graphql(desc = "Also traverse invalidated connections. false if omitted")]
+        include_invalid: Option<bool>,
+        #[graphql(
+            desc = "Only traverse connections of this level or higher. All levels if omitted"
+        )]
+        min_level: Option<ProofLevel>,
     ) -> Result<Vec<IdentityFromToRecord>> {
         let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
         debug!("Connection pool status: {:?}", pool.status());
-        self.neighbors_with_traversal(pool, depth.unwrap_or(1))
-            .await
+        self.neighbors_with_traversal(
+            pool,
+            depth.unwrap_or(1),
+            include_invalid.unwrap_or(false),
+            min_level.unwrap_or_default(),
+        )
+        .await
     }
 
     /// there's only `platform: lens` identity `ownedBy` is not null
@@ -182,6 +276,21 @@ impl IdentityRecord {
         debug!("Connection pool status: {:?}", pool.status());
         self.nfts(pool, category).await
     }
+
+    /// Third-party labels of this identity (e.g. Etherscan name tags).
+    /// These are NOT proved by the owner of this identity, check `confidence` before trusting them.
+    async fn annotations(&self, ctx: &Context<'_>) -> Result<Vec<AnnotationRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+        self.find_annotations(pool).await
+    }
+
+    /// Public keys held by this identity (e.g. PGP keys of a Keybase user).
+    async fn crypto_keys(&self, ctx: &Context<'_>) -> Result<Vec<CryptoKeyRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+        self.crypto_keys(pool).await
+    }
 }
 
 #[derive(Default)]
@@ -195,11 +304,37 @@ impl IdentityQuery {
     }
 
     /// Returns a list of all upstreams (data sources) supported by RelationService.
+    #[graphql(deprecation = "Use `availableDataSources` instead.")]
     async fn available_upstreams(&self) -> Result<Vec<DataSource>> {
         Ok(DataSource::iter().collect())
     }
 
+    /// Returns a list of all data sources a connection can come from.
+    async fn available_data_sources(&self) -> Result<Vec<DataSource>> {
+        Ok(DataSource::iter().collect())
+    }
+
+    /// Returns all fetchers registered in RelationService, and whether they are enabled now.
+    async fn available_fetchers(&self) -> Result<Vec<FetcherStatus>> {
+        Ok(UPSTREAMS
+            .upstreams()
+            .iter()
+            .map(|upstream| FetcherStatus {
+                name: upstream.name.to_string(),
+                enabled: upstream.is_enabled(),
+                platforms: Platform::iter()
+                    .filter(|platform| {
+                        upstream.can_fetch(&Target::Identity(*platform, String::new()))
+                    })
+                    .collect(),
+                circuit: upstream.breaker.state(),
+                trips: upstream.breaker.trips(),
+            })
+            .collect())
+    }
+
     /// Query an `identity` by given `platform` and `identity`.
+    /// Upstreams are fetched if it is not found, unless `web.fetch_on_miss` is disabled.
     #[tracing::instrument(level = "trace", skip(self, ctx))]
     async fn identity(
         &self,
@@ -218,30 +353,53 @@ impl IdentityQuery {
         let db = Object::take(conn);
 
         let platform: Platform = platform.parse()?;
-        let target = Target::Identity(platform, identity.clone());
-        // FIXME: Still kinda dirty. Should be in an background queue/worker-like shape.
-        match Identity::find_by_platform_identity(&db, &platform, &identity).await? {
-            None => {
-                let fetch_result = fetch_all(target).await;
-                if fetch_result.is_err() {
-                    event!(Level::WARN, ?platform, identity, err = fetch_result.unwrap_err().to_string(),  "Failed to fetch");
-                }
-                Ok(Identity::find_by_platform_identity(&db, &platform, &identity).await?)
-            }
-            Some(found) => {
-                if found.is_outdated() {
-                    event!(
-                        Level::DEBUG,
-                        ?platform, identity,
-                        "Outdated. Refetching."
-                    );
-                    tokio::spawn(fetch_all(target)); // Fetch in the background
-                }
-                Ok(Some(found))
-            }
+        let identity = check_identity(&platform, &identity)?;
+        Ok(find_identity(&db, platform, &identity).await?)
+    }
+
+    /// Query the whole connected component ("identity cluster") of an `identity`.
+    #[graphql(complexity = "traversal_complexity(depth, child_complexity)")]
+    async fn identity_graph(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Platform to query")] platform: String,
+        #[graphql(desc = "Identity on target Platform")] identity: String,
+        #[graphql(desc = "Depth of traversal, capped by server config. 1 if omitted")]
+        depth: Option<u16>,
+        #[graphql(desc = "Also traverse invalidated connections. false if omitted")]
+        include_invalid: Option<bool>,
+        #[graphql(
+            desc = "Only traverse connections of this level or higher. All levels if omitted"
+        )]
+        min_level: Option<ProofLevel>,
+    ) -> Result<Option<IdentityGraph>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = Object::take(conn);
+
+        let platform: Platform = platform.parse()?;
+        let identity = check_identity(&platform, &identity)?;
+        match find_identity(&db, platform, &identity).await? {
+            None => Ok(None),
+            Some(found) => Ok(Some(
+                found
+                    .identity_graph(
+                        pool,
+                        depth.unwrap_or(1),
+                        include_invalid.unwrap_or(false),
+                        min_level.unwrap_or_default(),
+                    )
+                    .await?,
+            )),
         }
     }
 
+    #[graphql(deprecation = "Use `identitiesConnection` for paginated results.")]
     async fn identities(
         &self,
         ctx: &Context<'_>,
@@ -250,25 +408,128 @@ impl IdentityQuery {
     ) -> Result<Vec<IdentityRecord>> {
         let pool: &ConnectionPool = ctx.data().map_err(|err| Error::GraphQLError(err.message))?;
         debug!("Connection pool status: {:?}", pool.status());
+        find_identities(pool, platforms, identity).await
+    }
 
-        let platform_list = vec_string_to_vec_platform(platforms)?;
-        let record: Vec<IdentityRecord> =
-            Identity::find_by_platforms_identity(&pool, &platform_list, identity.as_str()).await?;
-        if record.len() == 0 {
-            for platform in &platform_list {
-                let target = Target::Identity(platform.clone(), identity.clone());
+    /// Query identities by given `platforms` and `identity`, paginated by cursor.
+    async fn identities_connection(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Platform array to query")] platforms: Vec<String>,
+        #[graphql(desc = "Identity on target Platform")] identity: String,
+        #[graphql(desc = "Page size, 20 if omitted, 100 at most")] first: Option<usize>,
+        #[graphql(desc = "Cursor to start after, `endCursor` of last page")] after: Option<String>,
+    ) -> Result<Connection<usize, IdentityRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::GraphQLError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+        let mut found = find_identities(pool, platforms, identity).await?;
+        found.sort_by(|a, b| a.id().cmp(b.id()));
+        Ok(paginate(found, first, parse_cursor(after)?))
+    }
+
+    /// Search identities in DB by `identity` or `displayName` containing `keyword` (case-insensitive).
+    /// Sorted by relevance: exact matches first, then prefix matches.
+    async fn search_identities(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Keyword to search")] keyword: String,
+        #[graphql(desc = "Only search on this Platform. All platforms if omitted")]
+        platform: Option<String>,
+        #[graphql(desc = "Amount of results, 20 if omitted, 100 at most")] first: Option<usize>,
+    ) -> Result<Vec<IdentityRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+
+        let keyword = keyword.trim();
+        if keyword.is_empty() {
+            return Err(Error::ParamError("keyword should not be empty".into()));
+        }
+        let platform = platform.map(|p| p.parse::<Platform>()).transpose()?;
+        Identity::search(pool, keyword, platform.as_ref(), page_size(first)).await
+    }
+
+    /// Typeahead candidates whose `identity` or `displayName` has a word starting with `prefix`.
+    /// Served by a prefix-indexed search view, so it is much faster than `searchIdentities`.
+    async fn autocomplete(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Prefix typed by user")] prefix: String,
+        #[graphql(desc = "Only search on this Platform. All platforms if omitted")]
+        platform: Option<String>,
+        #[graphql(desc = "Amount of candidates, 20 if omitted, 100 at most")] first: Option<usize>,
+    ) -> Result<Vec<IdentityCandidate>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        let platform = platform.map(|p| p.parse::<Platform>()).transpose()?;
+        Identity::autocomplete(pool, &prefix, platform.as_ref(), page_size(first)).await
+    }
+}
+
+#[Object]
+impl IdentityCandidate {
+    /// Platform of this identity, shown as a badge.
+    async fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    async fn identity(&self) -> String {
+        self.identity.clone()
+    }
+
+    async fn display_name(&self) -> Option<String> {
+        self.display_name.clone()
+    }
+
+    async fn avatar_url(&self) -> Option<String> {
+        self.avatar_url.clone()
+    }
+}
+
+/// Find identities in DB, fetch them from upstreams if none found.
+/// `identity` is canonicalized for each platform (see `check_identity`),
+/// platforms it's invalid on are skipped.
+async fn find_identities(
+    pool: &ConnectionPool,
+    platforms: Vec<String>,
+    identity: String,
+) -> Result<Vec<IdentityRecord>> {
+    // Platforms sharing the same canonical form are queried together.
+    let mut groups: Vec<(String, Vec<Platform>)> = vec![];
+    let mut last_err = None;
+    for platform in vec_string_to_vec_platform(platforms)? {
+        let canonical = match check_identity(&platform, &identity) {
+            Ok(canonical) => canonical,
+            Err(err) => {
+                last_err = Some(err);
+                continue;
+            }
+        };
+        match groups.iter_mut().find(|(found, _)| *found == canonical) {
+            Some((_, platforms)) => platforms.push(platform),
+            None => groups.push((canonical, vec![platform])),
+        }
+    }
+    if groups.is_empty() {
+        if let Some(err) = last_err {
+            return Err(err);
+        }
+    }
+
+    let mut record: Vec<IdentityRecord> = vec![];
+    for (canonical, platforms) in groups.iter() {
+        record.extend(Identity::find_by_platforms_identity(pool, platforms, canonical).await?);
+    }
+    if record.is_empty() && C.web.fetch_on_miss {
+        for (canonical, platforms) in groups.iter() {
+            for platform in platforms {
+                let target = Target::Identity(*platform, canonical.clone());
                 let _ = fetch_all(target).await;
             }
-            Identity::find_by_platforms_identity(&pool, &platform_list, identity.as_str()).await
-        } else {
-            record.iter().filter(|r| r.is_outdated()).for_each(|r| {
-                // Refetch in the background
-                tokio::spawn(fetch_all(Target::Identity(
-                    r.platform.clone(),
-                    r.identity.clone(),
-                )));
-            });
-            Ok(record)
+            record.extend(Identity::find_by_platforms_identity(pool, platforms, canonical).await?);
         }
+    } else {
+        record.iter().filter(|r| r.is_outdated()).for_each(|r| {
+            worker::enqueue(Target::Identity(r.platform, r.identity.clone()));
+        });
     }
+    Ok(record)
 }
@@ -0,0 +1,39 @@
+use crate::metrics::{status, GRAPHQL_FIELD_SECONDS};
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo},
+    ServerResult, Value,
+};
+use std::{sync::Arc, time::Instant};
+
+/// Records count and latency of root fields in `GRAPHQL_FIELD_SECONDS`.
+/// Nested fields are left out, which are resolved too many times to be timed one by one.
+pub struct FieldMetrics;
+
+impl ExtensionFactory for FieldMetrics {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(FieldMetricsExtension)
+    }
+}
+
+struct FieldMetricsExtension;
+
+#[async_trait::async_trait]
+impl Extension for FieldMetricsExtension {
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if info.path_node.parent.is_some() {
+            return next.run(ctx, info).await;
+        }
+        let field = format!("{}.{}", info.parent_type, info.name);
+        let started = Instant::now();
+        let result = next.run(ctx, info).await;
+        GRAPHQL_FIELD_SECONDS
+            .with_label_values(&[&field, status(&result)])
+            .observe(started.elapsed().as_secs_f64());
+        result
+    }
+}
@@ -1,11 +1,47 @@
+mod annotation;
 mod contract;
+mod crypto_key;
+mod error_code;
+mod fetch;
 mod hold;
 mod identity;
+mod metrics;
+mod pagination;
 mod proof;
+mod provenance;
 mod resolve;
-use self::{hold::HoldQuery, identity::IdentityQuery, proof::ProofQuery, resolve::ResolveQuery};
-use async_graphql::{MergedObject, Object};
+use self::{
+    crypto_key::CryptoKeyQuery,
+    fetch::FetchMutation,
+    hold::HoldQuery,
+    identity::IdentityQuery,
+    proof::{ProofMutation, ProofQuery, ProofSubscription},
+    resolve::ResolveQuery,
+};
+pub use self::{error_code::ErrorCode, metrics::FieldMetrics, provenance::Provenance};
+use crate::{
+    error::{Error, Result},
+    graph::{
+        vertex::{contract::ContractLoadFn, FromToLoadFn, IdentityLoadFn},
+        ConnectionPool,
+    },
+    upstream::Platform,
+    util::canonical::canonicalize,
+};
+use async_graphql::{Data, MergedObject, MergedSubscription, Object};
+use dataloader::cached::Loader;
 const API_VERSION: &str = "0.1";
+/// Complexity of one level of graph traversal.
+const TRAVERSAL_COMPLEXITY: usize = 50;
+
+/// Complexity of a traversal field with `depth`. Sub-fields are resolved
+/// for every found vertex, so they are also weighted by `depth`.
+pub(crate) fn traversal_complexity(depth: Option<u16>, child_complexity: usize) -> usize {
+    let depth = depth.unwrap_or(1).max(1) as usize;
+    depth
+        .saturating_mul(TRAVERSAL_COMPLEXITY)
+        .saturating_add(depth.saturating_mul(child_complexity))
+}
 
 /// Base struct of GraphQL query request.
 #[derive(MergedObject, Default)]
@@ -15,8 +51,50 @@ pub struct Query(
     ResolveQuery,
     ProofQuery,
     HoldQuery,
+    CryptoKeyQuery,
 );
 
+/// Base struct of GraphQL mutation request.
+#[derive(MergedObject, Default)]
+pub struct Mutation(FetchMutation, ProofMutation);
+
+/// Base struct of GraphQL subscription request.
+#[derive(MergedSubscription, Default)]
+pub struct Subscription(ProofSubscription);
+
+/// Reject malformed `identity` given by client before querying or fetching it.
+/// Returns it normalized as how it is saved.
+pub(crate) fn check_identity(platform: &Platform, identity: &str) -> Result<String> {
+    if identity.is_empty() || identity.trim() != identity {
+        return Err(Error::InvalidIdentityFormat(format!(
+            "{:?} should be non-empty without surrounding whitespace",
+            identity
+        )));
+    }
+    canonicalize(platform, identity)
+}
+
+/// Add DataLoaders into data of a request (or a WebSocket connection),
+/// so vertices are batched and cached within it.
+pub fn insert_loaders(data: &mut Data, pool: &ConnectionPool) {
+    // HOLD ON: Specify the batch size number
+    data.insert(
+        Loader::new(ContractLoadFn { pool: pool.clone() })
+            .with_max_batch_size(100)
+            .with_yield_count(10),
+    );
+    data.insert(
+        Loader::new(IdentityLoadFn { pool: pool.clone() })
+            .with_max_batch_size(100)
+            .with_yield_count(10),
+    );
+    data.insert(
+        Loader::new(FromToLoadFn { pool: pool.clone() })
+            .with_max_batch_size(100)
+            .with_yield_count(10),
+    );
+}
+
 #[derive(Default)]
 pub struct GeneralQuery;
 
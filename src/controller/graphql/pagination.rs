@@ -0,0 +1,46 @@
+use crate::error::{Error, Result};
+use async_graphql::{
+    connection::{Connection, Edge},
+    OutputType,
+};
+
+/// Page size if `first` is omitted.
+const DEFAULT_PAGE_SIZE: usize = 20;
+/// Max page size a client can ask for.
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Page size asked by `first`, within `1..=MAX_PAGE_SIZE`.
+pub fn page_size(first: Option<usize>) -> usize {
+    first.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+/// Parse an `after` cursor given by `paginate`.
+pub fn parse_cursor(after: Option<String>) -> Result<Option<usize>> {
+    after
+        .map(|after| {
+            after
+                .parse()
+                .map_err(|_| Error::ParamError(format!("Invalid cursor: {}", after)))
+        })
+        .transpose()
+}
+
+/// Cut a page out of a sorted list. Cursors are positions in the list.
+pub fn paginate<T: OutputType>(
+    items: Vec<T>,
+    first: Option<usize>,
+    after: Option<usize>,
+) -> Connection<usize, T> {
+    let start = after.map_or(0, |after| after + 1);
+    let size = page_size(first);
+    let mut connection = Connection::new(start > 0, items.len() > start + size);
+    connection.edges.extend(
+        items
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(size)
+            .map(|(cursor, item)| Edge::new(cursor, item)),
+    );
+    connection
+}
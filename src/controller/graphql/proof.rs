@@ -1,13 +1,24 @@
+use super::pagination::page_size;
 use crate::error::{Error, Result};
-use crate::graph::edge::{IdentityFromToRecord, Proof, ProofRecord};
-use crate::graph::vertex::{FromToLoadFn, IdentityRecord};
+use crate::graph::edge::{
+    IdentityFromToRecord, InvalidationReason, Proof, ProofFilter, ProofLevel, ProofRecord,
+};
+use crate::graph::events;
+use crate::graph::proof_history::{ProofChange, ProofHistory};
+use crate::graph::vertex::{FromToLoadFn, Identity, IdentityRecord, Vertex};
 use crate::graph::ConnectionPool;
 use crate::graph::Edge;
-use crate::upstream::DataFetcher;
-use async_graphql::{Context, Object};
-use dataloader::non_cached::Loader;
+use crate::upstream::{DataFetcher, DataSource, Platform, SelfAttestation, SelfAttestationRequest};
+use crate::util::timestamp_to_naive;
+use crate::worker;
+use async_graphql::connection::{Connection, Edge as ConnectionEdge};
+use async_graphql::{Context, Object, Subscription};
+use dataloader::cached::Loader;
 use deadpool::managed::Object;
-use tracing::debug;
+use futures::future::ready;
+use futures::stream::{self, Stream, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, Instrument, Span};
 use uuid::Uuid;
 
 #[Object]
@@ -40,6 +51,38 @@ impl IdentityFromToRecord {
         self.fetcher
     }
 
+    /// Whether this connection is still valid, i.e. not invalidated.
+    async fn valid(&self) -> bool {
+        self.invalid_since.is_none()
+    }
+
+    /// Since when this connection is found invalid (e.g. revoked in upstream).
+    async fn invalid_since(&self) -> Option<i64> {
+        self.invalid_since.map(|is| is.timestamp())
+    }
+
+    /// Why this connection is invalid.
+    async fn invalidation_reason(&self) -> Option<InvalidationReason> {
+        self.invalidation_reason
+    }
+
+    /// How much this connection can be trusted.
+    async fn level(&self) -> ProofLevel {
+        self.level
+    }
+
+    /// Every change of this connection, oldest first.
+    async fn history(&self, ctx: &Context<'_>) -> Result<Vec<ProofHistory>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = Object::take(conn);
+
+        ProofHistory::find_by_proof(&db, &self.uuid).await
+    }
+
     /// Which `IdentityRecord` does this connection starts at.
     async fn from(&self, ctx: &Context<'_>) -> Result<IdentityRecord> {
         let loader: &Loader<String, Option<(IdentityRecord, IdentityRecord)>, FromToLoadFn> =
@@ -96,6 +139,36 @@ impl ProofRecord {
         self.fetcher
     }
 
+    /// Whether the signature of this proof is cryptographically verified by us.
+    async fn verified(&self) -> bool {
+        self.verified
+    }
+
+    /// When this connection expires in upstream platform (if platform gives such data).
+    async fn expired_at(&self) -> Option<i64> {
+        self.expired_at.map(|ea| ea.timestamp())
+    }
+
+    /// Whether this connection is still valid, i.e. neither expired nor invalidated.
+    async fn valid(&self) -> bool {
+        self.is_valid()
+    }
+
+    /// Since when this connection is found invalid (e.g. revoked in upstream).
+    async fn invalid_since(&self) -> Option<i64> {
+        self.invalid_since.map(|is| is.timestamp())
+    }
+
+    /// Why this connection is invalid.
+    async fn invalidation_reason(&self) -> Option<InvalidationReason> {
+        self.invalidation_reason
+    }
+
+    /// How much this connection can be trusted.
+    async fn level(&self) -> ProofLevel {
+        self.level
+    }
+
     /// Which `IdentityRecord` does this connection starts at.
     async fn from(&self, ctx: &Context<'_>) -> Result<IdentityRecord> {
         let loader: &Loader<String, Option<(IdentityRecord, IdentityRecord)>, FromToLoadFn> =
@@ -117,6 +190,34 @@ impl ProofRecord {
     }
 }
 
+#[Object]
+impl ProofHistory {
+    /// What happened to the connection.
+    async fn change(&self) -> ProofChange {
+        self.change
+    }
+
+    /// Data source (upstream) of the connection.
+    async fn source(&self) -> String {
+        self.source.to_string()
+    }
+
+    /// ID of the connection in upstream platform after this change (if any).
+    async fn record_id(&self) -> Option<String> {
+        self.record_id.clone()
+    }
+
+    /// Why the connection is invalid, if it is after this change.
+    async fn invalidation_reason(&self) -> Option<InvalidationReason> {
+        self.invalidation_reason
+    }
+
+    /// When this change is found by us RelationService.
+    async fn happened_at(&self) -> i64 {
+        self.happened_at.timestamp()
+    }
+}
+
 /// Query entrypoint for `Proof{,Record}`
 #[derive(Default)]
 pub struct ProofQuery;
@@ -147,11 +248,117 @@ impl ProofQuery {
         Ok(found)
     }
 
+    /// List proofs matching given conditions, paginated by cursor.
+    async fn proofs(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Data source (upstream) of proofs")] source: Option<DataSource>,
+        #[graphql(desc = "UUID of the identity proofs are from")] from: Option<String>,
+        #[graphql(desc = "UUID of the identity proofs are to")] to: Option<String>,
+        #[graphql(desc = "Only proofs created in upstream after this timestamp")]
+        created_after: Option<i64>,
+        #[graphql(desc = "Page size, 20 if omitted, 100 at most")] first: Option<usize>,
+        #[graphql(desc = "Cursor to start after, `endCursor` of last page")] after: Option<String>,
+    ) -> Result<Connection<String, ProofRecord>> {
+        let pool: &ConnectionPool = ctx.data().map_err(|err| Error::PoolError(err.message))?;
+        debug!("Connection pool status: {:?}", pool.status());
+
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = Object::take(conn);
+
+        let mut filter = ProofFilter {
+            source,
+            created_after: created_after.map(|ts| timestamp_to_naive(ts, 0)),
+            ..Default::default()
+        };
+        for (uuid, id) in [(from, &mut filter.from), (to, &mut filter.to)] {
+            if let Some(uuid) = uuid {
+                match Identity::find_by_uuid(&db, Uuid::parse_str(&uuid)?).await? {
+                    Some(identity) => *id = Some(identity.id().clone()),
+                    None => return Ok(Connection::new(false, false)),
+                }
+            }
+        }
+
+        let first = page_size(first);
+        let mut found = Proof::find_page(&db, &filter, after.as_deref(), first + 1).await?;
+        let has_next_page = found.len() > first;
+        found.truncate(first);
+
+        let mut connection = Connection::new(after.is_some(), has_next_page);
+        connection.edges.extend(
+            found
+                .into_iter()
+                .map(|proof| ConnectionEdge::new(proof.key().clone(), proof)),
+        );
+        Ok(connection)
+    }
+
     /// Prefetch proofs which are prefetchable, e.g. SybilList.
     async fn prefetch_proof(&self) -> Result<String> {
-        tokio::spawn(async move {
-            let _ = crate::upstream::prefetch().await;
-        });
+        tokio::spawn(
+            async move {
+                let _ = crate::upstream::prefetch().await;
+            }
+            .instrument(Span::current()),
+        );
         Ok("Fetching".into())
     }
 }
+
+#[derive(Default)]
+pub struct ProofMutation;
+
+#[Object]
+impl ProofMutation {
+    /// Submit a signed Sign-In with Ethereum (EIP-4361) message claiming other identities,
+    /// listed in `resources` as `urn:relation:<platform>:<identity>`.
+    /// Returns proofs saved for them (as `self_claimed`) once the signature is verified.
+    /// Each message is accepted once, and only before it expires.
+    async fn submit_proof(
+        &self,
+        #[graphql(desc = "Full SIWE message")] message: String,
+        #[graphql(desc = "`0x`-prefixed hex of signature")] signature: String,
+    ) -> Result<Vec<ProofRecord>> {
+        let request = SelfAttestationRequest { message, signature };
+        let submitted = SelfAttestation::submit(&request).await?;
+        let mut proofs = vec![];
+        for (target, proof) in submitted {
+            // Continue discovering from the newly bound identities.
+            worker::enqueue(target);
+            proofs.push(proof);
+        }
+        Ok(proofs)
+    }
+}
+
+#[derive(Default)]
+pub struct ProofSubscription;
+
+#[Subscription]
+impl ProofSubscription {
+    /// Proofs newly saved with given identity on either end.
+    async fn connection_added(
+        &self,
+        #[graphql(desc = "Platform to watch")] platform: String,
+        #[graphql(desc = "Identity on target Platform")] identity: String,
+    ) -> Result<impl Stream<Item = ProofRecord>> {
+        let platform: Platform = platform.parse()?;
+        let events = stream::unfold(events::subscribe(), |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    // Missed some events, go on with the rest.
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(events
+            .filter(move |event| ready(event.touches(&platform, &identity)))
+            .map(|event| event.proof))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::upstream::provenance;
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+    Response,
+};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Adds upstream calls made while executing a request into `extensions.fetches` of response,
+/// with their latency and error. No `fetches` means everything comes from DB.
+pub struct Provenance;
+
+impl ExtensionFactory for Provenance {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ProvenanceExtension)
+    }
+}
+
+struct ProvenanceExtension;
+
+#[async_trait::async_trait]
+impl Extension for ProvenanceExtension {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let (mut response, records) = provenance::collect(next.run(ctx, operation_name)).await;
+        if records.is_empty() {
+            return response;
+        }
+        match async_graphql::to_value(&records) {
+            Ok(fetches) => {
+                response.extensions.insert("fetches".to_string(), fetches);
+            }
+            Err(err) => warn!("Failed to serialize fetch provenance: {}", err),
+        }
+        response
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
         ConnectionPool,
     },
     upstream::{fetch_all, DataFetcher, DataSource, Target},
+    worker,
 };
 use async_graphql::{Context, Object};
 use strum::IntoEnumIterator;
@@ -41,6 +42,21 @@ impl ResolveEdge {
         self.name.clone()
     }
 
+    /// Address this name is resolving to, as reported by the upstream.
+    async fn resolved_address(&self) -> Option<String> {
+        self.resolved_address.clone()
+    }
+
+    /// When this name registration expires. `null` if unknown or never.
+    async fn expire_at(&self) -> Option<i64> {
+        self.expire_at.map(|expire_at| expire_at.timestamp())
+    }
+
+    /// Whether this name registration is expired.
+    async fn expired(&self) -> bool {
+        self.is_expired()
+    }
+
     /// Who collects this data.
     /// It works as a "data cleansing" or "proxy" between `source`s and us.
     async fn fetcher(&self) -> DataFetcher {
@@ -85,7 +101,7 @@ impl ResolveQuery {
         )]
         domain_system: DomainNameSystem,
         #[graphql(
-            desc = "Name of domain. For example the name is (name: \"abc.eth\") or (name: \"abc.bit\") or (name: \"abc.bnb\")"
+            desc = "Name of domain. For example the name is (name: \"abc.eth\") or (name: \"abc.bit\") or (name: \"abc.bnb\") or (name: \"abc.sol\") or (name: \"abc.apt\") or (name: \"abc.avax\") or (name: \"abc.osmo\") or (name: \"abc.stars\")"
         )]
         name: String,
     ) -> Result<Option<ResolveEdge>> {
@@ -107,7 +123,7 @@ impl ResolveQuery {
                     }
                     Some(resolve) => {
                         if resolve.is_outdated() {
-                            tokio::spawn(fetch_all(target));
+                            worker::enqueue(target);
                         }
                         Ok(Some(resolve))
                     }
@@ -116,7 +132,12 @@ impl ResolveQuery {
             DomainNameSystem::DotBit
             | DomainNameSystem::Lens
             | DomainNameSystem::UnstoppableDomains
-            | DomainNameSystem::SpaceId => {
+            | DomainNameSystem::SpaceId
+            | DomainNameSystem::SNS
+            | DomainNameSystem::AptosNames
+            | DomainNameSystem::Avvy
+            | DomainNameSystem::ICNS
+            | DomainNameSystem::StargazeNames => {
                 let platform = domain_system.into();
                 let target = Target::Identity(platform, name.clone());
                 match Resolve::find_by_domain_platform_name(&pool, &name, &domain_system, &platform)
@@ -134,7 +155,7 @@ impl ResolveQuery {
                     }
                     Some(resolve) => {
                         if resolve.is_outdated() {
-                            tokio::spawn(fetch_all(target));
+                            worker::enqueue(target);
                         }
                         Ok(Some(resolve))
                     }
@@ -1,8 +1,8 @@
 use crate::controller::{
-    error_response, healthz, Body as OurBody, Request as OurRequest, Response as OurResponse,
+    error_response, route, Body as OurBody, Request as OurRequest, Response as OurResponse,
 };
 use crate::error::Error;
-use http::{Method, StatusCode};
+use http::StatusCode;
 use lambda_http::{
     Body as LambdaBody, Error as LambdaError, IntoResponse, Request as LambdaRequest,
     Response as LambdaResponse,
@@ -41,11 +41,13 @@ pub async fn entrypoint(req: LambdaRequest) -> Result<impl IntoResponse, LambdaE
         req.uri().path().to_string()
     );
 
-    Ok(match (req.method(), req.uri().path()) {
-        (&Method::GET, "/api/healthz") => parse(req, healthz::controller).await,
-        _ => LambdaResponse::builder()
+    let path = req.uri().path();
+    Ok(if path.starts_with("/api/") || path.starts_with("/v1/") {
+        parse(req, route).await
+    } else {
+        LambdaResponse::builder()
             .status(StatusCode::NOT_FOUND)
             .body("Not Found".into())
-            .expect("Failed to render response"),
+            .expect("Failed to render response")
     })
 }
@@ -1,11 +1,26 @@
 pub mod graphql;
 pub mod healthz;
-
+pub mod polygon_id;
+pub mod request_id;
+pub mod rest;
+pub mod self_attestation;
+#[cfg(test)]
+mod tests;
+pub mod vc;
+
+use crate::config::C;
 use crate::graph::vertex::contract::ContractCategory;
-use crate::upstream::Platform;
-use http::StatusCode;
+use crate::graph::vertex::{Identity, IdentityRecord};
+use crate::graph::Vertex;
+use crate::metrics::IDENTITY_CACHE;
+use crate::upstream::{fetch_all, Platform, Target};
+use crate::worker;
+use aragog::DatabaseConnection;
+use http::{Method, StatusCode};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, ops::Deref};
+use std::{collections::HashMap, convert::Infallible, ops::Deref};
+use tracing::{event, Instrument, Level};
+use warp::{Filter, Rejection, Reply};
 
 use crate::error::Error;
 
@@ -40,6 +55,67 @@ impl From<Body> for LambdaBody {
     }
 }
 
+// MARK: Routes
+
+/// `/api/*` and `/v1/*`, for both the Lambda entrypoint and the standalone server.
+pub async fn route(req: Request) -> Result<Response, Error> {
+    let path = req.uri().path().trim_end_matches('/').to_string();
+    match (req.method(), path.as_str()) {
+        (&Method::GET, "/api/healthz") => healthz::controller(req).await,
+        (&Method::POST, "/api/polygon_id/claims") => polygon_id::controller(req).await,
+        (&Method::POST, "/api/vc") => vc::controller(req).await,
+        (&Method::POST, "/api/self_attestation") => self_attestation::controller(req).await,
+        (_, path) if path.starts_with("/v1/") => rest::route(req).await,
+        _ => Err(Error::General(
+            format!("Not found: {} {}", req.method(), path),
+            StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+/// `route` served by warp: requests are translated into `Request`, as the Lambda entrypoint does.
+pub fn filter() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    warp::path("api")
+        .or(warp::path("v1"))
+        .unify()
+        .and(warp::method())
+        .and(warp::path::full())
+        .and(warp::query::raw().or(warp::any().map(String::new)).unify())
+        .and(warp::header::optional::<String>(request_id::HEADER))
+        .and(warp::body::content_length_limit(64 * 1024))
+        .and(warp::body::bytes())
+        .and_then(
+            |method,
+             path: warp::path::FullPath,
+             query: String,
+             given_id,
+             body: warp::hyper::body::Bytes| {
+                let uri = if query.is_empty() {
+                    path.as_str().to_string()
+                } else {
+                    format!("{}?{}", path.as_str(), query)
+                };
+                let id = request_id::from_header(given_id);
+                let span = request_id::span(&id);
+                async move {
+                    let response = match http::Request::builder()
+                        .method(method)
+                        .uri(uri)
+                        .body(String::from_utf8_lossy(&body).into_owned())
+                    {
+                        Ok(req) => route(req).instrument(span).await,
+                        Err(err) => Err(err.into()),
+                    };
+                    Ok::<_, Infallible>(warp::reply::with_header(
+                        response.unwrap_or_else(error_response),
+                        request_id::HEADER,
+                        id,
+                    ))
+                }
+            },
+        )
+}
+
 // MARK: Helper fn
 
 pub fn json_parse_body<T>(req: &Request) -> Result<T, Error>
@@ -96,6 +172,40 @@ pub fn vec_string_to_vec_category(vec_string: Vec<String>) -> Result<Vec<Contrac
     Ok(platforms_result?)
 }
 
+/// Find a (checked) identity in DB, for GraphQL `identity` query and REST `GET /v1/identity`.
+/// Upstreams are fetched if it is not found, unless `web.fetch_on_miss` is disabled.
+/// If it's outdated, it's returned as is and refreshed in the background.
+pub(crate) async fn find_identity(
+    db: &DatabaseConnection,
+    platform: Platform,
+    identity: &str,
+) -> Result<Option<IdentityRecord>, Error> {
+    let target = Target::Identity(platform, identity.to_string());
+    match Identity::find_by_platform_identity(db, &platform, identity).await? {
+        None if !C.web.fetch_on_miss => {
+            IDENTITY_CACHE.with_label_values(&["miss"]).inc();
+            Ok(None)
+        }
+        None => {
+            IDENTITY_CACHE.with_label_values(&["miss"]).inc();
+            if let Err(err) = fetch_all(target).await {
+                event!(Level::WARN, ?platform, identity, %err, "Failed to fetch");
+            }
+            Identity::find_by_platform_identity(db, &platform, identity).await
+        }
+        Some(found) => {
+            if found.is_outdated() {
+                IDENTITY_CACHE.with_label_values(&["stale"]).inc();
+                event!(Level::DEBUG, ?platform, identity, "Outdated. Refetching.");
+                worker::enqueue(target);
+            } else {
+                IDENTITY_CACHE.with_label_values(&["hit"]).inc();
+            }
+            Ok(Some(found))
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct ErrorResponse {
     pub message: String,
@@ -0,0 +1,27 @@
+use tracing::{info_span, Span};
+use uuid::Uuid;
+
+/// Header carrying ID of a request, given by client (or a proxy in front of us) and echoed back.
+pub const HEADER: &str = "x-request-id";
+
+/// Longer IDs given are replaced, so they won't blow up the logs.
+const MAX_LENGTH: usize = 128;
+
+/// ID given in `HEADER` if it's sane, otherwise a new UUID.
+pub fn from_header(given: Option<String>) -> String {
+    match given {
+        Some(id)
+            if !id.is_empty()
+                && id.len() <= MAX_LENGTH
+                && id.chars().all(|c| c.is_ascii_graphic()) =>
+        {
+            id
+        }
+        _ => Uuid::new_v4().to_string(),
+    }
+}
+
+/// Span of a request: every log inside it (fetchers, DB, and refresh jobs enqueued by it) carries `request_id`.
+pub fn span(request_id: &str) -> Span {
+    info_span!("request", request_id)
+}
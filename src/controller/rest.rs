@@ -0,0 +1,166 @@
+use crate::{
+    controller::{
+        find_identity, graphql::check_identity, json_parse_body, json_response, query_parse,
+        Request, Response,
+    },
+    error::Error,
+    graph::{
+        db_connection,
+        edge::{Proof, ProofFilter, ProofRecord},
+        vertex::Identity,
+        Vertex,
+    },
+    upstream::{fetch_all, DataSource, FetchError, Platform, Target},
+    util::timestamp_to_naive,
+};
+use http::{Method, StatusCode};
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Page size of `GET /v1/proofs` if `first` is not given.
+const DEFAULT_PAGE_SIZE: usize = 20;
+/// Max page size of `GET /v1/proofs`.
+const MAX_PAGE_SIZE: usize = 100;
+
+/// `/v1/*`: REST API serving the same data as GraphQL, for clients not speaking GraphQL.
+pub async fn route(req: Request) -> Result<Response, Error> {
+    let path = req.uri().path().trim_end_matches('/').to_string();
+    let segments: Vec<&str> = path.split('/').skip(1).collect();
+    match (req.method(), segments.as_slice()) {
+        (&Method::GET, ["v1", "identity", platform, identity]) => {
+            get_identity(&decode(platform)?, &decode(identity)?).await
+        }
+        (&Method::GET, ["v1", "proofs"]) => get_proofs(req).await,
+        (&Method::POST, ["v1", "fetch"]) => post_fetch(req).await,
+        _ => Err(Error::General(
+            format!("Not found: {} {}", req.method(), path),
+            StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+/// GET /v1/identity/{platform}/{identity}
+/// Same as GraphQL `identity` query: upstreams are fetched if it is not found.
+async fn get_identity(platform: &str, identity: &str) -> Result<Response, Error> {
+    let platform: Platform = platform.parse()?;
+    let identity = check_identity(&platform, identity)?;
+    let db = db_connection().await?;
+    match find_identity(&db, platform, &identity).await? {
+        Some(found) => json_response(StatusCode::OK, &found),
+        None => Err(Error::General(
+            format!("Identity not found: {}/{}", platform, identity),
+            StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+#[derive(Serialize)]
+struct ProofsResponse {
+    proofs: Vec<ProofRecord>,
+    /// Give it as `after` to get the next page.
+    end_cursor: Option<String>,
+    has_next_page: bool,
+}
+
+/// GET /v1/proofs?source=&from=&to=&created_after=&first=&after=
+/// Same as GraphQL `proofs` query: `from` and `to` are UUIDs of identities,
+/// `created_after` is a timestamp in seconds.
+async fn get_proofs(req: Request) -> Result<Response, Error> {
+    let params = query_parse(req);
+    let db = db_connection().await?;
+    let mut filter = ProofFilter {
+        source: params
+            .get("source")
+            .map(|source| source.parse::<DataSource>())
+            .transpose()?,
+        created_after: params
+            .get("created_after")
+            .map(|ts| ts.parse::<i64>())
+            .transpose()?
+            .map(|ts| timestamp_to_naive(ts, 0)),
+        ..Default::default()
+    };
+    for (param, id) in [("from", &mut filter.from), ("to", &mut filter.to)] {
+        if let Some(uuid) = params.get(param) {
+            match Identity::find_by_uuid(&db, Uuid::parse_str(uuid)?).await? {
+                Some(identity) => *id = Some(identity.id().clone()),
+                None => {
+                    return json_response(
+                        StatusCode::OK,
+                        &ProofsResponse {
+                            proofs: vec![],
+                            end_cursor: None,
+                            has_next_page: false,
+                        },
+                    )
+                }
+            }
+        }
+    }
+
+    let first = params
+        .get("first")
+        .map(|first| first.parse::<usize>())
+        .transpose()?
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let after = params.get("after").map(String::as_str);
+    let mut proofs = Proof::find_page(&db, &filter, after, first + 1).await?;
+    let has_next_page = proofs.len() > first;
+    proofs.truncate(first);
+    json_response(
+        StatusCode::OK,
+        &ProofsResponse {
+            end_cursor: proofs.last().map(|proof| proof.key().clone()),
+            proofs,
+            has_next_page,
+        },
+    )
+}
+
+#[derive(Deserialize)]
+struct FetchRequest {
+    platform: String,
+    identity: String,
+}
+
+#[derive(Serialize)]
+struct FetchResponse {
+    /// Amount of identities created.
+    identities: u64,
+    /// Amount of connections created.
+    edges: u64,
+    /// Upstreams failed in this fetch. What's found by others is saved anyway.
+    errors: Vec<FetchError>,
+}
+
+/// POST /v1/fetch
+/// Body is `{"platform": "twitter", "identity": "alice"}`.
+/// Same as GraphQL `fetch` mutation: fetch all upstreams right now, and returns what's newly found.
+/// What's found is queried with `GET /v1/identity` afterwards.
+async fn post_fetch(req: Request) -> Result<Response, Error> {
+    if req.body().is_empty() {
+        return Err(Error::BodyMissing);
+    }
+    let request: FetchRequest = json_parse_body(&req)?;
+    let platform: Platform = request.platform.parse()?;
+    let identity = check_identity(&platform, &request.identity)?;
+    let crawled = fetch_all(Target::Identity(platform, identity)).await?;
+    json_response(
+        StatusCode::OK,
+        &FetchResponse {
+            identities: crawled.created.identities,
+            edges: crawled.created.edges,
+            errors: crawled.errors,
+        },
+    )
+}
+
+/// Percent-decode a path segment, e.g. `alice%40mastodon.social`.
+fn decode(segment: &str) -> Result<String, Error> {
+    percent_decode_str(segment)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|_| Error::ParamError(format!("Invalid path segment: {}", segment)))
+}
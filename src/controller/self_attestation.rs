@@ -0,0 +1,32 @@
+use crate::{
+    controller::{json_parse_body, json_response, Request, Response},
+    error::Error,
+    upstream::{SelfAttestation, SelfAttestationRequest},
+    worker,
+};
+use http::StatusCode;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct SelfAttestationResponse {
+    /// Identities bound to the signer.
+    pub linked: Vec<String>,
+}
+
+/// POST /api/self_attestation
+/// Body is `{"message": "<SIWE message>", "signature": "0x..."}`.
+pub async fn controller(req: Request) -> Result<Response, Error> {
+    if req.body().is_empty() {
+        return Err(Error::BodyMissing);
+    }
+    let request: SelfAttestationRequest = json_parse_body(&req)?;
+    let targets = SelfAttestation::ingest(&request).await?;
+    let linked = targets.iter().map(|target| target.to_string()).collect();
+
+    // Continue discovering from the newly bound identities.
+    for target in targets {
+        worker::enqueue(target);
+    }
+
+    json_response(StatusCode::CREATED, &SelfAttestationResponse { linked })
+}
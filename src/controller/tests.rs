@@ -0,0 +1,40 @@
+use crate::controller::{filter, request_id};
+use http::StatusCode;
+
+#[tokio::test]
+async fn test_filter_routes() {
+    let routes = filter();
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/api/healthz")
+        .reply(&routes)
+        .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.headers().contains_key(request_id::HEADER));
+
+    // Routed to the controllers, which reject an empty body.
+    for path in ["/api/vc", "/api/polygon_id/claims", "/api/self_attestation"] {
+        let resp = warp::test::request()
+            .method("POST")
+            .path(path)
+            .reply(&routes)
+            .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{}", path);
+    }
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/api/vc")
+        .reply(&routes)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    // Other paths are left to other filters.
+    let resp = warp::test::request()
+        .method("POST")
+        .path("/graphql")
+        .reply(&routes)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,31 @@
+use crate::{
+    controller::{json_response, Request, Response},
+    error::Error,
+    upstream::VerifiableCredential,
+    worker,
+};
+use http::StatusCode;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct IngestResponse {
+    /// Identities linked by this credential.
+    pub linked: Vec<String>,
+}
+
+/// POST /api/vc
+/// Body is a W3C Verifiable Credential, either JWT or JSON-LD.
+pub async fn controller(req: Request) -> Result<Response, Error> {
+    if req.body().is_empty() {
+        return Err(Error::BodyMissing);
+    }
+    let targets = VerifiableCredential::ingest(req.body()).await?;
+    let linked = targets.iter().map(|target| target.to_string()).collect();
+
+    // Continue discovering from the newly linked identities.
+    for target in targets {
+        worker::enqueue(target);
+    }
+
+    json_response(StatusCode::CREATED, &IngestResponse { linked })
+}
@@ -30,9 +30,9 @@ pub enum Error {
     #[error("UUID parse error: {0}")]
     UuidError(#[from] uuid::Error),
     #[error("ArangoDB error: {0}")]
-    ArangoDBError(#[from] aragog::Error),
+    ArangoDBError(aragog::Error),
     #[error("ArangoLiteDB error: {0}")]
-    ArangoLiteDBError(#[from] arangors_lite::ClientError),
+    ArangoLiteDBError(arangors_lite::ClientError),
     #[error("Parse error: {0}")]
     EnumParseError(#[from] strum::ParseError),
     #[error("Parse Int error: {0}")]
@@ -45,6 +45,45 @@ pub enum Error {
     ArangoConfigError(#[from] crate::graph::arangopool::ArangoConfigError),
     #[error("IsahcError error: {0}")]
     IsahcError(#[from] isahc::error::Error),
+    // upstream
+    #[error("Upstream rate limited: {0}")]
+    UpstreamRateLimited(String),
+    #[error("Not found in upstream: {0}")]
+    UpstreamNotFound(String),
+    // database
+    #[error("Database conflict: {0}")]
+    DbConflict(String),
+    // identity
+    #[error("Invalid identity format: {0}")]
+    InvalidIdentityFormat(String),
+}
+
+impl From<aragog::Error> for Error {
+    fn from(err: aragog::Error) -> Self {
+        match err {
+            aragog::Error::Conflict(_) => Error::DbConflict(err.to_string()),
+            err => Error::ArangoDBError(err),
+        }
+    }
+}
+
+/// `ERROR_ARANGO_CONFLICT`, i.e. a write-write conflict between transactions.
+const ARANGO_CONFLICT: u16 = 1200;
+/// `ERROR_ARANGO_UNIQUE_CONSTRAINT_VIOLATED`
+const ARANGO_UNIQUE_CONSTRAINT_VIOLATED: u16 = 1210;
+
+impl From<arangors_lite::ClientError> for Error {
+    fn from(err: arangors_lite::ClientError) -> Self {
+        match err {
+            arangors_lite::ClientError::Arango(ref arango)
+                if arango.error_num() == ARANGO_CONFLICT
+                    || arango.error_num() == ARANGO_UNIQUE_CONSTRAINT_VIOLATED =>
+            {
+                Error::DbConflict(err.to_string())
+            }
+            err => Error::ArangoLiteDBError(err),
+        }
+    }
 }
 
 impl Error {
@@ -70,10 +109,51 @@ impl Error {
             Error::PoolError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::ArangoConfigError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::IsahcError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::UpstreamRateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            Error::UpstreamNotFound(_) => StatusCode::NOT_FOUND,
+            Error::DbConflict(_) => StatusCode::CONFLICT,
+            Error::InvalidIdentityFormat(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// Machine-readable code of this error, given in `extensions.code` of GraphQL errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::General(_, status) => match *status {
+                StatusCode::TOO_MANY_REQUESTS => "UPSTREAM_RATE_LIMITED",
+                StatusCode::NOT_FOUND => "NOT_FOUND",
+                StatusCode::REQUEST_TIMEOUT => "TIMEOUT",
+                status if status.is_client_error() => "BAD_REQUEST",
+                _ => "INTERNAL_ERROR",
+            },
+            Error::ParamMissing(_) => "PARAM_MISSING",
+            Error::ParamError(_) => "PARAM_ERROR",
+            Error::BodyMissing => "BODY_MISSING",
+            Error::NoResult => "NO_RESULT",
+            Error::JSONParseError(_) => "JSON_PARSE_ERROR",
+            Error::SignatureValidationError(_) => "SIGNATURE_INVALID",
+            Error::EnumParseError(_) => "INVALID_ENUM_VALUE",
+            Error::UuidError(_) => "INVALID_UUID",
+            Error::HttpClientError(_) | Error::ManualHttpClientError(_) | Error::IsahcError(_) => {
+                "UPSTREAM_ERROR"
+            }
+            Error::ArangoDBError(_) | Error::ArangoLiteDBError(_) | Error::PoolError(_) => {
+                "DB_ERROR"
+            }
+            Error::HttpError(_)
+            | Error::ConfigError(_)
+            | Error::ArangoConfigError(_)
+            | Error::GraphQLError(_)
+            | Error::ParseIntError(_) => "INTERNAL_ERROR",
+            Error::UpstreamRateLimited(_) => "UPSTREAM_RATE_LIMITED",
+            Error::UpstreamNotFound(_) => "UPSTREAM_NOT_FOUND",
+            Error::DbConflict(_) => "DB_CONFLICT",
+            Error::InvalidIdentityFormat(_) => "INVALID_IDENTITY_FORMAT",
         }
     }
 }
 
+#[cfg(feature = "server")]
 impl warp::reject::Reject for Error {}
 
 unsafe impl Sync for Error {}
@@ -73,6 +73,30 @@ impl ArangoConnection {
 
 pub type ConnectionPool = Pool<ArangoConnectionManager>;
 
+/// A connection borrowed from `ConnectionPool`, returned to the pool on drop.
+pub type PooledConnection = Object<ArangoConnectionManager>;
+
+lazy_static! {
+    /// Pool shared by the whole application, built on first use.
+    static ref SHARED_POOL: ConnectionPool =
+        build_pool().expect("Failed to build ArangoDB connection pool");
+}
+
+/// Pool shared by the whole application (GraphQL resolvers, fetchers and workers).
+/// Cloning it is cheap: all clones share the same connections.
+pub fn shared_pool() -> ConnectionPool {
+    SHARED_POOL.clone()
+}
+
+/// Borrow a connection from `shared_pool`.
+/// It's health-checked before being handed out (see `recycle`), broken ones are replaced by a new connection.
+pub async fn db_connection() -> Result<PooledConnection, Error> {
+    SHARED_POOL
+        .get()
+        .await
+        .map_err(|err| Error::PoolError(err.to_string()))
+}
+
 #[async_trait::async_trait]
 impl Manager for ArangoConnectionManager {
     type Type = DatabaseConnection;
@@ -123,6 +147,10 @@ impl Manager for ArangoConnectionManager {
 
 /// Create connection pool for arangodb
 pub async fn new_connection_pool() -> Result<ConnectionPool, Error> {
+    build_pool()
+}
+
+fn build_pool() -> Result<ConnectionPool, Error> {
     let manager = ArangoConnectionManager {
         host: C.db.host.to_string(),
         username: C.db.username.to_string(),
@@ -136,16 +164,11 @@ pub async fn new_connection_pool() -> Result<ConnectionPool, Error> {
         timeouts: Timeouts::default(),
     };
 
-    let pool = Pool::builder(manager)
+    Pool::builder(manager)
         .config(pool_config)
         // .runtime(runtime)
         .build()
-        .map_err(|err| Error::PoolError(err.to_string()));
-
-    match pool {
-        Ok(p) => Ok(p),
-        Err(_) => todo!(),
-    }
+        .map_err(|err| Error::PoolError(err.to_string()))
 }
 
 impl From<Object<ArangoConnectionManager>> for ArangoConnection {
@@ -0,0 +1,738 @@
+use crate::{
+    error::Error,
+    graph::{
+        edge::{
+            proof::COLLECTION_NAME as PROOFS, Annotation, Hold, Participate, Proof, ProofRecord,
+            Resolve,
+        },
+        events::{self, ProofAdded},
+        proof_history::{ProofChange, ProofHistory},
+        vertex::{contract::Chain, Contract, CryptoKey, Identity, IdentityRecord},
+        GraphStats, GraphTx,
+    },
+    metrics::time_db,
+    upstream::{Connection, Platform},
+    util::{canonical::canonicalize, naive_now},
+};
+use aragog::{DatabaseAccess, DatabaseConnection, Record};
+use arangors_lite::AqlQuery;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, to_value, Value};
+use std::collections::HashMap;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Times to run a batch before giving up on write-write conflicts with concurrent ones.
+const CONFLICT_ATTEMPTS: usize = 3;
+
+/// What's done by `upsert`.
+pub struct Upserted {
+    /// Saved edge of each proof given (two for a `TwoWayBinding`), in the same order.
+    /// Proofs with a malformed identity on either end are skipped, see `Batch::proof`.
+    pub proofs: Vec<ProofRecord>,
+    /// What's newly created, things already there (updated or not) are not counted.
+    pub created: GraphStats,
+}
+
+/// Save connections of all kinds in 3 AQL round trips,
+/// no matter how many there are, instead of 3+ queries for each of them.
+/// Works the same as `Vertex::create_or_update` and `Edge::connect` (history and events included).
+/// All of them are saved in one `GraphTx`: if anything fails, nothing is saved.
+/// The whole batch is run again if it conflicts with a concurrent one (`Error::DbConflict`).
+/// Malformed identities (see `canonicalize`) are logged and skipped along with their connections,
+/// so one bad record from an upstream won't abort the others.
+/// `Republished` ones are saved as proofs, `upstream::import` skips duplicated ones before.
+pub async fn upsert(
+    db: &DatabaseConnection,
+    connections: &[Connection],
+) -> Result<Upserted, Error> {
+    save_all(db, connections, false).await
+}
+
+/// Same as `upsert`, but fails with `Error::ParamError` if any proof is saved already
+/// (same ends, `source` and `record_id`), instead of refreshing it.
+/// Checked in the same `GraphTx` the proofs are inserted in: of concurrent ones,
+/// `ProofRecordUniqueness` fails the later, which then finds the proof on its next attempt.
+pub async fn insert(
+    db: &DatabaseConnection,
+    connections: &[Connection],
+) -> Result<Upserted, Error> {
+    save_all(db, connections, true).await
+}
+
+async fn save_all(
+    db: &DatabaseConnection,
+    connections: &[Connection],
+    new_only: bool,
+) -> Result<Upserted, Error> {
+    let mut batch = Batch {
+        new_only,
+        ..Default::default()
+    };
+    for connection in connections {
+        batch.add(connection)?;
+    }
+    if batch.identities.is_empty() {
+        return Ok(Upserted {
+            proofs: vec![],
+            created: GraphStats::default(),
+        });
+    }
+
+    let mut attempt = 1;
+    let saved = loop {
+        match batch.save_in_tx(db).await {
+            Err(Error::DbConflict(err)) if attempt < CONFLICT_ATTEMPTS => {
+                warn!("Batch upsert | conflicted, retrying: {}", err);
+                attempt += 1;
+            }
+            result => break result?,
+        }
+    };
+    // Only tell subscribers what's really committed.
+    saved.added.into_iter().for_each(events::publish);
+
+    Ok(Upserted {
+        proofs: batch
+            .order
+            .iter()
+            .map(|&i| saved.proofs[i].clone())
+            .collect(),
+        created: saved.created,
+    })
+}
+
+/// Connections to be saved together, deduplicated.
+#[derive(Default)]
+struct Batch<'a> {
+    /// Canonicalized (see `canonicalize`).
+    identities: Vec<Identity>,
+    /// Position in `identities` of each `(platform, identity)`.
+    positions: HashMap<(Platform, String), usize>,
+    contracts: Vec<Contract>,
+    /// Position in `contracts` of each `(chain, address)`.
+    contract_positions: HashMap<(Chain, String), usize>,
+    /// With normalized fingerprints (see `CryptoKey::normalize_fingerprint`).
+    crypto_keys: Vec<CryptoKey>,
+    /// Position in `crypto_keys` of each fingerprint.
+    crypto_key_positions: HashMap<String, usize>,
+    /// `from` and `to` (positions in `identities`) of each proof.
+    proofs: Vec<(usize, usize, &'a Proof)>,
+    /// Position in `proofs` of each `(from, to, proof.source, proof.record_id)`.
+    proof_positions: HashMap<(usize, usize, String, Option<&'a str>), usize>,
+    /// Position in `proofs` of every proof added, in the order they are added.
+    order: Vec<usize>,
+    /// Edges of other kinds.
+    links: Vec<Link>,
+    /// Fail if any proof is saved already, see `insert`.
+    new_only: bool,
+    /// Position in `links` of each `(collection, from, to, key)`.
+    link_positions: HashMap<(&'static str, End, End, String), usize>,
+}
+
+/// Vertex on either end of a `Link`, by its position in `Batch`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "kind", content = "at", rename_all = "snake_case")]
+enum End {
+    Identity(usize),
+    Contract(usize),
+    CryptoKey(usize),
+}
+
+/// An edge other than `Proof`, saved by `save_links`.
+#[derive(Serialize)]
+struct Link {
+    #[serde(skip)]
+    collection: &'static str,
+    from: End,
+    to: End,
+    edge: Value,
+}
+
+/// Result of the first round trip.
+#[derive(Deserialize)]
+struct Found {
+    /// Saved `identities`, in the same order.
+    identities: Vec<IdentityRecord>,
+    /// Amount of `identities` newly created.
+    created: u64,
+    /// Existing edge of each one in `proofs`.
+    edges: Vec<Option<ProofRecord>>,
+}
+
+/// Result of `Batch::save`.
+struct Saved {
+    /// Saved edge of each one in `proofs`.
+    proofs: Vec<ProofRecord>,
+    /// Events of newly created proofs, to be published after committed.
+    added: Vec<ProofAdded>,
+    created: GraphStats,
+}
+
+/// `identity` canonicalized, `None` (logged) if it's malformed.
+fn canonical(identity: &Identity) -> Option<Identity> {
+    match canonicalize(&identity.platform, &identity.identity) {
+        Ok(canonical) => Some(Identity {
+            identity: canonical,
+            ..identity.clone()
+        }),
+        Err(err) => {
+            warn!(
+                "Batch upsert | skip malformed identity {}/{}: {}",
+                identity.platform, identity.identity, err
+            );
+            None
+        }
+    }
+}
+
+impl<'a> Batch<'a> {
+    /// Add a connection, skipped if any identity of it is malformed.
+    fn add(&mut self, connection: &'a Connection) -> Result<(), Error> {
+        match connection {
+            Connection::Identity(identity) => {
+                if let Some(identity) = canonical(identity) {
+                    self.identity(identity);
+                }
+            }
+            Connection::Proof(from, to, proof) | Connection::Republished(from, to, proof) => {
+                self.proof(from, to, proof)
+            }
+            Connection::TwoWayBinding(from, to, proof) => {
+                self.proof(from, to, proof);
+                self.proof(to, from, proof);
+            }
+            Connection::Hold(from, to, hold) => {
+                if let Some((from, to)) = self.identities_of(from, to) {
+                    self.hold(End::Identity(from), End::Identity(to), hold)?;
+                }
+            }
+            Connection::ContractHold(from, to, hold) => {
+                if let Some(from) = canonical(from) {
+                    let (from, to) = (self.identity(from), self.contract(to));
+                    self.hold(End::Identity(from), to, hold)?;
+                }
+            }
+            Connection::CryptoKeyHold(from, to, hold) => {
+                if let Some(from) = canonical(from) {
+                    let (from, to) = (self.identity(from), self.crypto_key(to));
+                    self.hold(End::Identity(from), to, hold)?;
+                }
+            }
+            Connection::Resolve(from, to, resolve) => {
+                if let Some((from, to)) = self.identities_of(from, to) {
+                    self.resolve(End::Identity(from), End::Identity(to), resolve)?;
+                }
+            }
+            Connection::Domain {
+                address,
+                domain,
+                hold,
+                resolve,
+                is_primary,
+            } => {
+                if let Some((address, domain)) = self.identities_of(address, domain) {
+                    let (address, domain) = (End::Identity(address), End::Identity(domain));
+                    self.hold(address, domain, hold)?;
+                    // 'regular' resolution
+                    self.resolve(domain, address, resolve)?;
+                    if *is_primary {
+                        // 'reverse' resolution
+                        let reverse = Resolve {
+                            uuid: Uuid::new_v4(),
+                            ..resolve.clone()
+                        };
+                        self.resolve(address, domain, &reverse)?;
+                    }
+                }
+            }
+            Connection::ContractDomain {
+                owner,
+                contract,
+                hold,
+                resolve,
+                resolved,
+            } => {
+                let resolved = match resolved {
+                    Some(resolved) => match canonical(resolved) {
+                        Some(resolved) => Some(resolved),
+                        None => return Ok(()),
+                    },
+                    None => None,
+                };
+                if let Some(owner) = canonical(owner) {
+                    let (owner, contract) =
+                        (End::Identity(self.identity(owner)), self.contract(contract));
+                    self.hold(owner, contract, hold)?;
+                    // 'regular' resolution
+                    self.resolve(contract, owner, resolve)?;
+                    if let Some(resolved) = resolved {
+                        // 'reverse' resolution
+                        let resolved = End::Identity(self.identity(resolved));
+                        self.resolve(resolved, contract, resolve)?;
+                    }
+                }
+            }
+            Connection::Participate(from, to, participate) => {
+                if let Some((from, to)) = self.identities_of(from, to) {
+                    let key = participate.source.to_string();
+                    self.link(
+                        Participate::COLLECTION_NAME,
+                        End::Identity(from),
+                        End::Identity(to),
+                        key,
+                        participate,
+                    )?;
+                }
+            }
+            Connection::Annotation(from, to, annotation) => {
+                if let Some((from, to)) = self.identities_of(from, to) {
+                    let key = annotation.source.to_string();
+                    self.link(
+                        Annotation::COLLECTION_NAME,
+                        End::Identity(from),
+                        End::Identity(to),
+                        key,
+                        annotation,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a canonicalized identity, the later one wins if it's added twice.
+    fn identity(&mut self, identity: Identity) -> usize {
+        let key = (identity.platform, identity.identity.clone());
+        match self.positions.get(&key) {
+            Some(&position) => {
+                self.identities[position] = identity;
+                position
+            }
+            None => {
+                self.identities.push(identity);
+                self.positions.insert(key, self.identities.len() - 1);
+                self.identities.len() - 1
+            }
+        }
+    }
+
+    /// Add both identities, `None` if either of them is malformed.
+    fn identities_of(&mut self, from: &Identity, to: &Identity) -> Option<(usize, usize)> {
+        match (canonical(from), canonical(to)) {
+            (Some(from), Some(to)) => Some((self.identity(from), self.identity(to))),
+            _ => None,
+        }
+    }
+
+    /// Add a contract, the later one wins if it's added twice.
+    fn contract(&mut self, contract: &Contract) -> End {
+        let key = (contract.chain, contract.address.clone());
+        let position = match self.contract_positions.get(&key) {
+            Some(&position) => {
+                self.contracts[position] = contract.clone();
+                position
+            }
+            None => {
+                self.contracts.push(contract.clone());
+                self.contract_positions
+                    .insert(key, self.contracts.len() - 1);
+                self.contracts.len() - 1
+            }
+        };
+        End::Contract(position)
+    }
+
+    /// Add a key, the later one wins if it's added twice.
+    fn crypto_key(&mut self, crypto_key: &CryptoKey) -> End {
+        let crypto_key = CryptoKey {
+            fingerprint: CryptoKey::normalize_fingerprint(&crypto_key.fingerprint),
+            ..crypto_key.clone()
+        };
+        let key = crypto_key.fingerprint.clone();
+        let position = match self.crypto_key_positions.get(&key) {
+            Some(&position) => {
+                self.crypto_keys[position] = crypto_key;
+                position
+            }
+            None => {
+                self.crypto_keys.push(crypto_key);
+                self.crypto_key_positions
+                    .insert(key, self.crypto_keys.len() - 1);
+                self.crypto_keys.len() - 1
+            }
+        };
+        End::CryptoKey(position)
+    }
+
+    /// Add a proof, skipped if either end is malformed.
+    fn proof(&mut self, from: &Identity, to: &Identity, proof: &'a Proof) {
+        let (from, to) = match self.identities_of(from, to) {
+            Some(ends) => ends,
+            None => return,
+        };
+        let key = (
+            from,
+            to,
+            proof.source.to_string(),
+            proof.record_id.as_deref(),
+        );
+        let position = match self.proof_positions.get(&key) {
+            Some(&position) => {
+                self.proofs[position] = (from, to, proof);
+                position
+            }
+            None => {
+                self.proofs.push((from, to, proof));
+                self.proof_positions.insert(key, self.proofs.len() - 1);
+                self.proofs.len() - 1
+            }
+        };
+        self.order.push(position);
+    }
+
+    /// Holds are told apart by `id` (see `Hold::find_by_from_to_id`).
+    fn hold(&mut self, from: End, to: End, hold: &Hold) -> Result<(), Error> {
+        self.link(Hold::COLLECTION_NAME, from, to, hold.id.clone(), hold)
+    }
+
+    /// Resolves are told apart by `system` and `name`.
+    fn resolve(&mut self, from: End, to: End, resolve: &Resolve) -> Result<(), Error> {
+        let key = format!("{}/{}", resolve.system, resolve.name);
+        self.link(Resolve::COLLECTION_NAME, from, to, key, resolve)
+    }
+
+    /// Add an edge of other kinds, the later one wins if it's added twice.
+    fn link<T: Serialize>(
+        &mut self,
+        collection: &'static str,
+        from: End,
+        to: End,
+        key: String,
+        edge: &T,
+    ) -> Result<(), Error> {
+        let link = Link {
+            collection,
+            from,
+            to,
+            edge: to_value(edge)?,
+        };
+        match self
+            .link_positions
+            .get(&(collection, from, to, key.clone()))
+        {
+            Some(&position) => self.links[position] = link,
+            None => {
+                self.links.push(link);
+                self.link_positions
+                    .insert((collection, from, to, key), self.links.len() - 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `save` in a `GraphTx`, committed only if everything is saved.
+    async fn save_in_tx(&self, db: &DatabaseConnection) -> Result<Saved, Error> {
+        let tx = GraphTx::begin(db).await?;
+        match time_db("batch_upsert", self.save(tx.db())).await {
+            Ok(result) => {
+                tx.commit().await?;
+                Ok(result)
+            }
+            Err(err) => {
+                tx.abort().await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Save everything.
+    async fn save<D: DatabaseAccess + ?Sized>(&self, db: &D) -> Result<Saved, Error> {
+        let found = self.upsert_identities(db).await?;
+        let links_created = self.save_links(db, &found.identities).await?;
+        let mut created = GraphStats {
+            identities: found.created,
+            edges: links_created,
+        };
+
+        // Decide what to save for each proof, the same way as `Proof::connect`.
+        let mut edges: Vec<Value> = vec![];
+        let mut histories: Vec<ProofHistory> = vec![];
+        // `from` and `to` of each newly created edge, `None` for existing ones.
+        let mut new_edges: Vec<Option<(usize, usize)>> = vec![];
+        for (&(from, to, proof), edge) in self.proofs.iter().zip(found.edges.into_iter()) {
+            match edge {
+                Some(_) if self.new_only => {
+                    return Err(Error::ParamError(format!(
+                        "Proof of {} with record {} is saved already",
+                        proof.source,
+                        proof.record_id.as_deref().unwrap_or_default()
+                    )));
+                }
+                Some(mut edge) => {
+                    for change in proof.refresh(&mut edge) {
+                        histories.push(ProofHistory::new(&edge, change));
+                    }
+                    edges.push(to_value(&edge)?);
+                    new_edges.push(None);
+                }
+                None => {
+                    let mut edge = to_value(proof)?;
+                    edge["_from"] = json!(found.identities[from].id());
+                    edge["_to"] = json!(found.identities[to].id());
+                    edges.push(edge);
+                    histories.push(ProofHistory::new(proof, ProofChange::Created));
+                    new_edges.push(Some((from, to)));
+                }
+            }
+        }
+        if edges.is_empty() {
+            return Ok(Saved {
+                proofs: vec![],
+                added: vec![],
+                created,
+            });
+        }
+
+        let proofs = save_edges(db, edges, histories).await?;
+        let added: Vec<ProofAdded> = proofs
+            .iter()
+            .zip(new_edges.into_iter())
+            .filter_map(|(proof, new_edge)| {
+                new_edge.map(|(from, to)| ProofAdded {
+                    proof: proof.clone(),
+                    from: found.identities[from].clone(),
+                    to: found.identities[to].clone(),
+                })
+            })
+            .collect();
+        created.edges += added.len() as u64;
+        Ok(Saved {
+            proofs,
+            added,
+            created,
+        })
+    }
+
+    /// Upsert all identities, and find existing edges of all proofs (by the same rule of `Proof::find_by_from_to`).
+    async fn upsert_identities<D: DatabaseAccess + ?Sized>(&self, db: &D) -> Result<Found, Error> {
+        let identities: Vec<Identity> = self
+            .identities
+            .iter()
+            .map(|identity| Identity {
+                uuid: identity.uuid.or(Some(Uuid::new_v4())),
+                ..identity.clone()
+            })
+            .collect();
+        let proofs: Vec<Value> = self
+            .proofs
+            .iter()
+            .map(|(from, to, proof)| {
+                json!({
+                    "from": from,
+                    "to": to,
+                    "source": proof.source.to_string(),
+                    "record_id": proof.record_id,
+                })
+            })
+            .collect();
+
+        let aql = AqlQuery::new(
+            r"LET upserted = (
+                FOR i IN @identities
+                    UPSERT { platform: i.platform, identity: i.identity }
+                    INSERT MERGE(i, { added_at: @now, updated_at: @now })
+                    UPDATE {
+                        display_name: NOT_NULL(i.display_name, OLD.display_name),
+                        profile_url: i.profile_url,
+                        avatar_url: i.avatar_url,
+                        created_at: NOT_NULL(i.created_at, OLD.created_at),
+                        updated_at: @now
+                    }
+                    IN @@identities
+                    RETURN { identity: NEW, created: OLD == null }
+            )
+            LET identities = upserted[*].identity
+            LET edges = (
+                FOR p IN @proofs
+                    LET from = identities[p.from]._id
+                    LET to = identities[p.to]._id
+                    RETURN FIRST(
+                        FOR e IN @@proofs
+                            FILTER e._from == from AND e._to == to AND e.source == p.source
+                            FILTER p.record_id == null OR e.record_id == p.record_id OR e.record_id == null
+                            SORT e.record_id == p.record_id DESC
+                            LIMIT 1
+                            RETURN e
+                    )
+            )
+            RETURN { identities, edges, created: LENGTH(upserted[* FILTER CURRENT.created]) }",
+        )
+        .bind_var("@identities", Identity::COLLECTION_NAME)
+        .bind_var("@proofs", PROOFS)
+        .bind_var("identities", to_value(identities)?)
+        .bind_var("proofs", proofs)
+        .bind_var("now", to_value(naive_now())?)
+        .count(false);
+        let result: Vec<Found> = db.database().aql_query(aql).await?;
+        result.into_iter().next().ok_or_else(|| {
+            Error::General(
+                "Batch upsert returns nothing".into(),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })
+    }
+
+    /// Save contracts, keys and edges other than proofs, the same way as their `create_or_update` and `connect`.
+    /// `identities` are saved already, in the same order as `self.identities`.
+    /// Returns amount of edges newly created.
+    async fn save_links<D: DatabaseAccess + ?Sized>(
+        &self,
+        db: &D,
+        identities: &[IdentityRecord],
+    ) -> Result<u64, Error> {
+        if self.links.is_empty() {
+            return Ok(0);
+        }
+        let links_in = |collection: &str| -> Result<Value, Error> {
+            let links: Vec<&Link> = self
+                .links
+                .iter()
+                .filter(|link| link.collection == collection)
+                .collect();
+            Ok(to_value(links)?)
+        };
+        let identities: Vec<&String> = identities.iter().map(|identity| identity.id()).collect();
+
+        let aql = AqlQuery::new(
+            r"LET contracts = (
+                FOR c IN @contracts
+                    UPSERT { chain: c.chain, address: c.address }
+                    INSERT MERGE(c, { updated_at: @now })
+                    UPDATE { symbol: c.symbol, updated_at: @now }
+                    IN @@contracts
+                    RETURN NEW._id
+            )
+            LET crypto_keys = (
+                FOR k IN @crypto_keys
+                    UPSERT { fingerprint: k.fingerprint }
+                    INSERT MERGE(k, { updated_at: @now })
+                    UPDATE {
+                        public_key: NOT_NULL(k.public_key, OLD.public_key),
+                        chain: NOT_NULL(k.chain, OLD.chain),
+                        created_at: NOT_NULL(k.created_at, OLD.created_at),
+                        updated_at: @now
+                    }
+                    IN @@crypto_keys
+                    RETURN NEW._id
+            )
+            LET ends = { identity: @identities, contract: contracts, crypto_key: crypto_keys }
+            LET holds = (
+                FOR l IN @holds
+                    LET from = ends[l.from.kind][l.from.at]
+                    LET to = ends[l.to.kind][l.to.at]
+                    UPSERT { _from: from, _to: to, id: l.edge.id }
+                    INSERT MERGE(l.edge, { _from: from, _to: to })
+                    UPDATE {}
+                    IN @@holds
+                    RETURN OLD == null
+            )
+            LET resolves = (
+                FOR l IN @resolves
+                    LET from = ends[l.from.kind][l.from.at]
+                    LET to = ends[l.to.kind][l.to.at]
+                    UPSERT { _from: from, _to: to, system: l.edge.system, name: l.edge.name }
+                    INSERT MERGE(l.edge, { _from: from, _to: to })
+                    UPDATE {
+                        resolved_address: NOT_NULL(l.edge.resolved_address, OLD.resolved_address),
+                        expire_at: NOT_NULL(l.edge.expire_at, OLD.expire_at),
+                        updated_at: @now
+                    }
+                    IN @@resolves
+                    RETURN OLD == null
+            )
+            LET participates = (
+                FOR l IN @participates
+                    LET from = ends[l.from.kind][l.from.at]
+                    LET to = ends[l.to.kind][l.to.at]
+                    UPSERT { _from: from, _to: to, source: l.edge.source }
+                    INSERT MERGE(l.edge, { _from: from, _to: to })
+                    UPDATE {
+                        following: l.edge.following,
+                        votes: l.edge.votes,
+                        created_at: NOT_NULL(l.edge.created_at, OLD.created_at),
+                        updated_at: @now
+                    }
+                    IN @@participates
+                    RETURN OLD == null
+            )
+            LET annotations = (
+                FOR l IN @annotations
+                    LET from = ends[l.from.kind][l.from.at]
+                    LET to = ends[l.to.kind][l.to.at]
+                    UPSERT { _from: from, _to: to, source: l.edge.source }
+                    INSERT MERGE(l.edge, { _from: from, _to: to })
+                    UPDATE {
+                        name: l.edge.name,
+                        tags: l.edge.tags,
+                        confidence: l.edge.confidence,
+                        created_at: NOT_NULL(l.edge.created_at, OLD.created_at),
+                        updated_at: @now
+                    }
+                    IN @@annotations
+                    RETURN OLD == null
+            )
+            RETURN LENGTH(UNION(holds, resolves, participates, annotations)[* FILTER CURRENT])",
+        )
+        .bind_var("@contracts", Contract::COLLECTION_NAME)
+        .bind_var("@crypto_keys", CryptoKey::COLLECTION_NAME)
+        .bind_var("@holds", Hold::COLLECTION_NAME)
+        .bind_var("@resolves", Resolve::COLLECTION_NAME)
+        .bind_var("@participates", Participate::COLLECTION_NAME)
+        .bind_var("@annotations", Annotation::COLLECTION_NAME)
+        .bind_var("identities", to_value(identities)?)
+        .bind_var("contracts", to_value(&self.contracts)?)
+        .bind_var("crypto_keys", to_value(&self.crypto_keys)?)
+        .bind_var("holds", links_in(Hold::COLLECTION_NAME)?)
+        .bind_var("resolves", links_in(Resolve::COLLECTION_NAME)?)
+        .bind_var("participates", links_in(Participate::COLLECTION_NAME)?)
+        .bind_var("annotations", links_in(Annotation::COLLECTION_NAME)?)
+        .bind_var("now", to_value(naive_now())?)
+        .count(false);
+        let result: Vec<u64> = db.database().aql_query(aql).await?;
+        Ok(result.into_iter().next().unwrap_or_default())
+    }
+}
+
+/// Insert new edges and replace existing ones (those with `_key`), and append their histories.
+/// Returns saved edges, in the same order.
+async fn save_edges<D: DatabaseAccess + ?Sized>(
+    db: &D,
+    edges: Vec<Value>,
+    histories: Vec<ProofHistory>,
+) -> Result<Vec<ProofRecord>, Error> {
+    let aql = AqlQuery::new(
+        r#"LET saved = (
+            FOR e IN @edges
+                UPSERT { _key: e._key }
+                INSERT UNSET(e, "_key", "_id", "_rev")
+                REPLACE UNSET(e, "_key", "_id", "_rev")
+                IN @@proofs
+                RETURN NEW
+        )
+        LET logged = (
+            FOR h IN @histories
+                INSERT h IN @@histories
+                RETURN NEW._key
+        )
+        RETURN saved"#,
+    )
+    .bind_var("@proofs", PROOFS)
+    .bind_var("@histories", ProofHistory::COLLECTION_NAME)
+    .bind_var("edges", edges)
+    .bind_var("histories", to_value(histories)?)
+    .count(false);
+    let result: Vec<Vec<ProofRecord>> = db.database().aql_query(aql).await?;
+    Ok(result.into_iter().next().unwrap_or_default())
+}
@@ -0,0 +1,161 @@
+use aragog::{
+    query::{Comparison, Filter, QueryResult},
+    DatabaseConnection, DatabaseRecord, EdgeRecord, Record,
+};
+use chrono::NaiveDateTime;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::Error,
+    graph::{vertex::Identity, Edge},
+    upstream::{DataFetcher, DataSource},
+    util::naive_now,
+};
+
+/// Edge for an unproven statement about an `Identity`: a third-party label
+/// (e.g. an Etherscan name tag), or a one-sided claim (e.g. a wallet in a profile bio).
+/// Unlike `Proof`, it is not confirmed by both sides,
+/// so it is not followed when traversing neighbors.
+#[derive(Debug, Clone, Serialize, Deserialize, Record)]
+#[collection_name = "Annotations"]
+pub struct Annotation {
+    /// UUID of this record.
+    pub uuid: Uuid,
+    /// Data source (upstream) which provides this info.
+    pub source: DataSource,
+    /// Label or claim given by upstream (e.g. `Binance 14`).
+    pub name: String,
+    /// Categories given by upstream (e.g. `["Exchange", "Binance"]`).
+    pub tags: Vec<String>,
+    /// How much this label could be trusted, from `0.0` to `1.0`.
+    /// A cryptographic `Proof` is considered as `1.0`.
+    pub confidence: f64,
+    /// When this label is recorded in upstream platform (if platform gives such data).
+    pub created_at: Option<NaiveDateTime>,
+    /// When this label is fetched by us RelationService.
+    pub updated_at: NaiveDateTime,
+    /// Who collects this data.
+    /// It works as a "data cleansing" or "proxy" between `source`s and us.
+    pub fetcher: DataFetcher,
+}
+
+impl Default for Annotation {
+    fn default() -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            source: DataSource::default(),
+            name: String::default(),
+            tags: vec![],
+            confidence: 0.0,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: Default::default(),
+        }
+    }
+}
+
+impl Annotation {
+    pub async fn find_by_from_to(
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+        source: &DataSource,
+    ) -> Result<Option<AnnotationRecord>, Error> {
+        let filter = Filter::new(Comparison::field("_from").equals_str(from.id()))
+            .and(Comparison::field("_to").equals_str(to.id()))
+            .and(Comparison::field("source").equals_str(source));
+        let query = EdgeRecord::<Annotation>::query().filter(filter);
+        let result: QueryResult<EdgeRecord<Self>> = query.call(db).await?;
+        if result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(result.first().unwrap().clone().into()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Edge<Identity, Identity, AnnotationRecord> for Annotation {
+    fn uuid(&self) -> Option<Uuid> {
+        Some(self.uuid)
+    }
+
+    /// Find an edge by UUID.
+    async fn find_by_uuid(
+        db: &DatabaseConnection,
+        uuid: &Uuid,
+    ) -> Result<Option<AnnotationRecord>, Error> {
+        let result: QueryResult<EdgeRecord<Annotation>> = EdgeRecord::<Annotation>::query()
+            .filter(Comparison::field("uuid").equals_str(uuid).into())
+            .call(db)
+            .await?;
+
+        if result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(result.first().unwrap().to_owned().into()))
+        }
+    }
+
+    /// Connect 2 vertex. Metadata of an existing edge will be updated.
+    async fn connect(
+        &self,
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+    ) -> Result<AnnotationRecord, Error> {
+        let found = Self::find_by_from_to(db, from, to, &self.source).await?;
+        match found {
+            Some(mut edge) => {
+                edge.name = self.name.clone();
+                edge.tags = self.tags.clone();
+                edge.confidence = self.confidence;
+                edge.created_at = self.created_at.or(edge.created_at);
+                edge.updated_at = naive_now();
+                edge.save(db).await?;
+                Ok(edge)
+            }
+            None => Ok(DatabaseRecord::link(from, to, db, self.clone())
+                .await?
+                .into()),
+        }
+    }
+
+    /// Annotations are one-way by nature, use `connect` instead.
+    async fn two_way_binding(
+        &self,
+        _db: &DatabaseConnection,
+        _from: &DatabaseRecord<Identity>,
+        _to: &DatabaseRecord<Identity>,
+    ) -> Result<(AnnotationRecord, AnnotationRecord), Error> {
+        Err(Error::General(
+            "Annotation can not be a two-way binding".into(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationRecord(DatabaseRecord<EdgeRecord<Annotation>>);
+
+impl std::ops::Deref for AnnotationRecord {
+    type Target = DatabaseRecord<EdgeRecord<Annotation>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for AnnotationRecord {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<DatabaseRecord<EdgeRecord<Annotation>>> for AnnotationRecord {
+    fn from(record: DatabaseRecord<EdgeRecord<Annotation>>) -> Self {
+        Self(record)
+    }
+}
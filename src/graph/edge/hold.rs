@@ -8,11 +8,13 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
+    config::C,
     error::Error,
     graph::{
         vertex::{contract::Chain, Contract, Identity},
         ConnectionPool,
     },
+    metrics::time_db,
     upstream::{DataFetcher, DataSource},
     util::naive_now,
 };
@@ -144,7 +146,7 @@ impl Hold {
             .batch_size(1)
             .count(false);
 
-        let holds = db.aql_query::<HoldRecord>(aql).await?;
+        let holds = time_db("hold_by_id_chain_address", db.aql_query::<HoldRecord>(aql)).await?;
         if holds.len() == 0 {
             Ok(None)
         } else {
@@ -153,7 +155,7 @@ impl Hold {
     }
 
     pub fn is_outdated(&self) -> bool {
-        let outdated_in = Duration::hours(8);
+        let outdated_in = Duration::seconds(C.cache.hold as i64);
         self.updated_at
             .checked_add_signed(outdated_in)
             .unwrap()
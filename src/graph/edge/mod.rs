@@ -1,10 +1,17 @@
+pub mod annotation;
 pub mod hold;
+pub mod participate;
 pub mod proof;
 pub mod resolve;
 // mod pubkey_derivation;
 
+pub use annotation::{Annotation, AnnotationRecord};
 pub use hold::{Hold, HoldRecord};
-pub use proof::{IdentityFromToRecord, Proof, ProofRecord};
+pub use participate::{Participate, ParticipateRecord};
+pub use proof::{
+    IdentityFromToRecord, InvalidationReason, Proof, ProofFilter, ProofLevel, ProofRecord,
+    StaleProof,
+};
 pub use resolve::{Resolve, ResolveRecord};
 
 use aragog::{DatabaseConnection, DatabaseRecord, Record};
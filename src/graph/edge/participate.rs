@@ -0,0 +1,154 @@
+use aragog::{
+    query::{Comparison, Filter, QueryResult},
+    DatabaseConnection, DatabaseRecord, EdgeRecord, Record,
+};
+use chrono::NaiveDateTime;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::Error,
+    graph::{vertex::Identity, Edge},
+    upstream::{DataFetcher, DataSource},
+    util::naive_now,
+};
+
+/// Edge to record how an `Identity` takes part in another `Identity`:
+/// following it (e.g. a CyberConnect follow), voting in it (e.g. a Snapshot space), etc.
+#[derive(Debug, Clone, Serialize, Deserialize, Record)]
+#[collection_name = "Participates"]
+pub struct Participate {
+    /// UUID of this record.
+    pub uuid: Uuid,
+    /// Data source (upstream) which provides this info.
+    pub source: DataSource,
+    /// If `from` follows `to`.
+    pub following: bool,
+    /// How many times `from` voted in `to`.
+    pub votes: i64,
+    /// When `from` started to follow / vote (if platform gives such data).
+    pub created_at: Option<NaiveDateTime>,
+    /// When this connection is fetched by us RelationService.
+    pub updated_at: NaiveDateTime,
+    /// Who collects this data.
+    /// It works as a "data cleansing" or "proxy" between `source`s and us.
+    pub fetcher: DataFetcher,
+}
+
+impl Default for Participate {
+    fn default() -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            source: DataSource::default(),
+            following: false,
+            votes: 0,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: Default::default(),
+        }
+    }
+}
+
+impl Participate {
+    pub async fn find_by_from_to(
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+        source: &DataSource,
+    ) -> Result<Option<ParticipateRecord>, Error> {
+        let filter = Filter::new(Comparison::field("_from").equals_str(from.id()))
+            .and(Comparison::field("_to").equals_str(to.id()))
+            .and(Comparison::field("source").equals_str(source));
+        let query = EdgeRecord::<Participate>::query().filter(filter);
+        let result: QueryResult<EdgeRecord<Self>> = query.call(db).await?;
+        if result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(result.first().unwrap().clone().into()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Edge<Identity, Identity, ParticipateRecord> for Participate {
+    fn uuid(&self) -> Option<Uuid> {
+        Some(self.uuid)
+    }
+
+    /// Find an edge by UUID.
+    async fn find_by_uuid(
+        db: &DatabaseConnection,
+        uuid: &Uuid,
+    ) -> Result<Option<ParticipateRecord>, Error> {
+        let result: QueryResult<EdgeRecord<Participate>> = EdgeRecord::<Participate>::query()
+            .filter(Comparison::field("uuid").equals_str(uuid).into())
+            .call(db)
+            .await?;
+
+        if result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(result.first().unwrap().to_owned().into()))
+        }
+    }
+
+    /// Connect 2 vertex. Metadata of an existing edge will be updated.
+    async fn connect(
+        &self,
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+    ) -> Result<ParticipateRecord, Error> {
+        let found = Self::find_by_from_to(db, from, to, &self.source).await?;
+        match found {
+            Some(mut edge) => {
+                edge.following = self.following;
+                edge.votes = self.votes;
+                edge.created_at = self.created_at.or(edge.created_at);
+                edge.updated_at = naive_now();
+                edge.save(db).await?;
+                Ok(edge)
+            }
+            None => Ok(DatabaseRecord::link(from, to, db, self.clone())
+                .await?
+                .into()),
+        }
+    }
+
+    /// Participation is one-way by nature, use `connect` instead.
+    async fn two_way_binding(
+        &self,
+        _db: &DatabaseConnection,
+        _from: &DatabaseRecord<Identity>,
+        _to: &DatabaseRecord<Identity>,
+    ) -> Result<(ParticipateRecord, ParticipateRecord), Error> {
+        Err(Error::General(
+            "Participate can not be a two-way binding".into(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipateRecord(DatabaseRecord<EdgeRecord<Participate>>);
+
+impl std::ops::Deref for ParticipateRecord {
+    type Target = DatabaseRecord<EdgeRecord<Participate>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ParticipateRecord {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<DatabaseRecord<EdgeRecord<Participate>>> for ParticipateRecord {
+    fn from(record: DatabaseRecord<EdgeRecord<Participate>>) -> Self {
+        Self(record)
+    }
+}
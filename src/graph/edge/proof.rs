@@ -2,13 +2,23 @@ use aragog::{
     query::{Comparison, Filter, QueryResult},
     DatabaseConnection, DatabaseRecord, EdgeRecord, Record,
 };
+use arangors_lite::AqlQuery;
 use chrono::{Duration, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
 use uuid::Uuid;
 
 use crate::{
+    config::C,
     error::Error,
-    graph::{vertex::Identity, Edge},
+    graph::{
+        events::{self, ProofAdded},
+        proof_history::{ProofChange, ProofHistory},
+        vertex::Identity,
+        Edge,
+    },
+    metrics::time_db,
     upstream::{DataFetcher, DataSource},
     util::naive_now,
 };
@@ -34,6 +44,103 @@ pub struct Proof {
     /// Who collects this data.
     /// It works as a "data cleansing" or "proxy" between `source`s and us.
     pub fetcher: DataFetcher,
+    /// Whether the signature of this proof is cryptographically verified by us
+    /// (e.g. Keybase sigchain), instead of trusting the upstream.
+    #[serde(default)]
+    pub verified: bool,
+    /// When this connection expires in upstream platform (e.g. Civic Pass).
+    /// Expired connections are ignored by graph traversal.
+    #[serde(default)]
+    pub expired_at: Option<NaiveDateTime>,
+    /// Since when this connection is found invalid (e.g. revoked in upstream).
+    /// Invalid connections are kept, but ignored by graph traversal by default.
+    #[serde(default)]
+    pub invalid_since: Option<NaiveDateTime>,
+    /// Why this connection is invalid.
+    #[serde(default)]
+    pub invalidation_reason: Option<InvalidationReason>,
+    /// How much this connection can be trusted, decided by fetcher.
+    #[serde(default)]
+    pub level: ProofLevel,
+}
+
+/// How much a `Proof` can be trusted, from lowest to highest.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Display,
+    EnumString,
+    EnumIter,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    async_graphql::Enum,
+)]
+pub enum ProofLevel {
+    /// Inferred by us or an upstream, e.g. follows or data defined in config.
+    /// Also for connections saved before levels are recorded.
+    #[default]
+    #[strum(serialize = "heuristic")]
+    #[serde(rename = "heuristic")]
+    #[graphql(name = "heuristic")]
+    Heuristic,
+
+    /// Declared on one side only, e.g. social links in a profile.
+    #[strum(serialize = "self_claimed")]
+    #[serde(rename = "self_claimed")]
+    #[graphql(name = "self_claimed")]
+    SelfClaimed,
+
+    /// Checked by upstream platform, e.g. by OAuth or proof posts, and trusted by us.
+    #[strum(serialize = "platform_verified")]
+    #[serde(rename = "platform_verified")]
+    #[graphql(name = "platform_verified")]
+    PlatformVerified,
+
+    /// Signature verified by us, or ownership recorded on chain.
+    #[strum(serialize = "cryptographic")]
+    #[serde(rename = "cryptographic")]
+    #[graphql(name = "cryptographic")]
+    Cryptographic,
+}
+
+impl ProofLevel {
+    /// All levels not lower than `self`.
+    pub fn and_above(&self) -> Vec<ProofLevel> {
+        ProofLevel::iter().filter(|level| level >= self).collect()
+    }
+}
+
+/// Why a `Proof` is invalidated.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Display,
+    EnumString,
+    PartialEq,
+    Eq,
+    async_graphql::Enum,
+)]
+pub enum InvalidationReason {
+    /// Taken down or broken in upstream platform, e.g. Keybase proof state is not ok.
+    #[strum(serialize = "revoked")]
+    #[serde(rename = "revoked")]
+    #[graphql(name = "revoked")]
+    Revoked,
+
+    /// No longer found in upstream when it's re-fetched.
+    #[strum(serialize = "gone")]
+    #[serde(rename = "gone")]
+    #[graphql(name = "gone")]
+    Gone,
 }
 
 impl Default for Proof {
@@ -45,6 +152,11 @@ impl Default for Proof {
             created_at: None,
             updated_at: naive_now(),
             fetcher: Default::default(),
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: Default::default(),
         }
     }
 }
@@ -57,29 +169,261 @@ impl Proof {
         source: &DataSource,
         record_id: &Option<String>,
     ) -> Result<Option<ProofRecord>, Error> {
-        let mut filter = Filter::new(Comparison::field("_from").equals_str(from.id()))
-            .and(Comparison::field("_to").equals_str(to.id()))
-            .and(Comparison::field("source").equals_str(source));
-        if record_id.is_some() {
-            filter =
-                filter.and(Comparison::field("record_id").equals_str(record_id.clone().unwrap()));
-        }
+        // Edges saved without `record_id` are matched too, if no exact one is found.
+        let aql = AqlQuery::new(
+            r"FOR p IN @@proofs
+            FILTER p._from == @from AND p._to == @to AND p.source == @source
+            FILTER @record_id == null OR p.record_id == @record_id OR p.record_id == null
+            SORT p.record_id == @record_id DESC
+            LIMIT 1
+            RETURN p",
+        )
+        .bind_var("@proofs", COLLECTION_NAME)
+        .bind_var("from", from.id().as_str())
+        .bind_var("to", to.id().as_str())
+        .bind_var("source", source.to_string())
+        .bind_var("record_id", serde_json::to_value(record_id)?)
+        .batch_size(1)
+        .count(false);
+        let result: Vec<ProofRecord> =
+            time_db("proof_by_from_to", db.database().aql_query(aql)).await?;
+
+        Ok(result.into_iter().next())
+    }
+
+    /// Whether any proof of `source` from `from` is saved with `record_id`.
+    pub async fn exists_by_record_id(
+        db: &DatabaseConnection,
+        source: &DataSource,
+        from: &Identity,
+        record_id: &str,
+    ) -> Result<bool, Error> {
+        let aql = AqlQuery::new(
+            r"LET from = FIRST(
+                FOR i IN @@identities
+                    FILTER i.platform == @platform AND i.identity == @identity
+                    RETURN i._id
+            )
+            FOR p IN @@proofs
+            FILTER p._from == from AND p.record_id == @record_id AND p.source == @source
+            LIMIT 1
+            RETURN p._key",
+        )
+        .bind_var("@identities", Identity::COLLECTION_NAME)
+        .bind_var("@proofs", COLLECTION_NAME)
+        .bind_var("platform", from.platform.to_string())
+        .bind_var("identity", from.identity.as_str())
+        .bind_var("source", source.to_string())
+        .bind_var("record_id", record_id)
+        .batch_size(1)
+        .count(false);
+        let result: Vec<String> =
+            time_db("proof_exists_by_record_id", db.database().aql_query(aql)).await?;
+
+        Ok(!result.is_empty())
+    }
+
+    /// Find all proofs from `from` to `to`, regardless of data source.
+    pub async fn find_all_by_from_to(
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+    ) -> Result<Vec<ProofRecord>, Error> {
+        let filter = Filter::new(Comparison::field("_from").equals_str(from.id()))
+            .and(Comparison::field("_to").equals_str(to.id()));
         let query = EdgeRecord::<Proof>::query().filter(filter);
         let result: QueryResult<EdgeRecord<Self>> = query.call(db).await?;
-        if result.len() == 0 {
-            Ok(None)
-        } else {
-            Ok(Some(result.first().unwrap().clone().into()))
+        Ok(result
+            .iter()
+            .map(|record| record.to_owned().into())
+            .collect())
+    }
+
+    /// Proofs of `source` neither fetched nor checked (see `mark_checked`) since `before`,
+    /// with identities on both ends.
+    /// Expired ones are skipped, invalid ones are not (they may be valid again).
+    /// Least recently fetched or checked ones come first, so ones which can't be confirmed gone
+    /// are not picked again and again ahead of the others.
+    pub async fn find_stale(
+        db: &DatabaseConnection,
+        source: &DataSource,
+        before: NaiveDateTime,
+        limit: usize,
+    ) -> Result<Vec<StaleProof>, Error> {
+        let aql = AqlQuery::new(
+            r###"FOR p IN @@proofs
+            LET checked_at = NOT_NULL(p.checked_at, p.updated_at)
+            FILTER p.source == @source AND checked_at < @before
+            FILTER p.expired_at == null OR p.expired_at > @now
+            SORT checked_at
+            LIMIT @limit
+            RETURN {"record": p, "from": DOCUMENT(p._from), "to": DOCUMENT(p._to)}"###,
+        )
+        .bind_var("@proofs", COLLECTION_NAME)
+        .bind_var("source", source.to_string())
+        .bind_var("before", serde_json::to_value(before)?)
+        .bind_var("now", serde_json::to_value(naive_now())?)
+        .bind_var("limit", limit)
+        .count(false);
+
+        Ok(time_db("proof_stale", db.database().aql_query(aql)).await?)
+    }
+
+    /// Record that the scheduler just checked `edge` in upstream, whether it's found again or not.
+    /// `checked_at` is not a field of `Proof`: it's dropped when the edge is saved again,
+    /// which bumps `updated_at` anyway.
+    pub async fn mark_checked(db: &DatabaseConnection, edge: &ProofRecord) -> Result<(), Error> {
+        let aql = AqlQuery::new(
+            r"UPDATE @key WITH { checked_at: @now } IN @@proofs
+            RETURN NEW._key",
+        )
+        .bind_var("@proofs", COLLECTION_NAME)
+        .bind_var("key", edge.key().as_str())
+        .bind_var("now", serde_json::to_value(naive_now())?)
+        .count(false);
+        let _: Vec<String> = time_db("proof_mark_checked", db.database().aql_query(aql)).await?;
+        Ok(())
+    }
+
+    /// Proofs matching `filter`, ordered by `_key`, which also works as cursor:
+    /// only ones after `after` (a `_key`) are returned, `limit` at most.
+    pub async fn find_page(
+        db: &DatabaseConnection,
+        filter: &ProofFilter,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ProofRecord>, Error> {
+        let aql = AqlQuery::new(
+            r###"FOR p IN @@proofs
+            FILTER @source == null OR p.source == @source
+            FILTER @from == null OR p._from == @from
+            FILTER @to == null OR p._to == @to
+            FILTER @created_after == null OR p.created_at > @created_after
+            FILTER @after == null OR p._key > @after
+            SORT p._key
+            LIMIT @limit
+            RETURN p"###,
+        )
+        .bind_var("@proofs", COLLECTION_NAME)
+        .bind_var("source", serde_json::to_value(filter.source)?)
+        .bind_var("from", serde_json::to_value(&filter.from)?)
+        .bind_var("to", serde_json::to_value(&filter.to)?)
+        .bind_var("created_after", serde_json::to_value(filter.created_after)?)
+        .bind_var("after", serde_json::to_value(after)?)
+        .bind_var("limit", limit)
+        .count(false);
+
+        Ok(time_db("proof_page", db.database().aql_query(aql)).await?)
+    }
+
+    /// Mark an edge as invalid, and checked just now.
+    /// The time it's first found invalid is kept.
+    pub async fn invalidate(
+        db: &DatabaseConnection,
+        mut edge: ProofRecord,
+        reason: InvalidationReason,
+    ) -> Result<ProofRecord, Error> {
+        let newly_invalid = edge.invalid_since.is_none();
+        if newly_invalid {
+            edge.0.invalid_since = Some(naive_now());
+            edge.0.invalidation_reason = Some(reason);
+        }
+        edge.0.updated_at = naive_now();
+        edge.0.save(db).await?;
+        if newly_invalid {
+            ProofHistory::log(db, &edge, ProofChange::Invalidated).await?;
         }
+        Ok(edge)
+    }
+
+    /// Refresh an existing edge with what we just fetched:
+    /// upgrade it to `verified` once we verified it (a later unverified
+    /// fetch never downgrades it), follow the renewed expiry, validity and `record_id`,
+    /// and bump `updated_at` to tell it's still there in upstream.
+    /// Changes are logged in `ProofHistory`.
+    async fn refresh_existing(
+        &self,
+        db: &DatabaseConnection,
+        mut edge: ProofRecord,
+    ) -> Result<ProofRecord, Error> {
+        let changes = self.refresh(&mut edge);
+        edge.0.save(db).await?;
+        for change in changes {
+            ProofHistory::log(db, &edge, change).await?;
+        }
+        Ok(edge)
+    }
+
+    /// Update existing `edge` by what's found this time (without saving it),
+    /// returns what's changed.
+    pub(crate) fn refresh(&self, edge: &mut ProofRecord) -> Vec<ProofChange> {
+        let mut changes = vec![];
+        if self.verified && !edge.verified {
+            changes.push(ProofChange::Verified);
+        }
+        if self.record_id.is_some() && self.record_id != edge.record_id {
+            changes.push(ProofChange::RecordIdChanged);
+            edge.0.record_id = self.record_id.clone();
+        }
+        if self.level > edge.level {
+            changes.push(ProofChange::LevelRaised);
+        }
+        match (edge.invalid_since, self.invalid_since) {
+            (None, Some(_)) => changes.push(ProofChange::Invalidated),
+            (Some(_), None) => changes.push(ProofChange::Revalidated),
+            _ => {}
+        }
+
+        edge.0.verified = edge.verified || self.verified;
+        edge.0.expired_at = self.expired_at;
+        // Keep the time it's first found invalid.
+        edge.0.invalid_since = self
+            .invalid_since
+            .and(edge.invalid_since.or(self.invalid_since));
+        edge.0.invalidation_reason = self.invalidation_reason;
+        // Like `verified`, a weaker upstream (e.g. a self-claim) never downgrades it.
+        edge.0.level = edge.level.max(self.level);
+        edge.0.updated_at = naive_now();
+        changes
+    }
+
+    /// Save a new edge, log its creation in `ProofHistory` and notify subscribers.
+    async fn link(
+        &self,
+        db: &DatabaseConnection,
+        from: &DatabaseRecord<Identity>,
+        to: &DatabaseRecord<Identity>,
+    ) -> Result<ProofRecord, Error> {
+        let created: ProofRecord = DatabaseRecord::link(from, to, db, self.clone())
+            .await?
+            .into();
+        ProofHistory::log(db, &created, ProofChange::Created).await?;
+        events::publish(ProofAdded {
+            proof: created.clone(),
+            from: from.clone().into(),
+            to: to.clone().into(),
+        });
+        Ok(created)
     }
 
     pub fn is_outdated(&self) -> bool {
-        let outdated_in = Duration::days(1);
+        let outdated_in = Duration::seconds(C.cache.proof as i64);
         self.updated_at
             .checked_add_signed(outdated_in)
             .unwrap()
             .lt(&naive_now())
     }
+
+    /// Whether this connection is no longer valid in upstream platform.
+    pub fn is_expired(&self) -> bool {
+        self.expired_at
+            .is_some_and(|expired_at| expired_at.lt(&naive_now()))
+    }
+
+    /// Neither expired nor invalidated.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_since.is_none() && !self.is_expired()
+    }
 }
 
 #[async_trait::async_trait]
@@ -114,10 +458,8 @@ impl Edge<Identity, Identity, ProofRecord> for Proof {
     ) -> Result<ProofRecord, Error> {
         let found = Self::find_by_from_to(db, from, to, &self.source, &self.record_id).await?;
         match found {
-            Some(edge) => Ok(edge),
-            None => Ok(DatabaseRecord::link(from, to, db, self.clone())
-                .await?
-                .into()),
+            Some(edge) => self.refresh_existing(db, edge).await,
+            None => self.link(db, from, to).await,
         }
     }
 
@@ -130,18 +472,14 @@ impl Edge<Identity, Identity, ProofRecord> for Proof {
     ) -> Result<(ProofRecord, ProofRecord), Error> {
         let forward =
             match Self::find_by_from_to(db, from, to, &self.source, &self.record_id).await? {
-                Some(edge) => edge,
-                None => DatabaseRecord::link(from, to, db, self.clone())
-                    .await?
-                    .into(),
+                Some(edge) => self.refresh_existing(db, edge).await?,
+                None => self.link(db, from, to).await?,
             };
 
         let reverse =
             match Self::find_by_from_to(db, to, from, &self.source, &self.record_id).await? {
-                Some(edge) => edge,
-                None => DatabaseRecord::link(to, from, db, self.clone())
-                    .await?
-                    .into(),
+                Some(edge) => self.refresh_existing(db, edge).await?,
+                None => self.link(db, to, from).await?,
             };
 
         Ok((forward, reverse))
@@ -165,6 +503,25 @@ impl From<DatabaseRecord<EdgeRecord<Proof>>> for ProofRecord {
     }
 }
 
+/// Conditions of `Proof::find_page`. `None` means no restriction.
+#[derive(Debug, Clone, Default)]
+pub struct ProofFilter {
+    pub source: Option<DataSource>,
+    /// `_id` of the `Identity` this proof is from.
+    pub from: Option<String>,
+    /// `_id` of the `Identity` this proof is to.
+    pub to: Option<String>,
+    pub created_after: Option<NaiveDateTime>,
+}
+
+/// A proof not fetched for a while, found by `Proof::find_stale`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StaleProof {
+    pub record: ProofRecord,
+    pub from: Identity,
+    pub to: Identity,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IdentityFromToRecord {
     /// The Document unique and indexed `_key`
@@ -188,6 +545,12 @@ pub struct IdentityFromToRecord {
     pub created_at: Option<NaiveDateTime>,
     pub updated_at: NaiveDateTime,
     pub fetcher: DataFetcher,
+    #[serde(default)]
+    pub invalid_since: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub invalidation_reason: Option<InvalidationReason>,
+    #[serde(default)]
+    pub level: ProofLevel,
 }
 
 impl IdentityFromToRecord {
@@ -212,6 +575,11 @@ mod tests {
                 created_at: Some(config.fake()),
                 updated_at: naive_now(),
                 fetcher: Default::default(),
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: Default::default(),
             }
         }
     }
@@ -241,4 +609,166 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_find_stale_and_invalidate() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let from = Identity::create_dummy(&db).await?;
+        let to = Identity::create_dummy(&db).await?;
+        let mut connection: Proof = Faker.fake();
+        connection.source = DataSource::Keybase;
+        connection.updated_at = naive_now() - Duration::days(30);
+        let generated = connection.connect(&db, &from, &to).await?;
+
+        let stale = Proof::find_stale(
+            &db,
+            &DataSource::Keybase,
+            naive_now() - Duration::days(29),
+            1000,
+        )
+        .await?;
+        let found = stale
+            .iter()
+            .find(|stale| stale.record.uuid == generated.uuid)
+            .unwrap();
+        assert_eq!(found.from.identity, from.identity);
+        assert_eq!(found.to.identity, to.identity);
+
+        let invalidated = Proof::invalidate(&db, generated, InvalidationReason::Gone).await?;
+        assert!(!invalidated.is_valid());
+        assert_eq!(
+            invalidated.invalidation_reason,
+            Some(InvalidationReason::Gone)
+        );
+
+        // Found again in upstream: valid again.
+        let refreshed = connection.connect(&db, &from, &to).await?;
+        assert_eq!(refreshed.uuid, invalidated.uuid);
+        assert!(refreshed.is_valid());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_checked() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let from = Identity::create_dummy(&db).await?;
+        let to = Identity::create_dummy(&db).await?;
+        let mut connection: Proof = Faker.fake();
+        connection.source = DataSource::Keybase;
+        connection.updated_at = naive_now() - Duration::days(30);
+        let generated = connection.connect(&db, &from, &to).await?;
+        let is_stale = |stale: Vec<StaleProof>| {
+            stale
+                .iter()
+                .any(|stale| stale.record.uuid == generated.uuid)
+        };
+        let before = naive_now() - Duration::days(29);
+
+        assert!(is_stale(
+            Proof::find_stale(&db, &DataSource::Keybase, before, 1000).await?
+        ));
+        // Checked but not found again (nor confirmed gone): not picked until stale again.
+        Proof::mark_checked(&db, &generated).await?;
+        assert!(!is_stale(
+            Proof::find_stale(&db, &DataSource::Keybase, before, 1000).await?
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_history() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let from = Identity::create_dummy(&db).await?;
+        let to = Identity::create_dummy(&db).await?;
+        let mut connection: Proof = Faker.fake();
+        connection.record_id = None;
+        let generated = connection.connect(&db, &from, &to).await?;
+
+        // Upstream gives a record ID, and it's revoked later.
+        connection.record_id = Some("revoked".into());
+        connection.invalid_since = Some(naive_now());
+        connection.invalidation_reason = Some(InvalidationReason::Revoked);
+        let refreshed = connection.connect(&db, &from, &to).await?;
+        assert_eq!(refreshed.uuid, generated.uuid);
+
+        let history = ProofHistory::find_by_proof(&db, &generated.uuid).await?;
+        let changes: Vec<ProofChange> = history.iter().map(|h| h.change).collect();
+        assert_eq!(
+            changes,
+            vec![
+                ProofChange::Created,
+                ProofChange::RecordIdChanged,
+                ProofChange::Invalidated
+            ]
+        );
+        assert_eq!(history.last().unwrap().record_id, Some("revoked".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_level() {
+        let mut edge = ProofRecord::default();
+        edge.0.level = ProofLevel::PlatformVerified;
+        let mut proof: Proof = Faker.fake();
+        proof.record_id = None;
+
+        proof.level = ProofLevel::SelfClaimed;
+        assert!(proof.refresh(&mut edge).is_empty());
+        assert_eq!(edge.level, ProofLevel::PlatformVerified);
+
+        proof.level = ProofLevel::Cryptographic;
+        assert_eq!(proof.refresh(&mut edge), vec![ProofChange::LevelRaised]);
+        assert_eq!(edge.level, ProofLevel::Cryptographic);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let mut proof: Proof = Faker.fake();
+        assert!(!proof.is_expired());
+
+        proof.expired_at = Some(naive_now() - Duration::days(1));
+        assert!(proof.is_expired());
+
+        proof.expired_at = Some(naive_now() + Duration::days(1));
+        assert!(!proof.is_expired());
+        assert!(proof.is_valid());
+
+        proof.invalid_since = Some(naive_now());
+        assert!(!proof.is_valid());
+    }
+
+    #[test]
+    fn test_level_and_above() {
+        assert_eq!(
+            ProofLevel::PlatformVerified.and_above(),
+            vec![ProofLevel::PlatformVerified, ProofLevel::Cryptographic]
+        );
+        assert_eq!(ProofLevel::Heuristic.and_above().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_find_page() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let from = Identity::create_dummy(&db).await?;
+        for _ in 0..3 {
+            let to = Identity::create_dummy(&db).await?;
+            let proof: Proof = Faker.fake();
+            proof.connect(&db, &from, &to).await?;
+        }
+        let filter = ProofFilter {
+            from: Some(from.id().clone()),
+            ..Default::default()
+        };
+
+        let first_page = Proof::find_page(&db, &filter, None, 2).await?;
+        assert_eq!(2, first_page.len());
+        let after = first_page.last().unwrap().key().clone();
+        let second_page = Proof::find_page(&db, &filter, Some(&after), 2).await?;
+        assert_eq!(1, second_page.len());
+        assert!(second_page[0].key() > &after);
+        Ok(())
+    }
 }
@@ -1,8 +1,10 @@
 use crate::{
+    config::C,
     error::Error,
     graph::edge::{Hold, HoldRecord},
     graph::vertex::{Identity, IdentityRecord},
     graph::{ConnectionPool, Edge},
+    metrics::time_db,
     upstream::{DataFetcher, DataSource, Platform},
     util::naive_now,
 };
@@ -62,6 +64,41 @@ pub enum DomainNameSystem {
     #[graphql(name = "space_id")]
     SpaceId,
 
+    /// Solana Name Service (Bonfida)
+    /// https://sns.id
+    #[strum(serialize = "sns")]
+    #[serde(rename = "sns")]
+    #[graphql(name = "sns")]
+    SNS,
+
+    /// Aptos Names
+    /// https://www.aptosnames.com
+    #[strum(serialize = "aptos_names")]
+    #[serde(rename = "aptos_names")]
+    #[graphql(name = "aptos_names")]
+    AptosNames,
+
+    /// Avvy Domains on Avalanche
+    /// https://avvy.domains
+    #[strum(serialize = "avvy")]
+    #[serde(rename = "avvy")]
+    #[graphql(name = "avvy")]
+    Avvy,
+
+    /// Interchain Name Service on Osmosis
+    /// https://icns.xyz
+    #[strum(serialize = "icns")]
+    #[serde(rename = "icns")]
+    #[graphql(name = "icns")]
+    ICNS,
+
+    /// Stargaze Names
+    /// https://www.stargaze.zone/names
+    #[strum(serialize = "stargaze_names")]
+    #[serde(rename = "stargaze_names")]
+    #[graphql(name = "stargaze_names")]
+    StargazeNames,
+
     #[default]
     #[strum(serialize = "unknown")]
     #[serde(rename = "unknown")]
@@ -76,6 +113,11 @@ impl From<DomainNameSystem> for Platform {
             DomainNameSystem::UnstoppableDomains => Platform::UnstoppableDomains,
             DomainNameSystem::Lens => Platform::Lens,
             DomainNameSystem::SpaceId => Platform::SpaceId,
+            DomainNameSystem::SNS => Platform::SNS,
+            DomainNameSystem::AptosNames => Platform::AptosNames,
+            DomainNameSystem::Avvy => Platform::Avvy,
+            DomainNameSystem::ICNS => Platform::ICNS,
+            DomainNameSystem::StargazeNames => Platform::StargazeNames,
             _ => Platform::Unknown,
         }
     }
@@ -103,6 +145,12 @@ pub struct Resolve {
     pub system: DomainNameSystem,
     /// Name of domain (e.g., `vitalik.eth`)
     pub name: String,
+    /// Address this name is resolving to, as reported by the upstream.
+    #[serde(default)]
+    pub resolved_address: Option<String>,
+    /// When this name registration expires. `None` if unknown or never.
+    #[serde(default)]
+    pub expire_at: Option<NaiveDateTime>,
     /// Who collects this data.
     /// It works as a "data cleansing" or "proxy" between `source`s and us.
     pub fetcher: DataFetcher,
@@ -117,6 +165,8 @@ impl Default for Resolve {
             source: Default::default(),
             name: Default::default(),
             system: Default::default(),
+            resolved_address: None,
+            expire_at: None,
             fetcher: Default::default(),
             updated_at: naive_now(),
         }
@@ -172,7 +222,7 @@ impl Resolve {
             .batch_size(1)
             .count(false);
 
-        let result: Vec<ResolveEdge> = db.aql_query(aql).await?;
+        let result: Vec<ResolveEdge> = time_db("resolve_by_ens_name", db.aql_query(aql)).await?;
         if result.len() == 0 {
             let aql_str = r###"
             FOR h IN @@holds FILTER h.id == @name
@@ -186,7 +236,7 @@ impl Resolve {
                 .batch_size(1)
                 .count(false);
 
-            let res: Vec<HoldEdge> = db.aql_query(aql).await?;
+            let res: Vec<HoldEdge> = time_db("resolve_ens_name_holds", db.aql_query(aql)).await?;
             if res.len() > 0 {
                 let r = res.first().unwrap().to_owned();
                 let mut resolve_edge = ResolveEdge::from(Resolve {
@@ -194,6 +244,8 @@ impl Resolve {
                     source: r.record.source,
                     system: DomainNameSystem::ENS,
                     name: name.to_string(),
+                    resolved_address: None,
+                    expire_at: None,
                     fetcher: r.record.fetcher,
                     updated_at: r.record.updated_at,
                 });
@@ -245,7 +297,7 @@ impl Resolve {
             .batch_size(1)
             .count(false);
 
-        let result: Vec<ResolveEdge> = db.aql_query(aql).await?;
+        let result: Vec<ResolveEdge> = time_db("resolve_by_domain_name", db.aql_query(aql)).await?;
         if result.len() == 0 {
             let aql_str = r###"
             FOR i IN @@identities
@@ -264,7 +316,8 @@ impl Resolve {
                 .batch_size(1)
                 .count(false);
 
-            let res: Vec<HoldEdge> = db.aql_query(aql).await?;
+            let res: Vec<HoldEdge> =
+                time_db("resolve_domain_name_holds", db.aql_query(aql)).await?;
             if res.len() > 0 {
                 let record = res.first().unwrap().to_owned().record;
                 let mut resolve_edge = ResolveEdge::from(Resolve {
@@ -279,6 +332,8 @@ impl Resolve {
                         .unwrap()
                         .identity
                         .clone(),
+                    resolved_address: None,
+                    expire_at: None,
                     fetcher: record.fetcher,
                     updated_at: record.updated_at,
                 });
@@ -293,8 +348,14 @@ impl Resolve {
         }
     }
 
+    /// Whether this name registration is expired.
+    pub fn is_expired(&self) -> bool {
+        self.expire_at
+            .is_some_and(|expire_at| expire_at < naive_now())
+    }
+
     pub fn is_outdated(&self) -> bool {
-        let outdated_in = Duration::days(1);
+        let outdated_in = Duration::seconds(C.cache.resolve as i64);
         self.updated_at
             .checked_add_signed(outdated_in)
             .unwrap()
@@ -383,7 +444,16 @@ impl<T1: Record + std::marker::Sync, T2: Record + std::marker::Sync> Edge<T1, T2
                 .await?
                 .into())
         } else {
-            Ok(result.first().unwrap().clone().into())
+            // Keep resolution info up to date, e.g. after a renewal.
+            let mut edge: ResolveRecord = result.first().unwrap().clone().into();
+            edge.0.resolved_address = self
+                .resolved_address
+                .clone()
+                .or(edge.resolved_address.clone());
+            edge.0.expire_at = self.expire_at.or(edge.expire_at);
+            edge.0.updated_at = naive_now();
+            edge.0.save(db).await?;
+            Ok(edge)
         }
     }
 
@@ -449,6 +519,18 @@ mod tests {
     use crate::graph::edge::resolve::DomainNameSystem;
     use crate::graph::edge::Resolve;
     use crate::upstream::Platform;
+    use crate::util::naive_now;
+    use chrono::Duration;
+
+    #[test]
+    fn test_is_expired() {
+        let mut resolve = Resolve::default();
+        assert!(!resolve.is_expired());
+        resolve.expire_at = Some(naive_now() - Duration::days(1));
+        assert!(resolve.is_expired());
+        resolve.expire_at = Some(naive_now() + Duration::days(1));
+        assert!(!resolve.is_expired());
+    }
 
     #[tokio::test]
     async fn test_find_by_ens_name() -> Result<(), Error> {
@@ -0,0 +1,40 @@
+use crate::{
+    graph::{edge::ProofRecord, vertex::IdentityRecord},
+    upstream::Platform,
+};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+/// Events a slow subscriber can fall behind before missing some.
+const CAPACITY: usize = 1024;
+
+lazy_static! {
+    /// New proofs saved in this process, no matter by a worker, a fetch or a mutation.
+    static ref PROOF_ADDED: Sender<ProofAdded> = broadcast::channel(CAPACITY).0;
+}
+
+/// A new `Proof` edge is saved.
+#[derive(Clone, Debug, Default)]
+pub struct ProofAdded {
+    pub proof: ProofRecord,
+    pub from: IdentityRecord,
+    pub to: IdentityRecord,
+}
+
+impl ProofAdded {
+    /// Whether `platform`/`identity` is on either end of this proof.
+    pub fn touches(&self, platform: &Platform, identity: &str) -> bool {
+        [&self.from, &self.to]
+            .iter()
+            .any(|record| record.platform == *platform && record.identity == identity)
+    }
+}
+
+/// Notify subscribers. Nothing happens if there's none.
+pub fn publish(event: ProofAdded) {
+    let _ = PROOF_ADDED.send(event);
+}
+
+/// Receive events published from now on.
+pub fn subscribe() -> Receiver<ProofAdded> {
+    PROOF_ADDED.subscribe()
+}
@@ -0,0 +1,73 @@
+use crate::{
+    error::Error,
+    graph::{db_connection, migrations::COLLECTIONS},
+};
+use arangors_lite::AqlQuery;
+use http::StatusCode;
+use serde_json::{json, Value};
+use std::io::Write;
+
+/// Documents read from DB in one query.
+const PAGE_SIZE: usize = 1000;
+
+/// Write every document of `collections` (all of them if empty) to `out`,
+/// as one `{"collection": ..., "document": ...}` per line.
+/// Documents are read page by page in `_key` order, so the whole DB is never loaded at once.
+/// Returns how many documents are written.
+pub async fn export<W: Write>(out: &mut W, collections: &[String]) -> Result<usize, Error> {
+    let collections: Vec<&str> = if collections.is_empty() {
+        COLLECTIONS.iter().map(|&(name, _)| name).collect()
+    } else {
+        collections.iter().map(String::as_str).collect()
+    };
+    if let Some(unknown) = collections
+        .iter()
+        .find(|name| !COLLECTIONS.iter().any(|&(known, _)| known == **name))
+    {
+        return Err(Error::ParamError(format!(
+            "Unknown collection: {}",
+            unknown
+        )));
+    }
+
+    let db = db_connection().await?;
+    let mut written = 0;
+    for collection in collections {
+        let mut after = String::new();
+        loop {
+            let aql = AqlQuery::new(
+                r"FOR d IN @@collection
+                FILTER d._key > @after
+                SORT d._key
+                LIMIT @limit
+                RETURN d",
+            )
+            .bind_var("@collection", collection)
+            .bind_var("after", after.as_str())
+            .bind_var("limit", PAGE_SIZE);
+            let page: Vec<Value> = db.database().aql_query(aql).await?;
+            for document in page.iter() {
+                writeln!(
+                    out,
+                    "{}",
+                    json!({ "collection": collection, "document": document })
+                )
+                .map_err(write_failed)?;
+            }
+            written += page.len();
+            match page.last().and_then(|last| last["_key"].as_str()) {
+                Some(key) if page.len() == PAGE_SIZE => after = key.to_string(),
+                _ => break,
+            }
+        }
+    }
+    out.flush().map_err(write_failed)?;
+    Ok(written)
+}
+
+fn write_failed(err: std::io::Error) -> Error {
+    Error::General(
+        format!("Export failed: {}", err),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )
+}
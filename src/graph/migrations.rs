@@ -0,0 +1,375 @@
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        arango_api,
+        edge::{Annotation, Hold, Participate, Proof, Resolve},
+        ensure_autocomplete_view, ensure_relation_view,
+        proof_history::ProofHistory,
+        vertex::{Contract, CryptoKey, Identity},
+    },
+    util::naive_now,
+};
+use aragog::Record;
+use arangors_lite::{AqlQuery, Connection, Database};
+use http::StatusCode;
+use hyper::Method;
+use serde_json::{json, to_value, Value};
+use tracing::{info, warn};
+
+/// Where applied `Migration`s are recorded, keyed by their versions.
+pub const MIGRATIONS_COLLECTION: &str = "_migrations";
+
+/// `(name, is_edge_collection)` of all collections.
+pub(crate) const COLLECTIONS: &[(&str, bool)] = &[
+    (Identity::COLLECTION_NAME, false),
+    (Contract::COLLECTION_NAME, false),
+    (CryptoKey::COLLECTION_NAME, false),
+    (ProofHistory::COLLECTION_NAME, false),
+    (Proof::COLLECTION_NAME, true),
+    (Hold::COLLECTION_NAME, true),
+    (Resolve::COLLECTION_NAME, true),
+    (Participate::COLLECTION_NAME, true),
+    (Annotation::COLLECTION_NAME, true),
+];
+
+/// A persistent index.
+struct IndexSpec {
+    name: &'static str,
+    collection: &'static str,
+    fields: &'static [&'static str],
+    unique: bool,
+    sparse: bool,
+}
+
+const INDEXES: &[IndexSpec] = &[
+    IndexSpec {
+        name: "PlatformIdentityUniqueness",
+        collection: Identity::COLLECTION_NAME,
+        fields: &["platform", "identity"],
+        unique: true,
+        sparse: true,
+    },
+    IndexSpec {
+        name: "AddressChainUniqueness",
+        collection: Contract::COLLECTION_NAME,
+        fields: &["address", "chain"],
+        unique: true,
+        sparse: true,
+    },
+    IndexSpec {
+        name: "FingerprintUniqueness",
+        collection: CryptoKey::COLLECTION_NAME,
+        fields: &["fingerprint"],
+        unique: true,
+        sparse: true,
+    },
+    IndexSpec {
+        name: "ProofHistoryProof",
+        collection: ProofHistory::COLLECTION_NAME,
+        fields: &["proof"],
+        unique: false,
+        sparse: false,
+    },
+    IndexSpec {
+        name: "ProofSource",
+        collection: Proof::COLLECTION_NAME,
+        fields: &["source"],
+        unique: false,
+        sparse: false,
+    },
+    IndexSpec {
+        name: "ProofRecordId",
+        collection: Proof::COLLECTION_NAME,
+        fields: &["record_id"],
+        unique: false,
+        sparse: true,
+    },
+    // Of concurrent `batch::insert`s of the same proof, only one is saved.
+    IndexSpec {
+        name: "ProofRecordUniqueness",
+        collection: Proof::COLLECTION_NAME,
+        fields: &["_from", "_to", "source", "record_id"],
+        unique: true,
+        sparse: true,
+    },
+];
+
+/// `(name, edge collection, from, to)` of all named graphs.
+const GRAPHS: &[(&str, &str, &str, &str)] = &[
+    (
+        "identities_proofs_graph",
+        Proof::COLLECTION_NAME,
+        Identity::COLLECTION_NAME,
+        Identity::COLLECTION_NAME,
+    ),
+    (
+        "identities_contracts_graph",
+        Hold::COLLECTION_NAME,
+        Identity::COLLECTION_NAME,
+        Contract::COLLECTION_NAME,
+    ),
+];
+
+/// Create collections, indexes, graphs and ArangoSearch views the server needs, if missing.
+/// Everything existing is left as is, so it is safe to run on every startup.
+/// Should be called before any `DatabaseConnection` is built, since it refuses to build without collections.
+pub async fn bootstrap() -> Result<(), Error> {
+    for &(name, is_edge) in COLLECTIONS {
+        // 2: document collection, 3: edge collection.
+        let collection = json!({ "name": name, "type": if is_edge { 3 } else { 2 } });
+        created(
+            "collection",
+            name,
+            arango_api(Method::POST, "collection", &collection).await?,
+        )?;
+    }
+    // Names starting with `_` are reserved for system collections.
+    let collection = json!({ "name": MIGRATIONS_COLLECTION, "isSystem": true });
+    created(
+        "collection",
+        MIGRATIONS_COLLECTION,
+        arango_api(Method::POST, "collection", &collection).await?,
+    )?;
+
+    // Every record has an `uuid` to be looked up with (optional for `Identity`).
+    for &(collection, _) in COLLECTIONS {
+        let index = json!({
+            "type": "persistent",
+            "name": format!("{}Uuid", collection),
+            "fields": ["uuid"],
+            "sparse": true,
+        });
+        let path = format!("index?collection={}", collection);
+        created(
+            "index",
+            collection,
+            arango_api(Method::POST, &path, &index).await?,
+        )?;
+    }
+    for spec in INDEXES {
+        let index = json!({
+            "type": "persistent",
+            "name": spec.name,
+            "fields": spec.fields,
+            "unique": spec.unique,
+            "sparse": spec.sparse,
+        });
+        let path = format!("index?collection={}", spec.collection);
+        created(
+            "index",
+            spec.name,
+            arango_api(Method::POST, &path, &index).await?,
+        )?;
+    }
+
+    for &(name, edge, from, to) in GRAPHS {
+        let graph = json!({
+            "name": name,
+            "edgeDefinitions": [{ "collection": edge, "from": [from], "to": [to] }],
+        });
+        created(
+            "graph",
+            name,
+            arango_api(Method::POST, "gharial", &graph).await?,
+        )?;
+    }
+
+    ensure_relation_view(&raw_db().await?).await?;
+    // Search is still available without it, only autocomplete is not.
+    if let Err(err) = ensure_autocomplete_view().await {
+        warn!("Autocomplete view is not ready: {}", err);
+    }
+
+    info!("Database {} is bootstrapped", C.db.db);
+    Ok(())
+}
+
+/// One step of a `Migration`.
+pub enum Step {
+    /// Run an AQL query.
+    Aql(&'static str),
+    /// Run an AQL query for each of `collections`, bound as `@@collection`.
+    AqlForEach {
+        query: &'static str,
+        collections: &'static [&'static str],
+    },
+    /// Rename a collection, skipped if `from` is gone (renamed already).
+    RenameCollection {
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+/// A versioned change of existing data (adding a field, renaming a collection, etc.),
+/// applied once by `migrate` and recorded in `MIGRATIONS_COLLECTION`.
+/// A migration interrupted halfway is run again from its first step, so steps should be idempotent.
+pub struct Migration {
+    /// `{yyyymmdd}_{what}`, so they are sorted in the order they are added.
+    pub version: &'static str,
+    pub description: &'static str,
+    pub steps: &'static [Step],
+}
+
+/// All migrations, in order. Append new ones to the end, never change applied ones.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: "20261016_backfill_proof_level",
+        description: "Save `level` of proofs saved before levels are recorded",
+        steps: &[Step::Aql(
+            r#"FOR p IN Proofs FILTER p.level == null UPDATE p WITH { level: "heuristic" } IN Proofs"#,
+        )],
+    },
+    Migration {
+        version: "20261016_lowercase_evm_addresses",
+        description: "Merge EVM address identities only different in case, and lowercase them",
+        // Platforms canonicalized as `EvmAddress`, see `util::canonical::canonicalizer`.
+        steps: &[
+            // Move edges of other variants to the kept one (lowercase if exists).
+            Step::AqlForEach {
+                query: r#"LET merges = (
+                    FOR i IN Identities
+                        FILTER i.platform IN ["ethereum", "proof_of_humanity"]
+                        COLLECT platform = i.platform, address = LOWER(i.identity) INTO variants = i
+                        FILTER LENGTH(variants) > 1
+                        LET ids = (FOR v IN variants SORT v.identity == address DESC, v._key RETURN v._id)
+                        FOR dup IN SLICE(ids, 1)
+                            RETURN { dup, keep: ids[0] }
+                )
+                FOR m IN merges
+                    FOR e IN @@collection
+                        FILTER e._from == m.dup OR e._to == m.dup
+                        UPDATE e WITH {
+                            _from: e._from == m.dup ? m.keep : e._from,
+                            _to: e._to == m.dup ? m.keep : e._to
+                        } IN @@collection"#,
+                collections: EDGE_COLLECTIONS,
+            },
+            // Moved edges may duplicate ones the kept variant has (same ends and the key `batch::upsert`
+            // finds them by, e.g. `source` and `record_id` of proofs): keep the latest updated.
+            Step::AqlForEach {
+                query: r#"LET dups = (
+                    FOR e IN @@collection
+                        COLLECT from = e._from, to = e._to,
+                            key = [e.source, e.record_id, e.id, e.system, e.name] INTO edges = e
+                        FILTER LENGTH(edges) > 1
+                        LET keys = (FOR d IN edges SORT d.updated_at DESC, d._key RETURN d._key)
+                        FOR dup IN SLICE(keys, 1)
+                            RETURN dup
+                )
+                FOR key IN dups REMOVE key IN @@collection"#,
+                collections: EDGE_COLLECTIONS,
+            },
+            // Remove other variants, which have no edge now.
+            Step::Aql(
+                r#"LET dups = (
+                    FOR i IN Identities
+                        FILTER i.platform IN ["ethereum", "proof_of_humanity"]
+                        COLLECT platform = i.platform, address = LOWER(i.identity) INTO variants = i
+                        FILTER LENGTH(variants) > 1
+                        LET keys = (FOR v IN variants SORT v.identity == address DESC, v._key RETURN v._key)
+                        FOR dup IN SLICE(keys, 1)
+                            RETURN dup
+                )
+                FOR key IN dups REMOVE key IN Identities"#,
+            ),
+            Step::Aql(
+                r#"LET mixed = (
+                    FOR i IN Identities
+                        FILTER i.platform IN ["ethereum", "proof_of_humanity"]
+                            AND i.identity != LOWER(i.identity)
+                        RETURN { key: i._key, identity: LOWER(i.identity) }
+                )
+                FOR m IN mixed UPDATE m.key WITH { identity: m.identity } IN Identities"#,
+            ),
+        ],
+    },
+];
+
+/// Edge collections, which may point to any vertex.
+const EDGE_COLLECTIONS: &[&str] = &[
+    Proof::COLLECTION_NAME,
+    Hold::COLLECTION_NAME,
+    Resolve::COLLECTION_NAME,
+    Participate::COLLECTION_NAME,
+    Annotation::COLLECTION_NAME,
+];
+
+/// Migrations not applied yet, in order.
+pub async fn pending() -> Result<Vec<&'static Migration>, Error> {
+    let db = raw_db().await?;
+    let aql = AqlQuery::new("FOR m IN @@migrations RETURN m._key")
+        .bind_var("@migrations", MIGRATIONS_COLLECTION);
+    let applied: Vec<String> = db.aql_query(aql).await?;
+    Ok(MIGRATIONS
+        .iter()
+        .filter(|migration| !applied.iter().any(|version| version == migration.version))
+        .collect())
+}
+
+/// Apply all pending migrations in order, returns versions applied.
+/// Stops at the first failure, leaving the failed one and those after it pending.
+pub async fn migrate() -> Result<Vec<&'static str>, Error> {
+    let db = raw_db().await?;
+    let mut applied = vec![];
+    for migration in pending().await? {
+        info!("Migrating {}: {}", migration.version, migration.description);
+        for step in migration.steps {
+            step.run(&db).await?;
+        }
+        let aql = AqlQuery::new(
+            r"INSERT { _key: @version, description: @description, applied_at: @now }
+            INTO @@migrations",
+        )
+        .bind_var("@migrations", MIGRATIONS_COLLECTION)
+        .bind_var("version", migration.version)
+        .bind_var("description", migration.description)
+        .bind_var("now", to_value(naive_now())?);
+        let _: Vec<Value> = db.aql_query(aql).await?;
+        applied.push(migration.version);
+    }
+    Ok(applied)
+}
+
+impl Step {
+    async fn run(&self, db: &Database) -> Result<(), Error> {
+        match self {
+            Step::Aql(query) => {
+                let _: Vec<Value> = db.aql_query(AqlQuery::new(query)).await?;
+            }
+            Step::AqlForEach { query, collections } => {
+                for collection in collections.iter() {
+                    let aql = AqlQuery::new(query).bind_var("@collection", *collection);
+                    let _: Vec<Value> = db.aql_query(aql).await?;
+                }
+            }
+            Step::RenameCollection { from, to } => {
+                let path = format!("collection/{}/rename", from);
+                let status = arango_api(Method::PUT, &path, &json!({ "name": to })).await?;
+                if !status.is_success() && status != StatusCode::NOT_FOUND {
+                    return Err(Error::General(
+                        format!("Rename collection {} to {} failed: {}", from, to, status),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn raw_db() -> Result<Database, Error> {
+    let conn = Connection::establish_basic_auth(&C.db.host, &C.db.username, &C.db.password).await?;
+    Ok(conn.db(&C.db.db).await?)
+}
+
+/// `Conflict` means it already exists.
+fn created(kind: &str, name: &str, status: StatusCode) -> Result<(), Error> {
+    if status.is_success() || status == StatusCode::CONFLICT {
+        return Ok(());
+    }
+    Err(Error::General(
+        format!("Create {} {} failed: {}", kind, name, status),
+        StatusCode::INTERNAL_SERVER_ERROR,
+    ))
+}
@@ -1,34 +1,46 @@
 pub mod arangopool;
+pub mod batch;
 pub mod edge;
+pub mod events;
+pub mod export;
+pub mod migrations;
+pub mod proof_history;
 mod tests;
+pub mod tx;
 pub mod vertex;
 use std::collections::HashMap;
 
-use crate::{config::C, error::Error};
-use aragog::{AuthMode, DatabaseConnection, OperationOptions};
-pub use arangopool::ConnectionPool;
+use crate::{
+    config::C,
+    error::Error,
+    util::{make_client, request_with_timeout},
+};
+use aragog::{AuthMode, DatabaseConnection, OperationOptions, Record};
+pub use arangopool::{db_connection, shared_pool, ConnectionPool, PooledConnection};
 use arangors_lite::{
     view::ArangoSearchViewLink, view::ArangoSearchViewPropertiesOptions, view::ViewDescription,
     view::ViewOptions, view::ViewType, Connection, Database,
 };
 pub use edge::Edge;
+use http::StatusCode;
+use hyper::{Body, Method, Request};
 use serde::Deserialize;
+use serde_json::{json, Value};
+pub use tx::GraphTx;
+use uuid::Uuid;
 pub use vertex::Vertex;
 
 use self::{
-    edge::{Hold, HoldRecord, Proof, Resolve},
-    vertex::{Contract, ContractRecord, Identity, IdentityRecord},
+    edge::{Annotation, Hold, HoldRecord, Participate, Proof, Resolve},
+    vertex::{Contract, ContractRecord, CryptoKey, CryptoKeyRecord, Identity, IdentityRecord},
 };
 
-// TODO: move this under `vertex/`
-#[derive(Deserialize, Debug)]
-pub struct CryptoIdentity {
-    pub uuid: String,
-    pub public_key: String,
-    pub algorithm: String,
-    pub curve: String,
-    pub created_at: u128,
-}
+/// ArangoSearch view for identity autocomplete.
+pub const AUTOCOMPLETE_VIEW: &str = "autocomplete";
+/// Analyzer indexing lowercased word prefixes of `identity` and `display_name`.
+pub const AUTOCOMPLETE_ANALYZER: &str = "autocomplete_prefix";
+/// Longest prefix indexed by `AUTOCOMPLETE_ANALYZER`.
+pub const AUTOCOMPLETE_MAX_PREFIX: usize = 16;
 
 // TODO: move this under `edge/`
 #[derive(Deserialize, Debug)]
@@ -37,6 +49,22 @@ pub struct PubKeyDerivation {
     pub method: String,
 }
 
+/// Amount of documents created by an import (see `batch::upsert`), to tell how much a fetch brings in.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GraphStats {
+    /// Amount of identities created.
+    pub identities: u64,
+    /// Amount of connections created.
+    pub edges: u64,
+}
+
+impl std::ops::AddAssign for GraphStats {
+    fn add_assign(&mut self, other: Self) {
+        self.identities += other.identities;
+        self.edges += other.edges;
+    }
+}
+
 /// Create a database connection instance.
 pub async fn new_db_connection() -> Result<DatabaseConnection, Error> {
     let connection = DatabaseConnection::builder()
@@ -61,6 +89,18 @@ pub async fn create_identity_to_contract_record(
     Ok((from_record, to_record, hold_record))
 }
 
+pub async fn create_identity_to_crypto_key_record(
+    db: &DatabaseConnection,
+    from: &Identity,
+    to: &CryptoKey,
+    hold: &Hold,
+) -> Result<(IdentityRecord, CryptoKeyRecord, HoldRecord), Error> {
+    let from_record = from.create_or_update(db).await?;
+    let to_record = to.create_or_update(db).await?;
+    let hold_record = hold.connect(db, &from_record, &to_record).await?;
+    Ok((from_record, to_record, hold_record))
+}
+
 pub async fn create_identity_to_identity_record(
     db: &DatabaseConnection,
     from: &Identity,
@@ -109,14 +149,69 @@ pub async fn create_domain_resolve_record(
     Ok(())
 }
 
+/// Save records of a domain held by an address:
+/// `Hold` and 'regular' `Resolve` from domain to address,
+/// and 'reverse' `Resolve` from address to domain if it is the primary name of the address.
+pub async fn create_domain_records(
+    db: &DatabaseConnection,
+    address: &Identity,
+    domain: &Identity,
+    hold: &Hold,
+    resolve: &Resolve,
+    is_primary: bool,
+) -> Result<(), Error> {
+    let address_record = address.create_or_update(db).await?;
+    let domain_record = domain.create_or_update(db).await?;
+    hold.connect(db, &address_record, &domain_record).await?;
+    resolve.connect(db, &domain_record, &address_record).await?;
+    if is_primary {
+        let reverse = Resolve {
+            uuid: Uuid::new_v4(),
+            ..resolve.clone()
+        };
+        reverse.connect(db, &address_record, &domain_record).await?;
+    }
+    Ok(())
+}
+
+pub async fn create_identity_to_identity_participate_record(
+    db: &DatabaseConnection,
+    from: &Identity,
+    to: &Identity,
+    participate: &Participate,
+) -> Result<(), Error> {
+    let from_record = from.create_or_update(db).await?;
+    let to_record = to.create_or_update(db).await?;
+    participate.connect(db, &from_record, &to_record).await?;
+    Ok(())
+}
+
+pub async fn create_identity_to_identity_annotation_record(
+    db: &DatabaseConnection,
+    from: &Identity,
+    to: &Identity,
+    annotation: &Annotation,
+) -> Result<(), Error> {
+    let from_record = from.create_or_update(db).await?;
+    let to_record = to.create_or_update(db).await?;
+    annotation.connect(db, &from_record, &to_record).await?;
+    Ok(())
+}
+
 // Create a row database connection instance for arangodb
 pub async fn new_raw_db_connection() -> Result<Database, Error> {
     let conn = Connection::establish_basic_auth(&C.db.host, &C.db.username, &C.db.password).await?;
     let db = conn.db(&C.db.db).await?;
+    ensure_relation_view(&db).await?;
+    Ok(db)
+}
+
+/// Create `relation` view if not exists.
+pub(crate) async fn ensure_relation_view(db: &Database) -> Result<(), Error> {
     let views: Vec<ViewDescription> = db.list_views().await?;
     let view_name = "relation";
     if views.into_iter().any(|r| r.name == view_name) {
-        return Ok(db);
+        return Ok(());
     }
 
     /* else create_arangosearch_view: [relation] {
@@ -161,5 +256,109 @@ pub async fn new_raw_db_connection() -> Result<Database, Error> {
         .properties(properties)
         .build();
     db.create_view(view_options).await?;
-    Ok(db)
+    Ok(())
+}
+
+/// Create `AUTOCOMPLETE_ANALYZER` and `AUTOCOMPLETE_VIEW` if not exist.
+/// Should be called once on startup.
+pub async fn ensure_autocomplete_view() -> Result<(), Error> {
+    create_autocomplete_analyzer().await?;
+
+    let conn = Connection::establish_basic_auth(&C.db.host, &C.db.username, &C.db.password).await?;
+    let db = conn.db(&C.db.db).await?;
+    let views: Vec<ViewDescription> = db.list_views().await?;
+    if views.into_iter().any(|r| r.name == AUTOCOMPLETE_VIEW) {
+        return Ok(());
+    }
+
+    let prefix_link = || {
+        ArangoSearchViewLink::builder()
+            .analyzers(vec![AUTOCOMPLETE_ANALYZER.to_string()])
+            .build()
+    };
+    let fields = HashMap::from([
+        ("identity".to_string(), prefix_link()),
+        ("display_name".to_string(), prefix_link()),
+        (
+            "platform".to_string(),
+            ArangoSearchViewLink::builder()
+                .analyzers(vec![String::from("identity")])
+                .build(),
+        ),
+    ]);
+    let links = ArangoSearchViewLink::builder()
+        .include_all_fields(false)
+        .fields(fields)
+        .build();
+    let properties = ArangoSearchViewPropertiesOptions::builder()
+        .links(HashMap::from([(
+            Identity::COLLECTION_NAME.to_string(),
+            links,
+        )]))
+        .build();
+    let view_options = ViewOptions::builder()
+        .name(AUTOCOMPLETE_VIEW.to_string())
+        .typ(ViewType::ArangoSearchView)
+        .properties(properties)
+        .build();
+    db.create_view(view_options).await?;
+    Ok(())
+}
+
+/// Analyzers are not supported by `arangors_lite`, so it is created by HTTP API.
+/// Creating an existing analyzer with the same definition is a no-op.
+async fn create_autocomplete_analyzer() -> Result<(), Error> {
+    let analyzer = json!({
+        "name": AUTOCOMPLETE_ANALYZER,
+        "type": "text",
+        "properties": {
+            "locale": "en",
+            "case": "lower",
+            "accent": false,
+            "stemming": false,
+            "stopwords": [],
+            "edgeNgram": {
+                "min": 1,
+                "max": AUTOCOMPLETE_MAX_PREFIX,
+                "preserveOriginal": true,
+            },
+        },
+        "features": ["frequency", "norm", "position"],
+    });
+    let status = arango_api(Method::POST, "analyzer", &analyzer).await?;
+    if !status.is_success() {
+        return Err(Error::General(
+            format!(
+                "Create analyzer {} failed: {}",
+                AUTOCOMPLETE_ANALYZER, status
+            ),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    }
+    Ok(())
+}
+
+/// Send `body` to ArangoDB HTTP API `/_db/{db}/_api/{path}`, returns response status.
+/// For what's not supported by `arangors_lite`.
+pub(crate) async fn arango_api(
+    method: Method,
+    path: &str,
+    body: &Value,
+) -> Result<StatusCode, Error> {
+    let uri = format!(
+        "{}/_db/{}/_api/{}",
+        C.db.host.trim_end_matches('/'),
+        C.db.db,
+        path
+    );
+    let auth = base64::encode(format!("{}:{}", C.db.username, C.db.password));
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Authorization", format!("Basic {}", auth))
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .map_err(|err| Error::ParamError(format!("Build request error: {}", err)))?;
+    let resp = request_with_timeout(&make_client(), req).await?;
+    Ok(resp.status())
 }
@@ -0,0 +1,121 @@
+use crate::{
+    error::Error,
+    graph::edge::{InvalidationReason, Proof},
+    upstream::DataSource,
+    util::naive_now,
+};
+use aragog::{query::Comparison, DatabaseConnection, DatabaseRecord, Record};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use uuid::Uuid;
+
+/// What happened to a `Proof`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    Display,
+    EnumString,
+    PartialEq,
+    Eq,
+    async_graphql::Enum,
+)]
+pub enum ProofChange {
+    /// Found for the first time.
+    #[strum(serialize = "created")]
+    #[serde(rename = "created")]
+    #[graphql(name = "created")]
+    Created,
+
+    /// Signature verified by us for the first time.
+    #[strum(serialize = "verified")]
+    #[serde(rename = "verified")]
+    #[graphql(name = "verified")]
+    Verified,
+
+    /// Found invalid, see `invalidation_reason`.
+    #[strum(serialize = "invalidated")]
+    #[serde(rename = "invalidated")]
+    #[graphql(name = "invalidated")]
+    Invalidated,
+
+    /// Found valid again after invalidated.
+    #[strum(serialize = "revalidated")]
+    #[serde(rename = "revalidated")]
+    #[graphql(name = "revalidated")]
+    Revalidated,
+
+    /// Upstream gives a new `record_id`.
+    #[strum(serialize = "record_id_changed")]
+    #[serde(rename = "record_id_changed")]
+    #[graphql(name = "record_id_changed")]
+    RecordIdChanged,
+
+    /// Found with a higher `level`. A lower one never replaces it.
+    #[strum(serialize = "level_raised")]
+    #[serde(rename = "level_raised")]
+    #[graphql(name = "level_raised")]
+    LevelRaised,
+}
+
+/// One change of a `Proof`. Append-only: never updated or deleted.
+#[derive(Clone, Serialize, Deserialize, Record, Debug)]
+#[collection_name = "ProofHistories"]
+pub struct ProofHistory {
+    /// UUID of this record.
+    pub uuid: Uuid,
+    /// UUID of the `Proof` changed.
+    pub proof: Uuid,
+    pub change: ProofChange,
+    /// Data source of the proof.
+    pub source: DataSource,
+    /// `record_id` of the proof after this change.
+    pub record_id: Option<String>,
+    /// Why the proof is invalidated, if it is.
+    pub invalidation_reason: Option<InvalidationReason>,
+    /// When this change is found by us.
+    pub happened_at: NaiveDateTime,
+}
+
+impl ProofHistory {
+    /// Append a change of `proof`, as it is after the change.
+    pub async fn log(
+        db: &DatabaseConnection,
+        proof: &Proof,
+        change: ProofChange,
+    ) -> Result<(), Error> {
+        DatabaseRecord::create(Self::new(proof, change), db).await?;
+        Ok(())
+    }
+
+    /// A change of `proof` happened just now, not saved yet.
+    pub fn new(proof: &Proof, change: ProofChange) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            proof: proof.uuid,
+            change,
+            source: proof.source,
+            record_id: proof.record_id.clone(),
+            invalidation_reason: proof.invalidation_reason,
+            happened_at: naive_now(),
+        }
+    }
+
+    /// All changes of a proof, oldest first.
+    pub async fn find_by_proof(
+        db: &DatabaseConnection,
+        proof: &Uuid,
+    ) -> Result<Vec<ProofHistory>, Error> {
+        let query = Self::query().filter(Comparison::field("proof").equals_str(proof).into());
+        let mut result: Vec<ProofHistory> = Self::get(&query, db)
+            .await?
+            .iter()
+            .map(|record| record.record.clone())
+            .collect();
+        result.sort_by_key(|history| history.happened_at);
+        Ok(result)
+    }
+}
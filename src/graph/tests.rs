@@ -1,6 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::graph::{new_db_connection, new_raw_db_connection};
+    use crate::graph::{
+        batch, db_connection,
+        edge::{Hold, Proof, ProofRecord},
+        events::{self, ProofAdded},
+        migrations, new_db_connection, new_raw_db_connection, shared_pool,
+        vertex::Identity,
+        GraphStats, GraphTx, Vertex,
+    };
+    use crate::upstream::{Connection, Platform};
+    use aragog::DatabaseRecord;
+    use fake::{Fake, Faker};
 
     #[tokio::test]
     async fn test_new_db_connection() {
@@ -11,6 +21,132 @@ mod tests {
             .is_empty())
     }
 
+    #[tokio::test]
+    async fn test_db_connection() {
+        let db = db_connection().await.unwrap();
+        assert!(!db.collections_names().is_empty());
+        assert!(shared_pool().status().size >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_upsert() {
+        let db = new_db_connection().await.unwrap();
+        let from: Identity = Faker.fake();
+        let to: Identity = Faker.fake();
+        let proof: Proof = Faker.fake();
+        let hold: Hold = Faker.fake();
+        let connections = vec![
+            Connection::Identity(from.clone()),
+            Connection::Proof(from.clone(), to.clone(), proof.clone()),
+            Connection::Hold(from.clone(), to.clone(), hold.clone()),
+        ];
+
+        let upserted = batch::upsert(&db, &connections).await.unwrap();
+        assert_eq!(upserted.proofs.len(), 1);
+        assert_eq!(
+            upserted.created,
+            GraphStats {
+                identities: 2,
+                edges: 2
+            }
+        );
+        // Saving the same connections again won't duplicate anything.
+        let again = batch::upsert(&db, &connections).await.unwrap();
+        assert_eq!(again.proofs[0].key(), upserted.proofs[0].key());
+        assert_eq!(again.created, GraphStats::default());
+
+        let from_record = from.create_or_update(&db).await.unwrap();
+        let to_record = to.create_or_update(&db).await.unwrap();
+        let found: Vec<ProofRecord> = Proof::find_all_by_from_to(&db, &from_record, &to_record)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].uuid, proof.uuid);
+        let held = Hold::find_by_from_to_id(&db, &from_record, &to_record, &hold.id)
+            .await
+            .unwrap();
+        assert_eq!(held.unwrap().uuid, hold.uuid);
+    }
+
+    #[tokio::test]
+    async fn test_batch_insert() {
+        let db = new_db_connection().await.unwrap();
+        let from: Identity = Faker.fake();
+        let to: Identity = Faker.fake();
+        let proof = Proof {
+            record_id: Some(Faker.fake()),
+            ..Faker.fake()
+        };
+        let connections = vec![Connection::Proof(from.clone(), to.clone(), proof.clone())];
+
+        // Of concurrent ones, only one is saved.
+        let (first, second) = tokio::join!(
+            batch::insert(&db, &connections),
+            batch::insert(&db, &connections)
+        );
+        assert!(first.is_ok() != second.is_ok());
+        assert!(batch::insert(&db, &connections).await.is_err());
+
+        let record_id = proof.record_id.as_deref().unwrap();
+        assert!(
+            Proof::exists_by_record_id(&db, &proof.source, &from, record_id)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !Proof::exists_by_record_id(&db, &proof.source, &to, record_id)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_graph_tx_abort() {
+        let db = new_db_connection().await.unwrap();
+        let identity: Identity = Faker.fake();
+
+        let tx = GraphTx::begin(&db).await.unwrap();
+        DatabaseRecord::create(identity.clone(), tx.db())
+            .await
+            .unwrap();
+        tx.abort().await.unwrap();
+
+        let found =
+            Identity::find_by_platform_identity(&db, &identity.platform, &identity.identity)
+                .await
+                .unwrap();
+        assert!(found.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap() {
+        migrations::bootstrap().await.unwrap();
+        // Nothing changes when everything exists.
+        migrations::bootstrap().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_migrate() {
+        migrations::bootstrap().await.unwrap();
+        migrations::migrate().await.unwrap();
+        assert!(migrations::pending().await.unwrap().is_empty());
+        // Applied ones are not run again.
+        assert!(migrations::migrate().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_proof_added_events() {
+        let mut receiver = events::subscribe();
+        let mut event = ProofAdded::default();
+        event.to.0.record.platform = Platform::Twitter;
+        event.to.0.record.identity = "suji_yan".into();
+        events::publish(event);
+
+        let received = receiver.recv().await.unwrap();
+        assert!(received.touches(&Platform::Twitter, "suji_yan"));
+        assert!(!received.touches(&Platform::Github, "suji_yan"));
+    }
+
     #[tokio::test]
     async fn test_new_raw_db_connection() {
         new_raw_db_connection().await.unwrap();
@@ -0,0 +1,36 @@
+use crate::error::Error;
+use aragog::{
+    transaction::{Transaction, TransactionDatabaseConnection},
+    DatabaseConnection,
+};
+
+/// An ArangoDB stream transaction over all collections of the graph.
+/// Queries run through `db()` are either all committed or none of them,
+/// so a failure in the middle of a multi-edge write leaves no dangling identities.
+pub struct GraphTx {
+    transaction: Transaction,
+}
+
+impl GraphTx {
+    /// Begin a new transaction.
+    pub async fn begin(db: &DatabaseConnection) -> Result<Self, Error> {
+        let transaction = Transaction::new(db).await?;
+        Ok(Self { transaction })
+    }
+
+    /// Connection to run queries in this transaction with.
+    pub fn db(&self) -> &TransactionDatabaseConnection {
+        self.transaction.database_connection()
+    }
+
+    pub async fn commit(self) -> Result<(), Error> {
+        self.transaction.commit().await?;
+        Ok(())
+    }
+
+    /// Discard everything done in this transaction.
+    pub async fn abort(self) -> Result<(), Error> {
+        self.transaction.abort().await?;
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
+    config::C,
     error::Error,
     graph::edge::Hold,
     graph::{ConnectionPool, Vertex},
+    metrics::time_db,
     util::naive_now,
 };
 use aragog::{
@@ -355,7 +357,7 @@ async fn get_contracts(
         .batch_size(1)
         .count(false);
 
-    let contracts = db.aql_query::<ToContractRecord>(aql).await;
+    let contracts = time_db("contract_loader", db.aql_query::<ToContractRecord>(aql)).await;
     match contracts {
         Ok(contents) => {
             let id_contracts_map = contents
@@ -466,9 +468,9 @@ impl Vertex<ContractRecord> for Contract {
         }
     }
 
-    /// Outdated in 1 hour
+    /// Outdated in `[cache] contract` seconds.
     fn is_outdated(&self) -> bool {
-        let outdated_in = Duration::hours(1);
+        let outdated_in = Duration::seconds(C.cache.contract as i64);
         self.updated_at
             .checked_add_signed(outdated_in)
             .unwrap()
@@ -0,0 +1,282 @@
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::Hold,
+        vertex::{contract::Chain, Identity, IdentityRecord},
+        ConnectionPool, Vertex,
+    },
+    metrics::time_db,
+    util::naive_now,
+};
+use aragog::{
+    query::Comparison, AqlQuery, DatabaseAccess, DatabaseConnection, DatabaseRecord, Record,
+};
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+use uuid::Uuid;
+
+/// Algorithm family of a `CryptoKey`.
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    EnumString,
+    Display,
+    Debug,
+    EnumIter,
+    PartialEq,
+    Eq,
+    async_graphql::Enum,
+    Hash,
+)]
+pub enum KeyAlgorithm {
+    /// OpenPGP key. Fingerprint is defined in RFC 4880.
+    #[strum(serialize = "pgp")]
+    #[serde(rename = "pgp")]
+    #[graphql(name = "pgp")]
+    PGP,
+
+    /// secp256k1 public key, used by Ethereum and most EVM chains.
+    #[strum(serialize = "secp256k1")]
+    #[serde(rename = "secp256k1")]
+    #[graphql(name = "secp256k1")]
+    Secp256k1,
+
+    /// Ed25519 public key, used by Solana, Aptos, Nostr etc.
+    #[strum(serialize = "ed25519")]
+    #[serde(rename = "ed25519")]
+    #[graphql(name = "ed25519")]
+    Ed25519,
+
+    #[default]
+    #[serde(rename = "unknown")]
+    #[graphql(name = "unknown")]
+    #[strum(serialize = "unknown")]
+    Unknown,
+}
+
+/// A public key which can be held by `Identity`s (e.g. PGP keys of a Keybase user).
+#[derive(Clone, Serialize, Deserialize, Record, Debug)]
+#[collection_name = "CryptoKeys"]
+pub struct CryptoKey {
+    /// UUID of this record
+    pub uuid: Uuid,
+    /// Algorithm family of this key.
+    pub algorithm: KeyAlgorithm,
+    /// Fingerprint of this key, lowercase hex string without spaces.
+    pub fingerprint: String,
+    /// Public key itself (e.g. ASCII-armored PGP key) if provided.
+    pub public_key: Option<String>,
+    /// On which chain this key is used, `None` if it is not a chain key (e.g. PGP).
+    #[serde(default)]
+    pub chain: Option<Chain>,
+    /// When this key is created (if upstream gives such data).
+    pub created_at: Option<NaiveDateTime>,
+    /// When this data is fetched by RelationService.
+    pub updated_at: NaiveDateTime,
+}
+
+impl Default for CryptoKey {
+    fn default() -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            algorithm: Default::default(),
+            fingerprint: Default::default(),
+            public_key: None,
+            chain: None,
+            created_at: None,
+            updated_at: naive_now(),
+        }
+    }
+}
+
+impl CryptoKey {
+    /// Normalize a fingerprint like `"9A82 0A4E..."` into `"9a820a4e..."`.
+    pub fn normalize_fingerprint(fingerprint: &str) -> String {
+        fingerprint
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    pub async fn find_by_fingerprint(
+        db: &DatabaseConnection,
+        fingerprint: &str,
+    ) -> Result<Option<CryptoKeyRecord>, Error> {
+        let query = Self::query().filter(
+            Comparison::field("fingerprint")
+                .equals_str(Self::normalize_fingerprint(fingerprint))
+                .into(),
+        );
+        let result = Self::get(&query, db).await?;
+        if result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(result.first().unwrap().to_owned().into()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Vertex<CryptoKeyRecord> for CryptoKey {
+    fn uuid(&self) -> Option<Uuid> {
+        Some(self.uuid)
+    }
+
+    /// Create or update a key by its fingerprint.
+    async fn create_or_update(&self, db: &DatabaseConnection) -> Result<CryptoKeyRecord, Error> {
+        let found = Self::find_by_fingerprint(db, &self.fingerprint).await?;
+        match found {
+            None => {
+                let mut to_be_created = self.clone();
+                to_be_created.fingerprint = Self::normalize_fingerprint(&self.fingerprint);
+                to_be_created.updated_at = naive_now();
+                let created = DatabaseRecord::create(to_be_created, db).await?;
+                Ok(created.into())
+            }
+            Some(mut found) => {
+                found.updated_at = naive_now();
+                found.public_key = self.public_key.clone().or(found.public_key.clone());
+                found.chain = self.chain.or(found.chain);
+                found.created_at = self.created_at.or(found.created_at);
+                found.save(db).await?;
+                Ok(found)
+            }
+        }
+    }
+
+    /// Find a key by UUID.
+    async fn find_by_uuid(
+        db: &DatabaseConnection,
+        uuid: Uuid,
+    ) -> Result<Option<CryptoKeyRecord>, Error> {
+        let query = Self::query().filter(Comparison::field("uuid").equals_str(uuid).into());
+        let query_result = Self::get(&query, db).await?;
+        if query_result.len() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(query_result.first().unwrap().to_owned().into()))
+        }
+    }
+
+    /// Outdated in `[cache] crypto_key` seconds.
+    fn is_outdated(&self) -> bool {
+        let outdated_in = Duration::seconds(C.cache.crypto_key as i64);
+        self.updated_at
+            .checked_add_signed(outdated_in)
+            .unwrap()
+            .lt(&naive_now())
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize, Default, Debug)]
+pub struct CryptoKeyRecord(pub DatabaseRecord<CryptoKey>);
+
+impl std::ops::Deref for CryptoKeyRecord {
+    type Target = DatabaseRecord<CryptoKey>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for CryptoKeyRecord {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<DatabaseRecord<CryptoKey>> for CryptoKeyRecord {
+    fn from(record: DatabaseRecord<CryptoKey>) -> Self {
+        Self(record)
+    }
+}
+
+impl CryptoKeyRecord {
+    /// Returns all `Identity`s holding this key.
+    pub async fn holders(&self, pool: &ConnectionPool) -> Result<Vec<IdentityRecord>, Error> {
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = conn.database();
+
+        let aql_str = r"
+        FOR h IN @@holds
+            FILTER h._to == @id AND IS_SAME_COLLECTION(@@identities, h._from)
+            FOR i IN @@identities
+                FILTER i._id == h._from
+                RETURN DISTINCT i";
+
+        let aql = AqlQuery::new(aql_str)
+            .bind_var("@holds", Hold::COLLECTION_NAME)
+            .bind_var("@identities", Identity::COLLECTION_NAME)
+            .bind_var("id", self.id().as_str())
+            .batch_size(1)
+            .count(false);
+
+        let result = time_db("crypto_key_holders", db.aql_query::<IdentityRecord>(aql)).await?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{arangopool::new_connection_pool, new_db_connection, Edge};
+    use fake::{Dummy, Fake, Faker};
+
+    impl Dummy<Faker> for CryptoKey {
+        fn dummy_with_rng<R: rand::Rng + ?Sized>(config: &Faker, _rng: &mut R) -> Self {
+            let mut key = CryptoKey::default();
+            key.algorithm = KeyAlgorithm::PGP;
+            key.fingerprint = config.fake();
+            key
+        }
+    }
+
+    #[test]
+    fn test_normalize_fingerprint() {
+        assert_eq!(
+            CryptoKey::normalize_fingerprint("9A82 0A4E 0DE6 3FBB"),
+            "9a820a4e0de63fbb"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_fingerprint() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let key: CryptoKey = Faker.fake();
+        let created = key.create_or_update(&db).await?;
+        let found = CryptoKey::find_by_fingerprint(&db, &key.fingerprint)
+            .await?
+            .expect("key should be found");
+        assert_eq!(found.key(), created.key());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_holders() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        let identity = Identity::create_dummy(&db).await?;
+        let key: CryptoKey = Faker.fake();
+        let key = key.create_or_update(&db).await?;
+        let hold: Hold = Faker.fake();
+        hold.connect(&db, &identity, &key).await?;
+
+        let holders = key.holders(&pool).await?;
+        assert_eq!(1, holders.len());
+        assert_eq!(holders[0].uuid, identity.uuid);
+
+        let keys = identity.crypto_keys(&pool).await?;
+        assert_eq!(1, keys.len());
+        assert_eq!(keys[0].fingerprint, key.fingerprint);
+        Ok(())
+    }
+}
@@ -1,14 +1,21 @@
 use crate::{
+    config::C,
     error::Error,
     graph::ConnectionPool,
     graph::{
-        edge::{Hold, HoldRecord, IdentityFromToRecord, Proof, ProofRecord},
+        edge::{
+            Annotation, AnnotationRecord, Hold, HoldRecord, IdentityFromToRecord, Proof,
+            ProofLevel, ProofRecord,
+        },
         vertex::contract::ContractCategory,
         vertex::vec_string_to_vec_datasource,
         vertex::Vertex,
+        vertex::{CryptoKey, CryptoKeyRecord},
     },
+    graph::{AUTOCOMPLETE_ANALYZER, AUTOCOMPLETE_MAX_PREFIX, AUTOCOMPLETE_VIEW},
+    metrics::time_db,
     upstream::{DataSource, Platform},
-    util::naive_now,
+    util::{canonical::canonicalize, naive_now},
 };
 use aragog::{
     query::{Comparison, Filter},
@@ -19,7 +26,6 @@ use array_tool::vec::Uniq;
 use async_trait::async_trait;
 use chrono::{Duration, NaiveDateTime};
 use dataloader::BatchFn;
-use http::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, json, to_value, value::Value};
 use std::collections::HashMap;
@@ -99,7 +105,7 @@ impl Identity {
             Filter::new(Comparison::field("platform").equals_str(platform))
                 .and(Comparison::field("identity").equals_str(identity)),
         );
-        let query_result = Self::get(&query, db).await?;
+        let query_result = time_db("identity_by_platform", Self::get(&query, db)).await?;
 
         if query_result.len() == 0 {
             trace!("Identity not found in DB");
@@ -111,6 +117,37 @@ impl Identity {
         }
     }
 
+    /// Create or update it in one AQL `UPSERT`, backed by the unique index on `(platform, identity)`.
+    /// `UPSERT` is not atomic between concurrent queries: if both of them insert,
+    /// the later one fails with `Error::DbConflict` instead of making a duplicate vertex.
+    async fn upsert(&self, db: &DatabaseConnection) -> Result<IdentityRecord, Error> {
+        let to_be_saved = Identity {
+            uuid: self.uuid.or(Some(Uuid::new_v4())),
+            identity: canonicalize(&self.platform, &self.identity)?,
+            ..self.clone()
+        };
+        let aql = AqlQuery::new(
+            r"UPSERT { platform: @identity.platform, identity: @identity.identity }
+            INSERT MERGE(@identity, { added_at: @now, updated_at: @now })
+            UPDATE {
+                display_name: NOT_NULL(@identity.display_name, OLD.display_name),
+                profile_url: @identity.profile_url,
+                avatar_url: @identity.avatar_url,
+                created_at: NOT_NULL(@identity.created_at, OLD.created_at),
+                updated_at: @now
+            }
+            IN @@collection_name
+            RETURN NEW",
+        )
+        .bind_var("@collection_name", Identity::COLLECTION_NAME)
+        .bind_var("identity", to_value(to_be_saved)?)
+        .bind_var("now", to_value(naive_now())?)
+        .count(false);
+        let result: Vec<IdentityRecord> =
+            time_db("identity_upsert", db.database().aql_query(aql)).await?;
+        result.into_iter().next().ok_or(Error::NoResult)
+    }
+
     pub async fn find_by_platforms_identity(
         pool: &ConnectionPool,
         platforms: &Vec<Platform>,
@@ -137,7 +174,104 @@ impl Identity {
             .bind_var("platform", platform_array)
             .batch_size(1)
             .count(false);
-        let result: Vec<IdentityRecord> = db.aql_query(aql).await?;
+        let result: Vec<IdentityRecord> =
+            time_db("identity_by_platforms", db.aql_query(aql)).await?;
+        Ok(result)
+    }
+
+    /// Search identities whose `identity` or `display_name` contains `keyword` (case-insensitive).
+    /// Exact matches come first, then prefix matches, then the rest, shorter ones first.
+    pub async fn search(
+        pool: &ConnectionPool,
+        keyword: &str,
+        platform: Option<&Platform>,
+        limit: usize,
+    ) -> Result<Vec<IdentityRecord>, Error> {
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = conn.database();
+
+        let aql = r"FOR v IN @@collection_name
+        FILTER @platform == null OR v.platform == @platform
+        LET identity = LOWER(v.identity)
+        LET display_name = LOWER(v.display_name)
+        FILTER CONTAINS(identity, @keyword) OR CONTAINS(display_name, @keyword)
+        LET rank = (identity == @keyword OR display_name == @keyword) ? 0
+            : (STARTS_WITH(identity, @keyword) OR STARTS_WITH(display_name, @keyword)) ? 1
+            : 2
+        SORT rank, LENGTH(v.identity), v.identity
+        LIMIT @limit
+        RETURN v";
+        let aql = AqlQuery::new(aql)
+            .bind_var("@collection_name", Identity::COLLECTION_NAME)
+            .bind_var("keyword", keyword.to_lowercase())
+            .bind_var("platform", platform.map(|p| p.to_string()))
+            .bind_var("limit", limit)
+            .batch_size(limit.max(1) as u32)
+            .count(false);
+        let result: Vec<IdentityRecord> = time_db("identity_search", db.aql_query(aql)).await?;
+        Ok(result)
+    }
+
+    /// Autocomplete identities by `prefix` of a word in `identity` or `display_name`,
+    /// using `AUTOCOMPLETE_VIEW` (see `graph::ensure_autocomplete_view`).
+    /// Only the first word of `prefix` is looked up in the view.
+    pub async fn autocomplete(
+        pool: &ConnectionPool,
+        prefix: &str,
+        platform: Option<&Platform>,
+        limit: usize,
+    ) -> Result<Vec<IdentityCandidate>, Error> {
+        let prefix = prefix.trim().to_lowercase();
+        let term: String = prefix
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(AUTOCOMPLETE_MAX_PREFIX)
+            .collect();
+        if term.is_empty() {
+            return Ok(vec![]);
+        }
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = conn.database();
+
+        let platform_filter = match platform {
+            Some(_) => "AND v.platform == @platform",
+            None => "",
+        };
+        let aql_str = format!(
+            r"FOR v IN {view}
+        SEARCH ANALYZER(v.identity == @term OR v.display_name == @term, @analyzer) {platform_filter}
+        FILTER CONTAINS(LOWER(v.identity), @prefix) OR CONTAINS(LOWER(v.display_name), @prefix)
+        SORT LOWER(v.identity) == @prefix DESC, BM25(v) DESC, LENGTH(v.identity)
+        LIMIT @limit
+        RETURN {{
+            platform: v.platform,
+            identity: v.identity,
+            display_name: v.display_name,
+            avatar_url: v.avatar_url
+        }}",
+            view = AUTOCOMPLETE_VIEW,
+            platform_filter = platform_filter
+        );
+        let mut aql = AqlQuery::new(&aql_str)
+            .bind_var("term", term.as_str())
+            .bind_var("prefix", prefix.as_str())
+            .bind_var("analyzer", AUTOCOMPLETE_ANALYZER)
+            .bind_var("limit", limit)
+            .batch_size(limit.max(1) as u32)
+            .count(false);
+        if let Some(platform) = platform {
+            aql = aql.bind_var("platform", platform.to_string());
+        }
+        let result: Vec<IdentityCandidate> =
+            time_db("identity_autocomplete", db.aql_query(aql)).await?;
         Ok(result)
     }
 
@@ -163,7 +297,8 @@ impl Identity {
             .batch_size(1)
             .count(false);
 
-        let result: Vec<IdentityRecord> = db.aql_query(aql).await?;
+        let result: Vec<IdentityRecord> =
+            time_db("identity_by_display_name", db.aql_query(aql)).await?;
         if result.len() == 0 {
             Ok(None)
         } else {
@@ -181,53 +316,10 @@ impl Vertex<IdentityRecord> for Identity {
     /// Do create / update side-effect.
     /// Used by upstream crawler.
     async fn create_or_update(&self, db: &DatabaseConnection) -> Result<IdentityRecord, Error> {
-        // Find first
-        let found = Self::find_by_platform_identity(db, &self.platform, &self.identity).await?;
-        match found {
-            None => {
-                // Create
-                let mut to_be_created = self.clone();
-                to_be_created.uuid = to_be_created.uuid.or(Some(Uuid::new_v4()));
-                to_be_created.added_at = naive_now();
-                to_be_created.updated_at = naive_now();
-                #[allow(unused_assignments)] // FIXME: ??
-                let mut need_refetch: bool = false;
-
-                // Must do this to avoid "future cannot be sent between threads safely" complain from compiler.
-                match DatabaseRecord::create(to_be_created, db).await {
-                    Ok(created) => return Ok(created.into()),
-                    // An exception is raised from ArangoDB complaining about unique index violation.
-                    // Refetch it later (after leaving this block).
-                    // Since `bool` is `Send`able.
-                    Err(aragog::Error::Conflict(_)) => {
-                        need_refetch = true;
-                    }
-                    Err(err) => {
-                        return Err(err.into());
-                    }
-                };
-
-                if need_refetch {
-                    let found =
-                        Self::find_by_platform_identity(db, &self.platform, &self.identity).await?;
-                    // FIXME: `.except()` below DOES have chance to be triggered. Really should take a look at the whole fn.
-                    Ok(found.expect("Not found after an race condition in create_or_update"))
-                } else {
-                    Err(Error::General("Impossible: no refetch triggered nor record found / created in create_or_update".into(), StatusCode::INTERNAL_SERVER_ERROR))
-                }
-            }
-
-            Some(mut found) => {
-                // Update
-                found.display_name = self.display_name.clone().or(found.display_name.clone());
-                found.profile_url = self.profile_url.clone();
-                found.avatar_url = self.avatar_url.clone();
-                found.created_at = self.created_at.or(found.created_at);
-                found.updated_at = naive_now();
-
-                found.save(db).await?;
-                Ok(found)
-            }
+        match self.upsert(db).await {
+            // A concurrent one inserted the same identity first (see `upsert`), so it's found now.
+            Err(Error::DbConflict(_)) => self.upsert(db).await,
+            result => result,
         }
     }
 
@@ -246,7 +338,7 @@ impl Vertex<IdentityRecord> for Identity {
 
     /// Judge if this record is outdated and should be refetched.
     fn is_outdated(&self) -> bool {
-        let outdated_in = Duration::hours(1);
+        let outdated_in = Duration::seconds(C.cache.identity as i64);
         self.updated_at
             .checked_add_signed(outdated_in)
             .unwrap()
@@ -273,6 +365,25 @@ pub struct IdentityWithSource {
     pub sources: Vec<DataSource>,
 }
 
+/// Lightweight identity returned by autocomplete.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct IdentityCandidate {
+    pub platform: Platform,
+    pub identity: String,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+/// Connected component ("identity cluster") around an identity.
+#[derive(Clone, Deserialize, Serialize, Default, Debug)]
+pub struct IdentityGraph {
+    /// All identities in this cluster, the queried one first (with no sources).
+    /// Sources of others are the ones supporting the paths to them.
+    pub vertices: Vec<IdentityWithSource>,
+    /// All connections between identities in this cluster.
+    pub edges: Vec<IdentityFromToRecord>,
+}
+
 #[derive(Clone, Deserialize, Serialize, Default, Debug)]
 pub struct FromToRecord {
     /// ProofRecord _id
@@ -367,7 +478,7 @@ async fn get_identities(
         .batch_size(1)
         .count(false);
 
-    let identities = db.aql_query::<ToIdentityRecord>(aql).await;
+    let identities = time_db("identity_loader", db.aql_query::<ToIdentityRecord>(aql)).await;
     match identities {
         Ok(contents) => {
             let id_identities_map = contents
@@ -426,7 +537,11 @@ async fn get_from_to_record(
         .batch_size(1)
         .count(false);
 
-    let edges = db.aql_query::<Vec<FromToRecord>>(aql).await;
+    let edges = time_db(
+        "identity_from_to_loader",
+        db.aql_query::<Vec<FromToRecord>>(aql),
+    )
+    .await;
     match edges {
         Ok(contents) => {
             let id_tuple_map = contents
@@ -448,13 +563,18 @@ async fn get_from_to_record(
 }
 
 impl IdentityRecord {
-    /// Returns all neighbors of this identity. Depth and upstream data souce can be specified.
-    #[tracing::instrument(skip(self, pool, _source), level = "trace")]
+    /// Returns all neighbors of this identity. Depth and upstream data souces can be specified.
+    /// Only connections from `sources` are traversed, all sources if empty.
+    /// Invalidated proofs are skipped unless `include_invalid`, so are proofs lower than `min_level`.
+    /// Holds have no level, they are traversed whatever `min_level` is.
+    #[tracing::instrument(skip(self, pool), level = "trace")]
     pub async fn neighbors(
         &self,
         pool: &ConnectionPool,
         depth: u16,
-        _source: Option<DataSource>,
+        sources: &[DataSource],
+        include_invalid: bool,
+        min_level: ProofLevel,
     ) -> Result<Vec<IdentityWithSource>, Error> {
         // let db = pool.db().await?;
         let conn = pool
@@ -462,24 +582,37 @@ impl IdentityRecord {
             .await
             .map_err(|err| Error::PoolError(err.to_string()))?;
         let db = conn.database();
-        let aql_str = r###"
+        let aql_str = format!(
+            r###"
         WITH @@collection_name FOR d IN @@collection_name
             FILTER d._id == @id
             LIMIT 1
             FOR vertex, edge, path
-                IN 1..@depth ANY d Proofs, Holds
-                PRUNE IS_SAME_COLLECTION('Contracts' , vertex)
+                IN 1..@depth ANY d {} Proofs, {} Holds
+                PRUNE IS_SAME_COLLECTION('Contracts' , vertex) OR (edge.expired_at != null AND edge.expired_at < @now) OR (NOT @include_invalid AND edge.invalid_since != null) OR (edge != null AND IS_SAME_COLLECTION("Proofs", edge) AND NOT_NULL(edge.level, "heuristic") NOT IN @levels) OR (edge != null AND LENGTH(@sources) > 0 AND edge.source NOT IN @sources)
                 FILTER NOT CONTAINS(path.edges[*]._to, "Contracts")
+                FILTER LENGTH(path.edges[* FILTER CURRENT.expired_at != null AND CURRENT.expired_at < @now]) == 0
+                FILTER @include_invalid OR LENGTH(path.edges[* FILTER CURRENT.invalid_since != null]) == 0
+                FILTER LENGTH(path.edges[* FILTER IS_SAME_COLLECTION("Proofs", CURRENT) AND NOT_NULL(CURRENT.level, "heuristic") NOT IN @levels]) == 0
+                FILTER LENGTH(@sources) == 0 OR LENGTH(path.edges[* FILTER CURRENT.source NOT IN @sources]) == 0
                 RETURN path
-        "###;
-        let aql = AqlQuery::new(aql_str)
+        "###,
+            C.traversal.proofs.aql(),
+            C.traversal.holds.aql()
+        );
+        let aql = AqlQuery::new(&aql_str)
             .bind_var("@collection_name", Identity::COLLECTION_NAME)
             .bind_var("id", self.id().as_str())
             .bind_var("depth", depth)
+            // Skip proofs expired in upstream platform (e.g. Civic Pass).
+            .bind_var("now", to_value(naive_now())?)
+            .bind_var("include_invalid", include_invalid)
+            .bind_var("levels", to_value(min_level.and_above())?)
+            .bind_var("sources", to_value(sources)?)
             .batch_size(1)
             .count(false);
         trace!("Querying...");
-        let resp: Vec<Value> = db.aql_query(aql).await?;
+        let resp: Vec<Value> = time_db("identity_neighbors", db.aql_query(aql)).await?;
         trace!(path_count = resp.len(), "Query completed.");
 
         let mut identity_map: HashMap<String, IdentityRecord> = HashMap::new();
@@ -522,6 +655,32 @@ impl IdentityRecord {
         Ok(identity_sources)
     }
 
+    /// Returns the connected component around this identity, up to `depth`
+    /// (capped by `traversal.max_depth` in config).
+    pub async fn identity_graph(
+        &self,
+        pool: &ConnectionPool,
+        depth: u16,
+        include_invalid: bool,
+        min_level: ProofLevel,
+    ) -> Result<IdentityGraph, Error> {
+        let depth = depth.clamp(1, C.traversal.max_depth.max(1));
+        let mut vertices = vec![IdentityWithSource {
+            identity: self.clone(),
+            sources: vec![],
+        }];
+        let mut neighbors = self
+            .neighbors(pool, depth, &[], include_invalid, min_level)
+            .await?;
+        neighbors.retain(|n| n.identity.id() != self.id());
+        neighbors.sort_by(|a, b| a.identity.id().cmp(b.identity.id()));
+        vertices.extend(neighbors);
+        let edges = self
+            .neighbors_with_traversal(pool, depth, include_invalid, min_level)
+            .await?;
+        Ok(IdentityGraph { vertices, edges })
+    }
+
     // Return domain name owned by wallet address.
     pub async fn domain_owned_by(
         &self,
@@ -551,7 +710,11 @@ impl IdentityRecord {
             .batch_size(1)
             .count(false);
 
-        let result = db.aql_query::<IdentityRecord>(aql).await?;
+        let result = time_db(
+            "identity_domains_owned",
+            db.aql_query::<IdentityRecord>(aql),
+        )
+        .await?;
 
         if result.len() == 0 {
             Ok(None)
@@ -566,6 +729,8 @@ impl IdentityRecord {
         &self,
         pool: &ConnectionPool,
         depth: u16,
+        include_invalid: bool,
+        min_level: ProofLevel,
     ) -> Result<Vec<IdentityFromToRecord>, Error> {
         // Using graph speed up FILTER
         // let db = pool.db().await?;
@@ -574,25 +739,37 @@ impl IdentityRecord {
             .await
             .map_err(|err| Error::PoolError(err.to_string()))?;
         let db = conn.database();
-        let aql_str = r###"
+        let aql_str = format!(
+            r###"
         WITH @@collection_name FOR d IN @@collection_name
             FILTER d._id == @id
             LIMIT 1
             FOR vertex, edge, path
-                IN 1..@depth ANY d Proofs, Holds
-                PRUNE IS_SAME_COLLECTION('Contracts' , vertex)
+                IN 1..@depth ANY d {} Proofs, {} Holds
+                PRUNE IS_SAME_COLLECTION('Contracts' , vertex) OR (edge.expired_at != null AND edge.expired_at < @now) OR (NOT @include_invalid AND edge.invalid_since != null) OR (edge != null AND IS_SAME_COLLECTION("Proofs", edge) AND NOT_NULL(edge.level, "heuristic") NOT IN @levels)
                 FILTER NOT CONTAINS(path.edges[*]._to, "Contracts")
+                FILTER LENGTH(path.edges[* FILTER CURRENT.expired_at != null AND CURRENT.expired_at < @now]) == 0
+                FILTER @include_invalid OR LENGTH(path.edges[* FILTER CURRENT.invalid_since != null]) == 0
+                FILTER LENGTH(path.edges[* FILTER IS_SAME_COLLECTION("Proofs", CURRENT) AND NOT_NULL(CURRENT.level, "heuristic") NOT IN @levels]) == 0
                 RETURN DISTINCT edge
-        "###;
-        let aql = AqlQuery::new(aql_str)
+        "###,
+            C.traversal.proofs.aql(),
+            C.traversal.holds.aql()
+        );
+        let aql = AqlQuery::new(&aql_str)
             .bind_var("@collection_name", Identity::COLLECTION_NAME)
             .bind_var("id", self.id().as_str())
             .bind_var("depth", depth)
+            // Skip proofs expired in upstream platform (e.g. Civic Pass).
+            .bind_var("now", to_value(naive_now())?)
+            .bind_var("include_invalid", include_invalid)
+            .bind_var("levels", to_value(min_level.and_above())?)
             .batch_size(1)
             .count(false);
 
         trace!("Querying...");
-        let resp: Vec<Value> = db.aql_query(aql).await?;
+        let resp: Vec<Value> =
+            time_db("identity_neighbors_with_traversal", db.aql_query(aql)).await?;
         debug!(records = resp.len(), "Query completed.");
         let mut paths: Vec<IdentityFromToRecord> = Vec::new();
         for p in resp {
@@ -651,8 +828,7 @@ impl IdentityRecord {
             .batch_size(1)
             .count(false);
 
-        let result = db
-            .aql_query::<HoldRecord>(aql)
+        let result = time_db("identity_nfts", db.aql_query::<HoldRecord>(aql))
             .await?
             .into_iter()
             .filter(|x| x.id_to().contains("Contracts"))
@@ -660,6 +836,63 @@ impl IdentityRecord {
 
         Ok(result)
     }
+
+    /// Returns all `CryptoKey`s held by this identity.
+    pub async fn crypto_keys(&self, pool: &ConnectionPool) -> Result<Vec<CryptoKeyRecord>, Error> {
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = conn.database();
+
+        let aql_str = r"
+        FOR h IN @@holds
+            FILTER h._from == @id AND IS_SAME_COLLECTION(@@keys, h._to)
+            FOR k IN @@keys
+                FILTER k._id == h._to
+                RETURN DISTINCT k";
+
+        let aql = AqlQuery::new(aql_str)
+            .bind_var("@holds", Hold::COLLECTION_NAME)
+            .bind_var("@keys", CryptoKey::COLLECTION_NAME)
+            .bind_var("id", self.id().as_str())
+            .batch_size(1)
+            .count(false);
+
+        let result = time_db("identity_crypto_keys", db.aql_query::<CryptoKeyRecord>(aql)).await?;
+        Ok(result)
+    }
+
+    /// Returns all third-party labels attached to this identity, most confident first.
+    pub async fn find_annotations(
+        &self,
+        pool: &ConnectionPool,
+    ) -> Result<Vec<AnnotationRecord>, Error> {
+        let conn = pool
+            .get()
+            .await
+            .map_err(|err| Error::PoolError(err.to_string()))?;
+        let db = conn.database();
+
+        let aql_str = r"
+        FOR d IN @@annotations
+            FILTER d._from == @id
+            SORT d.confidence DESC
+            RETURN d";
+
+        let aql = AqlQuery::new(aql_str)
+            .bind_var("@annotations", Annotation::COLLECTION_NAME)
+            .bind_var("id", self.id().as_str())
+            .batch_size(1)
+            .count(false);
+
+        let result = time_db(
+            "identity_annotations",
+            db.aql_query::<AnnotationRecord>(aql),
+        )
+        .await?;
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -675,9 +908,11 @@ mod tests {
     use crate::{
         error::Error,
         graph::arangopool::new_connection_pool,
-        graph::new_db_connection,
-        graph::{edge::IdentityFromToRecord, edge::Proof, Edge, Vertex},
-        upstream::Platform,
+        graph::{
+            edge::Hold, edge::IdentityFromToRecord, edge::Proof, edge::ProofLevel, Edge, Vertex,
+        },
+        graph::{ensure_autocomplete_view, new_db_connection},
+        upstream::{DataSource, Platform},
         util::naive_now,
     };
 
@@ -694,7 +929,8 @@ mod tests {
             Self {
                 uuid: Some(Uuid::new_v4()),
                 platform: Platform::Twitter,
-                identity: config.fake(),
+                // Canonical Twitter handle, so it's found by itself after saved.
+                identity: config.fake::<String>().to_lowercase(),
                 display_name: config.fake(),
                 profile_url: Some(config.fake()),
                 avatar_url: Some(config.fake()),
@@ -828,6 +1064,38 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_search() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        let created = Identity::create_dummy(&db).await?;
+        let keyword = created.identity.to_uppercase();
+
+        let found = Identity::search(&pool, &keyword, Some(&created.platform), 10).await?;
+        assert_eq!(found.first().unwrap().id(), created.id());
+        let found = Identity::search(&pool, &keyword, Some(&Platform::Ethereum), 10).await?;
+        assert!(found.iter().all(|r| r.id() != created.id()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete() -> Result<(), Error> {
+        ensure_autocomplete_view().await?;
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        let created = Identity::create_dummy(&db).await?;
+        // Wait for ArangoSearch view to be committed.
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        let prefix: String = created.identity.chars().take(3).collect();
+        let found =
+            Identity::autocomplete(&pool, &prefix.to_uppercase(), Some(&created.platform), 100)
+                .await?;
+        assert!(found.iter().all(|c| c.platform == created.platform));
+        assert!(found.iter().any(|c| c.identity == created.identity));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_neighbors() -> Result<(), Error> {
         let db = new_db_connection().await?;
@@ -844,7 +1112,9 @@ mod tests {
         proof1_raw.connect(&db, &id1, &id2).await?;
         proof2_raw.connect(&db, &id1, &id3).await?;
         proof3_raw.connect(&db, &id2, &id4).await?;
-        let neighbors = id1.neighbors(&pool, 2, None).await?;
+        let neighbors = id1
+            .neighbors(&pool, 2, &[], false, ProofLevel::default())
+            .await?;
         assert_eq!(3, neighbors.len());
         // assert!(neighbors
         //     .iter()
@@ -852,6 +1122,108 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_neighbors_reverse() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        // ID1 --Proof--> ID2, traversed from ID2.
+        let id1 = Identity::create_dummy(&db).await?;
+        let id2 = Identity::create_dummy(&db).await?;
+        let proof: Proof = Faker.fake();
+        proof.connect(&db, &id1, &id2).await?;
+
+        let neighbors = id2
+            .neighbors(&pool, 1, &[], false, ProofLevel::default())
+            .await?;
+        assert_eq!(1, neighbors.len());
+        assert_eq!(neighbors[0].identity.uuid, id1.uuid);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_neighbors_by_sources() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        // ID2 <--Keybase-- ID1 --SybilList--> ID3
+        let id1 = Identity::create_dummy(&db).await?;
+        let id2 = Identity::create_dummy(&db).await?;
+        let id3 = Identity::create_dummy(&db).await?;
+        let mut proof1: Proof = Faker.fake();
+        proof1.source = DataSource::Keybase;
+        let proof2: Proof = Faker.fake();
+        proof1.connect(&db, &id1, &id2).await?;
+        proof2.connect(&db, &id1, &id3).await?;
+
+        let neighbors = id1
+            .neighbors(
+                &pool,
+                1,
+                &[DataSource::Keybase],
+                false,
+                ProofLevel::default(),
+            )
+            .await?;
+        assert_eq!(1, neighbors.len());
+        assert_eq!(neighbors[0].identity.uuid, id2.uuid);
+        assert_eq!(neighbors[0].sources, vec![DataSource::Keybase]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_neighbors_by_level() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        // ID2 <--Verified-- ID1 --Heuristic--> ID3, ID1 --Hold--> ID4
+        let id1 = Identity::create_dummy(&db).await?;
+        let id2 = Identity::create_dummy(&db).await?;
+        let id3 = Identity::create_dummy(&db).await?;
+        let id4 = Identity::create_dummy(&db).await?;
+        let mut proof1: Proof = Faker.fake();
+        proof1.level = ProofLevel::PlatformVerified;
+        proof1.expired_at = None;
+        proof1.invalid_since = None;
+        let mut proof2: Proof = Faker.fake();
+        proof2.level = ProofLevel::Heuristic;
+        let hold: Hold = Faker.fake();
+        proof1.connect(&db, &id1, &id2).await?;
+        proof2.connect(&db, &id1, &id3).await?;
+        hold.connect(&db, &id1, &id4).await?;
+
+        let neighbors = id1
+            .neighbors(&pool, 1, &[], false, ProofLevel::SelfClaimed)
+            .await?;
+        let mut found: Vec<Uuid> = neighbors.iter().map(|n| n.identity.uuid).collect();
+        found.sort();
+        let mut expected = vec![id2.uuid, id4.uuid];
+        expected.sort();
+        assert_eq!(found, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_identity_graph() -> Result<(), Error> {
+        let db = new_db_connection().await?;
+        let pool = new_connection_pool().await?;
+        // ID1 --Proof1--> ID2 --Proof2--> ID3, ID1 --Proof3--> ID3
+        let id1 = Identity::create_dummy(&db).await?;
+        let id2 = Identity::create_dummy(&db).await?;
+        let id3 = Identity::create_dummy(&db).await?;
+        let proof1: Proof = Faker.fake();
+        let proof2: Proof = Faker.fake();
+        let proof3: Proof = Faker.fake();
+        proof1.connect(&db, &id1, &id2).await?;
+        proof2.connect(&db, &id2, &id3).await?;
+        proof3.connect(&db, &id1, &id3).await?;
+
+        let graph = id1
+            .identity_graph(&pool, 2, false, ProofLevel::default())
+            .await?;
+        assert_eq!(3, graph.vertices.len());
+        assert_eq!(graph.vertices[0].identity.uuid, id1.uuid);
+        assert_eq!(3, graph.edges.len());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_neighbors_with_traversal() -> Result<(), Error> {
         let pool = new_connection_pool().await?;
@@ -859,8 +1231,10 @@ mod tests {
             .await?
             .expect("Record not found");
         println!("{:#?}", found);
-        let neighbors: Vec<IdentityFromToRecord> =
-            found.neighbors_with_traversal(&pool, 3).await.unwrap();
+        let neighbors: Vec<IdentityFromToRecord> = found
+            .neighbors_with_traversal(&pool, 3, false, ProofLevel::default())
+            .await
+            .unwrap();
         println!("{:#?}", neighbors);
         Ok(())
     }
@@ -1,12 +1,16 @@
 pub mod contract;
+pub mod crypto_key;
 mod identity;
-// mod crypto_identity;
 
 use crate::upstream::DataSource;
 use aragog::{DatabaseConnection, Record};
 use async_trait::async_trait;
 pub use contract::{Contract, ContractRecord};
-pub use identity::{FromToLoadFn, Identity, IdentityLoadFn, IdentityRecord, IdentityWithSource};
+pub use crypto_key::{CryptoKey, CryptoKeyRecord, KeyAlgorithm};
+pub use identity::{
+    FromToLoadFn, Identity, IdentityCandidate, IdentityGraph, IdentityLoadFn, IdentityRecord,
+    IdentityWithSource,
+};
 use uuid::Uuid;
 
 use crate::error::Error;
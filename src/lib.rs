@@ -1,11 +1,15 @@
 #[macro_use]
 extern crate lazy_static;
 
+pub mod client;
 pub mod config;
+#[cfg(feature = "server")]
 pub mod controller;
 pub mod error;
 pub mod graph;
+pub mod metrics;
 pub mod util;
+pub mod worker;
 
 pub mod upstream;
 
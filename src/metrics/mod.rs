@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests;
+
+use crate::worker;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge, Encoder, HistogramVec,
+    IntCounterVec, IntGauge, TextEncoder,
+};
+use std::{future::Future, time::Instant};
+
+lazy_static! {
+    /// Latency of root GraphQL fields, by `field` (`Query.identity`) and `status` (`ok` or `error`).
+    pub static ref GRAPHQL_FIELD_SECONDS: HistogramVec = register_histogram_vec!(
+        "relation_graphql_field_duration_seconds",
+        "Latency of root GraphQL fields.",
+        &["field", "status"]
+    )
+    .unwrap();
+    /// Latency of upstream calls, by `upstream` and `status` (`ok` or `error`).
+    pub static ref UPSTREAM_FETCH_SECONDS: HistogramVec = register_histogram_vec!(
+        "relation_upstream_fetch_duration_seconds",
+        "Latency of upstream calls.",
+        &["upstream", "status"]
+    )
+    .unwrap();
+    /// Times circuit breaker of an upstream is opened.
+    pub static ref UPSTREAM_CIRCUIT_TRIPS: IntCounterVec = register_int_counter_vec!(
+        "relation_upstream_circuit_trips_total",
+        "Times circuit breaker of an upstream is opened.",
+        &["upstream"]
+    )
+    .unwrap();
+    /// Latency of DB queries, by `query`.
+    pub static ref DB_QUERY_SECONDS: HistogramVec = register_histogram_vec!(
+        "relation_db_query_duration_seconds",
+        "Latency of DB queries.",
+        &["query"]
+    )
+    .unwrap();
+    /// Identities queried, by `result`: `hit` (fresh in DB), `stale` (outdated in DB) or `miss`.
+    pub static ref IDENTITY_CACHE: IntCounterVec = register_int_counter_vec!(
+        "relation_identity_cache_total",
+        "Identities queried, by whether they are found in DB.",
+        &["result"]
+    )
+    .unwrap();
+    /// Refresh jobs waiting in queue or running.
+    pub static ref QUEUE_DEPTH: IntGauge = register_int_gauge!(
+        "relation_worker_queue_depth",
+        "Refresh jobs waiting in queue or running."
+    )
+    .unwrap();
+}
+
+/// `ok` or `error`, label of a result.
+pub fn status<T, E>(result: &Result<T, E>) -> &'static str {
+    if result.is_ok() {
+        "ok"
+    } else {
+        "error"
+    }
+}
+
+/// Run DB query `future`, recording its latency as `query`.
+pub async fn time_db<F: Future>(query: &str, future: F) -> F::Output {
+    let started = Instant::now();
+    let output = future.await;
+    DB_QUERY_SECONDS
+        .with_label_values(&[query])
+        .observe(started.elapsed().as_secs_f64());
+    output
+}
+
+/// All metrics in Prometheus text format.
+pub fn render() -> String {
+    QUEUE_DEPTH.set(worker::queue_depth() as i64);
+    let mut buffer = vec![];
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
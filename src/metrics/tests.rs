@@ -0,0 +1,21 @@
+use crate::metrics::{render, time_db, DB_QUERY_SECONDS, IDENTITY_CACHE};
+
+#[tokio::test]
+async fn test_render() {
+    let before = DB_QUERY_SECONDS
+        .with_label_values(&["test_render"])
+        .get_sample_count();
+    assert_eq!(time_db("test_render", async { 42 }).await, 42);
+    assert_eq!(
+        DB_QUERY_SECONDS
+            .with_label_values(&["test_render"])
+            .get_sample_count(),
+        before + 1
+    );
+    IDENTITY_CACHE.with_label_values(&["hit"]).inc();
+
+    let rendered = render();
+    assert!(rendered.contains(r#"relation_db_query_duration_seconds_count{query="test_render"}"#));
+    assert!(rendered.contains(r#"relation_identity_cache_total{result="hit"}"#));
+    assert!(rendered.contains("relation_worker_queue_depth"));
+}
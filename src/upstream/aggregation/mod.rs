@@ -4,13 +4,13 @@ mod tests;
 use super::{DataFetcher, Target};
 use crate::config::C;
 use crate::error::Error;
-use crate::graph::edge::Proof;
+use crate::graph::edge::{Proof, ProofLevel};
 use crate::graph::vertex::Identity;
-use crate::graph::{create_identity_to_identity_record, new_db_connection};
-use crate::upstream::{DataSource, Fetcher, Platform, TargetProcessedList};
-use crate::util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive};
+use crate::upstream::{Connection, DataSource, Fetched, Fetcher, Platform};
+use crate::util::{
+    make_client, naive_now, paginate, parse_body, request_with_timeout, timestamp_to_naive, Page,
+};
 use async_trait::async_trait;
-use futures::future::join_all;
 use hyper::{Body, Method};
 use serde::Deserialize;
 use std::str::FromStr;
@@ -45,16 +45,16 @@ pub struct Aggregation {}
 
 #[async_trait]
 impl Fetcher for Aggregation {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
@@ -66,72 +66,68 @@ impl Fetcher for Aggregation {
 async fn fetch_connections_by_platform_identity(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let client = make_client();
-    let mut page = 1;
-
-    let mut next_targets: TargetProcessedList = Vec::new();
-
-    loop {
-        let uri: http::Uri = match format!(
-            "{}?platform={}&identity={}&page={}&size=100",
-            C.upstream.aggregation_service.url, platform, identity, page
-        )
-        .parse()
-        {
-            Ok(n) => n,
-            Err(err) => return Err(Error::ParamError(format!("Uri format Error: {}", err))),
-        };
-
-        let req = hyper::Request::builder()
-            .method(Method::GET)
-            .uri(uri)
-            .body(Body::empty())
-            .map_err(|_err| {
-                Error::ParamError(format!("Aggregation Service Build Request Error {}", _err))
-            })?;
-
-        let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
-            Error::ManualHttpClientError(format!(
-                "Aggregation Service fetch | error: {:?}",
-                err.to_string()
-            ))
-        })?;
+) -> Result<Fetched, Error> {
+    let records = paginate(1, |page| fetch_page(platform, identity, page)).await?;
+    if records.items.is_empty() {
+        info!("aggregation service response is empty");
+    }
 
-        let body: Response = parse_body(&mut resp).await?;
-        if body.records.is_empty() {
-            info!("aggregation service response is empty");
-            break;
-        }
+    let mut fetched: Fetched = records.items.into_iter().map(parse_item).collect();
+    fetched.truncated = records.truncated;
+    Ok(fetched)
+}
 
-        let futures: Vec<_> = body.records.into_iter().map(save_item).collect();
-        let targets: TargetProcessedList = join_all(futures)
-            .await
-            .into_iter()
-            .flat_map(|result| result.unwrap_or_default())
-            .collect();
-        next_targets.extend(targets);
+async fn fetch_page(
+    platform: &Platform,
+    identity: &str,
+    page: u32,
+) -> Result<Page<Record, u32>, Error> {
+    let client = make_client();
+    let uri: http::Uri = match format!(
+        "{}?platform={}&identity={}&page={}&size=100",
+        C.upstream.aggregation_service.url, platform, identity, page
+    )
+    .parse()
+    {
+        Ok(n) => n,
+        Err(err) => return Err(Error::ParamError(format!("Uri format Error: {}", err))),
+    };
 
-        if body.pagination.current == body.pagination.next {
-            break;
-        }
-        page = body.pagination.next;
-    }
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| {
+            Error::ParamError(format!("Aggregation Service Build Request Error {}", _err))
+        })?;
 
-    Ok(next_targets)
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "Aggregation Service fetch | error: {:?}",
+            err.to_string()
+        ))
+    })?;
+
+    let body: Response = parse_body(&mut resp).await?;
+    let next = if body.pagination.current == body.pagination.next {
+        None
+    } else {
+        Some(body.pagination.next)
+    };
+    Ok(Page {
+        items: body.records,
+        next,
+    })
 }
 
-async fn save_item(p: Record) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
-    let mut targets = Vec::new();
-
+fn parse_item(p: Record) -> Fetched {
     let from_platform = Platform::from_str(p.sns_platform.as_str()).unwrap_or(Platform::Unknown);
     if from_platform == Platform::Unknown {
         error!(
             "AggregationService from_platform unknown , original data is: {:?}",
             p
         );
-        return Ok(vec![]);
+        return Fetched::default();
     }
     let from: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
@@ -151,7 +147,7 @@ async fn save_item(p: Record) -> Result<TargetProcessedList, Error> {
             "AggregationService to_platform unknown , original data is: {:?}",
             p
         );
-        return Ok(vec![]);
+        return Fetched::default();
     }
     let web3_addr = p.web3_addr.to_lowercase();
     let to: Identity = Identity {
@@ -177,7 +173,7 @@ async fn save_item(p: Record) -> Result<TargetProcessedList, Error> {
     let source = DataSource::from_str(p.source.as_str()).unwrap_or(DataSource::Unknown);
     if source == DataSource::Rss3 {
         debug!("AggregationService filter source={}", DataSource::Rss3);
-        return Ok(vec![]);
+        return Fetched::default();
     }
     let pf: Proof = Proof {
         uuid: Uuid::new_v4(),
@@ -192,10 +188,16 @@ async fn save_item(p: Record) -> Result<TargetProcessedList, Error> {
             update_ms_time,
         ),
         fetcher: DataFetcher::AggregationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
     };
 
-    let _ = create_identity_to_identity_record(&db, &from, &to, &pf).await;
-
-    targets.push(Target::Identity(to_platform, web3_addr.clone()));
-    Ok(targets)
+    Fetched {
+        next_targets: vec![Target::Identity(to_platform, web3_addr)],
+        connections: vec![Connection::Proof(from, to, pf)],
+        ..Default::default()
+    }
 }
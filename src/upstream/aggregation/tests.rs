@@ -6,14 +6,14 @@ use crate::{
         Contract, Identity,
     },
     upstream::{aggregation::Aggregation, Target},
-    upstream::{Fetcher, Platform},
+    upstream::{fetch_and_import, Platform},
     util::timestamp_to_naive,
 };
 
 #[tokio::test]
 async fn test_smoke_aggregation() -> Result<(), Error> {
     let target = Target::Identity(Platform::Twitter, "blake".to_string());
-    let _ = Aggregation::fetch(&target).await?;
+    let _ = fetch_and_import::<Aggregation>(&target).await?;
 
     let db = new_db_connection().await?;
 
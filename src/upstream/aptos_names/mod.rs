@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
+    graph::vertex::Identity,
+    upstream::{
+        Connection, DataFetcher, DataSource, DomainResolver, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method, Request};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// https://www.aptosnames.com/api/mainnet/v1/address/{name}
+#[derive(Deserialize, Debug)]
+pub struct AddressResponse {
+    pub address: Option<String>,
+}
+
+/// https://www.aptosnames.com/api/mainnet/v1/primary-name/{address}
+#[derive(Deserialize, Debug)]
+pub struct PrimaryNameResponse {
+    /// Name without `.apt`.
+    pub name: Option<String>,
+}
+
+pub struct AptosNames {}
+
+#[async_trait]
+impl Fetcher for AptosNames {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_connections_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::AptosNames, Platform::Aptos])
+    }
+}
+
+#[async_trait]
+impl DomainResolver for AptosNames {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        let resp: Option<AddressResponse> =
+            get(format!("/address/{}", name.trim_end_matches(".apt"))).await?;
+        Ok(resp
+            .and_then(|resp| resp.address)
+            .map(|address| normalize_address(&address)))
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        let resp: Option<PrimaryNameResponse> = get(format!("/primary-name/{}", address)).await?;
+        Ok(resp
+            .and_then(|resp| resp.name)
+            .map(|name| with_apt_suffix(&name)))
+    }
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    match *platform {
+        Platform::Aptos => fetch_name_by_address(identity).await,
+        Platform::AptosNames => fetch_address_by_name(identity).await,
+        _ => Ok(Fetched::default()),
+    }
+}
+
+/// `Aptosnames` / `aptosnames.apt` => `aptosnames.apt`
+fn with_apt_suffix(name: &str) -> String {
+    let name = name.to_lowercase();
+    if name.ends_with(".apt") {
+        name
+    } else {
+        format!("{}.apt", name)
+    }
+}
+
+/// Aptos account addresses are 32 bytes, but leading zeros could be
+/// omitted (i.e. `0x1`). Always use the full-length lowercase form.
+fn normalize_address(address: &str) -> String {
+    let hex = address.trim_start_matches("0x").to_lowercase();
+    format!("0x{:0>64}", hex)
+}
+
+async fn get<T: DeserializeOwned>(path: String) -> Result<Option<T>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}{}", C.upstream.aptos_names_api.url, path)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("AptosNames Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("AptosNames fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("AptosNames fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+/// Hold / resolve / reverse resolve records of a name.
+fn name_connection(address: &str, name: &str, is_primary: bool) -> Connection {
+    let aptos_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Aptos,
+        identity: address.to_string(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let apt_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::AptosNames,
+        identity: name.to_string(),
+        created_at: None,
+        display_name: Some(name.to_string()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!(
+            "https://www.aptosnames.com/name/{}",
+            name.trim_end_matches(".apt")
+        )),
+        updated_at: naive_now(),
+    };
+    let hold: Hold = Hold {
+        uuid: Uuid::new_v4(),
+        source: DataSource::AptosNames,
+        transaction: None,
+        id: name.to_string(),
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+    let resolve: Resolve = Resolve {
+        uuid: Uuid::new_v4(),
+        source: DataSource::AptosNames,
+        system: DomainNameSystem::AptosNames,
+        name: name.to_string(),
+        resolved_address: Some(aptos_identity.identity.clone()),
+        expire_at: None,
+        fetcher: DataFetcher::RelationService,
+        updated_at: naive_now(),
+    };
+
+    Connection::Domain {
+        address: aptos_identity,
+        domain: apt_identity,
+        hold,
+        resolve,
+        is_primary,
+    }
+}
+
+async fn fetch_name_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = normalize_address(address);
+    // Aptos Names API only exposes the primary name of an address.
+    let aptos_names = AptosNames {};
+    let name = match aptos_names.reverse_resolve(&address).await? {
+        Some(name) => name,
+        None => {
+            info!("AptosNames fetch | {} has no primary name", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    Ok(Fetched {
+        connections: vec![name_connection(&address, &name, true)],
+        next_targets: vec![Target::Identity(Platform::AptosNames, name)],
+        ..Default::default()
+    })
+}
+
+async fn fetch_address_by_name(name: &str) -> Result<Fetched, Error> {
+    let name = with_apt_suffix(name);
+    let aptos_names = AptosNames {};
+    let address = match aptos_names.resolve(&name).await? {
+        Some(address) => address,
+        None => {
+            warn!("AptosNames fetch | {} is not registered", name);
+            return Ok(Fetched::default());
+        }
+    };
+    let is_primary = aptos_names.reverse_resolve(&address).await?.as_ref() == Some(&name);
+
+    Ok(Fetched {
+        connections: vec![name_connection(&address, &name, is_primary)],
+        next_targets: vec![Target::Identity(Platform::Aptos, address)],
+        ..Default::default()
+    })
+}
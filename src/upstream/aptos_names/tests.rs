@@ -0,0 +1,33 @@
+use crate::{
+    error::Error,
+    upstream::{
+        aptos_names::{normalize_address, with_apt_suffix, AptosNames},
+        DomainResolver,
+    },
+};
+
+#[test]
+fn test_with_apt_suffix() {
+    assert_eq!(with_apt_suffix("Aptosnames"), "aptosnames.apt");
+    assert_eq!(with_apt_suffix("aptosnames.apt"), "aptosnames.apt");
+}
+
+#[test]
+fn test_normalize_address() {
+    assert_eq!(
+        normalize_address("0x1"),
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        normalize_address("0xA1B2c3D4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90"),
+        "0xa1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90"
+    );
+}
+
+#[tokio::test]
+async fn test_resolve() -> Result<(), Error> {
+    let address = AptosNames {}.resolve("aptosnames.apt").await?;
+    println!("address: {:?}", address);
+    assert!(address.is_some());
+    Ok(())
+}
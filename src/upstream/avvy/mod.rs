@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
+    graph::vertex::Identity,
+    upstream::{
+        Connection, DataFetcher, DataSource, DomainResolver, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method, Request};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct ResolveResponse {
+    /// EVM address on Avalanche C-Chain.
+    pub address: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReverseResolveResponse {
+    pub name: Option<String>,
+}
+
+const AVAX_SUFFIX: &str = ".avax";
+
+/// Avvy names hash into field elements on-chain, so resolution goes
+/// through a proxy of `@avvy/client` instead of calling contracts directly.
+pub struct Avvy {}
+
+#[async_trait]
+impl Fetcher for Avvy {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_connections_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        if C.upstream.avvy_api.url.is_empty() {
+            return false;
+        }
+        match target {
+            Target::Identity(Platform::Avvy, name) => is_avax_name(name),
+            _ => target.in_platform_supported(vec![Platform::Ethereum]),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainResolver for Avvy {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        let resp: Option<ResolveResponse> = get(format!("/resolve/{}", name)).await?;
+        Ok(resp
+            .and_then(|resp| resp.address)
+            .map(|address| address.to_lowercase())
+            .filter(|address| address != "0x0000000000000000000000000000000000000000"))
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        let resp: Option<ReverseResolveResponse> = get(format!("/reverse/{}", address)).await?;
+        Ok(resp
+            .and_then(|resp| resp.name)
+            .map(|name| name.to_lowercase()))
+    }
+}
+
+/// `avvy.avax` is, `.avax` / `avvy.eth` are not.
+fn is_avax_name(name: &str) -> bool {
+    name.to_lowercase()
+        .strip_suffix(AVAX_SUFFIX)
+        .is_some_and(|label| !label.is_empty())
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    match *platform {
+        Platform::Ethereum => fetch_name_by_address(identity).await,
+        Platform::Avvy => fetch_address_by_name(identity).await,
+        _ => Ok(Fetched::default()),
+    }
+}
+
+async fn get<T: DeserializeOwned>(path: String) -> Result<Option<T>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}{}", C.upstream.avvy_api.url, path)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Avvy Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Avvy fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Avvy fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+/// Hold / resolve / reverse resolve records of a name.
+fn name_connection(address: &str, name: &str, is_primary: bool) -> Connection {
+    // Avalanche C-Chain shares address format with Ethereum.
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let avvy_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Avvy,
+        identity: name.to_string(),
+        created_at: None,
+        display_name: Some(name.to_string()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://app.avvy.domains/domains/{}", name)),
+        updated_at: naive_now(),
+    };
+    let hold: Hold = Hold {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Avvy,
+        transaction: None,
+        id: name.to_string(),
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+    let resolve: Resolve = Resolve {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Avvy,
+        system: DomainNameSystem::Avvy,
+        name: name.to_string(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: None,
+        fetcher: DataFetcher::RelationService,
+        updated_at: naive_now(),
+    };
+
+    Connection::Domain {
+        address: eth_identity,
+        domain: avvy_identity,
+        hold,
+        resolve,
+        is_primary,
+    }
+}
+
+async fn fetch_name_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let avvy = Avvy {};
+    let name = match avvy.reverse_resolve(&address).await? {
+        Some(name) => name,
+        None => {
+            info!("Avvy fetch | {} has no reverse record", address);
+            return Ok(Fetched::default());
+        }
+    };
+    // Reverse record is only trustworthy when the name resolves back.
+    if avvy.resolve(&name).await?.as_ref() != Some(&address) {
+        warn!("Avvy fetch | {} does not resolve back to {}", name, address);
+        return Ok(Fetched::default());
+    }
+
+    Ok(Fetched {
+        connections: vec![name_connection(&address, &name, true)],
+        next_targets: vec![Target::Identity(Platform::Avvy, name)],
+        ..Default::default()
+    })
+}
+
+async fn fetch_address_by_name(name: &str) -> Result<Fetched, Error> {
+    let name = name.to_lowercase();
+    let avvy = Avvy {};
+    let address = match avvy.resolve(&name).await? {
+        Some(address) => address,
+        None => {
+            warn!("Avvy fetch | {} is not resolving to any address", name);
+            return Ok(Fetched::default());
+        }
+    };
+    let is_primary = avvy.reverse_resolve(&address).await?.as_ref() == Some(&name);
+
+    Ok(Fetched {
+        connections: vec![name_connection(&address, &name, is_primary)],
+        next_targets: vec![Target::Identity(Platform::Ethereum, address)],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,9 @@
+use crate::upstream::avvy::is_avax_name;
+
+#[test]
+fn test_is_avax_name() {
+    assert!(is_avax_name("avvy.avax"));
+    assert!(is_avax_name("Sub.Avvy.AVAX"));
+    assert!(!is_avax_name(".avax"));
+    assert!(!is_avax_name("avvy.eth"));
+}
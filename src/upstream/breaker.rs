@@ -0,0 +1,131 @@
+use crate::{config::C, error::Error, metrics::UPSTREAM_CIRCUIT_TRIPS};
+use http::StatusCode;
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// State of a `Breaker`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, async_graphql::Enum)]
+pub enum CircuitState {
+    /// Upstream is called as usual.
+    Closed,
+    /// Upstream failed too many times in a row, and is skipped until cooled down.
+    Open,
+    /// Cooled down: one trial call to upstream is let through,
+    /// it's closed if the trial succeeds, and opened again if it fails.
+    HalfOpen,
+}
+
+/// Circuit breaker of one upstream.
+/// After `[breaker] failures` consecutive failures it's open, and the upstream is skipped
+/// (what's in the graph is served as is) for `[breaker] cooldown` seconds.
+#[derive(Default)]
+pub struct Breaker {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// Consecutive failures.
+    failures: u32,
+    /// When it's opened, `None` if closed.
+    opened_at: Option<Instant>,
+    /// How many times it's opened.
+    trips: u64,
+    /// When the trial call of `HalfOpen` is let through, `None` if there's none running.
+    trial_since: Option<Instant>,
+}
+
+impl Inner {
+    fn state(&self) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < cooldown() => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+fn cooldown() -> Duration {
+    Duration::from_secs(C.breaker.cooldown)
+}
+
+impl Breaker {
+    pub fn state(&self) -> CircuitState {
+        self.inner.lock().unwrap().state()
+    }
+
+    /// How many times it's opened since started.
+    pub fn trips(&self) -> u64 {
+        self.inner.lock().unwrap().trips
+    }
+
+    /// Whether the upstream can be called now.
+    /// When it's `HalfOpen`, only the first call is let through until its result is recorded,
+    /// or another cooldown passes (in case it's never recorded, e.g. cancelled).
+    pub fn allow(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state() {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => match inner.trial_since {
+                Some(trial_since) if trial_since.elapsed() < cooldown() => false,
+                _ => {
+                    inner.trial_since = Some(Instant::now());
+                    true
+                }
+            },
+        }
+    }
+
+    /// Record result of a call to upstream `name`.
+    pub fn record<T>(&self, name: &str, result: &Result<T, Error>) {
+        let mut inner = self.inner.lock().unwrap();
+        let state = inner.state();
+        inner.trial_since = None;
+        match result {
+            Err(err) if is_failure(err) => {
+                inner.failures += 1;
+                let threshold = C.breaker.failures;
+                if state == CircuitState::HalfOpen
+                    || (state == CircuitState::Closed
+                        && threshold > 0
+                        && inner.failures >= threshold)
+                {
+                    inner.opened_at = Some(Instant::now());
+                    inner.trips += 1;
+                    UPSTREAM_CIRCUIT_TRIPS.with_label_values(&[name]).inc();
+                    warn!(
+                        upstream = name,
+                        failures = inner.failures,
+                        "Circuit {:?} => Open, skipped for {}s: {}",
+                        state,
+                        C.breaker.cooldown,
+                        err
+                    );
+                }
+            }
+            _ => {
+                inner.failures = 0;
+                if state != CircuitState::Closed {
+                    inner.opened_at = None;
+                    info!(upstream = name, "Circuit {:?} => Closed", state);
+                }
+            }
+        }
+    }
+}
+
+/// Errors telling the upstream is unhealthy: unreachable, timed out, or failed on its side (5xx).
+/// Others (e.g. not found, rate limited or a malformed identity) mean it's working as expected.
+fn is_failure(err: &Error) -> bool {
+    match err {
+        Error::HttpClientError(_) | Error::ManualHttpClientError(_) | Error::IsahcError(_) => true,
+        Error::General(_, status) => {
+            *status == StatusCode::REQUEST_TIMEOUT || status.is_server_error()
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// https://dev.brightid.org/docs/node-api/
+#[derive(Deserialize, Debug)]
+pub struct VerificationResponse {
+    pub data: Option<Verification>,
+    #[serde(default)]
+    pub error: bool,
+    #[serde(rename = "errorNum")]
+    pub error_num: Option<i32>,
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Verification {
+    /// If this BrightID user passed the sybil-resistance verification of the context.
+    pub unique: bool,
+    pub context: String,
+    /// All context IDs linked to the same BrightID user, the most recent one first.
+    pub context_ids: Vec<String>,
+    /// Millisecond-based timestamp of the verification.
+    pub timestamp: Option<i64>,
+}
+
+/// `errorNum` returned by node when this context ID is not linked to any BrightID.
+const CONTEXTID_NOT_FOUND: i32 = 2;
+/// `errorNum` returned by node when the linked BrightID is not verified.
+const NOT_VERIFIED: i32 = 3;
+
+pub struct BrightID {}
+
+#[async_trait]
+impl Fetcher for BrightID {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, identity) => fetch_verification_by_context_id(identity).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_verification(context_id: &str) -> Result<Option<Verification>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/verifications/{}/{}",
+        C.upstream.brightid.url, C.upstream.brightid.context, context_id
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("BrightID Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("BrightID fetch | error: {:?}", err.to_string()))
+    })?;
+
+    let status = resp.status();
+    let result: VerificationResponse = parse_body(&mut resp).await?;
+    if result.error {
+        return match result.error_num {
+            Some(CONTEXTID_NOT_FOUND) | Some(NOT_VERIFIED) => Ok(None),
+            _ => Err(Error::General(
+                format!(
+                    "BrightID Get error: {}",
+                    result.error_message.unwrap_or_default()
+                ),
+                status,
+            )),
+        };
+    }
+
+    Ok(result.data)
+}
+
+async fn fetch_verification_by_context_id(context_id: &str) -> Result<Fetched, Error> {
+    let context_id = context_id.to_lowercase();
+    let verification = match fetch_verification(&context_id).await? {
+        Some(verification) if verification.unique => verification,
+        _ => {
+            info!("BrightID fetch | {} is not verified", context_id);
+            return Ok(Fetched::default());
+        }
+    };
+    // A BrightID user is represented by the most recent context ID linked to it.
+    let brightid = match verification.context_ids.first() {
+        Some(id) => id.to_lowercase(),
+        None => return Ok(Fetched::default()),
+    };
+
+    let created_at = verification
+        .timestamp
+        .map(|ts| timestamp_to_naive(ts / 1000, (ts % 1000) as u32));
+    let brightid_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::BrightID,
+        identity: brightid,
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for linked_id in verification.context_ids.iter() {
+        let linked_id = linked_id.to_lowercase();
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: linked_id.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::BrightID,
+            record_id: Some(verification.context.clone()),
+            created_at,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            eth_identity,
+            brightid_identity.clone(),
+            proof,
+        ));
+        if linked_id != context_id {
+            fetched
+                .next_targets
+                .push(Target::Identity(Platform::Ethereum, linked_id));
+        }
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,9 @@
+use crate::{error::Error, upstream::brightid::fetch_verification};
+
+#[tokio::test]
+async fn test_fetch_verification() -> Result<(), Error> {
+    let context_id = "0x0ef8d5d4f5f8e47a9bc2ea0c0ed8af0b7d9d6e11";
+    let result = fetch_verification(context_id).await?;
+    println!("verification: {:?}", result);
+    Ok(())
+}
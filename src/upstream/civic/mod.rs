@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// EVM chains where Civic `GatewayToken` contract is deployed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CivicChain {
+    Ethereum,
+    Polygon,
+    Arbitrum,
+    Optimism,
+    BSC,
+}
+
+impl CivicChain {
+    pub const ALL: [CivicChain; 5] = [
+        CivicChain::Ethereum,
+        CivicChain::Polygon,
+        CivicChain::Arbitrum,
+        CivicChain::Optimism,
+        CivicChain::BSC,
+    ];
+
+    /// `chain` param of gateway token API.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            CivicChain::Ethereum => "ethereum",
+            CivicChain::Polygon => "polygon",
+            CivicChain::Arbitrum => "arbitrum",
+            CivicChain::Optimism => "optimism",
+            CivicChain::BSC => "bsc",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GatewayTokensResponse {
+    pub tokens: Vec<GatewayToken>,
+}
+
+/// Gateway token (Civic Pass) issued to an address by a gatekeeper.
+#[derive(Deserialize, Debug, Clone)]
+pub struct GatewayToken {
+    pub token_id: String,
+    pub owner: String,
+    /// Gatekeeper network key, which decides the type of this pass.
+    pub gatekeeper_network: String,
+    /// `ACTIVE`, `FROZEN` or `REVOKED`.
+    pub state: String,
+    /// Expiry in unix timestamp (second). `0` or `None` means it never expires.
+    pub expiration: Option<i64>,
+}
+
+const TOKEN_STATE_ACTIVE: &str = "ACTIVE";
+
+/// Known gatekeeper networks: https://docs.civic.com/civic-pass/overview/gatekeeper-networks
+const GATEKEEPER_NETWORKS: [(&str, &str); 4] = [
+    ("uniqobk8oGh4XBLMqM68K8M2zNu3CdYX7q5go7whQiv", "uniqueness"),
+    ("ignREusXmGrscGNUesoU9mxfds9AiYTezUKex2PsZV6", "captcha"),
+    (
+        "bni1ewus6aMxTxBi5SAfzEmmXLf8KcVFRmTfproJuKw",
+        "id-verification",
+    ),
+    ("vaa1QRNEBb1G2XjPohqGWnPsvxWnwwXF67pdjrhDSwM", "liveness"),
+];
+
+impl GatewayToken {
+    /// Human-readable pass type. Unknown networks are kept as-is.
+    pub fn pass_type(&self) -> String {
+        GATEKEEPER_NETWORKS
+            .iter()
+            .find(|(network, _)| *network == self.gatekeeper_network)
+            .map(|(_, pass_type)| pass_type.to_string())
+            .unwrap_or_else(|| self.gatekeeper_network.clone())
+    }
+
+    pub fn expired_at(&self) -> Option<NaiveDateTime> {
+        match self.expiration {
+            Some(expiration) if expiration > 0 => Some(timestamp_to_naive(expiration, 0)),
+            _ => None,
+        }
+    }
+
+    /// When the proof of this pass stops being valid.
+    /// A frozen or revoked pass is invalidated right now, so the edge
+    /// recorded while it was active gets invalidated as well.
+    pub fn valid_until(&self) -> Option<NaiveDateTime> {
+        if self.state == TOKEN_STATE_ACTIVE {
+            self.expired_at()
+        } else {
+            Some(naive_now())
+        }
+    }
+}
+
+pub struct Civic {}
+
+#[async_trait]
+impl Fetcher for Civic {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_passes_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        // Only available when a gateway token API is configured.
+        !C.upstream.civic_api.url.is_empty()
+            && target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_gateway_tokens(
+    chain: CivicChain,
+    address: &str,
+) -> Result<Vec<GatewayToken>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/v1/gateway-tokens?chain={}&owner={}",
+        C.upstream.civic_api.url,
+        chain.api_name(),
+        address
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Civic Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Civic fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Civic Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: GatewayTokensResponse = parse_body(&mut resp).await?;
+    Ok(body.tokens)
+}
+
+async fn fetch_passes_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    // All passes of an address are held by the same Civic identity.
+    let civic_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Civic,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    for chain in CivicChain::ALL {
+        let tokens = fetch_gateway_tokens(chain, &address).await?;
+        for token in tokens {
+            info!(
+                "Civic fetch | {} holds {} pass ({}) on {}",
+                address,
+                token.pass_type(),
+                token.state,
+                chain.api_name()
+            );
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::Civic,
+                record_id: Some(format!("{}:{}", chain.api_name(), token.pass_type())),
+                created_at: None,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: token.valid_until(),
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::PlatformVerified,
+            };
+            fetched.connections.push(Connection::Proof(
+                eth_identity.clone(),
+                civic_identity.clone(),
+                proof,
+            ));
+        }
+    }
+
+    // Civic passes don't lead to any other identity.
+    Ok(fetched)
+}
@@ -0,0 +1,48 @@
+use crate::upstream::civic::GatewayToken;
+use crate::util::naive_now;
+
+fn token(gatekeeper_network: &str, state: &str, expiration: Option<i64>) -> GatewayToken {
+    GatewayToken {
+        token_id: "1".into(),
+        owner: "0x934b510d4c9103e6a87aef13b816fb080286d649".into(),
+        gatekeeper_network: gatekeeper_network.into(),
+        state: state.into(),
+        expiration,
+    }
+}
+
+#[test]
+fn test_pass_type() {
+    let uniqueness = token(
+        "uniqobk8oGh4XBLMqM68K8M2zNu3CdYX7q5go7whQiv",
+        "ACTIVE",
+        None,
+    );
+    assert_eq!(uniqueness.pass_type(), "uniqueness");
+    let unknown = token("unknownNetwork", "ACTIVE", None);
+    assert_eq!(unknown.pass_type(), "unknownNetwork");
+}
+
+#[test]
+fn test_valid_until() {
+    let never_expires = token(
+        "ignREusXmGrscGNUesoU9mxfds9AiYTezUKex2PsZV6",
+        "ACTIVE",
+        Some(0),
+    );
+    assert_eq!(never_expires.valid_until(), None);
+
+    let expires = token(
+        "ignREusXmGrscGNUesoU9mxfds9AiYTezUKex2PsZV6",
+        "ACTIVE",
+        Some(4102444800),
+    );
+    assert_eq!(expires.valid_until().unwrap().timestamp(), 4102444800);
+
+    let revoked = token(
+        "ignREusXmGrscGNUesoU9mxfds9AiYTezUKex2PsZV6",
+        "REVOKED",
+        Some(4102444800),
+    );
+    assert!(revoked.valid_until().unwrap() <= naive_now());
+}
@@ -0,0 +1,362 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
+    graph::vertex::Identity,
+    upstream::{
+        Connection, DataFetcher, DataSource, DomainResolver, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use bech32::Variant;
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method, Request};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// Bech32 prefix of Stargaze addresses. Stargaze Names are `{name}.stars`.
+const STARGAZE_PREFIX: &str = "stars";
+
+/// Name systems on Cosmos chains, both queried as CosmWasm contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosmosNameSystem {
+    /// Interchain Name Service on Osmosis. `{name}.{bech32 prefix}`
+    /// resolves to the address of `name` on the chain of that prefix.
+    /// https://docs.icns.xyz
+    ICNS,
+    /// Stargaze Names. `{name}.stars` resolves to a Stargaze address.
+    /// https://www.stargaze.zone/names
+    Stargaze,
+}
+
+/// Smart query response of CosmWasm contract through LCD.
+#[derive(Deserialize, Debug)]
+pub struct SmartQueryResponse<T> {
+    pub data: T,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ICNSAddressResponse {
+    pub address: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ICNSPrimaryNameResponse {
+    pub name: Option<String>,
+}
+
+impl CosmosNameSystem {
+    pub const ALL: [CosmosNameSystem; 2] = [CosmosNameSystem::ICNS, CosmosNameSystem::Stargaze];
+
+    pub fn platform(&self) -> Platform {
+        match self {
+            CosmosNameSystem::ICNS => Platform::ICNS,
+            CosmosNameSystem::Stargaze => Platform::StargazeNames,
+        }
+    }
+
+    fn data_source(&self) -> DataSource {
+        match self {
+            CosmosNameSystem::ICNS => DataSource::ICNS,
+            CosmosNameSystem::Stargaze => DataSource::StargazeNames,
+        }
+    }
+
+    fn domain_name_system(&self) -> DomainNameSystem {
+        match self {
+            CosmosNameSystem::ICNS => DomainNameSystem::ICNS,
+            CosmosNameSystem::Stargaze => DomainNameSystem::StargazeNames,
+        }
+    }
+
+    /// (LCD endpoint, contract address) to query.
+    fn contract(&self) -> (String, String) {
+        match self {
+            CosmosNameSystem::ICNS => (
+                C.upstream.icns.lcd_url.clone(),
+                C.upstream.icns.resolver.clone(),
+            ),
+            CosmosNameSystem::Stargaze => (
+                C.upstream.stargaze_names.lcd_url.clone(),
+                C.upstream.stargaze_names.contract.clone(),
+            ),
+        }
+    }
+
+    /// Whether `prefix` could be resolved / reverse resolved by this name system.
+    fn supports_prefix(&self, prefix: &str) -> bool {
+        match self {
+            CosmosNameSystem::ICNS => true,
+            CosmosNameSystem::Stargaze => prefix == STARGAZE_PREFIX,
+        }
+    }
+
+    fn profile_url(&self, name: &str) -> String {
+        match self {
+            CosmosNameSystem::ICNS => format!("https://app.icns.xyz/names/{}", name),
+            CosmosNameSystem::Stargaze => format!("https://www.stargaze.zone/names/{}", name),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainResolver for CosmosNameSystem {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        let (label, prefix) = match split_name(name) {
+            Some((label, prefix)) if self.supports_prefix(prefix) => (label, prefix),
+            _ => return Ok(None),
+        };
+        let address: Option<String> = match self {
+            CosmosNameSystem::ICNS => smart_query::<ICNSAddressResponse>(
+                *self,
+                json!({"address": {"name": label, "bech32_prefix": prefix}}),
+            )
+            .await?
+            .and_then(|resp| resp.address),
+            CosmosNameSystem::Stargaze => {
+                smart_query(*self, json!({"associated_address": {"name": label}})).await?
+            }
+        };
+        Ok(address.and_then(|address| normalize_address(&address, Some(prefix))))
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        let prefix = match split_address(address) {
+            Some(prefix) if self.supports_prefix(&prefix) => prefix,
+            _ => return Ok(None),
+        };
+        let label: Option<String> = match self {
+            CosmosNameSystem::ICNS => smart_query::<ICNSPrimaryNameResponse>(
+                *self,
+                json!({"primary_name": {"address": address}}),
+            )
+            .await?
+            .and_then(|resp| resp.name),
+            CosmosNameSystem::Stargaze => {
+                smart_query(*self, json!({"name": {"address": address}})).await?
+            }
+        };
+        Ok(label
+            .filter(|label| !label.is_empty())
+            .map(|label| format!("{}.{}", label.to_lowercase(), prefix)))
+    }
+}
+
+/// `alice.osmo` => (`alice`, `osmo`)
+pub fn split_name(name: &str) -> Option<(&str, &str)> {
+    name.rsplit_once('.')
+        .filter(|(label, prefix)| !label.is_empty() && !prefix.is_empty())
+}
+
+/// Bech32 prefix of a valid address. `osmo1...` => `osmo`
+pub fn split_address(address: &str) -> Option<String> {
+    bech32::decode(address)
+        .ok()
+        .filter(|(_, _, variant)| *variant == Variant::Bech32)
+        .map(|(prefix, _, _)| prefix)
+}
+
+/// Same key has different addresses on different Cosmos chains, told
+/// apart by prefix. Validate checksum (and prefix, if given), then return
+/// the canonical lowercase form, so each chain address has one identity.
+pub fn normalize_address(address: &str, expected_prefix: Option<&str>) -> Option<String> {
+    let (prefix, data, variant) = bech32::decode(address).ok()?;
+    if variant != Variant::Bech32 || expected_prefix.is_some_and(|expected| expected != prefix) {
+        return None;
+    }
+    bech32::encode(&prefix, data, variant).ok()
+}
+
+/// Query a CosmWasm contract through LCD REST API.
+/// Failed queries (i.e. name not registered) give `None`.
+async fn smart_query<T: DeserializeOwned>(
+    system: CosmosNameSystem,
+    query: Value,
+) -> Result<Option<T>, Error> {
+    let (lcd_url, contract) = system.contract();
+    let encoded = base64::encode_config(query.to_string(), base64::URL_SAFE);
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+        lcd_url, contract, encoded
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("CosmosNames Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("CosmosNames fetch | error: {:?}", err.to_string()))
+    })?;
+
+    // Contract errors are given as 400 / 500 by LCD.
+    if resp.status() == StatusCode::BAD_REQUEST
+        || resp.status() == StatusCode::INTERNAL_SERVER_ERROR
+    {
+        debug!("CosmosNames fetch | {:?} query {} failed", system, query);
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("CosmosNames fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    let result: SmartQueryResponse<Option<T>> = parse_body(&mut resp).await?;
+    Ok(result.data)
+}
+
+pub struct CosmosNames {}
+
+#[async_trait]
+impl Fetcher for CosmosNames {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_connections_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![
+            Platform::Cosmos,
+            Platform::ICNS,
+            Platform::StargazeNames,
+        ])
+    }
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    match *platform {
+        Platform::Cosmos => fetch_names_by_address(identity).await,
+        Platform::ICNS => fetch_address_by_name(CosmosNameSystem::ICNS, identity).await,
+        Platform::StargazeNames => {
+            fetch_address_by_name(CosmosNameSystem::Stargaze, identity).await
+        }
+        _ => Ok(Fetched::default()),
+    }
+}
+
+async fn fetch_names_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = match normalize_address(address, None) {
+        Some(address) => address,
+        None => {
+            warn!(
+                "CosmosNames fetch | {} is not a valid bech32 address",
+                address
+            );
+            return Ok(Fetched::default());
+        }
+    };
+    let mut fetched = Fetched::default();
+    for system in CosmosNameSystem::ALL {
+        if let Some(name) = system.reverse_resolve(&address).await? {
+            fetched
+                .connections
+                .push(name_connection(system, &address, &name, true));
+            fetched
+                .next_targets
+                .push(Target::Identity(system.platform(), name));
+        }
+    }
+    if fetched.next_targets.is_empty() {
+        info!("CosmosNames fetch | {} has no primary name", address);
+    }
+
+    Ok(fetched)
+}
+
+async fn fetch_address_by_name(system: CosmosNameSystem, name: &str) -> Result<Fetched, Error> {
+    let name = name.to_lowercase();
+    let address = match system.resolve(&name).await? {
+        Some(address) => address,
+        None => {
+            warn!("CosmosNames fetch | {:?} {} is not resolving", system, name);
+            return Ok(Fetched::default());
+        }
+    };
+    let is_primary = system.reverse_resolve(&address).await?.as_ref() == Some(&name);
+
+    Ok(Fetched {
+        connections: vec![name_connection(system, &address, &name, is_primary)],
+        next_targets: vec![Target::Identity(Platform::Cosmos, address)],
+        ..Default::default()
+    })
+}
+
+/// Hold / resolve / reverse resolve records of a name.
+fn name_connection(
+    system: CosmosNameSystem,
+    address: &str,
+    name: &str,
+    is_primary: bool,
+) -> Connection {
+    let cosmos_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Cosmos,
+        identity: address.to_string(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let name_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: system.platform(),
+        identity: name.to_string(),
+        created_at: None,
+        display_name: Some(name.to_string()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(system.profile_url(name)),
+        updated_at: naive_now(),
+    };
+    let hold: Hold = Hold {
+        uuid: Uuid::new_v4(),
+        source: system.data_source(),
+        transaction: None,
+        id: name.to_string(),
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+    let resolve: Resolve = Resolve {
+        uuid: Uuid::new_v4(),
+        source: system.data_source(),
+        system: system.domain_name_system(),
+        name: name.to_string(),
+        resolved_address: Some(cosmos_identity.identity.clone()),
+        expire_at: None,
+        fetcher: DataFetcher::RelationService,
+        updated_at: naive_now(),
+    };
+
+    Connection::Domain {
+        address: cosmos_identity,
+        domain: name_identity,
+        hold,
+        resolve,
+        is_primary,
+    }
+}
@@ -0,0 +1,46 @@
+use crate::upstream::cosmos_names::{normalize_address, split_address, split_name};
+
+#[test]
+fn test_split_name() {
+    assert_eq!(split_name("alice.osmo"), Some(("alice", "osmo")));
+    assert_eq!(split_name("alice.stars"), Some(("alice", "stars")));
+    assert_eq!(split_name("alice"), None);
+    assert_eq!(split_name(".osmo"), None);
+}
+
+#[test]
+fn test_normalize_address() {
+    assert_eq!(
+        normalize_address("OSMO1QYPQXPQ9QCRSSZG2PVXQ6RS0ZQG3YYC5HELWSW", None),
+        Some("osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw".into())
+    );
+    assert_eq!(
+        normalize_address(
+            "stars1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5t7mrdd",
+            Some("stars")
+        ),
+        Some("stars1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5t7mrdd".into())
+    );
+    // Prefix mismatch
+    assert_eq!(
+        normalize_address(
+            "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu",
+            Some("osmo")
+        ),
+        None
+    );
+    // Checksum mismatch
+    assert_eq!(
+        normalize_address("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xa", None),
+        None
+    );
+}
+
+#[test]
+fn test_split_address() {
+    assert_eq!(
+        split_address("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu"),
+        Some("cosmos".into())
+    );
+    assert_eq!(split_address("alice.osmo"), None);
+}
@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// https://indexer.crossbell.io/docs
+#[derive(Deserialize, Debug)]
+pub struct CharactersResponse {
+    pub list: Vec<Character>,
+    pub count: i64,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Character {
+    pub character_id: i64,
+    /// Without `.csb` suffix.
+    pub handle: String,
+    pub owner: String,
+    pub metadata: Option<CharacterMetadata>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CharacterMetadata {
+    pub content: Option<CharacterContent>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CharacterContent {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub avatars: Vec<String>,
+    /// `csb://account:{username}@{platform}`
+    #[serde(default)]
+    pub connected_accounts: Vec<String>,
+}
+
+pub struct Crossbell {}
+
+#[async_trait]
+impl Fetcher for Crossbell {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_characters_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+/// Parse `csb://account:{username}@{platform}` into `(Platform, username)`.
+/// Username may also contain `@` (e.g. Mastodon `user@instance`), so split by the last one.
+fn parse_connected_account(uri: &str) -> Option<(Platform, String)> {
+    let account = uri.strip_prefix("csb://account:")?;
+    let (username, platform) = account.rsplit_once('@')?;
+    let platform = Platform::from_str(&platform.to_lowercase()).ok()?;
+    if username.is_empty() || platform == Platform::Unknown {
+        return None;
+    }
+    Some((platform, username.to_lowercase()))
+}
+
+async fn fetch_characters(address: &str) -> Result<CharactersResponse, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/v1/addresses/{}/characters?limit=20",
+        C.upstream.crossbell_api.url, address
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Crossbell Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Crossbell fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Crossbell Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_characters_by_address(address: &str) -> Result<Fetched, Error> {
+    let result = fetch_characters(address).await?;
+    if result.list.is_empty() {
+        info!("Crossbell fetch | address: {} has no character", address);
+        return Ok(Fetched::default());
+    }
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for character in result.list.into_iter() {
+        let handle = format!("{}.csb", character.handle.to_lowercase());
+        let content = character.metadata.and_then(|metadata| metadata.content);
+        let created_at = character
+            .created_at
+            .as_deref()
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+            .map(|dt| dt.naive_utc());
+        let character_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Crossbell,
+            identity: handle.clone(),
+            created_at,
+            display_name: content.as_ref().and_then(|content| content.name.clone()),
+            added_at: naive_now(),
+            avatar_url: content
+                .as_ref()
+                .and_then(|content| content.avatars.first().cloned()),
+            profile_url: Some(format!("https://xchar.app/{}", character.handle)),
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Crossbell,
+            record_id: Some(character.character_id.to_string()),
+            created_at,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::Cryptographic,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            eth_identity.clone(),
+            character_identity.clone(),
+            proof,
+        ));
+
+        let connected_accounts = content
+            .map(|content| content.connected_accounts)
+            .unwrap_or_default();
+        for uri in connected_accounts.into_iter() {
+            let (platform, username) = match parse_connected_account(&uri) {
+                Some(account) => account,
+                None => {
+                    debug!(uri, "Crossbell fetch | Connected account skipped");
+                    continue;
+                }
+            };
+            let account_identity: Identity = Identity {
+                uuid: Some(Uuid::new_v4()),
+                platform,
+                identity: username.clone(),
+                created_at: None,
+                display_name: None,
+                added_at: naive_now(),
+                avatar_url: None,
+                profile_url: None,
+                updated_at: naive_now(),
+            };
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::Crossbell,
+                record_id: Some(uri),
+                created_at: None,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::SelfClaimed,
+            };
+            fetched.connections.push(Connection::Proof(
+                character_identity.clone(),
+                account_identity,
+                proof,
+            ));
+            fetched
+                .next_targets
+                .push(Target::Identity(platform, username));
+        }
+    }
+
+    Ok(fetched)
+}
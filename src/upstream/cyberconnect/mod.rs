@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Participate, Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{naive_now, within_timeout},
+};
+use async_trait::async_trait;
+use gql_client::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct QueryVars {
+    address: String,
+}
+
+/// ccProfile: https://docs.cyberconnect.me/api/profile
+#[derive(Deserialize, Debug)]
+pub struct ProfileResponse {
+    pub address: Option<AddressInfo>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AddressInfo {
+    pub wallet: Option<Wallet>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Wallet {
+    pub primary_profile: Option<Profile>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    #[serde(rename = "profileID")]
+    pub profile_id: i64,
+    /// `name.cyber`
+    pub handle: String,
+    pub metadata_info: Option<ProfileMetadata>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileMetadata {
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+}
+
+/// Social graph: https://docs.cyberconnect.me/api/social-graph
+#[derive(Deserialize, Debug)]
+pub struct IdentityResponse {
+    pub identity: Option<SocialIdentity>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SocialIdentity {
+    pub address: String,
+    pub social: Option<Social>,
+    pub followings: Option<Followings>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Social {
+    /// Twitter handle verified by CyberConnect.
+    pub twitter: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Followings {
+    pub list: Vec<Following>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Following {
+    pub address: String,
+}
+
+const PROFILE_QUERY: &str = r#"
+    query PrimaryProfile($address: AddressEVM!) {
+        address(address: $address) {
+            wallet {
+                primaryProfile {
+                    profileID
+                    handle
+                    metadataInfo {
+                        displayName
+                        avatar
+                    }
+                }
+            }
+        }
+    }
+"#;
+
+const IDENTITY_QUERY: &str = r#"
+    query Identity($address: String!) {
+        identity(address: $address, network: ETH) {
+            address
+            social {
+                twitter
+            }
+            followings(first: 100) {
+                list {
+                    address
+                }
+            }
+        }
+    }
+"#;
+
+pub struct CyberConnect {}
+
+#[async_trait]
+impl Fetcher for CyberConnect {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_profile_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn query<T: DeserializeOwned>(
+    url: &str,
+    query: &str,
+    address: &str,
+) -> Result<Option<T>, Error> {
+    let mut headers = HashMap::new();
+    headers.insert("X-API-KEY", C.upstream.cyberconnect.token.as_str());
+    let client = Client::new_with_headers(url, headers);
+    let vars = QueryVars {
+        address: address.to_string(),
+    };
+    let response = client.query_with_vars::<T, _>(query, vars);
+
+    within_timeout("CyberConnect", response).await
+}
+
+async fn fetch_primary_profile(address: &str) -> Result<Option<Profile>, Error> {
+    let resp: Option<ProfileResponse> =
+        query(&C.upstream.cyberconnect.url, PROFILE_QUERY, address).await?;
+    Ok(resp
+        .and_then(|resp| resp.address)
+        .and_then(|address| address.wallet)
+        .and_then(|wallet| wallet.primary_profile))
+}
+
+async fn fetch_social_identity(address: &str) -> Result<Option<SocialIdentity>, Error> {
+    let resp: Option<IdentityResponse> =
+        query(&C.upstream.cyberconnect.social_url, IDENTITY_QUERY, address).await?;
+    Ok(resp.and_then(|resp| resp.identity))
+}
+
+async fn fetch_profile_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let mut fetched = Fetched::default();
+
+    match fetch_primary_profile(&address).await? {
+        Some(profile) => {
+            let metadata = profile.metadata_info;
+            let profile_identity: Identity = Identity {
+                uuid: Some(Uuid::new_v4()),
+                platform: Platform::CyberConnect,
+                identity: profile.handle.to_lowercase(),
+                created_at: None,
+                display_name: metadata.as_ref().and_then(|m| m.display_name.clone()),
+                added_at: naive_now(),
+                avatar_url: metadata.as_ref().and_then(|m| m.avatar.clone()),
+                profile_url: Some(format!("https://link3.to/{}", profile.handle)),
+                updated_at: naive_now(),
+            };
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::CyberConnect,
+                record_id: Some(profile.profile_id.to_string()),
+                created_at: None,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::Cryptographic,
+            };
+            fetched.connections.push(Connection::TwoWayBinding(
+                eth_identity.clone(),
+                profile_identity,
+                proof,
+            ));
+        }
+        None => info!("CyberConnect fetch | {} has no ccProfile", address),
+    }
+
+    let social_identity = match fetch_social_identity(&address).await? {
+        Some(social_identity) => social_identity,
+        None => return Ok(fetched),
+    };
+    if let Some(twitter) = social_identity
+        .social
+        .and_then(|social| social.twitter)
+        .filter(|twitter| !twitter.is_empty())
+    {
+        let twitter = twitter.to_lowercase();
+        let twitter_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Twitter,
+            identity: twitter.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::CyberConnect,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::Proof(
+            eth_identity.clone(),
+            twitter_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::Twitter, twitter));
+    }
+
+    // Follow graph is not an identity proof, so followed addresses are not fetched further.
+    let followings = social_identity
+        .followings
+        .map(|followings| followings.list)
+        .unwrap_or_default();
+    for following in followings.into_iter() {
+        let following_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: following.address.to_lowercase(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let participate: Participate = Participate {
+            uuid: Uuid::new_v4(),
+            source: DataSource::CyberConnect,
+            following: true,
+            votes: 0,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Participate(
+            eth_identity.clone(),
+            following_identity,
+            participate,
+        ));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,20 @@
+use crate::{
+    error::Error,
+    upstream::cyberconnect::{fetch_primary_profile, fetch_social_identity},
+};
+
+#[tokio::test]
+async fn test_fetch_primary_profile() -> Result<(), Error> {
+    let address = "0x148d59faf10b52063071eddf4aaf63a395f2d41c";
+    let profile = fetch_primary_profile(address).await?;
+    println!("profile: {:?}", profile);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_social_identity() -> Result<(), Error> {
+    let address = "0x148d59faf10b52063071eddf4aaf63a395f2d41c";
+    let identity = fetch_social_identity(address).await?;
+    println!("identity: {:?}", identity);
+    Ok(())
+}
@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Participate, Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, paginate, parse_body, request_with_timeout, Page, Paginated},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method, Request};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::info;
+use uuid::Uuid;
+
+/// Page size of `/user/following_list`.
+const FOLLOWING_PAGE_SIZE: usize = 100;
+
+/// Every DeBank response is wrapped in `{"data": ..., "error_code": 0}`.
+#[derive(Deserialize, Debug)]
+pub struct DeBankResponse<T> {
+    pub data: Option<T>,
+    pub error_code: i64,
+    pub error_msg: Option<String>,
+}
+
+/// https://api.debank.com/user?id={address}
+#[derive(Deserialize, Debug)]
+pub struct UserData {
+    pub user: Option<User>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct User {
+    /// Address of this user.
+    pub id: String,
+    /// Web3 ID, `None` if not registered.
+    pub web3_id: Option<String>,
+    pub desc: Option<UserDesc>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UserDesc {
+    /// Unix timestamp (seconds) of the first transaction of this address.
+    pub born_at: Option<i64>,
+    pub profile_vision: Option<ProfileVision>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ProfileVision {
+    pub thumbnail_url: Option<String>,
+}
+
+/// https://api.debank.com/user/following_list?id={address}&start=0&limit=100
+#[derive(Deserialize, Debug)]
+pub struct FollowingData {
+    pub following_list: Vec<Following>,
+    pub total_count: usize,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Following {
+    /// Address being followed.
+    pub id: String,
+}
+
+pub struct DeBank {}
+
+#[async_trait]
+impl Fetcher for DeBank {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, identity) => fetch_profile_by_address(identity).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn get<T: DeserializeOwned>(path: String) -> Result<Option<T>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}{}", C.upstream.debank_api.url, path)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let mut builder = Request::builder().method(Method::GET).uri(uri);
+    if !C.upstream.debank_api.access_key.is_empty() {
+        builder = builder.header("AccessKey", C.upstream.debank_api.access_key.clone());
+    }
+    let req = builder
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("DeBank Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("DeBank fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("DeBank fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: DeBankResponse<T> = parse_body(&mut resp).await?;
+    if body.error_code != 0 {
+        return Err(Error::General(
+            format!("DeBank fetch error: {}", body.error_msg.unwrap_or_default()),
+            resp.status(),
+        ));
+    }
+    Ok(body.data)
+}
+
+async fn fetch_user(address: &str) -> Result<Option<User>, Error> {
+    let data: Option<UserData> = get(format!("/user?id={}", address)).await?;
+    Ok(data.and_then(|data| data.user))
+}
+
+async fn fetch_followings(address: &str) -> Result<Paginated<String>, Error> {
+    paginate(0, |start| fetch_followings_page(address, start)).await
+}
+
+async fn fetch_followings_page(address: &str, start: usize) -> Result<Page<String, usize>, Error> {
+    let data: Option<FollowingData> = get(format!(
+        "/user/following_list?id={}&start={}&limit={}",
+        address, start, FOLLOWING_PAGE_SIZE
+    ))
+    .await?;
+    let data = match data {
+        Some(data) => data,
+        None => {
+            return Ok(Page {
+                items: vec![],
+                next: None,
+            })
+        }
+    };
+    let followings: Vec<String> = data
+        .following_list
+        .into_iter()
+        .map(|following| following.id.to_lowercase())
+        .collect();
+    let mut page = Page::offset(followings, start, FOLLOWING_PAGE_SIZE);
+    if start + page.items.len() >= data.total_count {
+        page.next = None;
+    }
+    Ok(page)
+}
+
+/// `@Alice` / `alice` => `alice`
+fn normalize_web3_id(web3_id: &str) -> String {
+    web3_id.trim_start_matches('@').to_lowercase()
+}
+
+async fn fetch_profile_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let user = match fetch_user(&address).await? {
+        Some(user) => user,
+        None => {
+            info!("DeBank fetch | {} has no DeBank profile", address);
+            return Ok(Fetched::default());
+        }
+    };
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    match user
+        .web3_id
+        .as_deref()
+        .map(normalize_web3_id)
+        .filter(|web3_id| !web3_id.is_empty())
+    {
+        Some(web3_id) => {
+            let avatar_url = user
+                .desc
+                .as_ref()
+                .and_then(|desc| desc.profile_vision.as_ref())
+                .and_then(|vision| vision.thumbnail_url.clone());
+            let debank_identity: Identity = Identity {
+                uuid: Some(Uuid::new_v4()),
+                platform: Platform::DeBank,
+                identity: web3_id.clone(),
+                created_at: None,
+                display_name: Some(web3_id.clone()),
+                added_at: naive_now(),
+                avatar_url,
+                profile_url: Some(format!("https://debank.com/profile/{}", address)),
+                updated_at: naive_now(),
+            };
+            // Web3 ID is registered by the address itself on DeBank.
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::DeBank,
+                record_id: None,
+                created_at: None,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::PlatformVerified,
+            };
+            fetched.connections.push(Connection::TwoWayBinding(
+                eth_identity.clone(),
+                debank_identity,
+                proof,
+            ));
+        }
+        None => info!("DeBank fetch | {} has no Web3 ID", address),
+    }
+
+    // Follow graph is not an identity proof, so followed addresses are not fetched further.
+    let followings = fetch_followings(&address).await?;
+    fetched.truncated = followings.truncated;
+    for following in followings.items.into_iter() {
+        let following_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: following,
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let participate: Participate = Participate {
+            uuid: Uuid::new_v4(),
+            source: DataSource::DeBank,
+            following: true,
+            votes: 0,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Participate(
+            eth_identity.clone(),
+            following_identity,
+            participate,
+        ));
+    }
+
+    Ok(fetched)
+}
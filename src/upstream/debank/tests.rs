@@ -0,0 +1,27 @@
+use crate::{
+    error::Error,
+    upstream::debank::{fetch_followings, fetch_user, normalize_web3_id},
+};
+
+#[test]
+fn test_normalize_web3_id() {
+    assert_eq!(normalize_web3_id("@Alice"), "alice");
+    assert_eq!(normalize_web3_id("alice"), "alice");
+}
+
+#[tokio::test]
+async fn test_fetch_user() -> Result<(), Error> {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let user = fetch_user(address).await?;
+    println!("user: {:?}", user);
+    assert!(user.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_followings() -> Result<(), Error> {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let followings = fetch_followings(address).await?;
+    println!("followings: {:?}", followings);
+    Ok(())
+}
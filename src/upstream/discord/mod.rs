@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// Attestations issued by the configured Discord OAuth attestation service.
+/// A Discord user logs in with OAuth and signs a challenge with their wallet,
+/// then the service publishes the binding.
+#[derive(Deserialize, Debug)]
+pub struct AttestationsResponse {
+    #[serde(default)]
+    pub attestations: Vec<Attestation>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Attestation {
+    /// Discord snowflake ID of the user. Never changes.
+    pub user_id: String,
+    /// Username at the time of attestation. May change later.
+    pub username: String,
+    pub avatar: Option<String>,
+    pub address: String,
+    pub signature: String,
+    /// Unix timestamp in seconds.
+    pub created_at: i64,
+    pub is_valid: bool,
+}
+
+pub struct Discord {}
+
+#[async_trait]
+impl Fetcher for Discord {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_attestations_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        if C.upstream.discord_attestation.url.is_empty() {
+            return false;
+        }
+        match target {
+            Target::Identity(Platform::Discord, user_id) => is_snowflake(user_id),
+            Target::Identity(Platform::Ethereum, _) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Discord snowflake IDs are unsigned 64-bit integers in decimal form.
+pub fn is_snowflake(identity: &str) -> bool {
+    !identity.is_empty()
+        && identity.chars().all(|c| c.is_ascii_digit())
+        && identity.parse::<u64>().is_ok()
+}
+
+async fn fetch_attestations(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Vec<Attestation>, Error> {
+    let client = make_client();
+    let query = match platform {
+        Platform::Discord => format!("user_id={}", identity),
+        _ => format!("address={}", identity.to_lowercase()),
+    };
+    let uri: http::Uri = format!(
+        "{}/attestations?{}",
+        C.upstream.discord_attestation.url, query
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header(
+            "Authorization",
+            format!("Bearer {}", C.upstream.discord_attestation.token),
+        )
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Discord Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Discord fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Discord Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: AttestationsResponse = parse_body(&mut resp).await?;
+    Ok(body.attestations)
+}
+
+async fn fetch_attestations_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let attestations = fetch_attestations(platform, identity).await?;
+    if attestations.is_empty() {
+        info!(
+            "Discord fetch | {}: {} has no attestation",
+            platform, identity
+        );
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    for attestation in attestations.into_iter() {
+        if !attestation.is_valid || !is_snowflake(&attestation.user_id) {
+            debug!("Discord fetch | invalid attestation: {:?}", attestation);
+            continue;
+        }
+        let address = attestation.address.to_lowercase();
+
+        let discord_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Discord,
+            identity: attestation.user_id.clone(),
+            created_at: None,
+            display_name: Some(attestation.username.clone()),
+            added_at: naive_now(),
+            avatar_url: attestation.avatar.as_ref().map(|avatar| {
+                format!(
+                    "https://cdn.discordapp.com/avatars/{}/{}.png",
+                    attestation.user_id, avatar
+                )
+            }),
+            profile_url: Some(format!("https://discord.com/users/{}", attestation.user_id)),
+            updated_at: naive_now(),
+        };
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: address.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Discord,
+            record_id: Some(attestation.signature.clone()),
+            created_at: Some(timestamp_to_naive(attestation.created_at, 0)),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            discord_identity,
+            eth_identity,
+            proof,
+        ));
+
+        match platform {
+            Platform::Discord => fetched
+                .next_targets
+                .push(Target::Identity(Platform::Ethereum, address)),
+            _ => fetched
+                .next_targets
+                .push(Target::Identity(Platform::Discord, attestation.user_id)),
+        }
+    }
+
+    Ok(fetched)
+}
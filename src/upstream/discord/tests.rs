@@ -0,0 +1,10 @@
+use crate::upstream::discord::is_snowflake;
+
+#[test]
+fn test_is_snowflake() {
+    assert!(is_snowflake("175928847299117063"));
+    assert!(!is_snowflake("suji_yan"));
+    assert!(!is_snowflake("suji#1234"));
+    assert!(!is_snowflake(""));
+    assert!(!is_snowflake("99999999999999999999999"));
+}
@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{
+        keybase::lookup, Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// DNS-over-HTTPS JSON response.
+/// https://developers.cloudflare.com/1.1.1.1/encryption/dns-over-https/make-api-requests/dns-json/
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct DoHResponse {
+    /// DNS response code. `0` is `NOERROR`.
+    pub status: i32,
+    #[serde(default)]
+    pub answer: Vec<DoHAnswer>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DoHAnswer {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: u16,
+    /// Quoted TXT content, e.g. `"keybase-site-verification=..."`
+    pub data: String,
+}
+
+const TXT_RECORD_TYPE: u16 = 16;
+
+/// A domain binding claimed in a TXT record.
+#[derive(Debug, PartialEq)]
+pub enum TxtClaim {
+    /// `keybase-site-verification=SIG`
+    Keybase,
+    /// `nextid=PERSONA`
+    NextID(String),
+}
+
+pub struct DNSRecord {}
+
+#[async_trait]
+impl Fetcher for DNSRecord {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, domain) => fetch_txt_proofs_by_domain(domain).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::DNS])
+    }
+}
+
+/// Unquote TXT content. Long TXT records may be split into several quoted strings.
+fn unquote_txt(data: &str) -> String {
+    data.split("\" \"")
+        .map(|part| part.trim_matches('"'))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn parse_txt_claim(txt: &str) -> Option<TxtClaim> {
+    let (key, value) = txt.split_once('=')?;
+    if value.is_empty() {
+        return None;
+    }
+    match key.trim() {
+        "keybase-site-verification" => Some(TxtClaim::Keybase),
+        "nextid" if value.starts_with("0x") => Some(TxtClaim::NextID(value.to_lowercase())),
+        _ => None,
+    }
+}
+
+async fn fetch_txt_records(domain: &str) -> Result<Vec<String>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}?name={}&type=TXT", C.upstream.dns.url, domain)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("Accept", "application/dns-json")
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("DNS Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("DNS fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("DNS Get error: {} {}", domain, resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let result: DoHResponse = parse_body(&mut resp).await?;
+    Ok(result
+        .answer
+        .into_iter()
+        .filter(|answer| answer.record_type == TXT_RECORD_TYPE)
+        .map(|answer| unquote_txt(&answer.data))
+        .collect())
+}
+
+async fn fetch_txt_proofs_by_domain(domain: &str) -> Result<Fetched, Error> {
+    let domain = domain.trim_end_matches('.').to_lowercase();
+    let records = fetch_txt_records(&domain).await?;
+
+    let dns_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::DNS,
+        identity: domain.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for txt in records.into_iter() {
+        let (platform, identity, display_name, source) = match parse_txt_claim(&txt) {
+            // TXT record only contains a signature, find who claimed this domain on Keybase.
+            Some(TxtClaim::Keybase) => match lookup(&format!("domain={}", domain)).await {
+                Ok(person_info) => (
+                    Platform::Keybase,
+                    person_info.id,
+                    Some(person_info.basics.username),
+                    DataSource::Keybase,
+                ),
+                Err(err) => {
+                    warn!("DNS fetch | Keybase user of {}: {}", domain, err);
+                    continue;
+                }
+            },
+            Some(TxtClaim::NextID(persona)) => {
+                (Platform::NextID, persona, None, DataSource::NextID)
+            }
+            None => continue,
+        };
+        let claimed_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        // TXT record content is the evidence.
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source,
+            record_id: Some(txt),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            dns_identity.clone(),
+            claimed_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+    if fetched.next_targets.is_empty() {
+        info!("DNS fetch | {} has no proof TXT record", domain);
+    }
+
+    Ok(fetched)
+}
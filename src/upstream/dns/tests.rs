@@ -0,0 +1,26 @@
+use crate::{
+    error::Error,
+    upstream::dns::{fetch_txt_records, parse_txt_claim, unquote_txt, TxtClaim},
+};
+
+#[test]
+fn test_parse_txt_claim() {
+    assert_eq!(
+        parse_txt_claim(&unquote_txt(
+            "\"keybase-site-verification=Hq0Uz2ITW7VTb2VFzArcnOVd\" \"cQ8tQAP1Zgn3nGyhv9c\""
+        )),
+        Some(TxtClaim::Keybase)
+    );
+    assert_eq!(
+        parse_txt_claim("nextid=0x03A1B2"),
+        Some(TxtClaim::NextID("0x03a1b2".into()))
+    );
+    assert_eq!(parse_txt_claim("v=spf1 include:_spf.google.com ~all"), None);
+}
+
+#[tokio::test]
+async fn test_fetch_txt_records() -> Result<(), Error> {
+    let records = fetch_txt_records("keybase.io").await?;
+    println!("records: {:?}", records);
+    Ok(())
+}
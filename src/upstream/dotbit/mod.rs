@@ -2,14 +2,10 @@
 mod tests;
 use crate::config::C;
 use crate::error::Error;
-use crate::graph::create_domain_resolve_record;
-use crate::graph::create_identity_to_identity_hold_record;
-use crate::graph::edge::Edge;
 use crate::graph::edge::Resolve;
 use crate::graph::edge::{hold::Hold, resolve::DomainNameSystem};
-use crate::graph::vertex::Vertex;
-use crate::graph::{new_db_connection, vertex::Identity};
-use crate::upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList};
+use crate::graph::vertex::Identity;
+use crate::upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target};
 use crate::util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive};
 use async_trait::async_trait;
 use hyper::{Body, Method, Request};
@@ -21,16 +17,16 @@ pub struct DotBit {}
 
 #[async_trait]
 impl Fetcher for DotBit {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
@@ -155,18 +151,18 @@ const UNKNOWN_OWNER: &str = "0x0000000000000000000000000000000000000000";
 async fn fetch_connections_by_platform_identity(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     match *platform {
         Platform::Dotbit => fetch_connections_by_account_info(platform, identity).await,
         Platform::Ethereum => fetch_hold_acc_and_reverse_record_by_addrs(platform, identity).await,
-        _ => Ok(vec![]),
+        _ => Ok(Fetched::default()),
     }
 }
 
 async fn fetch_connections_by_account_info(
     _platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     let request_acc = AccInfoRequestParams {
         account: identity.to_string(),
     };
@@ -193,7 +189,7 @@ async fn fetch_connections_by_account_info(
     })?;
 
     let resp: AccountInfoResponse = parse_body(&mut result).await?;
-    if resp.result.errno.unwrap() != 0 {
+    if resp.result.errno.unwrap_or_default() != 0 || resp.result.data.is_none() {
         warn!("fail to fetch the result from .bit, resp {:?}", resp);
         return Err(Error::NoResult);
     }
@@ -209,8 +205,6 @@ async fn fetch_connections_by_account_info(
         return Err(Error::NoResult);
     }
 
-    // add to db
-    let db = new_db_connection().await?;
     let created_at_naive = timestamp_to_naive(account_info.create_at_unix, 0);
 
     let eth_identity: Identity = Identity {
@@ -252,25 +246,31 @@ async fn fetch_connections_by_account_info(
         source: DataSource::Dotbit,
         system: DomainNameSystem::DotBit,
         name: identity.to_string(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: Some(timestamp_to_naive(account_info.expired_at_unix, 0)),
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
 
-    // hold record
-    create_identity_to_identity_hold_record(&db, &eth_identity, &dotbit_identity, &hold).await?;
-    // 'regular' resolution involves mapping from a name to an address.
-    create_domain_resolve_record(&db, &dotbit_identity, &eth_identity, &resolve).await?;
-
-    return Ok(vec![Target::Identity(
-        Platform::Ethereum,
-        account_info.owner_key,
-    )]);
+    Ok(Fetched {
+        connections: vec![
+            // hold record
+            Connection::Hold(eth_identity.clone(), dotbit_identity.clone(), hold),
+            // 'regular' resolution involves mapping from a name to an address.
+            Connection::Resolve(dotbit_identity, eth_identity, resolve),
+        ],
+        next_targets: vec![Target::Identity(
+            Platform::Ethereum,
+            account_info.owner_key.to_lowercase(),
+        )],
+        ..Default::default()
+    })
 }
 
 async fn fetch_hold_acc_and_reverse_record_by_addrs(
     _platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     // account_list api result doesn't provide any proofs(tx, recordID...)
     // remove the function first
     // fetch_account_list_by_addrs(_platform, identity).await?;
@@ -300,7 +300,7 @@ async fn fetch_hold_acc_and_reverse_record_by_addrs(
     })?;
 
     let resp: ReverseResponse = parse_body(&mut result).await?;
-    if resp.result.errno.unwrap() != 0 {
+    if resp.result.errno.unwrap_or_default() != 0 {
         warn!("fail to fetch the result from .bit, resp {:?}", resp);
         return Err(Error::NoResult);
     }
@@ -309,7 +309,6 @@ async fn fetch_hold_acc_and_reverse_record_by_addrs(
     }
 
     let result_data = resp.result.data.unwrap();
-    let db = new_db_connection().await?;
     let eth_identity: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::Ethereum,
@@ -349,27 +348,34 @@ async fn fetch_hold_acc_and_reverse_record_by_addrs(
         source: DataSource::Dotbit,
         system: DomainNameSystem::DotBit,
         name: result_data.account.clone(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: None,
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
 
-    // hold record
-    create_identity_to_identity_hold_record(&db, &eth_identity, &dotbit_identity, &hold).await?;
-    // 'regular' resolution involves mapping from a name to an address.
-    create_domain_resolve_record(&db, &dotbit_identity, &eth_identity, &resolve).await?;
-    // das_reverseRecord: 'reverse' resolution maps from an address back to a name.
-    create_domain_resolve_record(&db, &eth_identity, &dotbit_identity, &resolve).await?;
-
-    return Ok(vec![Target::Identity(
-        Platform::Dotbit,
-        result_data.account.clone(),
-    )]);
+    Ok(Fetched {
+        connections: vec![
+            // hold record
+            Connection::Hold(eth_identity.clone(), dotbit_identity.clone(), hold),
+            // 'regular' resolution involves mapping from a name to an address.
+            Connection::Resolve(
+                dotbit_identity.clone(),
+                eth_identity.clone(),
+                resolve.clone(),
+            ),
+            // das_reverseRecord: 'reverse' resolution maps from an address back to a name.
+            Connection::Resolve(eth_identity, dotbit_identity, resolve),
+        ],
+        next_targets: vec![Target::Identity(Platform::Dotbit, result_data.account)],
+        ..Default::default()
+    })
 }
 
 async fn fetch_account_list_by_addrs(
     _platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     // das_accountList
     let request_params = get_req_params_by_platform(_platform, identity);
     let params = ReverseRecordRequest {
@@ -395,12 +401,12 @@ async fn fetch_account_list_by_addrs(
     })?;
 
     let resp: AccountListResponse = parse_body(&mut result).await?;
-    if resp.result.errno.unwrap() != 0 || resp.result.data.is_none() {
+    if resp.result.errno.unwrap_or_default() != 0 || resp.result.data.is_none() {
         warn!("fail to fetch the result from .bit, resp {:?}", resp);
         return Err(Error::NoResult);
     }
 
-    let db = new_db_connection().await?;
+    let mut fetched = Fetched::default();
     let from: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::Ethereum,
@@ -412,7 +418,6 @@ async fn fetch_account_list_by_addrs(
         profile_url: None,
         updated_at: naive_now(),
     };
-    let from_record = from.create_or_update(&db).await?;
 
     for i in resp.result.data.unwrap().account_list.into_iter() {
         let to: Identity = Identity {
@@ -437,11 +442,12 @@ async fn fetch_account_list_by_addrs(
             fetcher: DataFetcher::RelationService,
         };
 
-        let to_record = to.create_or_update(&db).await?;
-        hold.connect(&db, &from_record, &to_record).await?;
+        fetched
+            .connections
+            .push(Connection::Hold(from.clone(), to, hold));
     }
 
-    Ok(vec![])
+    Ok(fetched)
 }
 
 fn get_req_params_by_platform(_platform: &Platform, identity: &str) -> RequestTypeKeyInfoParams {
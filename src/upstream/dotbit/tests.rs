@@ -1,6 +1,6 @@
 use crate::graph::edge::Hold;
 use crate::upstream::Target;
-use crate::{error::Error, upstream::dotbit::DotBit, upstream::Fetcher};
+use crate::{error::Error, upstream::dotbit::DotBit, upstream::fetch_and_import};
 use crate::{
     graph::new_db_connection, graph::vertex::Identity, upstream::Platform, util::naive_now,
 };
@@ -9,7 +9,7 @@ use crate::{
 async fn test_smoke_dotbit_by_dotbit_identity() -> Result<(), Error> {
     let target = Target::Identity(Platform::Dotbit, "test0920.bit".into());
 
-    DotBit::fetch(&target).await?;
+    fetch_and_import::<DotBit>(&target).await?;
 
     let db = new_db_connection().await?;
     let found = Identity::find_by_platform_identity(&db, &target.platform()?, &target.identity()?)
@@ -29,13 +29,13 @@ async fn test_smoke_dotbit_reverse_record() -> Result<(), Error> {
         Platform::Ethereum,
         "0x4271B15dCa69f8C1c942c64028dBd3B84c5D03B0".into(),
     );
-    assert_eq!(DotBit::fetch(&target).await.is_err(), true);
+    assert_eq!(fetch_and_import::<DotBit>(&target).await.is_err(), true);
 
     let target2 = Target::Identity(
         Platform::Ethereum,
         "0X9176ACD39A3A9AE99DCB3922757F8AF4F94CDF3C".into(),
     );
-    DotBit::fetch(&target2).await?;
+    fetch_and_import::<DotBit>(&target2).await?;
     let db = new_db_connection().await?;
 
     assert_eq!(
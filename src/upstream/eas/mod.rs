@@ -0,0 +1,273 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::{ConfigEASSchema, C},
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{
+        eth::to_checksum_address, naive_now, paginate, timestamp_to_naive, within_timeout, Page,
+        Paginated,
+    },
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use gql_client::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::str::FromStr;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct QueryVars {
+    recipient: String,
+    schemas: Vec<String>,
+    take: usize,
+    skip: usize,
+}
+
+/// Page size of `attestations` query.
+const PAGE_SIZE: usize = 100;
+
+const ATTESTATIONS_QUERY: &str = r#"
+    query Attestations($recipient: String!, $schemas: [String!], $take: Int, $skip: Int) {
+        attestations(
+            where: { recipient: { equals: $recipient }, schemaId: { in: $schemas } }
+            orderBy: { time: asc }
+            take: $take
+            skip: $skip
+        ) {
+            id
+            schemaId
+            attester
+            recipient
+            revoked
+            revocationTime
+            expirationTime
+            time
+            decodedDataJson
+        }
+    }
+"#;
+
+/// https://docs.attest.sh/docs/developer-tools/api
+#[derive(Deserialize, Debug)]
+pub struct AttestationsResponse {
+    pub attestations: Vec<Attestation>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Attestation {
+    /// Attestation UID.
+    pub id: String,
+    pub schema_id: String,
+    pub attester: String,
+    pub recipient: String,
+    pub revoked: bool,
+    /// Unix timestamp, `0` if not revoked.
+    pub revocation_time: i64,
+    /// Unix timestamp, `0` if it never expires.
+    pub expiration_time: i64,
+    /// Unix timestamp of creation.
+    pub time: i64,
+    /// `[{"name": "handle", "type": "string", "value": {"name": "handle", "type": "string", "value": "alice"}}]`
+    pub decoded_data_json: String,
+}
+
+impl Attestation {
+    /// Value of `field` in attestation data.
+    pub fn field(&self, field: &str) -> Option<String> {
+        let items: Vec<Value> = serde_json::from_str(&self.decoded_data_json).ok()?;
+        items
+            .iter()
+            .find(|item| item["name"] == field)
+            .and_then(|item| item["value"]["value"].as_str())
+            .map(|value| value.to_string())
+    }
+
+    /// When the proof of this attestation stops being valid.
+    /// A revoked attestation invalidates the edge recorded before it was revoked.
+    pub fn valid_until(&self) -> Option<NaiveDateTime> {
+        if self.revoked {
+            Some(if self.revocation_time > 0 {
+                timestamp_to_naive(self.revocation_time, 0)
+            } else {
+                naive_now()
+            })
+        } else if self.expiration_time > 0 {
+            Some(timestamp_to_naive(self.expiration_time, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Whether this attestation is of `schema` and made by an attester it trusts.
+    pub fn is_trusted_by(&self, schema: &ConfigEASSchema) -> bool {
+        schema.uid.eq_ignore_ascii_case(&self.schema_id)
+            && (schema.attesters.is_empty()
+                || schema
+                    .attesters
+                    .iter()
+                    .any(|attester| attester.eq_ignore_ascii_case(&self.attester)))
+    }
+
+    /// Level of the proof made from this attestation with `schema`.
+    /// Only attesters listed in `schema` are trusted to verify: anyone else's is just a claim.
+    pub fn level(&self, schema: &ConfigEASSchema) -> ProofLevel {
+        if schema
+            .attesters
+            .iter()
+            .any(|attester| attester.eq_ignore_ascii_case(&self.attester))
+        {
+            ProofLevel::PlatformVerified
+        } else {
+            ProofLevel::SelfClaimed
+        }
+    }
+}
+
+pub struct EAS {}
+
+#[async_trait]
+impl Fetcher for EAS {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_attestations_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        !C.upstream.eas.schemas.is_empty() && target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_attestations(address: &str) -> Result<Paginated<Attestation>, Error> {
+    paginate(0, |skip| fetch_attestations_page(address, skip)).await
+}
+
+async fn fetch_attestations_page(
+    address: &str,
+    skip: usize,
+) -> Result<Page<Attestation, usize>, Error> {
+    let client = Client::new(&C.upstream.eas.url);
+    let vars = QueryVars {
+        // EAS indexer stores checksummed addresses.
+        recipient: to_checksum_address(address),
+        schemas: C
+            .upstream
+            .eas
+            .schemas
+            .iter()
+            .map(|schema| schema.uid.clone())
+            .collect(),
+        take: PAGE_SIZE,
+        skip,
+    };
+    let response = client.query_with_vars::<AttestationsResponse, _>(ATTESTATIONS_QUERY, vars);
+
+    let attestations = within_timeout("EAS", response)
+        .await?
+        .map(|response| response.attestations)
+        .unwrap_or_default();
+    Ok(Page::offset(attestations, skip, PAGE_SIZE))
+}
+
+async fn fetch_attestations_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let attestations = fetch_attestations(&address).await?;
+    if attestations.items.is_empty() {
+        info!("EAS fetch | {} has no attestation", address);
+        return Ok(Fetched::default());
+    }
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched {
+        truncated: attestations.truncated,
+        ..Default::default()
+    };
+    for attestation in attestations.items.into_iter() {
+        let schema = match C
+            .upstream
+            .eas
+            .schemas
+            .iter()
+            .find(|schema| attestation.is_trusted_by(schema))
+        {
+            Some(schema) => schema,
+            None => continue,
+        };
+        let platform = match Platform::from_str(&schema.platform) {
+            Ok(platform) => platform,
+            Err(_) => {
+                warn!(
+                    "EAS fetch | unknown platform {} of schema {}",
+                    schema.platform, schema.uid
+                );
+                continue;
+            }
+        };
+        let identity = match attestation.field(&schema.field) {
+            Some(identity) if !identity.is_empty() => identity.to_lowercase(),
+            _ => continue,
+        };
+
+        let attested_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::EAS,
+            record_id: Some(attestation.id.clone()),
+            created_at: Some(timestamp_to_naive(attestation.time, 0)),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: attestation.valid_until(),
+            invalid_since: None,
+            invalidation_reason: None,
+            level: attestation.level(schema),
+        };
+        fetched.connections.push(Connection::Proof(
+            eth_identity.clone(),
+            attested_identity,
+            proof,
+        ));
+        if !attestation.revoked {
+            fetched
+                .next_targets
+                .push(Target::Identity(platform, identity));
+        }
+    }
+
+    Ok(fetched)
+}
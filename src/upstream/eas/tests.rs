@@ -0,0 +1,76 @@
+use crate::{
+    config::ConfigEASSchema,
+    graph::edge::ProofLevel,
+    upstream::eas::{Attestation, AttestationsResponse},
+    util::timestamp_to_naive,
+};
+
+const SCHEMA: &str = "0x5f2ad6ee1b1bd9a9ea1b2dee0a3e05e1c63f6fd4f1c1ac7a0c0b6b4ac6e8b2e1";
+
+fn attestation(revoked: bool, revocation_time: i64) -> Attestation {
+    let body = format!(
+        r#"{{"attestations": [{{
+            "id": "0x0e4a1b7c7e6f2c3f8b9f1f8a1e2d3c4b5a69788796a5b4c3d2e1f0a9b8c7d6e5",
+            "schemaId": "{}",
+            "attester": "0x1E3A1B7C7e6f2C3f8b9F1F8a1E2D3C4B5A697887",
+            "recipient": "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "revoked": {},
+            "revocationTime": {},
+            "expirationTime": 0,
+            "time": 1690000000,
+            "decodedDataJson": "[{{\"name\":\"handle\",\"type\":\"string\",\"signature\":\"string handle\",\"value\":{{\"name\":\"handle\",\"type\":\"string\",\"value\":\"VitalikButerin\"}}}}]"
+        }}]}}"#,
+        SCHEMA, revoked, revocation_time
+    );
+    let resp: AttestationsResponse = serde_json::from_str(&body).unwrap();
+    resp.attestations.into_iter().next().unwrap()
+}
+
+#[test]
+fn test_attestation_field() {
+    let attestation = attestation(false, 0);
+    assert_eq!(
+        attestation.field("handle").as_deref(),
+        Some("VitalikButerin")
+    );
+    assert_eq!(attestation.field("missing"), None);
+}
+
+#[test]
+fn test_attestation_valid_until() {
+    assert_eq!(attestation(false, 0).valid_until(), None);
+    assert_eq!(
+        attestation(true, 1700000000).valid_until(),
+        Some(timestamp_to_naive(1700000000, 0))
+    );
+}
+
+#[test]
+fn test_attestation_is_trusted_by() {
+    let attestation = attestation(false, 0);
+    let mut schema = ConfigEASSchema {
+        uid: SCHEMA.to_uppercase().replace("0X", "0x"),
+        platform: "twitter".into(),
+        field: "handle".into(),
+        attesters: vec![],
+    };
+    assert!(attestation.is_trusted_by(&schema));
+    schema.attesters = vec!["0x1e3a1b7c7e6f2c3f8b9f1f8a1e2d3c4b5a697887".into()];
+    assert!(attestation.is_trusted_by(&schema));
+    schema.attesters = vec!["0x0000000000000000000000000000000000000001".into()];
+    assert!(!attestation.is_trusted_by(&schema));
+}
+
+#[test]
+fn test_attestation_level() {
+    let attestation = attestation(false, 0);
+    let mut schema = ConfigEASSchema {
+        uid: SCHEMA.into(),
+        platform: "twitter".into(),
+        field: "handle".into(),
+        attesters: vec![],
+    };
+    assert_eq!(attestation.level(&schema), ProofLevel::SelfClaimed);
+    schema.attesters = vec!["0x1e3a1b7c7e6f2c3f8b9f1f8a1e2d3c4b5a697887".into()];
+    assert_eq!(attestation.level(&schema), ProofLevel::PlatformVerified);
+}
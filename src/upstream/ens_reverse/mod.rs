@@ -4,7 +4,7 @@ mod tests;
 use crate::{
     config::C,
     error::Error,
-    graph::{new_db_connection, vertex::Identity, Vertex},
+    graph::vertex::Identity,
     util::{make_client, parse_body, request_with_timeout},
 };
 use async_trait::async_trait;
@@ -12,7 +12,9 @@ use hyper::{Body, Method};
 use serde::Deserialize;
 use tracing::info;
 
-use super::{Fetcher, Platform, Target, TargetProcessedList};
+use super::{
+    the_graph::resolve_ens_name, Connection, DomainResolver, Fetched, Fetcher, Platform, Target,
+};
 
 #[derive(Deserialize, Debug, Clone)]
 struct Response {
@@ -27,16 +29,15 @@ pub struct ENSReverseLookup {}
 
 #[async_trait]
 impl Fetcher for ENSReverseLookup {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
         let wallet = target.identity().unwrap().to_lowercase();
-        let record = fetch_record(&wallet).await?;
         // If reverse lookup record is reset to empty by user,
         // our cache should also be cleared.
         // Reach this by setting `display_name` into `Some("")`.
-        let reverse_ens = record.reverse_record.unwrap_or("".into());
+        let reverse_ens = Self {}.reverse_resolve(&wallet).await?.unwrap_or_default();
 
         info!("ENS Reverse record: {} => {}", wallet, reverse_ens);
 
@@ -44,10 +45,11 @@ impl Fetcher for ENSReverseLookup {
         identity.platform = Platform::Ethereum;
         identity.identity = wallet.clone();
         identity.display_name = Some(reverse_ens);
-        let db = new_db_connection().await?;
-        identity.create_or_update(&db).await?;
 
-        Ok(vec![])
+        Ok(Fetched {
+            connections: vec![Connection::Identity(identity)],
+            ..Default::default()
+        })
     }
 
     fn can_fetch(target: &Target) -> bool {
@@ -55,6 +57,18 @@ impl Fetcher for ENSReverseLookup {
     }
 }
 
+#[async_trait]
+impl DomainResolver for ENSReverseLookup {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        resolve_ens_name(name).await
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        let record = fetch_record(address).await?;
+        Ok(record.reverse_record.filter(|name| !name.is_empty()))
+    }
+}
+
 async fn fetch_record(wallet: &str) -> Result<Response, Error> {
     let client = make_client();
     let url: http::Uri = format!("{}{}", C.upstream.ens_reverse.url, wallet)
@@ -1,4 +1,8 @@
 use super::*;
+use crate::{
+    graph::{new_db_connection, Vertex},
+    upstream::fetch_and_import,
+};
 
 #[tokio::test]
 async fn test_fetch_success() -> Result<(), Error> {
@@ -8,7 +12,7 @@ async fn test_fetch_success() -> Result<(), Error> {
     );
     let db = new_db_connection().await?;
     db.truncate().await;
-    ENSReverseLookup::fetch(&target).await?;
+    fetch_and_import::<ENSReverseLookup>(&target).await?;
     let found = Identity::find_by_platform_identity(
         &db,
         &target.platform().unwrap(),
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{edge::Annotation, vertex::Identity},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method, Request};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// Name tags are curated by Etherscan without any signature from the address owner,
+/// so they are only a hint of who the address belongs to.
+pub const CONFIDENCE: f64 = 0.3;
+
+/// https://docs.etherscan.io/api-endpoints/accounts#get-address-name-tag
+#[derive(Deserialize, Debug)]
+pub struct NameTagResponse {
+    /// `"1"` if succeeded, `"0"` if nothing found or failed.
+    pub status: String,
+    pub message: String,
+    pub result: Option<Vec<NameTag>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NameTag {
+    pub address: String,
+    /// e.g. `Binance 14`
+    pub nametag: String,
+    pub url: Option<String>,
+    /// e.g. `["Exchange", "Binance"]`
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+pub struct Etherscan {}
+
+#[async_trait]
+impl Fetcher for Etherscan {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, identity) => fetch_name_tag_by_address(identity).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        // Name tag API requires an API Pro key.
+        !C.upstream.etherscan_api.api_key.is_empty()
+            && target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_name_tag(address: &str) -> Result<Option<NameTag>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/api?module=nametag&action=getaddresstag&address={}&apikey={}",
+        C.upstream.etherscan_api.url, address, C.upstream.etherscan_api.api_key
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Etherscan Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Etherscan fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Etherscan fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: NameTagResponse = parse_body(&mut resp).await?;
+    if body.status != "1" {
+        info!("Etherscan fetch | {}: {}", address, body.message);
+        return Ok(None);
+    }
+    Ok(body
+        .result
+        .unwrap_or_default()
+        .into_iter()
+        .find(|tag| !tag.nametag.is_empty()))
+}
+
+async fn fetch_name_tag_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let name_tag = match fetch_name_tag(&address).await? {
+        Some(name_tag) => name_tag,
+        None => return Ok(Fetched::default()),
+    };
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let tag_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Etherscan,
+        identity: name_tag.nametag.to_lowercase(),
+        created_at: None,
+        display_name: Some(name_tag.nametag.clone()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: name_tag.url.clone().filter(|url| !url.is_empty()),
+        updated_at: naive_now(),
+    };
+    let annotation: Annotation = Annotation {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Etherscan,
+        name: name_tag.nametag,
+        tags: name_tag.labels,
+        confidence: CONFIDENCE,
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+
+    // A name tag is not an identity, nothing to fetch further.
+    Ok(Fetched {
+        connections: vec![Connection::Annotation(
+            eth_identity,
+            tag_identity,
+            annotation,
+        )],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,38 @@
+use crate::{
+    error::Error,
+    upstream::etherscan::{fetch_name_tag, NameTagResponse},
+};
+
+#[test]
+fn test_parse_name_tag_response() -> Result<(), Error> {
+    let body = r#"{
+        "status": "1",
+        "message": "OK",
+        "result": [{
+            "address": "0x28c6c06298d514db089934071355e5743bf21d60",
+            "nametag": "Binance 14",
+            "internal_nametag": "",
+            "url": "https://www.binance.com",
+            "shortdescription": "",
+            "notes_1": "",
+            "notes_2": "",
+            "labels": ["Exchange", "Binance"],
+            "labels_slug": ["exchange", "binance"],
+            "reputation": 0,
+            "other_attributes": []
+        }]
+    }"#;
+    let resp: NameTagResponse = serde_json::from_str(body)?;
+    let tag = resp.result.unwrap().remove(0);
+    assert_eq!(tag.nametag, "Binance 14");
+    assert_eq!(tag.labels, vec!["Exchange", "Binance"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_name_tag() -> Result<(), Error> {
+    let address = "0x28c6c06298d514db089934071355e5743bf21d60";
+    let name_tag = fetch_name_tag(address).await?;
+    println!("name_tag: {:?}", name_tag);
+    Ok(())
+}
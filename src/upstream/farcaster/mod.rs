@@ -4,21 +4,22 @@ use crate::{
     config::C,
     error::Error,
     graph::{
-        edge::Edge,
-        edge::Hold,
-        new_db_connection,
+        edge::{Hold, Proof, ProofLevel},
         vertex::Identity,
-        vertex::{IdentityRecord, Vertex},
     },
-    upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList},
-    util::naive_now,
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{
+        eth::{abi_word, decode_hex, eip712_hash, is_signed_by, keccak256},
+        make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive,
+        within_timeout,
+    },
 };
-use aragog::DatabaseConnection;
 use async_trait::async_trait;
 use futures::future::join_all;
 use gql_client::Client;
+use hyper::{Body, Method};
 use serde::{Deserialize, Serialize};
-use tracing::warn;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -62,19 +63,107 @@ pub struct SignerAddressQueryResponse {
     data: Vec<FarcasterProfile>,
 }
 
+/// Farcaster epoch (2021-01-01T00:00:00Z). Timestamps in Hub messages are seconds since this.
+const FARCASTER_EPOCH: i64 = 1609459200;
+
+/// https://www.thehubble.xyz/docs/httpapi/verification.html
+#[derive(Deserialize, Debug)]
+struct VerificationsResponse {
+    messages: Vec<VerificationMessage>,
+}
+
+#[derive(Deserialize, Debug)]
+struct VerificationMessage {
+    data: VerificationMessageData,
+    /// Hash of this message in Hub.
+    #[allow(dead_code)]
+    hash: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct VerificationMessageData {
+    #[serde(rename = "type")]
+    message_type: String,
+    /// Seconds since `FARCASTER_EPOCH`.
+    timestamp: i64,
+    /// `FARCASTER_NETWORK_MAINNET`, `FARCASTER_NETWORK_TESTNET` or `FARCASTER_NETWORK_DEVNET`.
+    network: Option<String>,
+    verification_add_eth_address_body: Option<VerificationAddEthAddressBody>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct VerificationAddEthAddressBody {
+    address: String,
+    /// Signature produced by the verified address, which is the on-chain proof of this claim.
+    eth_signature: String,
+    block_hash: String,
+}
+
+/// EIP-712 domain of verification claims signed by Ethereum addresses.
+/// https://github.com/farcasterxyz/hub-monorepo/blob/main/packages/core/src/crypto/eip712.ts
+const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,bytes32 salt)";
+const EIP712_DOMAIN_NAME: &str = "Farcaster Verify Ethereum Address";
+const EIP712_DOMAIN_VERSION: &str = "2.0.0";
+const EIP712_DOMAIN_SALT: &str =
+    "0xf2d857f4a3edcb9b78b4d503bfe733db1e3f6cdc2b7971ee739626c97e86a558";
+const EIP712_VERIFICATION_CLAIM_TYPE: &str =
+    "VerificationClaim(uint256 fid,address address,bytes32 blockHash,uint8 network)";
+
+fn network_id(network: &str) -> u8 {
+    match network {
+        "FARCASTER_NETWORK_MAINNET" => 1,
+        "FARCASTER_NETWORK_TESTNET" => 2,
+        "FARCASTER_NETWORK_DEVNET" => 3,
+        _ => 0,
+    }
+}
+
+impl VerificationAddEthAddressBody {
+    /// EIP-712 hash of the `VerificationClaim` signed by `address`.
+    fn claim_hash(&self, fid: i32, network: u8) -> Result<[u8; 32], Error> {
+        let mut domain = keccak256(EIP712_DOMAIN_TYPE.as_bytes()).to_vec();
+        domain.extend_from_slice(&keccak256(EIP712_DOMAIN_NAME.as_bytes()));
+        domain.extend_from_slice(&keccak256(EIP712_DOMAIN_VERSION.as_bytes()));
+        domain.extend_from_slice(&decode_hex(EIP712_DOMAIN_SALT)?);
+
+        let mut claim = keccak256(EIP712_VERIFICATION_CLAIM_TYPE.as_bytes()).to_vec();
+        claim.extend_from_slice(&abi_word(&(fid as u64).to_be_bytes()));
+        claim.extend_from_slice(&abi_word(&decode_hex(&self.address)?));
+        claim.extend_from_slice(&abi_word(&decode_hex(&self.block_hash)?));
+        claim.extend_from_slice(&abi_word(&[network]));
+
+        Ok(eip712_hash(&keccak256(&domain), &keccak256(&claim)))
+    }
+
+    /// Verify `eth_signature` by ourselves instead of trusting the Hub.
+    /// Supports both EOAs and contract wallets (EIP-1271).
+    async fn is_signed(&self, fid: i32, network: u8) -> Result<bool, Error> {
+        // Hub HTTP API gives hex, while its protobuf JSON gives base64.
+        let signature = if self.eth_signature.starts_with("0x") {
+            decode_hex(&self.eth_signature)?
+        } else {
+            base64::decode(&self.eth_signature)
+                .map_err(|err| Error::ParamError(format!("Farcaster signature: {}", err)))?
+        };
+        is_signed_by(&self.claim_hash(fid, network)?, &signature, &self.address).await
+    }
+}
+
 pub struct Farcaster {}
 
 #[async_trait]
 impl Fetcher for Farcaster {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
     fn can_fetch(target: &Target) -> bool {
@@ -85,11 +174,11 @@ impl Fetcher for Farcaster {
 async fn fetch_connections_by_platform_identity(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     match *platform {
         Platform::Farcaster => fetch_by_username(platform, identity).await,
         Platform::Ethereum => fetch_by_signer(platform, identity).await,
-        _ => Ok(vec![]),
+        _ => Ok(Fetched::default()),
     }
 }
 
@@ -110,26 +199,8 @@ async fn get_farcaster_profile_by_username(username: &str) -> Result<Vec<Farcast
     };
     let response = client.query_with_vars::<UsernameQueryResponse, _>(QUERY_BY_NAME, vars);
 
-    let data = match tokio::time::timeout(std::time::Duration::from_secs(5), response).await {
-        Ok(response) => match response {
-            Ok(response) => {
-                let result = response.unwrap();
-                result.data
-            }
-            Err(err) => {
-                warn!(
-                    "GraphQLError: Farcaster fetch | Failed to fetch profile using `username`: {}, error: {:?}", 
-                    username,
-                    err);
-                vec![]
-            }
-        },
-        Err(_) => {
-            warn!("Farcaster fetch | Timeout: no response in 5 seconds.");
-            vec![]
-        }
-    };
-    Ok(data)
+    let response = within_timeout("Farcaster", response).await?;
+    Ok(response.map(|result| result.data).unwrap_or_default())
 }
 
 async fn get_farcaster_profile_by_signer(address: &str) -> Result<Vec<FarcasterProfile>, Error> {
@@ -149,36 +220,15 @@ async fn get_farcaster_profile_by_signer(address: &str) -> Result<Vec<FarcasterP
     };
     let response = client.query_with_vars::<SignerAddressQueryResponse, _>(QUERY_BY_SIGNER, vars);
 
-    let data = match tokio::time::timeout(std::time::Duration::from_secs(5), response).await {
-        Ok(response) => match response {
-            Ok(response) => {
-                let result = response.unwrap();
-                result.data
-            }
-            Err(err) => {
-                warn!(
-                    "Farcaster fetch | Failed to fetch profile using `signer`: {}, error: {:?}",
-                    address, err
-                );
-                vec![]
-            }
-        },
-        Err(_) => {
-            warn!("Farcaster fetch | Timeout: no response in 5 seconds.");
-            vec![]
-        }
-    };
-    Ok(data)
+    let response = within_timeout("Farcaster", response).await?;
+    Ok(response.map(|result| result.data).unwrap_or_default())
 }
 
-async fn save_profile_ethereum(
-    db: &DatabaseConnection,
-    profile: FarcasterProfile,
-) -> Result<TargetProcessedList, Error> {
-    let target_list = match profile.signerAddress {
-        None => vec![], // signer address is null
+async fn fetch_profile_ethereum(profile: FarcasterProfile) -> Result<Fetched, Error> {
+    let fetched = match profile.signerAddress {
+        None => Fetched::default(), // signer address is null
         Some(signer_address) => match signer_address.as_str() {
-            "" => vec![], // signer address is empty string
+            "" => Fetched::default(), // signer address is empty string
             &_ => {
                 let eth_identity: Identity = Identity {
                     uuid: Some(Uuid::new_v4()),
@@ -211,24 +261,22 @@ async fn save_profile_ethereum(
                     updated_at: naive_now(),
                     fetcher: DataFetcher::DataMgrService,
                 };
-                let eth_record = eth_identity.create_or_update(&db).await?;
-                let farcaster_record = farcaster_identity.create_or_update(&db).await?;
-                hold.connect(&db, &eth_record, &farcaster_record).await?;
-
-                vec![Target::Identity(
+                let mut fetched = fetch_verifications(&farcaster_identity, profile.fid).await?;
+                fetched
+                    .connections
+                    .push(Connection::Hold(eth_identity, farcaster_identity, hold));
+                fetched.next_targets.push(Target::Identity(
                     Platform::Ethereum,
                     signer_address.to_lowercase().to_string(),
-                )]
+                ));
+                fetched
             }
         },
     };
-    Ok(target_list)
+    Ok(fetched)
 }
 
-async fn save_profile_signer(
-    db: &DatabaseConnection,
-    profile: FarcasterProfile,
-) -> Result<TargetProcessedList, Error> {
+async fn fetch_profile_signer(profile: FarcasterProfile) -> Result<Fetched, Error> {
     let eth_identity: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::Ethereum,
@@ -265,53 +313,153 @@ async fn save_profile_signer(
         updated_at: naive_now(),
         fetcher: DataFetcher::DataMgrService,
     };
-    let eth_record = eth_identity.create_or_update(&db).await?;
-    let farcaster_record = farcaster_identity.create_or_update(&db).await?;
-    hold.connect(&db, &eth_record, &farcaster_record).await?;
-    Ok(vec![Target::Identity(
-        Platform::Farcaster,
-        profile.username.clone(),
-    )])
+    let mut fetched = fetch_verifications(&farcaster_identity, profile.fid).await?;
+    fetched
+        .connections
+        .push(Connection::Hold(eth_identity, farcaster_identity, hold));
+    fetched
+        .next_targets
+        .push(Target::Identity(Platform::Farcaster, profile.username));
+    Ok(fetched)
 }
 
-async fn fetch_by_username(
-    _platform: &Platform,
-    username: &str,
-) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
+/// Fetch all verified Ethereum addresses of a `fid` from Farcaster Hub.
+async fn get_verifications_by_fid(fid: i32) -> Result<Vec<VerificationMessage>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/v1/verificationsByFid?fid={}",
+        C.upstream.farcaster_hub_api.url, fid
+    )
+    .parse()
+    .map_err(|_err: http::uri::InvalidUri| {
+        Error::ParamError(format!("Uri format Error {}", _err))
+    })?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Farcaster Hub Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "Farcaster Hub fetch | verificationsByFid error: {:?}",
+            err.to_string()
+        ))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Farcaster Hub fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: VerificationsResponse = parse_body(&mut resp).await?;
+    Ok(body.messages)
+}
+
+/// Fetch verified-address messages of a Farcaster account as `Proof`s.
+/// Signature of the verified address is stored in `record_id`.
+async fn fetch_verifications(farcaster_identity: &Identity, fid: i32) -> Result<Fetched, Error> {
+    let messages = get_verifications_by_fid(fid).await?;
+
+    let mut fetched = Fetched::default();
+    for message in messages.into_iter() {
+        if message.data.message_type != "MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS" {
+            continue;
+        }
+        let body = match message.data.verification_add_eth_address_body {
+            Some(body) => body,
+            None => continue,
+        };
+        debug!(
+            fid,
+            address = body.address,
+            "Farcaster verified address found."
+        );
+
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: body.address.to_lowercase(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let network = network_id(message.data.network.as_deref().unwrap_or_default());
+        let verified = match body.is_signed(fid, network).await {
+            Ok(verified) => verified,
+            Err(err) => {
+                warn!(
+                    fid,
+                    address = body.address,
+                    "Farcaster fetch | Failed to verify signature: {}",
+                    err
+                );
+                false
+            }
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Farcaster,
+            record_id: Some(body.eth_signature.clone()),
+            created_at: Some(timestamp_to_naive(
+                FARCASTER_EPOCH + message.data.timestamp,
+                0,
+            )),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: if verified {
+                ProofLevel::Cryptographic
+            } else {
+                ProofLevel::PlatformVerified
+            },
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            farcaster_identity.clone(),
+            eth_identity,
+            proof,
+        ));
+        fetched.next_targets.push(Target::Identity(
+            Platform::Ethereum,
+            body.address.to_lowercase(),
+        ));
+    }
+    Ok(fetched)
+}
+
+async fn fetch_by_username(_platform: &Platform, username: &str) -> Result<Fetched, Error> {
     let profiles = get_farcaster_profile_by_username(&username).await?;
     if profiles.is_empty() {
         return Err(Error::NoResult);
     }
-    let futures: Vec<_> = profiles
-        .into_iter()
-        .map(|profile| save_profile_ethereum(&db, profile))
-        .collect();
-    let targets: TargetProcessedList = join_all(futures)
+    let futures: Vec<_> = profiles.into_iter().map(fetch_profile_ethereum).collect();
+    let fetched: Fetched = join_all(futures)
         .await
         .into_iter()
-        .flat_map(|result| result.unwrap_or_default())
+        .map(|result| result.unwrap_or_default())
         .collect();
-    Ok(targets)
+    Ok(fetched)
 }
 
-async fn fetch_by_signer(
-    _platform: &Platform,
-    address: &str,
-) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
+async fn fetch_by_signer(_platform: &Platform, address: &str) -> Result<Fetched, Error> {
     let profiles = get_farcaster_profile_by_signer(&address).await?;
     if profiles.is_empty() {
         return Err(Error::NoResult);
     }
-    let futures: Vec<_> = profiles
-        .into_iter()
-        .map(|profile| save_profile_signer(&db, profile))
-        .collect();
-    let targets: TargetProcessedList = join_all(futures)
+    let futures: Vec<_> = profiles.into_iter().map(fetch_profile_signer).collect();
+    let fetched: Fetched = join_all(futures)
         .await
         .into_iter()
-        .flat_map(|result| result.unwrap_or_default())
+        .map(|result| result.unwrap_or_default())
         .collect();
-    Ok(targets)
+    Ok(fetched)
 }
@@ -2,7 +2,8 @@
 mod tests {
     use crate::error::Error;
     use crate::upstream::farcaster::{
-        get_farcaster_profile_by_signer, get_farcaster_profile_by_username,
+        get_farcaster_profile_by_signer, get_farcaster_profile_by_username, network_id,
+        VerificationAddEthAddressBody,
     };
 
     #[tokio::test]
@@ -20,4 +21,26 @@ mod tests {
         println!("data: {:?}", data);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_verify_eth_signature() -> Result<(), Error> {
+        let mut body = VerificationAddEthAddressBody {
+            address: "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23".into(),
+            eth_signature: "0x08b2a8c29506cdf27fe61b47f6f0852e0ad0abc1fcb50ebce19d2fd9eed93ed707962e6522f0e954efeef72a6b7100378ab661e54c73d0ad8b88aa55e75792221b".into(),
+            block_hash: "0xd74860af2da9ae5ea9fa4fa0af4bf0d6de2b54dc3dc8cea04bb5e0b8271fbb3b".into(),
+        };
+        let network = network_id("FARCASTER_NETWORK_MAINNET");
+        assert_eq!(
+            hex::encode(body.claim_hash(3, network)?),
+            "6265da012988c7efa47afc616707a12d9956626edfdaf9caade766dd2bb49da6"
+        );
+        assert!(body.is_signed(3, network).await?);
+
+        body.block_hash =
+            "0x0000000000000000000000000000000000000000000000000000000000000000".into();
+        body.address = "0x934b510d4c9103e6a87aef13b816fb080286d649".into();
+        // Not the signer, and no RPC to ask as a contract wallet.
+        assert!(!body.is_signed(3, network).await.unwrap_or(false));
+        Ok(())
+    }
 }
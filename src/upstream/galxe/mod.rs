@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{naive_now, within_timeout},
+};
+use async_trait::async_trait;
+use gql_client::Client;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct QueryVars {
+    address: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInfoResponse {
+    pub address_info: Option<AddressInfo>,
+}
+
+/// Galxe ID of an address and social accounts bound to it.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInfo {
+    /// Galxe ID
+    pub id: String,
+    pub username: Option<String>,
+    pub avatar: Option<String>,
+    pub twitter_user_name: Option<String>,
+    #[serde(rename = "discordUserID")]
+    pub discord_user_id: Option<String>,
+    pub discord_user_name: Option<String>,
+    pub github_user_name: Option<String>,
+    /// Hashed email address.
+    pub email_hash: Option<String>,
+}
+
+const QUERY: &str = r#"
+    query AddressInfo($address: String!) {
+        addressInfo(address: $address) {
+            id
+            username
+            avatar
+            twitterUserName
+            discordUserID
+            discordUserName
+            githubUserName
+            emailHash
+        }
+    }
+"#;
+
+pub struct Galxe {}
+
+#[async_trait]
+impl Fetcher for Galxe {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_address_info_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+impl AddressInfo {
+    /// `(platform, identity, display_name)` of every social account bound to this Galxe ID.
+    /// Discord accounts are keyed by their snowflake ID.
+    pub fn social_accounts(&self) -> Vec<(Platform, String, Option<String>)> {
+        let mut accounts = vec![];
+        let non_empty = |value: &Option<String>| value.clone().filter(|v| !v.is_empty());
+        if let Some(twitter) = non_empty(&self.twitter_user_name) {
+            accounts.push((Platform::Twitter, twitter.to_lowercase(), Some(twitter)));
+        }
+        if let Some(discord_id) = non_empty(&self.discord_user_id) {
+            accounts.push((
+                Platform::Discord,
+                discord_id,
+                non_empty(&self.discord_user_name),
+            ));
+        }
+        if let Some(github) = non_empty(&self.github_user_name) {
+            accounts.push((Platform::Github, github.to_lowercase(), Some(github)));
+        }
+        // Email is hashed, nothing can be fetched further from it.
+        if let Some(email_hash) = non_empty(&self.email_hash) {
+            accounts.push((Platform::Email, email_hash, None));
+        }
+        accounts
+    }
+}
+
+async fn fetch_address_info(address: &str) -> Result<Option<AddressInfo>, Error> {
+    let client = Client::new(C.upstream.galxe_api.url.clone());
+    let vars = QueryVars {
+        address: address.to_string(),
+    };
+    let response = client.query_with_vars::<AddressInfoResponse, _>(QUERY, vars);
+
+    let response = within_timeout("Galxe", response).await?;
+    Ok(response
+        .and_then(|resp| resp.address_info)
+        .filter(|info| !info.id.is_empty()))
+}
+
+async fn fetch_address_info_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let info = match fetch_address_info(&address).await? {
+        Some(info) => info,
+        None => {
+            info!("Galxe fetch | {} has no Galxe ID", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let galxe_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Galxe,
+        identity: info.id.clone(),
+        created_at: None,
+        display_name: info.username.clone(),
+        added_at: naive_now(),
+        avatar_url: info.avatar.clone(),
+        profile_url: Some(format!("https://galxe.com/id/{}", address)),
+        updated_at: naive_now(),
+    };
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Galxe,
+        record_id: Some(info.id.clone()),
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+    let mut fetched = Fetched::default();
+    fetched.connections.push(Connection::TwoWayBinding(
+        eth_identity,
+        galxe_identity.clone(),
+        proof,
+    ));
+
+    for (platform, identity, display_name) in info.social_accounts().into_iter() {
+        let social_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        // Every binding is a credential issued under this Galxe ID.
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Galxe,
+            record_id: Some(info.id.clone()),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::Proof(
+            galxe_identity.clone(),
+            social_identity,
+            proof,
+        ));
+        if platform != Platform::Email {
+            fetched
+                .next_targets
+                .push(Target::Identity(platform, identity));
+        }
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,41 @@
+use crate::{
+    error::Error,
+    upstream::{
+        galxe::{fetch_address_info, AddressInfo},
+        Platform,
+    },
+};
+
+#[test]
+fn test_social_accounts() {
+    let info = AddressInfo {
+        id: "GZKxbU4hXH".into(),
+        twitter_user_name: Some("Suji_Yan".into()),
+        discord_user_id: Some("175928847299117063".into()),
+        discord_user_name: Some("suji".into()),
+        github_user_name: Some("".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        info.social_accounts(),
+        vec![
+            (
+                Platform::Twitter,
+                "suji_yan".to_string(),
+                Some("Suji_Yan".to_string())
+            ),
+            (
+                Platform::Discord,
+                "175928847299117063".to_string(),
+                Some("suji".to_string())
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_address_info() -> Result<(), Error> {
+    let info = fetch_address_info("0x934b510d4c9103e6a87aef13b816fb080286d649").await?;
+    println!("info: {:?}", info);
+    Ok(())
+}
@@ -0,0 +1,299 @@
+//! Upstreams defined in config (`[[upstream.generic]]`) instead of code:
+//! GET a JSON API with the identity being fetched, then extract connected
+//! identities from the response by JSONPath.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::{ConfigGenericUpstream, C},
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde_json::Value;
+use std::str::FromStr;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Characters escaped in a path segment (WHATWG URL), plus `/` and `%`,
+/// so an identity can't reach other paths or query parameters of an upstream.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%');
+
+/// One step of a JSONPath.
+#[derive(Debug, PartialEq)]
+enum PathSegment {
+    /// `.key` / `['key']`
+    Key(String),
+    /// `[0]`
+    Index(usize),
+    /// `.*` / `[*]`
+    Wildcard,
+}
+
+/// Parse the subset of JSONPath we support: `$`, `.key`, `['key']`, `[0]`, `.*` and `[*]`.
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, Error> {
+    let invalid = || Error::ParamError(format!("JSONPath invalid: {}", path));
+    let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            let key = &after[..end];
+            if key.is_empty() {
+                return Err(invalid());
+            }
+            segments.push(if key == "*" {
+                PathSegment::Wildcard
+            } else {
+                PathSegment::Key(key.to_string())
+            });
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').ok_or_else(invalid)?;
+            let inner = &after[..end];
+            segments.push(if inner == "*" {
+                PathSegment::Wildcard
+            } else if let Ok(index) = inner.parse::<usize>() {
+                PathSegment::Index(index)
+            } else if inner.len() >= 2 && inner.starts_with('\'') && inner.ends_with('\'') {
+                PathSegment::Key(inner[1..inner.len() - 1].to_string())
+            } else {
+                return Err(invalid());
+            });
+            rest = &after[end + 1..];
+        } else {
+            return Err(invalid());
+        }
+    }
+    Ok(segments)
+}
+
+/// Every value `path` matches in `value`.
+fn select<'a>(value: &'a Value, path: &str) -> Result<Vec<&'a Value>, Error> {
+    let mut current = vec![value];
+    for segment in parse_path(path)? {
+        current = current
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (&segment, value) {
+                    (PathSegment::Key(key), Value::Object(map)) => {
+                        map.get(key).into_iter().collect()
+                    }
+                    (PathSegment::Index(index), Value::Array(array)) => {
+                        array.get(*index).into_iter().collect()
+                    }
+                    (PathSegment::Wildcard, Value::Array(array)) => array.iter().collect(),
+                    (PathSegment::Wildcard, Value::Object(map)) => map.values().collect(),
+                    _ => vec![],
+                }
+            })
+            .collect();
+    }
+    Ok(current)
+}
+
+/// Strings and numbers are accepted as identities.
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+pub struct Generic {}
+
+#[async_trait]
+impl Fetcher for Generic {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        let (platform, identity) = match target {
+            Target::Identity(platform, identity) => (platform, identity),
+            Target::NFT(_, _, _, _) => return Ok(Fetched::default()),
+        };
+        let mut fetched = Fetched::default();
+        for upstream in C.upstream.generic.iter() {
+            if !accepts(upstream, platform) {
+                continue;
+            }
+            // One broken definition should not stop the others.
+            match fetch_by_upstream(upstream, platform, identity).await {
+                Ok(result) => fetched.extend(result),
+                Err(err) => warn!("Generic fetch | {}: {}", upstream.name, err),
+            }
+        }
+        Ok(fetched)
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        match target {
+            Target::Identity(platform, _) => C
+                .upstream
+                .generic
+                .iter()
+                .any(|upstream| accepts(upstream, platform)),
+            Target::NFT(_, _, _, _) => false,
+        }
+    }
+}
+
+fn accepts(upstream: &ConfigGenericUpstream, platform: &Platform) -> bool {
+    upstream
+        .platforms
+        .iter()
+        .any(|p| Platform::from_str(p).is_ok_and(|p| p == *platform))
+}
+
+/// `url` of `upstream` with `identity` percent-encoded in it.
+fn request_url(upstream: &ConfigGenericUpstream, identity: &str) -> String {
+    upstream.url.replace(
+        "{identity}",
+        &utf8_percent_encode(identity, PATH_SEGMENT).to_string(),
+    )
+}
+
+async fn fetch_json(upstream: &ConfigGenericUpstream, identity: &str) -> Result<Value, Error> {
+    let client = make_client();
+    let uri: http::Uri = request_url(upstream, identity)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let mut builder = hyper::Request::builder().method(Method::GET).uri(uri);
+    for (key, value) in upstream.headers.iter() {
+        builder = builder.header(key.as_str(), value.as_str());
+    }
+    let req = builder
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Generic Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Generic fetch | error: {:?}", err.to_string()))
+    })?;
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Generic fetch {} error: {}", upstream.name, resp.status()),
+            resp.status(),
+        ));
+    }
+    parse_body(&mut resp).await
+}
+
+/// `(platform, identity, record_id)` of every identity `upstream` extracts from `body`.
+fn extract_identities(
+    upstream: &ConfigGenericUpstream,
+    body: &Value,
+) -> Result<Vec<(Platform, String, String)>, Error> {
+    let mut extracted = vec![];
+    for definition in upstream.identities.iter() {
+        let platform = Platform::from_str(&definition.platform).map_err(|_| {
+            Error::ParamError(format!("Generic platform invalid: {}", definition.platform))
+        })?;
+        let record_id = match &definition.record_id {
+            Some(path) => select(body, path)?
+                .first()
+                .and_then(|value| value_to_string(value))
+                .map(|id| format!("{}:{}", upstream.name, id))
+                .unwrap_or_else(|| upstream.name.clone()),
+            None => upstream.name.clone(),
+        };
+        for value in select(body, &definition.path)? {
+            if let Some(identity) = value_to_string(value) {
+                extracted.push((platform, identity.to_lowercase(), record_id.clone()));
+            }
+        }
+    }
+    Ok(extracted)
+}
+
+async fn fetch_by_upstream(
+    upstream: &ConfigGenericUpstream,
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let body = fetch_json(upstream, identity).await?;
+    let extracted = extract_identities(upstream, &body)?;
+    if extracted.is_empty() {
+        info!(
+            "Generic fetch | {} found nothing for {}",
+            upstream.name, identity
+        );
+        return Ok(Fetched::default());
+    }
+
+    let from: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: *platform,
+        identity: identity.to_string(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for (to_platform, to_identity, record_id) in extracted.into_iter() {
+        let to: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: to_platform,
+            identity: to_identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Generic,
+            record_id: Some(record_id),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::Heuristic,
+        };
+        if upstream.two_way {
+            fetched
+                .connections
+                .push(Connection::TwoWayBinding(from.clone(), to, proof));
+        } else {
+            fetched
+                .connections
+                .push(Connection::Proof(from.clone(), to, proof));
+        }
+        fetched
+            .next_targets
+            .push(Target::Identity(to_platform, to_identity));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,83 @@
+use crate::{
+    config::{ConfigGenericIdentity, ConfigGenericUpstream},
+    upstream::{
+        generic::{extract_identities, parse_path, request_url, select, PathSegment},
+        Platform,
+    },
+};
+use serde_json::json;
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(
+        parse_path("$.data.accounts[*]['handle'][0]").unwrap(),
+        vec![
+            PathSegment::Key("data".into()),
+            PathSegment::Key("accounts".into()),
+            PathSegment::Wildcard,
+            PathSegment::Key("handle".into()),
+            PathSegment::Index(0),
+        ]
+    );
+    assert_eq!(parse_path("$").unwrap(), vec![]);
+    assert!(parse_path("data.accounts").is_err());
+    assert!(parse_path("$.data[").is_err());
+    assert!(parse_path("$..data").is_err());
+}
+
+#[test]
+fn test_select() {
+    let body = json!({"data": {"accounts": [{"handle": "alice"}, {"handle": "bob"}, {}]}});
+    let handles: Vec<_> = select(&body, "$.data.accounts[*].handle")
+        .unwrap()
+        .into_iter()
+        .cloned()
+        .collect();
+    assert_eq!(handles, vec![json!("alice"), json!("bob")]);
+    assert_eq!(
+        select(&body, "$.data.accounts[1].handle").unwrap(),
+        vec![&json!("bob")]
+    );
+    assert!(select(&body, "$.data.missing").unwrap().is_empty());
+}
+
+#[test]
+fn test_extract_identities() {
+    let upstream = ConfigGenericUpstream {
+        name: "example".into(),
+        url: "https://api.example.com/users/{identity}".into(),
+        platforms: vec!["ethereum".into()],
+        identities: vec![ConfigGenericIdentity {
+            platform: "twitter".into(),
+            path: "$.data.socials[*].twitter".into(),
+            record_id: Some("$.data.id".into()),
+        }],
+        ..Default::default()
+    };
+    let body = json!({"data": {"id": 42, "socials": [{"twitter": "Alice"}, {"twitter": ""}]}});
+    assert_eq!(
+        extract_identities(&upstream, &body).unwrap(),
+        vec![(
+            Platform::Twitter,
+            "alice".to_string(),
+            "example:42".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_request_url() {
+    let upstream = ConfigGenericUpstream {
+        name: "example".into(),
+        url: "https://api.example.com/users/{identity}?chain=1".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        request_url(&upstream, "alice.eth"),
+        "https://api.example.com/users/alice.eth?chain=1"
+    );
+    assert_eq!(
+        request_url(&upstream, "../admin?x=1#"),
+        "https://api.example.com/users/..%2Fadmin%3Fx=1%23?chain=1"
+    );
+}
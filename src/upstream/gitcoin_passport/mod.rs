@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{edge::Annotation, vertex::Identity},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// A stamp is issued by Gitcoin after the address owner logs in to the provider,
+/// but the account itself stays hidden behind a hash, so it can not be linked to
+/// any identity on the provider platform.
+pub const CONFIDENCE: f64 = 0.8;
+
+/// https://docs.passport.gitcoin.co/building-with-passport/scorer-api/api-reference
+#[derive(Deserialize, Debug)]
+pub struct StampsResponse {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+    pub items: Vec<StampItem>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StampItem {
+    pub version: String,
+    pub credential: Credential,
+}
+
+/// A W3C Verifiable Credential issued by Gitcoin Passport.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Credential {
+    pub credential_subject: CredentialSubject,
+    pub issuance_date: String,
+    pub expiration_date: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CredentialSubject {
+    /// `did:pkh:eip155:1:0xADDRESS`
+    pub id: String,
+    /// Hashed account ID on `provider`. Raw account ID will never be exposed by Passport.
+    pub hash: String,
+    /// Stamp provider, e.g. `Twitter`, `Github`.
+    pub provider: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ErrorResponse {
+    pub detail: String,
+}
+
+pub struct GitcoinPassport {}
+
+#[async_trait]
+impl Fetcher for GitcoinPassport {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, identity) => fetch_stamps_by_address(identity).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_stamps(address: &str) -> Result<StampsResponse, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/registry/stamps/{}?limit=1000",
+        C.upstream.gitcoin_passport_api.url, address
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("X-API-KEY", C.upstream.gitcoin_passport_api.token.clone())
+        .body(Body::empty())
+        .map_err(|_err| {
+            Error::ParamError(format!("GitcoinPassport Build Request Error {}", _err))
+        })?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "GitcoinPassport fetch | error: {:?}",
+            err.to_string()
+        ))
+    })?;
+
+    if !resp.status().is_success() {
+        let body: ErrorResponse = parse_body(&mut resp).await?;
+        return Err(Error::General(
+            format!("GitcoinPassport Get error: {}", body.detail),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_stamps_by_address(address: &str) -> Result<Fetched, Error> {
+    let result = fetch_stamps(address).await?;
+    if result.items.is_empty() {
+        info!("GitcoinPassport fetch | address: {} has no stamp", address);
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    for item in result.items.into_iter() {
+        let subject = item.credential.credential_subject;
+        let issued_at = DateTime::parse_from_rfc3339(&item.credential.issuance_date)
+            .ok()
+            .map(|dt| dt.naive_utc());
+
+        let stamp_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::GitcoinPassport,
+            identity: subject.hash,
+            created_at: issued_at,
+            display_name: Some(subject.provider.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let annotation: Annotation = Annotation {
+            uuid: Uuid::new_v4(),
+            source: DataSource::GitcoinPassport,
+            name: subject.provider.clone(),
+            tags: vec![subject.provider],
+            confidence: CONFIDENCE,
+            created_at: issued_at,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Annotation(
+            eth_identity.clone(),
+            stamp_identity,
+            annotation,
+        ));
+    }
+
+    // Account IDs of stamps are hashed, nothing can be fetched further from them.
+    Ok(fetched)
+}
@@ -0,0 +1,30 @@
+use crate::{
+    error::Error,
+    upstream::gitcoin_passport::{fetch_stamps, fetch_stamps_by_address},
+    upstream::{Connection, Platform},
+};
+
+#[tokio::test]
+async fn test_fetch_stamps() -> Result<(), Error> {
+    let address = "0x934b510d4c9103e6a87aef13b816fb080286d649";
+    let result = fetch_stamps(address).await?;
+    println!("stamps: {:?}", result.items);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_stamps_by_address() -> Result<(), Error> {
+    let address = "0x934b510d4c9103e6a87aef13b816fb080286d649";
+    let fetched = fetch_stamps_by_address(address).await?;
+    for connection in fetched.connections.iter() {
+        match connection {
+            Connection::Annotation(from, to, _) => {
+                assert_eq!(from.platform, Platform::Ethereum);
+                assert_eq!(to.platform, Platform::GitcoinPassport);
+            }
+            _ => panic!("Stamps should be saved as annotations"),
+        }
+    }
+    assert!(fetched.next_targets.is_empty());
+    Ok(())
+}
@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{
+        keybase::lookup, Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{body::HttpBody as _, Body, Method};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// https://docs.github.com/en/rest/gists/gists#list-gists-for-a-user
+#[derive(Deserialize, Debug)]
+pub struct Gist {
+    pub id: String,
+    pub created_at: String,
+    pub files: HashMap<String, GistFile>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GistFile {
+    pub filename: String,
+    pub raw_url: String,
+}
+
+/// Next.ID proof payload, published as `0x{PERSONA}.json`.
+#[derive(Deserialize, Debug)]
+pub struct NextIDPayload {
+    /// Compressed secp256k1 public key of the Next.ID persona.
+    pub persona: String,
+    pub github_username: String,
+}
+
+/// An identity claim found in a gist.
+#[derive(Debug, PartialEq)]
+pub enum GistClaim {
+    Keybase(String),
+    NextID(String),
+}
+
+pub struct Github {}
+
+#[async_trait]
+impl Fetcher for Github {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, username) => fetch_gist_proofs_by_username(username).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Github])
+    }
+}
+
+/// Find Keybase username in a Keybase proof gist (`keybase.md`), e.g.
+/// `  * I am alice (https://keybase.io/alice) on keybase.`
+fn parse_keybase_gist(content: &str, username: &str) -> Option<String> {
+    let admin_line = format!("I am an admin of https://github.com/{}", username);
+    if !content.to_lowercase().contains(&admin_line.to_lowercase()) {
+        return None;
+    }
+    const PREFIX: &str = "(https://keybase.io/";
+    let start = content.find(PREFIX)? + PREFIX.len();
+    let end = content[start..].find(')')?;
+    let keybase_username = &content[start..start + end];
+    if keybase_username.is_empty() {
+        return None;
+    }
+    Some(keybase_username.to_lowercase())
+}
+
+/// Find Next.ID persona in a Next.ID proof gist (`0x{PERSONA}.json`).
+fn parse_nextid_gist(filename: &str, content: &str, username: &str) -> Option<String> {
+    let payload: NextIDPayload = serde_json::from_str(content).ok()?;
+    let persona = payload.persona.to_lowercase();
+    if !payload.github_username.eq_ignore_ascii_case(username)
+        || filename.to_lowercase() != format!("{}.json", persona)
+    {
+        return None;
+    }
+    Some(persona)
+}
+
+fn request_builder(uri: http::Uri) -> http::request::Builder {
+    let builder = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("User-Agent", "relation_server")
+        .header("Accept", "application/vnd.github+json");
+    if C.upstream.github_api.token.is_empty() {
+        builder
+    } else {
+        builder.header(
+            "Authorization",
+            format!("Bearer {}", C.upstream.github_api.token),
+        )
+    }
+}
+
+async fn fetch_gists(username: &str) -> Result<Vec<Gist>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/users/{}/gists?per_page=100",
+        C.upstream.github_api.url, username
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = request_builder(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Github Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Github fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Github Get error: {} {}", username, resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_raw(raw_url: &str) -> Result<String, Error> {
+    let client = make_client();
+    let uri: http::Uri = raw_url
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+    let req = request_builder(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Github Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Github fetch | error: {:?}", err.to_string()))
+    })?;
+
+    let mut body_bytes: Vec<u8> = vec![];
+    while let Some(chunk) = resp.body_mut().data().await {
+        let chunk = chunk.map_err(|err| {
+            Error::ManualHttpClientError(format!("Github fetch | error: {:?}", err.to_string()))
+        })?;
+        body_bytes.extend_from_slice(&chunk);
+    }
+    Ok(String::from_utf8_lossy(&body_bytes).to_string())
+}
+
+/// Find identity claim in a gist.
+async fn find_claim(gist: &Gist, username: &str) -> Result<Option<GistClaim>, Error> {
+    for file in gist.files.values() {
+        let filename = file.filename.to_lowercase();
+        if filename == "keybase.md" {
+            let content = fetch_raw(&file.raw_url).await?;
+            if let Some(keybase) = parse_keybase_gist(&content, username) {
+                return Ok(Some(GistClaim::Keybase(keybase)));
+            }
+        } else if filename.starts_with("0x") && filename.ends_with(".json") {
+            let content = fetch_raw(&file.raw_url).await?;
+            if let Some(persona) = parse_nextid_gist(&filename, &content, username) {
+                return Ok(Some(GistClaim::NextID(persona)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+async fn fetch_gist_proofs_by_username(username: &str) -> Result<Fetched, Error> {
+    let username = username.to_lowercase();
+    let gists = fetch_gists(&username).await?;
+
+    let github_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Github,
+        identity: username.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://github.com/{}", username)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for gist in gists.iter() {
+        let claim = match find_claim(gist, &username).await {
+            Ok(Some(claim)) => claim,
+            Ok(None) => continue,
+            Err(err) => {
+                warn!("Github fetch | gist {}: {}", gist.id, err);
+                continue;
+            }
+        };
+        let (platform, identity, display_name, source) = match claim {
+            // Keybase identity is stored as Keybase user ID.
+            GistClaim::Keybase(keybase) => match lookup(&format!("usernames={}", keybase)).await {
+                Ok(person_info) => (
+                    Platform::Keybase,
+                    person_info.id,
+                    Some(person_info.basics.username),
+                    DataSource::Keybase,
+                ),
+                Err(err) => {
+                    warn!("Github fetch | Keybase user {}: {}", keybase, err);
+                    continue;
+                }
+            },
+            GistClaim::NextID(persona) => (Platform::NextID, persona, None, DataSource::NextID),
+        };
+        let claimed_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source,
+            record_id: Some(gist.id.clone()),
+            created_at: DateTime::parse_from_rfc3339(&gist.created_at)
+                .ok()
+                .map(|dt| dt.naive_utc()),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            github_identity.clone(),
+            claimed_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+    if fetched.next_targets.is_empty() {
+        info!("Github fetch | {} has no proof gist", username);
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,32 @@
+use crate::{
+    error::Error,
+    upstream::github::{fetch_gists, parse_keybase_gist, parse_nextid_gist},
+};
+
+#[test]
+fn test_parse_keybase_gist() {
+    let content = "### Keybase proof\n\nI hereby claim:\n\n  * I am an admin of https://github.com/Alice\n  * I am alice (https://keybase.io/alice) on keybase.\n";
+    assert_eq!(parse_keybase_gist(content, "alice"), Some("alice".into()));
+    assert_eq!(parse_keybase_gist(content, "bob"), None);
+}
+
+#[test]
+fn test_parse_nextid_gist() {
+    let persona = "0x03a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90";
+    let content = format!(
+        r#"{{"version":"1","persona":"{}","github_username":"Alice","sign_payload":"","signature":""}}"#,
+        persona
+    );
+    assert_eq!(
+        parse_nextid_gist(&format!("{}.json", persona), &content, "alice"),
+        Some(persona.into())
+    );
+    assert_eq!(parse_nextid_gist("0x00.json", &content, "alice"), None);
+}
+
+#[tokio::test]
+async fn test_fetch_gists() -> Result<(), Error> {
+    let gists = fetch_gists("fengshanshan").await?;
+    println!("gists: {:?}", gists);
+    Ok(())
+}
@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{edge::Annotation, vertex::contract::ContractCategory, vertex::Identity},
+    upstream::{
+        keybase::lookup, twitter::parse_ens_names, Connection, DataFetcher, DataSource, Fetched,
+        Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Anyone could write anything in the "about" field, nothing is confirmed by the other side.
+pub const CONFIDENCE: f64 = 0.2;
+
+/// https://github.com/HackerNews/API#users
+#[derive(Deserialize, Debug)]
+pub struct User {
+    /// Username, case-sensitive.
+    pub id: String,
+    /// Unix timestamp of creation.
+    pub created: i64,
+    /// HTML of the self-description.
+    pub about: Option<String>,
+}
+
+/// Something claimed in the "about" field.
+#[derive(Debug, PartialEq)]
+pub enum AboutClaim {
+    /// `keybase.io/{username}`
+    Keybase(String),
+    /// `0x` + 40 hex
+    Ethereum(String),
+    /// `{name}.eth`
+    ENS(String),
+}
+
+pub struct HackerNews {}
+
+#[async_trait]
+impl Fetcher for HackerNews {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, username) => fetch_claims_by_username(username).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::HackerNews])
+    }
+}
+
+async fn fetch_user(username: &str) -> Result<Option<User>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/user/{}.json", C.upstream.hacker_news_api.url, username)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("HackerNews Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("HackerNews fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("HackerNews Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    // Unknown user is `null`.
+    parse_body(&mut resp).await
+}
+
+/// Decode the HTML entities used by Hacker News.
+fn unescape_html(html: &str) -> String {
+    html.replace("<p>", "\n")
+        .replace("&#x2F;", "/")
+        .replace("&#x27;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn parse_about(about: &str) -> Vec<AboutClaim> {
+    let about = unescape_html(about);
+    let mut claims: Vec<AboutClaim> = vec![];
+
+    let mut rest = about.as_str();
+    while let Some(start) = rest.find("keybase.io/") {
+        rest = &rest[start + "keybase.io/".len()..];
+        let username: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect();
+        if !username.is_empty() {
+            claims.push(AboutClaim::Keybase(username.to_lowercase()));
+        }
+    }
+
+    for word in about.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if word.len() == 42
+            && word.starts_with("0x")
+            && word[2..].chars().all(|c| c.is_ascii_hexdigit())
+        {
+            claims.push(AboutClaim::Ethereum(word.to_lowercase()));
+        }
+    }
+
+    claims.extend(parse_ens_names(&about).into_iter().map(AboutClaim::ENS));
+    let mut unique: Vec<AboutClaim> = vec![];
+    for claim in claims.into_iter() {
+        if !unique.contains(&claim) {
+            unique.push(claim);
+        }
+    }
+    unique
+}
+
+async fn fetch_claims_by_username(username: &str) -> Result<Fetched, Error> {
+    let user = match fetch_user(username).await? {
+        Some(user) => user,
+        None => {
+            info!("HackerNews fetch | user {} not found", username);
+            return Ok(Fetched::default());
+        }
+    };
+    let claims = parse_about(&user.about.unwrap_or_default());
+    if claims.is_empty() {
+        return Ok(Fetched::default());
+    }
+
+    let hn_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::HackerNews,
+        identity: user.id.clone(),
+        created_at: Some(timestamp_to_naive(user.created, 0)),
+        display_name: Some(user.id.clone()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://news.ycombinator.com/user?id={}", user.id)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for claim in claims.into_iter() {
+        let (platform, identity, display_name) = match claim {
+            // Let ENS upstreams resolve and verify it.
+            AboutClaim::ENS(name) => {
+                fetched.next_targets.push(Target::NFT(
+                    ContractCategory::ENS.default_chain().unwrap(),
+                    ContractCategory::ENS,
+                    ContractCategory::ENS.default_contract_address().unwrap(),
+                    name,
+                ));
+                continue;
+            }
+            AboutClaim::Ethereum(address) => {
+                fetched
+                    .next_targets
+                    .push(Target::Identity(Platform::Ethereum, address.clone()));
+                (Platform::Ethereum, address, None)
+            }
+            AboutClaim::Keybase(username) => {
+                match lookup(&format!("usernames={}", username)).await {
+                    Ok(person_info) => (Platform::Keybase, person_info.id, Some(username)),
+                    Err(err) => {
+                        warn!("HackerNews fetch | Keybase user {}: {}", username, err);
+                        continue;
+                    }
+                }
+            }
+        };
+        let claimed_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: display_name.clone(),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let annotation: Annotation = Annotation {
+            uuid: Uuid::new_v4(),
+            source: DataSource::HackerNews,
+            name: display_name.unwrap_or(identity),
+            tags: vec![],
+            confidence: CONFIDENCE,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Annotation(
+            hn_identity.clone(),
+            claimed_identity,
+            annotation,
+        ));
+    }
+
+    Ok(fetched)
+}
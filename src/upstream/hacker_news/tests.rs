@@ -0,0 +1,26 @@
+use crate::{
+    error::Error,
+    upstream::hacker_news::{fetch_user, parse_about, AboutClaim},
+};
+
+#[test]
+fn test_parse_about() {
+    let about = "Building things.<p>Keybase: <a href=\"https:&#x2F;&#x2F;keybase.io&#x2F;Alice\" rel=\"nofollow\">https:&#x2F;&#x2F;keybase.io&#x2F;Alice</a><p>ETH: alice.eth &#x2F; 0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+    assert_eq!(
+        parse_about(about),
+        vec![
+            AboutClaim::Keybase("alice".into()),
+            AboutClaim::Ethereum("0xd8da6bf26964af9d7eed9e03e53415d37aa96045".into()),
+            AboutClaim::ENS("alice.eth".into()),
+        ]
+    );
+    assert!(parse_about("Nothing to see here.").is_empty());
+}
+
+#[tokio::test]
+async fn test_fetch_user() -> Result<(), Error> {
+    let user = fetch_user("pg").await?;
+    println!("user: {:?}", user);
+    assert!(user.is_some());
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{
+    error::Error,
+    graph::{batch, edge::Proof, vertex::Identity, GraphStats},
+    upstream::{rss3::is_duplicated, Connection},
+};
+use aragog::DatabaseConnection;
+use tracing::{debug, warn};
+
+/// If `from` and `to` are already connected by a first-party upstream.
+async fn is_republished(
+    db: &DatabaseConnection,
+    from: &Identity,
+    to: &Identity,
+) -> Result<bool, Error> {
+    let from_record =
+        Identity::find_by_platform_identity(db, &from.platform, &from.identity).await?;
+    let to_record = Identity::find_by_platform_identity(db, &to.platform, &to.identity).await?;
+    let (from_record, to_record) = match (from_record, to_record) {
+        (Some(from_record), Some(to_record)) => (from_record, to_record),
+        _ => return Ok(false),
+    };
+    let existing: Vec<Proof> = Proof::find_all_by_from_to(db, &from_record, &to_record)
+        .await?
+        .into_iter()
+        .map(|record| Proof::clone(&record.record))
+        .collect();
+    Ok(is_duplicated(&existing))
+}
+
+/// Save connections found by upstreams, all of them in one transaction (see `batch::upsert`):
+/// if it fails, nothing is saved and the error is returned.
+/// `Republished` ones already connected by a first-party upstream are skipped.
+/// Returns what's newly created.
+pub async fn import(
+    db: &DatabaseConnection,
+    connections: &[Connection],
+) -> Result<GraphStats, Error> {
+    let mut to_be_saved = Vec::with_capacity(connections.len());
+    for connection in connections {
+        if let Connection::Republished(from, to, _) = connection {
+            if is_republished(db, from, to).await? {
+                debug!(
+                    "Import | {} -> {} already exists, skip",
+                    from.identity, to.identity
+                );
+                continue;
+            }
+        }
+        to_be_saved.push(connection.clone());
+    }
+    let upserted = batch::upsert(db, &to_be_saved).await.map_err(|err| {
+        warn!("Error happened when importing: {}", err);
+        err
+    })?;
+    Ok(upserted.created)
+}
@@ -3,15 +3,26 @@ mod tests;
 
 use crate::config::C;
 use crate::error::Error;
-use crate::graph::create_identity_to_identity_two_way_binding;
-use crate::graph::{edge::Proof, new_db_connection, vertex::Identity};
-use crate::upstream::{DataSource, Fetcher, Platform, TargetProcessedList};
+use crate::graph::vertex::{CryptoKey, KeyAlgorithm};
+use crate::graph::{
+    edge::{Hold, InvalidationReason, Proof, ProofLevel},
+    vertex::Identity,
+};
+use crate::upstream::{
+    openpgp::{parse_certificate, Certificate},
+    reddit::verify_proof_post,
+    Connection, DataSource, Fetched, Fetcher, Platform,
+};
 use crate::util::{make_client, naive_now, parse_body, request_with_timeout};
 use async_trait::async_trait;
+use ed25519_dalek::{PublicKey as Ed25519PublicKey, Signature, Verifier};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 
 use hyper::{Body, Method};
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
 use super::{DataFetcher, Target};
@@ -27,6 +38,66 @@ pub struct PersonInfo {
     pub id: String,
     pub basics: Basics,
     pub proofs_summary: ProofsSummary,
+    #[serde(default)]
+    pub public_keys: Option<PublicKeys>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PublicKeys {
+    pub primary: Option<PublicKey>,
+    /// ASCII-armored PGP keys of the user. The eldest key is usually not one of them.
+    #[serde(default)]
+    pub pgp_public_keys: Vec<String>,
+    #[serde(default)]
+    pub eldest_kid: Option<String>,
+    /// Kids of keys delegated by the eldest one (or other sibkeys), not revoked.
+    #[serde(default)]
+    pub sibkeys: Vec<String>,
+}
+
+impl PublicKeys {
+    /// Kids of the current key family, which can sign links of the sigchain.
+    fn family(&self) -> HashSet<String> {
+        self.eldest_kid
+            .iter()
+            .chain(self.sibkeys.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// PGP keys in `pgp_public_keys` (and `primary` if it's a PGP key), without duplicates.
+    fn pgp_keys(&self) -> Vec<Certificate> {
+        let primary = self
+            .primary
+            .iter()
+            .filter(|key| key.key_type == KEY_TYPE_PGP)
+            .map(|key| &key.bundle);
+        let mut keys: Vec<Certificate> = vec![];
+        for bundle in self.pgp_public_keys.iter().chain(primary) {
+            match parse_certificate(bundle) {
+                Some(key) if !keys.iter().any(|k| k.fingerprint == key.fingerprint) => {
+                    keys.push(key)
+                }
+                _ => {}
+            }
+        }
+        keys
+    }
+}
+
+/// `key_type` of PGP keys in `public_keys`.
+const KEY_TYPE_PGP: i32 = 1;
+
+#[derive(Deserialize, Debug)]
+pub struct PublicKey {
+    pub kid: String,
+    pub key_type: i32,
+    /// ASCII-armored key.
+    pub bundle: String,
+    /// Unix timestamp in seconds.
+    pub ctime: i64,
+    /// Only PGP keys have a fingerprint.
+    pub key_fingerprint: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -54,6 +125,9 @@ pub struct Basics {
     pub eldest_seqno: i32,
 }
 
+/// `state` of proofs which are still in place, see `ProofItem`.
+pub(crate) const PROOF_STATE_OK: i32 = 1;
+
 #[derive(Deserialize, Debug)]
 pub struct ProofItem {
     pub proof_type: String,
@@ -68,6 +142,33 @@ pub struct ProofItem {
     pub presentation_tag: String,
 }
 
+/// Response of `sig/get.json`.
+#[derive(Deserialize, Debug)]
+pub struct SigchainResponse {
+    pub status: Status,
+    #[serde(default)]
+    pub sigs: Vec<SigchainLink>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SigchainLink {
+    pub seqno: i64,
+    pub sig_id: String,
+    /// Key ID which signs this link.
+    pub kid: String,
+    /// Base64 encoded msgpack packet for NaCl keys, armored message for PGP keys.
+    pub sig: String,
+    pub payload_json: String,
+    pub payload_hash: String,
+    /// `payload_hash` of the previous link.
+    pub prev: Option<String>,
+}
+
+/// Prefix of ed25519 `kid`s. PGP keys are not supported.
+const KID_PREFIX_ED25519: &str = "0120";
+/// Suffix of `sig_id` after the hash of the signature packet.
+const SIG_ID_SUFFIX: &str = "0f";
+
 #[derive(Deserialize, Debug)]
 pub struct ErrorResponse {
     pub message: String,
@@ -78,16 +179,16 @@ pub struct Keybase {}
 
 #[async_trait]
 impl Fetcher for Keybase {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
@@ -96,14 +197,13 @@ impl Fetcher for Keybase {
     }
 }
 
-async fn fetch_connections_by_platform_identity(
-    platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
+/// Lookup a Keybase user. `params` is the query of `user/lookup.json` API,
+/// e.g. `github=fengshanshan` or `usernames=alice`.
+pub(crate) async fn lookup(params: &str) -> Result<PersonInfo, Error> {
     let client = make_client();
     let uri: http::Uri = match format!(
-        "{}?{}={}&fields=proofs_summary",
-        C.upstream.keybase_service.url, platform, identity
+        "{}?{}&fields=proofs_summary,public_keys",
+        C.upstream.keybase_service.url, params
     )
     .parse()
     {
@@ -137,16 +237,50 @@ async fn fetch_connections_by_platform_identity(
         ));
     }
 
-    let person_info = match body.them.pop() {
-        Some(i) => i,
-        None => {
-            return Err(Error::NoResult);
-        }
-    };
+    body.them.pop().ok_or(Error::NoResult)
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let person_info = lookup(&format!("{}={}", platform, identity)).await?;
     let user_id = person_info.id;
     let user_name = person_info.basics.username;
-    let db = new_db_connection().await?;
-    let mut next_targets: TargetProcessedList = Vec::new();
+    let sigchain = if C.upstream.keybase_service.verify_sigchain {
+        fetch_sigchain(&user_id).await?
+    } else {
+        vec![]
+    };
+    let family = person_info
+        .public_keys
+        .as_ref()
+        .map(PublicKeys::family)
+        .unwrap_or_default();
+    let verified_links = verify_sigchain(&sigchain, &family);
+    let mut fetched = Fetched::default();
+
+    let pgp_keys = person_info
+        .public_keys
+        .as_ref()
+        .map(PublicKeys::pgp_keys)
+        .unwrap_or_default();
+    for key in pgp_keys {
+        let keybase_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Keybase,
+            identity: user_id.clone(),
+            created_at: None,
+            display_name: Some(user_name.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        fetched
+            .connections
+            .push(pgp_key_connection(keybase_identity, key));
+    }
 
     for p in person_info.proofs_summary.all.into_iter() {
         let from: Identity = Identity {
@@ -176,6 +310,25 @@ async fn fetch_connections_by_platform_identity(
             updated_at: naive_now(),
         };
 
+        let mut verified = verified_links
+            .get(&p.sig_id)
+            .is_some_and(|payload| is_proof_of(payload, &user_id, &p.proof_type, &p.nametag));
+        // The signed statement must also be posted by the claimed account.
+        if verified && p.proof_type == "reddit" {
+            let signature = sigchain
+                .iter()
+                .find(|link| link.sig_id == p.sig_id)
+                .map(|link| link.sig.as_str())
+                .unwrap_or_default();
+            verified = verify_proof_post(&p.proof_url, &p.nametag, signature)
+                .await
+                .unwrap_or_else(|err| {
+                    warn!("Keybase fetch | Reddit proof {}: {}", p.proof_url, err);
+                    false
+                });
+        }
+        // Proofs taken down or broken are kept, but invalidated.
+        let revoked = p.state != PROOF_STATE_OK;
         let pf: Proof = Proof {
             uuid: Uuid::new_v4(),
             source: DataSource::Keybase,
@@ -183,15 +336,227 @@ async fn fetch_connections_by_platform_identity(
             created_at: None,
             updated_at: naive_now(),
             fetcher: DataFetcher::RelationService,
+            verified,
+            expired_at: None,
+            invalid_since: revoked.then(naive_now),
+            invalidation_reason: revoked.then_some(InvalidationReason::Revoked),
+            level: if verified {
+                ProofLevel::Cryptographic
+            } else {
+                ProofLevel::PlatformVerified
+            },
         };
 
-        create_identity_to_identity_two_way_binding(&db, &from, &to, &pf).await?;
+        fetched
+            .connections
+            .push(Connection::TwoWayBinding(from, to, pf));
 
-        next_targets.push(Target::Identity(
+        fetched.next_targets.push(Target::Identity(
             Platform::from_str(&p.proof_type).unwrap(),
             p.nametag,
         ));
     }
 
-    Ok(next_targets)
+    Ok(fetched)
+}
+
+async fn fetch_sigchain(user_id: &str) -> Result<Vec<SigchainLink>, Error> {
+    let client = make_client();
+    let uri: http::Uri = match format!(
+        "{}?uid={}",
+        C.upstream.keybase_service.sigchain_url, user_id
+    )
+    .parse()
+    {
+        Ok(n) => n,
+        Err(err) => return Err(Error::ParamError(format!("Uri format Error: {}", err))),
+    };
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Keybase Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "Keybase fetch sigchain | error: {:?}",
+            err.to_string()
+        ))
+    })?;
+
+    if !resp.status().is_success() {
+        let body: ErrorResponse = parse_body(&mut resp).await?;
+        return Err(Error::General(
+            format!("Keybase Sigchain Get Error: {}", body.message),
+            resp.status(),
+        ));
+    }
+
+    let body: SigchainResponse = parse_body(&mut resp).await?;
+    if body.status.code != 0 {
+        return Err(Error::General(
+            format!("Keybase Sigchain Get Error: {}", body.status.name),
+            resp.status(),
+        ));
+    }
+    Ok(body.sigs)
+}
+
+/// Walk through the sigchain and return `sig_id => payload` of every link
+/// which is correctly chained, correctly signed by a key in `family` (see `PublicKeys::family`)
+/// and not revoked afterwards.
+/// The walk stops at the first broken link, since nothing after it can be trusted.
+fn verify_sigchain(
+    links: &[SigchainLink],
+    family: &HashSet<String>,
+) -> HashMap<String, serde_json::Value> {
+    let mut verified: HashMap<String, serde_json::Value> = HashMap::new();
+    let mut revoked: HashSet<String> = HashSet::new();
+    let mut prev: Option<&str> = None;
+
+    for link in links.iter() {
+        if prev.is_some() && link.prev.as_deref() != prev {
+            warn!(seqno = link.seqno, "Keybase sigchain | broken chain");
+            break;
+        }
+        prev = Some(&link.payload_hash);
+
+        if hex::encode(Sha256::digest(link.payload_json.as_bytes())) != link.payload_hash {
+            warn!(
+                seqno = link.seqno,
+                "Keybase sigchain | payload hash mismatch"
+            );
+            break;
+        }
+        let payload: serde_json::Value = match serde_json::from_str(&link.payload_json) {
+            Ok(payload) => payload,
+            Err(_) => break,
+        };
+        if !verify_link_signature(link) {
+            debug!(
+                seqno = link.seqno,
+                kid = link.kid,
+                "Keybase sigchain | unverified link"
+            );
+            continue;
+        }
+
+        let revoke = &payload["body"]["revoke"];
+        revoked.extend(
+            revoke["sig_ids"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .chain(Some(&revoke["sig_id"]))
+                .filter_map(|sig_id| sig_id.as_str().map(String::from)),
+        );
+        // Signed by a key no longer in the family (e.g. a revoked device):
+        // what it revoked is still revoked, but what it claims can't be trusted now.
+        if !family.contains(&link.kid) {
+            debug!(
+                seqno = link.seqno,
+                kid = link.kid,
+                "Keybase sigchain | signed by a key out of family"
+            );
+            continue;
+        }
+        verified.insert(link.sig_id.clone(), payload);
+    }
+
+    verified.retain(|sig_id, _| !revoked.contains(sig_id));
+    verified
+}
+
+/// Verify the NaCl signature packet of a link against its `kid`.
+fn verify_link_signature(link: &SigchainLink) -> bool {
+    if !link.kid.starts_with(KID_PREFIX_ED25519) {
+        return false;
+    }
+    let packet = match base64::decode(&link.sig) {
+        Ok(packet) => packet,
+        Err(_) => return false,
+    };
+    // `sig_id` is the hash of the whole packet.
+    if format!("{}{}", hex::encode(Sha256::digest(&packet)), SIG_ID_SUFFIX) != link.sig_id {
+        return false;
+    }
+    let value = match rmpv::decode::read_value(&mut packet.as_slice()) {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+    let field = |map: &rmpv::Value, key: &str| -> Option<rmpv::Value> {
+        map.as_map()?
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, v)| v.clone())
+    };
+    let body = match field(&value, "body") {
+        Some(body) => body,
+        None => return false,
+    };
+    let (key, sig) = match (field(&body, "key"), field(&body, "sig")) {
+        (Some(rmpv::Value::Binary(key)), Some(rmpv::Value::Binary(sig))) => (key, sig),
+        _ => return false,
+    };
+    // Detached signatures sign `payload_json`, attached ones carry the payload in the packet,
+    // which must be `payload_json` byte for byte: it's what's hashed and read afterwards.
+    let payload = link.payload_json.as_bytes();
+    match field(&body, "payload") {
+        Some(rmpv::Value::Binary(attached)) if attached != payload => return false,
+        _ => {}
+    }
+    // kid = 0x01 0x20 || public key (32 bytes) || 0x0a
+    if hex::encode(&key) != link.kid || key.len() != 35 {
+        return false;
+    }
+    let public_key = match Ed25519PublicKey::from_bytes(&key[2..34]) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    match Signature::try_from(sig.as_slice()) {
+        Ok(signature) => public_key.verify(payload, &signature).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Check the payload of a verified link is really a proof of `nametag` on `proof_type`
+/// (see `ProofItem`), made by user `uid`.
+fn is_proof_of(payload: &serde_json::Value, uid: &str, proof_type: &str, nametag: &str) -> bool {
+    let body = &payload["body"];
+    if body["key"]["uid"].as_str() != Some(uid) {
+        return false;
+    }
+    let service = &body["service"];
+    let name = match proof_type {
+        "generic_web_site" => service["hostname"].as_str(),
+        "dns" => service["domain"].as_str(),
+        _ if service["name"].as_str() == Some(proof_type) => service["username"].as_str(),
+        _ => None,
+    };
+    name.is_some_and(|name| name.eq_ignore_ascii_case(nametag))
+}
+
+/// PGP key of a Keybase user as a `CryptoKey` held by the user.
+fn pgp_key_connection(keybase_identity: Identity, key: Certificate) -> Connection {
+    let created_at = Some(key.created_at);
+    let crypto_key: CryptoKey = CryptoKey {
+        uuid: Uuid::new_v4(),
+        algorithm: KeyAlgorithm::PGP,
+        fingerprint: CryptoKey::normalize_fingerprint(&key.fingerprint),
+        public_key: Some(key.armored),
+        chain: None,
+        created_at,
+        updated_at: naive_now(),
+    };
+    let hold: Hold = Hold {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Keybase,
+        transaction: None,
+        id: crypto_key.fingerprint.clone(),
+        created_at,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+    Connection::CryptoKeyHold(keybase_identity, crypto_key, hold)
 }
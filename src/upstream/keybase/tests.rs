@@ -2,15 +2,15 @@ use crate::{
     error::Error,
     graph::new_db_connection,
     graph::vertex::Identity,
+    upstream::{fetch_and_import, Platform},
     upstream::{keybase::Keybase, Target},
-    upstream::{Fetcher, Platform},
     util::naive_now,
 };
 
 #[tokio::test]
 async fn test_smoke_keybase() -> Result<(), Error> {
     let target = Target::Identity(Platform::Github, "fengshanshan".into());
-    Keybase::fetch(&target).await?;
+    fetch_and_import::<Keybase>(&target).await?;
     let db = new_db_connection().await?;
     let found = Identity::find_by_platform_identity(&db, &target.platform()?, &target.identity()?)
         .await?
@@ -19,3 +19,93 @@ async fn test_smoke_keybase() -> Result<(), Error> {
     assert!((found.updated_at.timestamp() - naive_now().timestamp()).abs() < 3);
     Ok(())
 }
+
+#[test]
+fn test_is_proof_of() {
+    use crate::upstream::keybase::is_proof_of;
+
+    let uid = "dbb165b7879fe7b1174df73bed0b9500";
+    let payload = serde_json::json!({
+        "body": {
+            "type": "web_service_binding",
+            "key": { "uid": uid },
+            "service": { "name": "github", "username": "FengShanShan" }
+        }
+    });
+    assert!(is_proof_of(&payload, uid, "github", "fengshanshan"));
+    assert!(!is_proof_of(&payload, uid, "github", "someone_else"));
+    // Same username on another service.
+    assert!(!is_proof_of(&payload, uid, "twitter", "fengshanshan"));
+    // Signed for another user.
+    assert!(!is_proof_of(
+        &payload,
+        "9f9d7870b3a2d8fb1f9c0f7a1e5b2e19",
+        "github",
+        "fengshanshan"
+    ));
+
+    let payload = serde_json::json!({
+        "body": {
+            "key": { "uid": uid },
+            "service": { "hostname": "example.com", "protocol": "https:" }
+        }
+    });
+    assert!(is_proof_of(
+        &payload,
+        uid,
+        "generic_web_site",
+        "example.com"
+    ));
+    assert!(!is_proof_of(&payload, uid, "dns", "example.com"));
+}
+
+#[test]
+fn test_verify_sigchain_broken_chain() {
+    use crate::upstream::keybase::{verify_sigchain, SigchainLink};
+    use std::collections::HashSet;
+
+    let payload_json = r#"{"body":{"type":"eldest"}}"#.to_string();
+    let link = SigchainLink {
+        seqno: 1,
+        sig_id: "".into(),
+        kid: "".into(),
+        sig: "".into(),
+        payload_hash: "not a hash".into(),
+        payload_json,
+        prev: None,
+    };
+    assert!(verify_sigchain(&[link], &HashSet::from(["".to_string()])).is_empty());
+}
+
+#[test]
+fn test_pgp_keys() {
+    use crate::upstream::keybase::PublicKeys;
+
+    // Fake V4 key packets, created at different times.
+    let armored = |created_at: u8| {
+        let key_body: Vec<u8> = vec![4, 0x5f, 0x5e, 0x10, created_at, 22, 0, 1, 0];
+        let mut data = vec![0xc6, key_body.len() as u8];
+        data.extend(&key_body);
+        format!(
+            "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\n{}\n-----END PGP PUBLIC KEY BLOCK-----\n",
+            base64::encode(data)
+        )
+    };
+    // Eldest key is an ed25519 device key, PGP keys are listed apart.
+    let public_keys: PublicKeys = serde_json::from_value(serde_json::json!({
+        "primary": {
+            "kid": "0120a1b2",
+            "key_type": 3,
+            "bundle": "g6Rib2R5",
+            "ctime": 1500000000
+        },
+        "pgp_public_keys": [armored(1), armored(2), armored(1), "not a key"],
+        "eldest_kid": "0120a1b2",
+        "sibkeys": []
+    }))
+    .unwrap();
+    let keys = public_keys.pgp_keys();
+    assert_eq!(keys.len(), 2);
+    assert_ne!(keys[0].fingerprint, keys[1].fingerprint);
+    assert_eq!(keys[1].created_at.timestamp(), 0x5f5e1002);
+}
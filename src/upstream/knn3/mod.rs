@@ -5,12 +5,9 @@ use crate::config::C;
 use crate::graph::edge::hold::Hold;
 use crate::graph::vertex::{contract::Chain, contract::ContractCategory, Contract};
 
-use crate::upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList};
+use crate::upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target};
 use crate::util::naive_now;
-use crate::{
-    error::Error,
-    graph::{create_identity_to_contract_record, new_db_connection, vertex::Identity},
-};
+use crate::{error::Error, graph::vertex::Identity};
 
 use async_trait::async_trait;
 use gql_client::Client;
@@ -52,9 +49,9 @@ pub struct Knn3 {}
 
 #[async_trait]
 impl Fetcher for Knn3 {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
@@ -72,7 +69,7 @@ impl Fetcher for Knn3 {
 }
 
 /// Use ethereum address to fetch NFTs (especially ENS).
-async fn fetch_ens_by_eth_wallet(identity: &str) -> Result<TargetProcessedList, Error> {
+async fn fetch_ens_by_eth_wallet(identity: &str) -> Result<Fetched, Error> {
     let query = r#"
         query EnsByAddressQuery($addr: String!){
             addrs(where: { address: $addr }) {
@@ -88,7 +85,7 @@ async fn fetch_ens_by_eth_wallet(identity: &str) -> Result<TargetProcessedList,
 
     let resp = client.query_with_vars(query, vars);
     let data: Option<EthQueryResponse> =
-        match tokio::time::timeout(std::time::Duration::from_secs(5), resp).await {
+        match tokio::time::timeout(std::time::Duration::from_secs(C.http.timeout), resp).await {
             Ok(resp) => match resp {
                 Ok(resp) => {
                     let res = resp.unwrap();
@@ -103,24 +100,27 @@ async fn fetch_ens_by_eth_wallet(identity: &str) -> Result<TargetProcessedList,
                 }
             },
             Err(_) => {
-                warn!("KNN3 fetch | Timeout: no response in 5 seconds.");
+                warn!(
+                    "KNN3 fetch | Timeout: no response in {} seconds.",
+                    C.http.timeout
+                );
                 None
             }
         };
 
     if data.is_none() {
         info!("KNN3 fetch | address: {} cannot find any result", identity);
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
     let res = data.unwrap();
     if res.addrs.is_empty() {
         info!("KNN3 fetch | address: {} cannot find any result", identity);
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
 
     let ens_vec = res.addrs.first().unwrap();
-    let db = new_db_connection().await?;
 
+    let mut fetched = Fetched::default();
     for ens in ens_vec.ens.iter() {
         let from: Identity = Identity {
             uuid: Some(Uuid::new_v4()),
@@ -151,23 +151,20 @@ async fn fetch_ens_by_eth_wallet(identity: &str) -> Result<TargetProcessedList,
             updated_at: naive_now(),
             fetcher: DataFetcher::RelationService,
         };
-        create_identity_to_contract_record(&db, &from, &to, &ownership).await?;
+        fetched
+            .connections
+            .push(Connection::ContractHold(from, to, ownership));
+        fetched.next_targets.push(Target::NFT(
+            Chain::Ethereum,
+            ContractCategory::ENS,
+            ContractCategory::ENS.default_contract_address().unwrap(),
+            ens.clone(),
+        ));
     }
-    Ok(ens_vec
-        .ens
-        .iter()
-        .map(|ens| {
-            Target::NFT(
-                Chain::Ethereum,
-                ContractCategory::ENS,
-                ContractCategory::ENS.default_contract_address().unwrap(),
-                ens.clone(),
-            )
-        })
-        .collect())
+    Ok(fetched)
 }
 
-async fn fetch_eth_wallet_by_ens(id: &str) -> Result<TargetProcessedList, Error> {
+async fn fetch_eth_wallet_by_ens(id: &str) -> Result<Fetched, Error> {
     let query = r#"
         query AddressByENSQuery($ens: [String]){
             addrs(where: { ens: $ens }) {
@@ -181,32 +178,39 @@ async fn fetch_eth_wallet_by_ens(id: &str) -> Result<TargetProcessedList, Error>
     };
     let response = client.query_with_vars::<EnsQueryResponse, _>(query, vars);
 
-    let data: Option<EnsQueryResponse> =
-        match tokio::time::timeout(std::time::Duration::from_secs(5), response).await {
-            Ok(response) => match response {
-                Ok(response) => response,
-                Err(err) => {
-                    warn!(
-                        "KNN3 fetch | Failed to fetch addrs using ENS: {}, error: {:?}",
-                        id, err
-                    );
-                    None
-                }
-            },
-            Err(_) => {
-                warn!("KNN3 fetch | Timeout: no response in 5 seconds.");
+    let data: Option<EnsQueryResponse> = match tokio::time::timeout(
+        std::time::Duration::from_secs(C.http.timeout),
+        response,
+    )
+    .await
+    {
+        Ok(response) => match response {
+            Ok(response) => response,
+            Err(err) => {
+                warn!(
+                    "KNN3 fetch | Failed to fetch addrs using ENS: {}, error: {:?}",
+                    id, err
+                );
                 None
             }
-        };
+        },
+        Err(_) => {
+            warn!(
+                "KNN3 fetch | Timeout: no response in {} seconds.",
+                C.http.timeout
+            );
+            None
+        }
+    };
 
     if data.is_none() {
         info!("KNN3 fetch | ENS {} has no result", id);
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
     let result = data.unwrap();
     if result.addrs.is_empty() {
         info!("KNN3 fetch | ENS {} has no result", id);
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
 
     // NOTE: not sure if this result must have one and only one.
@@ -240,8 +244,10 @@ async fn fetch_eth_wallet_by_ens(id: &str) -> Result<TargetProcessedList, Error>
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
     };
-    let db = new_db_connection().await?;
-    create_identity_to_contract_record(&db, &from, &to, &hold).await?;
 
-    Ok(vec![Target::Identity(Platform::Ethereum, address)])
+    Ok(Fetched {
+        connections: vec![Connection::ContractHold(from, to, hold)],
+        next_targets: vec![Target::Identity(Platform::Ethereum, address)],
+        ..Default::default()
+    })
 }
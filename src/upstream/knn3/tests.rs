@@ -7,7 +7,7 @@ use crate::{
         vertex::Identity,
         vertex::{contract::ContractCategory, Contract},
     },
-    upstream::{knn3::Knn3, Fetcher, Platform, Target},
+    upstream::{fetch_and_import, knn3::Knn3, Platform, Target},
 };
 
 #[tokio::test]
@@ -18,7 +18,7 @@ async fn test_knn3() -> Result<(), Error> {
             .to_string()
             .to_lowercase(),
     );
-    Knn3::fetch(&target).await?;
+    fetch_and_import::<Knn3>(&target).await?;
 
     let db = new_db_connection().await?;
 
@@ -51,7 +51,7 @@ async fn test_knn3_fail_get_result() -> Result<(), Error> {
         Platform::Ethereum,
         "0xd8da6bf26964af9d7eed9e03e53415d37aa96044".to_string(),
     );
-    let res = Knn3::fetch(&target).await?;
+    let res = fetch_and_import::<Knn3>(&target).await?;
     assert_eq!(res.len(), 0);
     Ok(())
 }
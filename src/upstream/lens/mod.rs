@@ -6,14 +6,11 @@ use crate::{
     error::Error,
     graph::{
         edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
-        new_db_connection,
         vertex::Identity,
-        Edge, Vertex,
     },
-    upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
     util::naive_now,
 };
-use aragog::DatabaseConnection;
 use async_trait::async_trait;
 use cynic::{http::SurfExt, QueryBuilder};
 use std::convert::TryInto;
@@ -43,9 +40,44 @@ mod queries {
         pub is_followed_by_me: bool,
         pub name: Option<String>,
         pub metadata: Option<String>,
+        pub picture: Option<ProfileMedia>,
         pub owned_by: String,
     }
 
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum ProfileMedia {
+        NftImage(NftImage),
+        MediaSet(MediaSet),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    impl ProfileMedia {
+        /// URL of the original picture (if any).
+        pub fn url(&self) -> Option<String> {
+            match self {
+                Self::NftImage(image) => Some(image.uri.clone()),
+                Self::MediaSet(set) => Some(set.original.url.clone()),
+                Self::Unknown => None,
+            }
+        }
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NftImage {
+        pub uri: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct MediaSet {
+        pub original: Media,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Media {
+        pub url: String,
+    }
+
     #[derive(cynic::FragmentArguments, Debug)]
     pub struct ProfileQueryArguments {
         pub request: SingleProfileQueryRequest,
@@ -107,15 +139,15 @@ pub struct Lens {}
 
 #[async_trait]
 impl Fetcher for Lens {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target.platform()? {
             Platform::Ethereum => fetch_by_addr(target).await,
             Platform::Lens => fetch_by_lens_profile(target).await,
-            _ => Ok(vec![]),
+            _ => Ok(Fetched::default()),
         }
     }
 
@@ -125,7 +157,7 @@ impl Fetcher for Lens {
 }
 
 /// https://docs.lens.xyz/docs/get-profiles
-async fn fetch_by_addr(target: &Target) -> Result<TargetProcessedList, Error> {
+async fn fetch_by_addr(target: &Target) -> Result<Fetched, Error> {
     use queries::*;
 
     let operation = ProfilesQuery::build(ProfilesQueryArguments {
@@ -144,22 +176,21 @@ async fn fetch_by_addr(target: &Target) -> Result<TargetProcessedList, Error> {
             target,
             response.unwrap_err(),
         );
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
     let data = response.unwrap().data.unwrap().profiles.items;
     if data.len() == 0 {
         info!("Lens profile {} | No result", target);
-        return Ok(vec![]);
-    }
-    let db = new_db_connection().await?;
-    for profile in data.into_iter() {
-        save_profile(&db, &profile).await?;
+        return Ok(Fetched::default());
     }
     // there is no other upstream can get lens protocol
-    Ok(vec![])
+    Ok(Fetched {
+        connections: data.iter().flat_map(profile_connections).collect(),
+        ..Default::default()
+    })
 }
 
-async fn fetch_by_lens_profile(target: &Target) -> Result<TargetProcessedList, Error> {
+async fn fetch_by_lens_profile(target: &Target) -> Result<Fetched, Error> {
     use queries::*;
 
     let operation = ProfileQuery::build(ProfileQueryArguments {
@@ -178,26 +209,27 @@ async fn fetch_by_lens_profile(target: &Target) -> Result<TargetProcessedList, E
             target,
             response.unwrap_err(),
         );
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
 
     let data: Option<Profile> = response.unwrap().data.unwrap().profile;
     if data.is_none() {
         info!("Lens profile {} | No result", target);
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
     let profile: Profile = data.unwrap();
-    let db = new_db_connection().await?;
-
-    save_profile(&db, &profile).await?;
 
-    Ok(vec![Target::Identity(
-        Platform::Ethereum,
-        profile.owned_by.to_lowercase(),
-    )])
+    Ok(Fetched {
+        connections: profile_connections(&profile),
+        next_targets: vec![Target::Identity(
+            Platform::Ethereum,
+            profile.owned_by.to_lowercase(),
+        )],
+        ..Default::default()
+    })
 }
 
-async fn save_profile(db: &DatabaseConnection, profile: &Profile) -> Result<(), Error> {
+fn profile_connections(profile: &Profile) -> Vec<Connection> {
     let from: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::Ethereum,
@@ -217,7 +249,7 @@ async fn save_profile(db: &DatabaseConnection, profile: &Profile) -> Result<(),
         created_at: None,
         display_name: profile.name.clone(),
         added_at: naive_now(),
-        avatar_url: profile.metadata.clone(),
+        avatar_url: profile.picture.as_ref().and_then(|picture| picture.url()),
         profile_url: Some("https://lenster.xyz/u/".to_owned() + &profile.handle.clone()),
         updated_at: naive_now(),
     };
@@ -231,9 +263,7 @@ async fn save_profile(db: &DatabaseConnection, profile: &Profile) -> Result<(),
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
     };
-    let from_record = from.create_or_update(db).await?;
-    let to_record = to.create_or_update(db).await?;
-    hold.connect(db, &from_record, &to_record).await?;
+    let mut connections = vec![Connection::Hold(from.clone(), to.clone(), hold)];
 
     if profile.is_default {
         let resolve: Resolve = Resolve {
@@ -241,10 +271,12 @@ async fn save_profile(db: &DatabaseConnection, profile: &Profile) -> Result<(),
             source: DataSource::Lens,
             system: DomainNameSystem::Lens,
             name: profile.handle.clone(),
+            resolved_address: Some(from.identity.clone()),
+            expire_at: None,
             fetcher: DataFetcher::RelationService,
             updated_at: naive_now(),
         };
-        resolve.connect(db, &to_record, &from_record).await?;
+        connections.push(Connection::Resolve(to, from, resolve));
     }
-    Ok(())
+    connections
 }
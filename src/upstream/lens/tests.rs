@@ -7,7 +7,7 @@ use crate::{
         vertex::Identity,
         vertex::{contract::ContractCategory, Contract},
     },
-    upstream::{lens::Lens, DataFetcher, DataSource, Fetcher, Platform, Target},
+    upstream::{fetch_and_import, lens::Lens, DataFetcher, DataSource, Platform, Target},
 };
 
 #[tokio::test]
@@ -16,7 +16,7 @@ async fn test_fetch_by_lens_profile() -> Result<(), Error> {
     db.truncate().await;
 
     let target = Target::Identity(Platform::Lens, "stani.lens".into());
-    Lens::fetch(&target).await?;
+    fetch_and_import::<Lens>(&target).await?;
 
     Identity::find_by_platform_identity(&db, &target.platform()?, &target.identity()?)
         .await?
@@ -34,7 +34,7 @@ async fn test_fetch_by_addrs() -> Result<(), Error> {
         Platform::Ethereum,
         "0x7241dddec3a6af367882eaf9651b87e1c7549dff".to_string(),
     );
-    Lens::fetch(&target).await?;
+    fetch_and_import::<Lens>(&target).await?;
 
     Identity::find_by_platform_identity(&db, &target.platform()?, &target.identity()?)
         .await?
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{
+        naive_now,
+        scrape::{extract_json_ld, extract_next_data, fetch_html, find_key, link_to_identity},
+    },
+};
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::info;
+use uuid::Uuid;
+
+/// Structured data of a Link3 profile page.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Link3Profile {
+    /// Without `.cyber` suffix.
+    pub handle: String,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    /// Owner wallet of this profile.
+    pub address: Option<String>,
+    /// Social links verified by Link3.
+    pub socials: Vec<String>,
+}
+
+pub struct Link3 {}
+
+#[async_trait]
+impl Fetcher for Link3 {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, handle) => fetch_profile_by_handle(handle).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::CyberConnect])
+    }
+}
+
+/// Extract a Link3 profile from its page.
+/// JSON-LD `Person` gives name, avatar and `sameAs` links,
+/// `__NEXT_DATA__` gives the owner address.
+pub fn parse_profile(handle: &str, html: &str) -> Link3Profile {
+    let mut profile = Link3Profile {
+        handle: handle.to_string(),
+        ..Default::default()
+    };
+
+    if let Some(person) = extract_json_ld(html)
+        .into_iter()
+        .find(|ld| ld["@type"] == "Person")
+    {
+        profile.display_name = person["name"].as_str().map(String::from);
+        profile.avatar = person["image"].as_str().map(String::from);
+        profile.socials = match &person["sameAs"] {
+            Value::Array(links) => links
+                .iter()
+                .filter_map(|link| link.as_str().map(String::from))
+                .collect(),
+            Value::String(link) => vec![link.clone()],
+            _ => vec![],
+        };
+    }
+
+    profile.address = extract_next_data(html)
+        .as_ref()
+        .and_then(|data| find_key(data, "ownerAddress").or_else(|| find_key(data, "address")))
+        .and_then(|address| address.as_str())
+        .filter(|address| address.starts_with("0x") && address.len() == 42)
+        .map(|address| address.to_lowercase());
+
+    profile
+}
+
+async fn fetch_profile_by_handle(handle: &str) -> Result<Fetched, Error> {
+    let handle = handle.trim_end_matches(".cyber").to_lowercase();
+    let html = fetch_html(&format!("{}/{}", C.upstream.link3.url, handle)).await?;
+    let profile = parse_profile(&handle, &html);
+    if profile.address.is_none() && profile.socials.is_empty() {
+        info!("Link3 fetch | {} has no profile data", handle);
+        return Ok(Fetched::default());
+    }
+
+    let cc_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::CyberConnect,
+        identity: format!("{}.cyber", handle),
+        created_at: None,
+        display_name: profile.display_name.clone(),
+        added_at: naive_now(),
+        avatar_url: profile.avatar.clone(),
+        profile_url: Some(format!("https://link3.to/{}", handle)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    if let Some(address) = profile.address.clone() {
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: address.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Link3,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::Cryptographic,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            eth_identity,
+            cc_identity.clone(),
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::Ethereum, address));
+    }
+
+    for link in profile.socials.iter() {
+        let (platform, identity) = match link_to_identity(link) {
+            Some(found) => found,
+            None => continue,
+        };
+        let social_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Link3,
+            record_id: Some(link.clone()),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::Proof(
+            cc_identity.clone(),
+            social_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,26 @@
+use crate::upstream::link3::{parse_profile, Link3Profile};
+
+#[test]
+fn test_parse_profile() {
+    let html = r#"<html><head>
+<script type="application/ld+json">{"@context": "https://schema.org", "@type": "Person", "name": "Alice", "image": "https://link3.to/alice.png", "sameAs": ["https://twitter.com/alice"]}</script>
+<script id="__NEXT_DATA__" type="application/json">{"props": {"pageProps": {"profile": {"handle": "alice", "ownerAddress": "0x934B510D4C9103E6a87AEf13b816fb080286D649"}}}}</script>
+</head></html>"#;
+    assert_eq!(
+        parse_profile("alice", html),
+        Link3Profile {
+            handle: "alice".into(),
+            display_name: Some("Alice".into()),
+            avatar: Some("https://link3.to/alice.png".into()),
+            address: Some("0x934b510d4c9103e6a87aef13b816fb080286d649".into()),
+            socials: vec!["https://twitter.com/alice".into()],
+        }
+    );
+    assert_eq!(
+        parse_profile("nobody", "<html></html>"),
+        Link3Profile {
+            handle: "nobody".into(),
+            ..Default::default()
+        }
+    );
+}
@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{
+        make_client, naive_now, parse_body, request_with_timeout,
+        scrape::{extract_href, link_to_identity},
+    },
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// https://docs.joinmastodon.org/spec/webfinger/
+#[derive(Deserialize, Debug)]
+pub struct WebFinger {
+    /// `acct:user@domain`
+    pub subject: String,
+    #[serde(default)]
+    pub links: Vec<WebFingerLink>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WebFingerLink {
+    pub rel: String,
+    #[serde(rename = "type")]
+    pub link_type: Option<String>,
+    pub href: Option<String>,
+}
+
+/// https://docs.joinmastodon.org/spec/activitypub/#as
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Actor {
+    pub id: String,
+    pub preferred_username: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub icon: Option<ActorImage>,
+    pub published: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ActorImage {
+    pub url: String,
+}
+
+/// https://docs.joinmastodon.org/entities/Account/
+/// ActivityPub actor carries no verification result, it only exists in instance REST API.
+#[derive(Deserialize, Debug)]
+pub struct Account {
+    #[serde(default)]
+    pub fields: Vec<AccountField>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AccountField {
+    pub name: String,
+    /// HTML, e.g. `<a href="https://github.com/user" rel="nofollow noopener noreferrer me">...</a>`
+    pub value: String,
+    /// `rel=me` link verified by instance at this time. `None` if not verified.
+    pub verified_at: Option<String>,
+}
+
+const ACTIVITY_JSON: &str = "application/activity+json";
+
+pub struct Mastodon {}
+
+#[async_trait]
+impl Fetcher for Mastodon {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, acct) => fetch_verified_links_by_acct(acct).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        match target {
+            Target::Identity(Platform::Mastodon, acct) => parse_acct(acct).is_some(),
+            _ => false,
+        }
+    }
+}
+
+/// Split `@user@domain` / `user@domain` into `(user, domain)`.
+fn parse_acct(acct: &str) -> Option<(&str, &str)> {
+    let acct = acct.trim_start_matches('@');
+    match acct.split_once('@') {
+        Some((user, domain)) if !user.is_empty() && domain.contains('.') => Some((user, domain)),
+        _ => None,
+    }
+}
+
+async fn get_json<T: DeserializeOwned>(uri: String, accept: &str) -> Result<T, Error> {
+    let client = make_client();
+    let uri: http::Uri = uri
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("Accept", accept)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Mastodon Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Mastodon fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Mastodon Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+/// Resolve ActivityPub actor of an account using WebFinger.
+async fn fetch_actor(user: &str, domain: &str) -> Result<Actor, Error> {
+    let webfinger: WebFinger = get_json(
+        format!(
+            "https://{}/.well-known/webfinger?resource=acct:{}@{}",
+            domain, user, domain
+        ),
+        "application/jrd+json",
+    )
+    .await?;
+    let actor_url = webfinger
+        .links
+        .into_iter()
+        .find(|link| link.rel == "self" && link.link_type.as_deref() == Some(ACTIVITY_JSON))
+        .and_then(|link| link.href)
+        .ok_or(Error::NoResult)?;
+
+    get_json(actor_url, ACTIVITY_JSON).await
+}
+
+async fn fetch_account(user: &str, actor: &Actor) -> Result<Account, Error> {
+    let actor_url: url::Url = actor
+        .id
+        .parse()
+        .map_err(|err| Error::ParamError(format!("Mastodon actor url error: {}", err)))?;
+    let instance = actor_url
+        .host_str()
+        .ok_or_else(|| Error::ParamError(format!("Mastodon actor url error: {}", actor.id)))?;
+    get_json(
+        format!("https://{}/api/v1/accounts/lookup?acct={}", instance, user),
+        "application/json",
+    )
+    .await
+}
+
+async fn fetch_verified_links_by_acct(acct: &str) -> Result<Fetched, Error> {
+    let (user, domain) = parse_acct(acct).unwrap();
+    let actor = fetch_actor(user, domain).await?;
+    let account = fetch_account(&actor.preferred_username, &actor).await?;
+
+    let mastodon_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Mastodon,
+        identity: format!("{}@{}", user, domain).to_lowercase(),
+        created_at: actor
+            .published
+            .as_deref()
+            .and_then(|published| DateTime::parse_from_rfc3339(published).ok())
+            .map(|dt| dt.naive_utc()),
+        display_name: actor.name.clone(),
+        added_at: naive_now(),
+        avatar_url: actor.icon.as_ref().map(|icon| icon.url.clone()),
+        profile_url: actor.url.clone(),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for field in account.fields.iter() {
+        let verified_at = match &field.verified_at {
+            Some(verified_at) => DateTime::parse_from_rfc3339(verified_at)
+                .ok()
+                .map(|dt| dt.naive_utc()),
+            None => continue,
+        };
+        let link = match extract_href(&field.value) {
+            Some(link) => link,
+            None => continue,
+        };
+        let (platform, identity) = match link_to_identity(&link) {
+            Some(result) => result,
+            None => {
+                debug!(link, "Mastodon fetch | Verified link skipped");
+                continue;
+            }
+        };
+
+        let linked_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Mastodon,
+            record_id: Some(link),
+            created_at: verified_at,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::Proof(
+            mastodon_identity.clone(),
+            linked_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+    if fetched.next_targets.is_empty() {
+        info!("Mastodon fetch | {} has no verified link", acct);
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,24 @@
+use crate::{
+    error::Error,
+    upstream::mastodon::{fetch_actor, parse_acct},
+};
+
+#[test]
+fn test_parse_acct() {
+    assert_eq!(
+        parse_acct("@Gargron@mastodon.social"),
+        Some(("Gargron", "mastodon.social"))
+    );
+    assert_eq!(
+        parse_acct("Gargron@mastodon.social"),
+        Some(("Gargron", "mastodon.social"))
+    );
+    assert_eq!(parse_acct("Gargron"), None);
+}
+
+#[tokio::test]
+async fn test_fetch_actor() -> Result<(), Error> {
+    let actor = fetch_actor("Gargron", "mastodon.social").await?;
+    assert_eq!(actor.preferred_username, "Gargron");
+    Ok(())
+}
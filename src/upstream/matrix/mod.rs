@@ -0,0 +1,265 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use tracing::info;
+use uuid::Uuid;
+
+/// https://spec.matrix.org/latest/identity-service-api/#get_matrixidentityv2hash_details
+#[derive(Deserialize, Debug)]
+pub struct HashDetails {
+    pub algorithms: Vec<String>,
+    pub lookup_pepper: String,
+}
+
+/// https://spec.matrix.org/latest/identity-service-api/#post_matrixidentityv2lookup
+#[derive(Deserialize, Debug)]
+pub struct LookupResponse {
+    /// Hashed 3PID => MXID
+    #[serde(default)]
+    pub mappings: HashMap<String, String>,
+}
+
+/// https://spec.matrix.org/latest/client-server-api/#get_matrixclientv3profileuserid
+#[derive(Deserialize, Debug)]
+pub struct Profile {
+    pub displayname: Option<String>,
+    /// `mxc://` URI.
+    pub avatar_url: Option<String>,
+}
+
+pub struct Matrix {}
+
+#[async_trait]
+impl Fetcher for Matrix {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_connections_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        // Lookup API of identity server requires an access token.
+        !C.upstream.matrix.access_token.is_empty()
+            && target.in_platform_supported(vec![Platform::Email, Platform::Phone])
+    }
+}
+
+/// 3PID medium of `platform`.
+fn medium(platform: &Platform) -> Option<&'static str> {
+    match platform {
+        Platform::Email => Some("email"),
+        Platform::Phone => Some("msisdn"),
+        _ => None,
+    }
+}
+
+/// `unpadded_base64url(sha256("{address} {medium} {pepper}"))`
+fn hash_3pid(address: &str, medium: &str, pepper: &str) -> String {
+    let digest = Sha256::digest(format!("{} {} {}", address, medium, pepper).as_bytes());
+    base64::encode_config(digest, base64::URL_SAFE_NO_PAD)
+}
+
+/// Emails are case-insensitive, phone numbers are digits only.
+fn normalize_3pid(platform: &Platform, address: &str) -> String {
+    match platform {
+        Platform::Phone => address.chars().filter(char::is_ascii_digit).collect(),
+        _ => address.trim().to_lowercase(),
+    }
+}
+
+/// `mxc://matrix.org/abc` => `https://{homeserver}/_matrix/media/v3/download/matrix.org/abc`
+fn mxc_to_http(mxc: &str) -> Option<String> {
+    let media = mxc.strip_prefix("mxc://")?;
+    Some(format!(
+        "{}/_matrix/media/v3/download/{}",
+        C.upstream.matrix.homeserver, media
+    ))
+}
+
+async fn request<T: DeserializeOwned>(
+    method: Method,
+    url: String,
+    body: Option<serde_json::Value>,
+    access_token: Option<&str>,
+) -> Result<Option<T>, Error> {
+    let client = make_client();
+    let uri: http::Uri = url
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let mut builder = hyper::Request::builder().method(method).uri(uri);
+    if let Some(access_token) = access_token {
+        builder = builder.header("Authorization", format!("Bearer {}", access_token));
+    }
+    let req = match body {
+        Some(body) => builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .map_err(|_err| Error::ParamError(format!("Matrix Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Matrix fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Matrix fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+/// Find the MXID bound to a 3PID.
+async fn lookup(address: &str, medium: &str) -> Result<Option<String>, Error> {
+    let base = &C.upstream.matrix.identity_server;
+    let access_token = C.upstream.matrix.access_token.as_str();
+    let details: HashDetails = request(
+        Method::GET,
+        format!("{}/_matrix/identity/v2/hash_details", base),
+        None,
+        Some(access_token),
+    )
+    .await?
+    .ok_or(Error::NoResult)?;
+    if !details
+        .algorithms
+        .iter()
+        .any(|algorithm| algorithm == "sha256")
+    {
+        return Err(Error::General(
+            "Matrix fetch error: identity server does not support sha256 lookup".to_string(),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+
+    let hash = hash_3pid(address, medium, &details.lookup_pepper);
+    let resp: Option<LookupResponse> = request(
+        Method::POST,
+        format!("{}/_matrix/identity/v2/lookup", base),
+        Some(json!({
+            "addresses": [hash],
+            "algorithm": "sha256",
+            "pepper": details.lookup_pepper,
+        })),
+        Some(access_token),
+    )
+    .await?;
+    Ok(resp
+        .and_then(|mut resp| resp.mappings.remove(&hash))
+        .map(|mxid| mxid.to_lowercase()))
+}
+
+async fn fetch_profile(mxid: &str) -> Result<Option<Profile>, Error> {
+    request(
+        Method::GET,
+        format!(
+            "{}/_matrix/client/v3/profile/{}",
+            C.upstream.matrix.homeserver, mxid
+        ),
+        None,
+        None,
+    )
+    .await
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let medium = match medium(platform) {
+        Some(medium) => medium,
+        None => return Ok(Fetched::default()),
+    };
+    let address = normalize_3pid(platform, identity);
+    let mxid = match lookup(&address, medium).await? {
+        Some(mxid) => mxid,
+        None => {
+            info!(
+                "Matrix fetch | {} {} is not bound to any MXID",
+                medium, address
+            );
+            return Ok(Fetched::default());
+        }
+    };
+    let profile = fetch_profile(&mxid).await?;
+
+    let three_pid_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: *platform,
+        identity: address,
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let matrix_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Matrix,
+        identity: mxid.clone(),
+        created_at: None,
+        display_name: profile.as_ref().and_then(|p| p.displayname.clone()),
+        added_at: naive_now(),
+        avatar_url: profile
+            .as_ref()
+            .and_then(|p| p.avatar_url.as_deref())
+            .and_then(mxc_to_http),
+        profile_url: Some(format!("https://matrix.to/#/{}", mxid)),
+        updated_at: naive_now(),
+    };
+    // Identity server only binds a 3PID after its owner validated it.
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Matrix,
+        record_id: None,
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+
+    // MXID can not be reverse looked up to other 3PIDs.
+    Ok(Fetched {
+        connections: vec![Connection::TwoWayBinding(
+            three_pid_identity,
+            matrix_identity,
+            proof,
+        )],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,35 @@
+use crate::{
+    error::Error,
+    upstream::{
+        matrix::{fetch_profile, hash_3pid, normalize_3pid},
+        Platform,
+    },
+};
+
+#[test]
+fn test_hash_3pid() {
+    // https://spec.matrix.org/latest/identity-service-api/#client-behaviour
+    assert_eq!(
+        hash_3pid("alice@example.com", "email", "matrixrocks"),
+        "4kenr7N9drpCJ4AfalmlGQVsOn3o2RHjkADUpXJWZUc"
+    );
+}
+
+#[test]
+fn test_normalize_3pid() {
+    assert_eq!(
+        normalize_3pid(&Platform::Email, " Alice@Example.com"),
+        "alice@example.com"
+    );
+    assert_eq!(
+        normalize_3pid(&Platform::Phone, "+1 (234) 567-890"),
+        "1234567890"
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_profile() -> Result<(), Error> {
+    let profile = fetch_profile("@matthew:matrix.org").await?;
+    println!("profile: {:?}", profile);
+    Ok(())
+}
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Participate, Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{naive_now, within_timeout},
+};
+use async_trait::async_trait;
+use gql_client::Client;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct QueryVars {
+    #[serde(rename = "projectAddress")]
+    project_address: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFeedResponse {
+    pub project_feed: Option<Project>,
+}
+
+/// A Mirror publication.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    /// Owner address of this publication.
+    pub address: String,
+    /// ENS name of this publication, if any.
+    pub ens: Option<String>,
+    /// Subdomain label of `{label}.mirror.xyz`, if any.
+    pub ens_label: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(rename = "avatarURL")]
+    pub avatar_url: Option<String>,
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Contributor {
+    pub address: String,
+}
+
+const QUERY: &str = r#"
+    query ProjectFeed($projectAddress: String!) {
+        projectFeed(projectAddress: $projectAddress) {
+            address
+            ens
+            ensLabel
+            displayName
+            avatarURL
+            contributors {
+                address
+            }
+        }
+    }
+"#;
+
+pub struct Mirror {}
+
+#[async_trait]
+impl Fetcher for Mirror {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_publication_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+impl Project {
+    /// Publications are hosted on `{ens_label}.mirror.xyz`, or `mirror.xyz/{address}` without a label.
+    pub fn publication_url(&self) -> String {
+        match &self.ens_label {
+            Some(label) if !label.is_empty() => {
+                format!("https://{}.mirror.xyz", label.to_lowercase())
+            }
+            _ => format!("https://mirror.xyz/{}", self.address.to_lowercase()),
+        }
+    }
+
+    /// Identity of the publication: ENS name if it has one, otherwise its subdomain.
+    pub fn publication_identity(&self) -> Option<String> {
+        self.ens
+            .as_ref()
+            .filter(|ens| !ens.is_empty())
+            .or(self.ens_label.as_ref().filter(|label| !label.is_empty()))
+            .map(|name| name.to_lowercase())
+    }
+}
+
+async fn fetch_project(address: &str) -> Result<Option<Project>, Error> {
+    let client = Client::new(C.upstream.mirror_api.url.clone());
+    let vars = QueryVars {
+        project_address: address.to_string(),
+    };
+    let response = client.query_with_vars::<ProjectFeedResponse, _>(QUERY, vars);
+
+    let response = within_timeout("Mirror", response).await?;
+    Ok(response.and_then(|resp| resp.project_feed))
+}
+
+async fn fetch_publication_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let project = match fetch_project(&address).await? {
+        Some(project) if project.address.to_lowercase() == address => project,
+        _ => {
+            info!("Mirror fetch | {} has no publication", address);
+            return Ok(Fetched::default());
+        }
+    };
+    let publication = match project.publication_identity() {
+        Some(publication) => publication,
+        None => {
+            info!("Mirror fetch | publication of {} has no name", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let owner: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let publication_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Mirror,
+        identity: publication.clone(),
+        created_at: None,
+        display_name: project.display_name.clone(),
+        added_at: naive_now(),
+        avatar_url: project.avatar_url.clone(),
+        profile_url: Some(project.publication_url()),
+        updated_at: naive_now(),
+    };
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Mirror,
+        record_id: None,
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+    let mut fetched = Fetched::default();
+    fetched.connections.push(Connection::TwoWayBinding(
+        owner,
+        publication_identity.clone(),
+        proof,
+    ));
+
+    for contributor in project.contributors.into_iter() {
+        let contributor_address = contributor.address.to_lowercase();
+        if contributor_address == address {
+            continue;
+        }
+        let writer: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: contributor_address.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let participate: Participate = Participate {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Mirror,
+            following: false,
+            votes: 0,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Participate(
+            writer,
+            publication_identity.clone(),
+            participate,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::Ethereum, contributor_address));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,39 @@
+use crate::{
+    error::Error,
+    upstream::mirror::{fetch_project, Project},
+};
+
+#[test]
+fn test_publication_identity() {
+    let mut project = Project {
+        address: "0x934B510D4C9103E6a87AEf13b816fb080286D649".into(),
+        ens: None,
+        ens_label: Some("Dev".into()),
+        display_name: None,
+        avatar_url: None,
+        contributors: vec![],
+    };
+    assert_eq!(project.publication_identity(), Some("dev".into()));
+    assert_eq!(project.publication_url(), "https://dev.mirror.xyz");
+
+    project.ens = Some("dev.mirror.eth".into());
+    assert_eq!(
+        project.publication_identity(),
+        Some("dev.mirror.eth".into())
+    );
+
+    project.ens = None;
+    project.ens_label = None;
+    assert_eq!(project.publication_identity(), None);
+    assert_eq!(
+        project.publication_url(),
+        "https://mirror.xyz/0x934b510d4c9103e6a87aef13b816fb080286d649"
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_project() -> Result<(), Error> {
+    let project = fetch_project("0x934b510d4c9103e6a87aef13b816fb080286d649").await?;
+    println!("project: {:?}", project);
+    Ok(())
+}
@@ -1,16 +1,60 @@
 // Upstreams
 mod aggregation;
+mod aptos_names;
+mod avvy;
+pub mod breaker;
+mod brightid;
+mod civic;
+mod cosmos_names;
+mod crossbell;
+mod cyberconnect;
+mod debank;
+mod discord;
+mod dns;
 mod dotbit;
+mod eas;
 mod ens_reverse;
+mod etherscan;
 mod farcaster;
+mod galxe;
+mod generic;
+mod gitcoin_passport;
+mod github;
+mod hacker_news;
+pub mod import;
 mod keybase;
 mod knn3;
 mod lens;
+mod link3;
+mod mastodon;
+mod matrix;
+mod mirror;
+mod nostr;
+mod openpgp;
+mod opensea;
+mod orcid;
+mod poap;
+mod polygon_id;
 mod proof_client;
+mod proof_of_humanity;
+pub mod provenance;
+mod rarible;
+mod reddit;
 mod rss3;
+mod sbt;
+mod siwe;
+mod snapshot;
+mod sns;
 mod space_id;
 mod sybil_list;
+mod telegram;
+mod twitter;
 mod unstoppable;
+mod vc;
+mod website;
+mod world_id;
+mod yat;
+mod zora;
 
 #[cfg(test)]
 mod tests;
@@ -19,62 +63,132 @@ mod types;
 
 use std::{
     collections::HashSet,
-    sync::{Arc, Mutex},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use crate::{
+    config::C,
     error::Error,
+    graph::db_connection,
+    metrics::{self, UPSTREAM_FETCH_SECONDS},
     upstream::{
-        aggregation::Aggregation, dotbit::DotBit, ens_reverse::ENSReverseLookup,
-        farcaster::Farcaster, keybase::Keybase, knn3::Knn3, lens::Lens, proof_client::ProofClient,
-        rss3::Rss3, space_id::SpaceId, sybil_list::SybilList, the_graph::TheGraph,
-        unstoppable::UnstoppableDomains,
+        aggregation::Aggregation, aptos_names::AptosNames, avvy::Avvy, breaker::Breaker,
+        brightid::BrightID, civic::Civic, cosmos_names::CosmosNames, crossbell::Crossbell,
+        cyberconnect::CyberConnect, debank::DeBank, discord::Discord, dns::DNSRecord,
+        dotbit::DotBit, eas::EAS, ens_reverse::ENSReverseLookup, etherscan::Etherscan,
+        farcaster::Farcaster, galxe::Galxe, generic::Generic, gitcoin_passport::GitcoinPassport,
+        github::Github, hacker_news::HackerNews, keybase::Keybase, knn3::Knn3, lens::Lens,
+        link3::Link3, mastodon::Mastodon, matrix::Matrix, mirror::Mirror, nostr::Nostr,
+        openpgp::OpenPGP, opensea::OpenSea, orcid::Orcid, poap::Poap, proof_client::ProofClient,
+        proof_of_humanity::ProofOfHumanity, provenance::FetchRecord, rarible::Rarible, rss3::Rss3,
+        sbt::SBT, snapshot::Snapshot, sns::SolanaNameService, space_id::SpaceId,
+        sybil_list::SybilList, telegram::Telegram, the_graph::TheGraph, twitter::Twitter,
+        unstoppable::UnstoppableDomains, website::Website, world_id::WorldID, yat::Yat, zora::Zora,
     },
-    util::hashset_append,
+    util::{hashset_append, single_flight::SingleFlight},
 };
 use async_trait::async_trait;
-use futures::{future::join_all, StreamExt};
+use futures::{
+    future::{join_all, BoxFuture},
+    StreamExt,
+};
+use http::StatusCode;
 use tracing::{event, info, warn, Level};
 
-pub(crate) use types::{DataFetcher, DataSource, Platform, Target, TargetProcessedList};
+pub(crate) use polygon_id::PolygonID;
+pub(crate) use siwe::{SelfAttestation, SelfAttestationRequest};
+pub use types::{
+    Connection, Crawled, DataFetcher, DataSource, FetchError, FetchOutcome, Fetched, Platform,
+    Target, TargetProcessedList,
+};
+pub(crate) use vc::VerifiableCredential;
 
 lazy_static! {
-    /// Global processing queue to prevent duplicated query. i.e. multiple same request from frontend.
-    pub static ref FETCHING: Arc<Mutex<HashSet<Target>>> = Arc::new(Mutex::new(HashSet::new()));
+    /// Crawls going on, so the same request from many clients at once crawls only once.
+    static ref CRAWLING: SingleFlight<Target, Result<Crawled, Arc<Error>>> =
+        SingleFlight::default();
+    /// Targets being fetched, so crawls reaching the same target at once fetch it only once.
+    static ref FETCHING: SingleFlight<Target, Result<FetchOutcome, Arc<Error>>> =
+        SingleFlight::default();
 }
 
 /// Fetcher defines how to fetch data from upstream.
 #[async_trait]
 pub trait Fetcher {
     /// Fetch data from given source.
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error>;
+    /// Fetchers should not touch DB: what's found is saved by `import` later.
+    async fn fetch(target: &Target) -> Result<Fetched, Error>;
 
     /// Determine if this upstream can fetch this target.
     fn can_fetch(target: &Target) -> bool;
 }
 
-/// Find all available (platform, identity) in all `Upstream`s.
-#[tracing::instrument(name = "fetch_all", level = "trace")]
-pub async fn fetch_all(initial_target: Target) -> Result<(), Error> {
+/// DomainResolver defines how a domain name system maps names and addresses.
+#[async_trait]
+pub trait DomainResolver {
+    /// 'Regular' resolution: find the address a domain name is resolving to.
+    /// Returns `None` if this name is not registered or not resolving to anything.
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error>;
+
+    /// 'Reverse' resolution: find the primary domain name set by an address.
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error>;
+}
+
+/// Find all available (platform, identity) in all `Upstream`s,
+/// limited by `[crawler]` in config.
+/// Returns what's newly created, and errors of failed upstreams (what's found by others is saved anyway).
+/// Concurrent calls for the same target share one crawl.
+pub async fn fetch_all(initial_target: Target) -> Result<Crawled, Error> {
+    let target = initial_target.clone();
+    CRAWLING
+        .run(initial_target, move || async move {
+            crawl(target, C.crawler.max_depth, C.crawler.budget)
+                .await
+                .map_err(Arc::new)
+        })
+        .await
+        .map_err(unshare)
+}
+
+/// Feed identities found by upstreams back into them, starting from `initial_target`.
+/// Stops after `max_depth` rounds, or `budget` targets fetched in total.
+/// Returns what's newly created and errors of failed upstreams, in all rounds.
+#[tracing::instrument(name = "crawl", level = "trace")]
+pub async fn crawl(
+    initial_target: Target,
+    max_depth: u16,
+    budget: usize,
+) -> Result<Crawled, Error> {
     let mut round: u16 = 0;
     const CONCURRENT: usize = 5;
-    if FETCHING.lock().unwrap().contains(&initial_target) {
-        event!(Level::INFO, ?initial_target, "Fetching. Skipped.");
-        return Ok(());
-    }
-
-    FETCHING.lock().unwrap().insert(initial_target.clone());
     // queues of this session.
-    let mut up_next = HashSet::from([initial_target.clone()]);
+    let mut up_next = vec![initial_target.clone()];
     let mut processed: HashSet<Target> = HashSet::new();
+    let mut crawled = Crawled::default();
 
-    while up_next.len() > 0 {
+    while !up_next.is_empty() {
+        if round >= max_depth {
+            event!(
+                Level::INFO,
+                round,
+                left = up_next.len(),
+                "Max depth reached."
+            );
+            break;
+        }
+        if processed.len() >= budget {
+            event!(
+                Level::INFO,
+                round,
+                left = up_next.len(),
+                "Budget exhausted."
+            );
+            break;
+        }
         round += 1;
-        let futures: Vec<_> = up_next
-            .iter()
-            .filter(|target| !processed.contains(target))
-            .map(|target| fetch_one(target))
-            .collect();
+        let this_round = next_round(up_next, &processed, budget - processed.len());
+        let futures: Vec<_> = this_round.iter().map(|target| fetch_one(target)).collect();
         // Limit concurrent tasks to 5.
         event!(
             Level::DEBUG,
@@ -84,20 +198,23 @@ pub async fn fetch_all(initial_target: Target) -> Result<(), Error> {
         );
         let futures_stream = futures::stream::iter(futures).buffer_unordered(CONCURRENT);
 
-        let mut result: Vec<Target> = futures_stream
-            .collect::<Vec<Result<Vec<Target>, Error>>>()
+        let result: Vec<Target> = futures_stream
+            .collect::<Vec<Result<FetchOutcome, Error>>>()
             .await
             .into_iter()
             .flat_map(|handle_result| -> Vec<Target> {
                 match handle_result {
-                    Ok(result) => {
+                    Ok(outcome) => {
                         event!(
                             Level::DEBUG,
                             round,
-                            fetched_length = result.len(),
+                            fetched_length = outcome.fetched.next_targets.len(),
+                            failed = outcome.errors.len(),
                             "Round completed."
                         );
-                        result
+                        crawled.created += outcome.created;
+                        crawled.errors.extend(outcome.errors);
+                        outcome.fetched.next_targets
                     }
                     Err(err) => {
                         event!(Level::WARN, round, %err, "Error happened in fetching task");
@@ -106,62 +223,349 @@ pub async fn fetch_all(initial_target: Target) -> Result<(), Error> {
                 }
             })
             .collect();
-        result.dedup();
 
-        // Add previous up_next into processed, and replace with new up_next
-        hashset_append(&mut processed, up_next.into_iter().collect());
-        up_next = HashSet::from_iter(result.into_iter());
+        // Add this round into processed, and replace up_next with targets found.
+        hashset_append(&mut processed, this_round);
+        up_next = result;
     }
 
-    FETCHING.lock().unwrap().remove(&initial_target);
     event!(
         Level::INFO,
         round,
         processed = processed.len(),
+        failed = crawled.errors.len(),
+        identities_created = crawled.created.identities,
+        edges_created = crawled.created.edges,
         "Fetch completed."
     );
-    Ok(())
+    Ok(crawled)
+}
+
+/// Targets to fetch in next round: not processed before (which breaks cycles),
+/// deduplicated, and at most `remaining` of them.
+fn next_round(up_next: Vec<Target>, processed: &HashSet<Target>, remaining: usize) -> Vec<Target> {
+    let mut seen = HashSet::new();
+    up_next
+        .into_iter()
+        .filter(|target| !processed.contains(target) && seen.insert(target.clone()))
+        .take(remaining)
+        .collect()
 }
 
-/// Find one (platform, identity) pair in all upstreams.
-/// Returns amount of identities just fetched for next iter.
-pub async fn fetch_one(target: &Target) -> Result<Vec<Target>, Error> {
-    let mut up_next: TargetProcessedList = join_all(vec![
-        Aggregation::fetch(target),
-        SybilList::fetch(target),
-        Keybase::fetch(target),
-        ProofClient::fetch(target),
-        Rss3::fetch(target),
-        Knn3::fetch(target),
-        TheGraph::fetch(target),
-        ENSReverseLookup::fetch(target),
-        DotBit::fetch(target),
-        UnstoppableDomains::fetch(target),
-        Farcaster::fetch(target),
-        SpaceId::fetch(target),
-        Lens::fetch(target),
-    ])
-    .await
-    .into_iter()
-    .flat_map(|res| {
-        match res {
-            Ok(up_next_list) => up_next_list,
-            Err(err) => {
-                warn!("Error happened when fetching {}: {}", target, err);
-                vec![] // Don't break the procedure
+/// Find one (platform, identity) pair in all upstreams, and save what's found.
+/// Returns identities just fetched for next iter, and errors of failed upstreams.
+/// Concurrent calls for the same target share one call to each upstream.
+pub async fn fetch_one(target: &Target) -> Result<FetchOutcome, Error> {
+    let owned = target.clone();
+    FETCHING
+        .run(target.clone(), move || async move {
+            let mut outcome = UPSTREAMS.fetch_all(&owned).await;
+            let db = db_connection().await?;
+            outcome.created = import::import(&db, &outcome.fetched.connections).await?;
+            Ok(outcome)
+        })
+        .await
+        .map_err(unshare)
+}
+
+/// Error shared by runs of a `SingleFlight`, as is if not shared actually.
+fn unshare(err: Arc<Error>) -> Error {
+    Arc::try_unwrap(err).unwrap_or_else(|err| Error::General(err.to_string(), err.http_status()))
+}
+
+/// Fetch `target` again using all upstreams (no crawling), and save what's found.
+/// Returns which upstreams succeeded, which left results out and which failed:
+/// what's missing in the result of a truncated or failed one may still be there in upstream.
+pub async fn refetch(target: &Target) -> Result<FetchOutcome, Error> {
+    let mut outcome = UPSTREAMS.fetch_all(target).await;
+    let db = db_connection().await?;
+    outcome.created = import::import(&db, &outcome.fetched.connections).await?;
+    Ok(outcome)
+}
+
+/// Fetch `target` using upstream `F` only, and save what's found.
+pub async fn fetch_and_import<F: Fetcher>(target: &Target) -> Result<TargetProcessedList, Error> {
+    let fetched = F::fetch(target).await?;
+    let db = db_connection().await?;
+    import::import(&db, &fetched.connections).await?;
+
+    Ok(fetched.next_targets)
+}
+
+type FetchFn = for<'a> fn(&'a Target) -> BoxFuture<'a, Result<Fetched, Error>>;
+
+/// A `Fetcher` registered in `UpstreamFactory`.
+pub struct Upstream {
+    /// Name of this upstream, same as its module name.
+    pub name: &'static str,
+    can_fetch: fn(&Target) -> bool,
+    fetch: FetchFn,
+    pub breaker: Breaker,
+}
+
+impl Upstream {
+    fn new<F: Fetcher>(name: &'static str) -> Self {
+        fn fetch<F: Fetcher>(target: &Target) -> BoxFuture<'_, Result<Fetched, Error>> {
+            F::fetch(target)
+        }
+        Self {
+            name,
+            can_fetch: F::can_fetch,
+            fetch: fetch::<F>,
+            breaker: Breaker::default(),
+        }
+    }
+
+    /// Whether this upstream is enabled in `[upstream.enabled]` of config.
+    pub fn is_enabled(&self) -> bool {
+        C.upstream.is_enabled(self.name)
+    }
+
+    pub fn can_fetch(&self, target: &Target) -> bool {
+        (self.can_fetch)(target)
+    }
+
+    /// Fetch `target`, recording this call in `provenance`.
+    pub async fn fetch(&self, target: &Target) -> Result<Fetched, Error> {
+        self.fetch_within(target, None).await
+    }
+
+    /// Same as `fetch`, but gives up after `timeout` if given.
+    /// Skipped if its `breaker` is open.
+    pub async fn fetch_within(
+        &self,
+        target: &Target,
+        timeout: Option<Duration>,
+    ) -> Result<Fetched, Error> {
+        let started = Instant::now();
+        let result = if self.breaker.allow() {
+            let result = match timeout {
+                None => (self.fetch)(target).await,
+                Some(timeout) => tokio::time::timeout(timeout, (self.fetch)(target))
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::General(
+                            format!("Timeout: no result in {:?}.", timeout),
+                            StatusCode::REQUEST_TIMEOUT,
+                        ))
+                    }),
+            };
+            self.breaker.record(self.name, &result);
+            result
+        } else {
+            Err(Error::General(
+                format!("{} is skipped after failing repeatedly.", self.name),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ))
+        };
+        provenance::record(FetchRecord {
+            upstream: self.name,
+            target: target.to_string(),
+            latency_ms: started.elapsed().as_millis() as u64,
+            error: result.as_ref().err().map(|err| err.to_string()),
+        });
+        UPSTREAM_FETCH_SECONDS
+            .with_label_values(&[self.name, metrics::status(&result)])
+            .observe(started.elapsed().as_secs_f64());
+        result
+    }
+}
+
+/// Registry of all upstreams, dispatching a target to every upstream which can fetch it.
+#[derive(Default)]
+pub struct UpstreamFactory {
+    upstreams: Vec<Upstream>,
+}
+
+impl UpstreamFactory {
+    pub fn register<F: Fetcher>(mut self, name: &'static str) -> Self {
+        self.upstreams.push(Upstream::new::<F>(name));
+        self
+    }
+
+    /// All registered upstreams.
+    pub fn upstreams(&self) -> &[Upstream] {
+        &self.upstreams
+    }
+
+    /// Enabled upstreams which can fetch given target.
+    pub fn fetchers_for(&self, target: &Target) -> Vec<&Upstream> {
+        self.upstreams
+            .iter()
+            .filter(|upstream| upstream.is_enabled() && upstream.can_fetch(target))
+            .collect()
+    }
+
+    /// Run every upstream which can fetch given target concurrently, and merge their results.
+    /// It takes as long as the slowest one, but no longer than `[crawler] fetch_timeout`.
+    /// Error of one upstream won't break the others: it's collected into `FetchOutcome::errors`.
+    pub async fn fetch_all(&self, target: &Target) -> FetchOutcome {
+        self.fetch_all_within(target, Duration::from_secs(C.crawler.fetch_timeout))
+            .await
+    }
+
+    /// Same as `fetch_all`, but upstreams still running after `timeout` are reported as failed.
+    pub async fn fetch_all_within(&self, target: &Target, timeout: Duration) -> FetchOutcome {
+        let fetchers = self.fetchers_for(target);
+        let results = join_all(
+            fetchers
+                .iter()
+                .map(|upstream| upstream.fetch_within(target, Some(timeout))),
+        )
+        .await;
+        let mut outcome = FetchOutcome::default();
+        for (upstream, result) in fetchers.into_iter().zip(results.into_iter()) {
+            match result {
+                Ok(result) => {
+                    if result.truncated {
+                        outcome.truncated.push(upstream.name);
+                    }
+                    outcome.fetched.extend(result);
+                    outcome.succeeded.push(upstream.name);
+                }
+                Err(err) => {
+                    warn!(
+                        "Error happened when fetching {} from {}: {}",
+                        target, upstream.name, err
+                    );
+                    outcome.errors.push(FetchError {
+                        upstream: upstream.name.to_string(),
+                        target: target.to_string(),
+                        error: err.to_string(),
+                    });
+                }
             }
         }
-    })
-    .collect();
-    up_next.dedup();
+        let mut seen = HashSet::new();
+        outcome
+            .fetched
+            .next_targets
+            .retain(|t| seen.insert(t.clone()));
 
-    Ok(up_next)
+        outcome
+    }
+}
+
+/// Names of upstreams saving proofs of `source`.
+/// Empty if it's not saved by any upstream (or by `aggregation` only), so whether it's gone can't be told.
+pub fn upstreams_of(source: &DataSource) -> &'static [&'static str] {
+    match source {
+        DataSource::Keybase => &["keybase", "dns", "github", "website"],
+        DataSource::NextID => &["proof_client", "dns", "github", "website"],
+        DataSource::Rss3 => &["rss3", "aggregation"],
+        DataSource::Knn3 => &["knn3"],
+        DataSource::CyberConnect => &["cyberconnect"],
+        DataSource::TheGraph => &["the_graph"],
+        DataSource::Dotbit => &["dotbit"],
+        DataSource::UnstoppableDomains => &["unstoppable"],
+        DataSource::Lens => &["lens"],
+        DataSource::Farcaster => &["farcaster"],
+        DataSource::SpaceId => &["space_id"],
+        DataSource::GitcoinPassport => &["gitcoin_passport"],
+        DataSource::ProofOfHumanity => &["proof_of_humanity"],
+        DataSource::BrightID => &["brightid"],
+        DataSource::Nostr => &["nostr"],
+        DataSource::Mastodon => &["mastodon"],
+        DataSource::Twitter => &["twitter"],
+        DataSource::POAP => &["poap"],
+        DataSource::Snapshot => &["snapshot"],
+        DataSource::OpenSea => &["opensea"],
+        DataSource::SNS => &["sns"],
+        DataSource::Crossbell => &["crossbell"],
+        DataSource::Yat => &["yat"],
+        DataSource::Telegram => &["telegram"],
+        DataSource::Discord => &["discord"],
+        DataSource::OpenPGP => &["openpgp"],
+        DataSource::ORCID => &["orcid"],
+        DataSource::Mirror => &["mirror"],
+        DataSource::Galxe => &["galxe"],
+        DataSource::Link3 => &["link3"],
+        DataSource::WorldID => &["world_id"],
+        DataSource::Civic => &["civic"],
+        DataSource::AptosNames => &["aptos_names"],
+        DataSource::Avvy => &["avvy"],
+        DataSource::ICNS | DataSource::StargazeNames => &["cosmos_names"],
+        DataSource::DeBank => &["debank"],
+        DataSource::Etherscan => &["etherscan"],
+        DataSource::Rarible => &["rarible"],
+        DataSource::Zora => &["zora"],
+        DataSource::Website => &["website"],
+        DataSource::Matrix => &["matrix"],
+        DataSource::HackerNews => &["hacker_news"],
+        DataSource::SBT => &["sbt"],
+        DataSource::EAS => &["eas"],
+        DataSource::Generic => &["generic"],
+        DataSource::SybilList
+        | DataSource::EthLeaderboard
+        | DataSource::RPCServer
+        | DataSource::PolygonID
+        | DataSource::VerifiableCredential
+        | DataSource::SelfAttested
+        | DataSource::Unknown => &[],
+    }
+}
+
+lazy_static! {
+    /// All upstreams, used by `fetch_one`. Register new upstreams here.
+    pub static ref UPSTREAMS: UpstreamFactory = UpstreamFactory::default()
+            .register::<Aggregation>("aggregation")
+            .register::<SybilList>("sybil_list")
+            .register::<Keybase>("keybase")
+            .register::<ProofClient>("proof_client")
+            .register::<Rss3>("rss3")
+            .register::<Knn3>("knn3")
+            .register::<TheGraph>("the_graph")
+            .register::<ENSReverseLookup>("ens_reverse")
+            .register::<DotBit>("dotbit")
+            .register::<UnstoppableDomains>("unstoppable")
+            .register::<Farcaster>("farcaster")
+            .register::<SpaceId>("space_id")
+            .register::<Lens>("lens")
+            .register::<GitcoinPassport>("gitcoin_passport")
+            .register::<ProofOfHumanity>("proof_of_humanity")
+            .register::<BrightID>("brightid")
+            .register::<Nostr>("nostr")
+            .register::<Mastodon>("mastodon")
+            .register::<Twitter>("twitter")
+            .register::<Github>("github")
+            .register::<DNSRecord>("dns")
+            .register::<Poap>("poap")
+            .register::<Snapshot>("snapshot")
+            .register::<OpenSea>("opensea")
+            .register::<SolanaNameService>("sns")
+            .register::<Crossbell>("crossbell")
+            .register::<CyberConnect>("cyberconnect")
+            .register::<Yat>("yat")
+            .register::<Telegram>("telegram")
+            .register::<Discord>("discord")
+            .register::<OpenPGP>("openpgp")
+            .register::<Orcid>("orcid")
+            .register::<Mirror>("mirror")
+            .register::<Galxe>("galxe")
+            .register::<Link3>("link3")
+            .register::<WorldID>("world_id")
+            .register::<Civic>("civic")
+            .register::<AptosNames>("aptos_names")
+            .register::<Avvy>("avvy")
+            .register::<CosmosNames>("cosmos_names")
+            .register::<DeBank>("debank")
+            .register::<Etherscan>("etherscan")
+            .register::<Rarible>("rarible")
+            .register::<Zora>("zora")
+            .register::<Website>("website")
+            .register::<Matrix>("matrix")
+            .register::<HackerNews>("hacker_news")
+            .register::<SBT>("sbt")
+            .register::<EAS>("eas")
+            .register::<Generic>("generic");
 }
 
 /// Prefetch all prefetchable upstreams, e.g. SybilList.
 pub async fn prefetch() -> Result<(), Error> {
-    info!("Prefetching sybil_list ...");
-    sybil_list::prefetch().await?;
+    if C.upstream.is_enabled("sybil_list") {
+        info!("Prefetching sybil_list ...");
+        sybil_list::prefetch().await?;
+    }
     info!("Prefetch completed.");
     Ok(())
 }
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use bech32::{ToBase32, Variant};
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// https://github.com/nostr-protocol/nips/blob/master/05.md
+#[derive(Deserialize, Debug)]
+pub struct NostrJson {
+    /// `name` => hex-encoded public key
+    pub names: HashMap<String, String>,
+}
+
+pub struct Nostr {}
+
+#[async_trait]
+impl Fetcher for Nostr {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(Platform::Nostr, identifier) => {
+                let (name, domain) = identifier.split_once('@').unwrap();
+                fetch_names_by_domain(domain, Some(name)).await
+            }
+            Target::Identity(_, domain) => fetch_names_by_domain(domain, None).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        match target {
+            // npub cannot be looked up without a relay, only `name@domain` is accepted.
+            Target::Identity(Platform::Nostr, identity) => is_nip05_identifier(identity),
+            _ => target.in_platform_supported(vec![Platform::DNS]),
+        }
+    }
+}
+
+/// Judge if given identity is a NIP-05 internet identifier (`name@domain`).
+fn is_nip05_identifier(identity: &str) -> bool {
+    match identity.split_once('@') {
+        Some((name, domain)) => !name.is_empty() && domain.contains('.'),
+        None => false,
+    }
+}
+
+/// Encode a hex-encoded public key into NIP-19 `npub` form.
+fn hex_to_npub(hex_pubkey: &str) -> Result<String, Error> {
+    if hex_pubkey.len() != 64 {
+        return Err(Error::ParamError(format!(
+            "Nostr pubkey length error: {}",
+            hex_pubkey
+        )));
+    }
+    let bytes = hex::decode(hex_pubkey)
+        .map_err(|err| Error::ParamError(format!("Nostr pubkey format error: {}", err)))?;
+    bech32::encode("npub", bytes.to_base32(), Variant::Bech32)
+        .map_err(|err| Error::ParamError(format!("Nostr npub encode error: {}", err)))
+}
+
+async fn fetch_nostr_json(domain: &str, name: Option<&str>) -> Result<NostrJson, Error> {
+    let client = make_client();
+    let uri: http::Uri = match name {
+        Some(name) => format!("https://{}/.well-known/nostr.json?name={}", domain, name),
+        None => format!("https://{}/.well-known/nostr.json", domain),
+    }
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Nostr Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Nostr fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Ok(NostrJson {
+            names: HashMap::new(),
+        });
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Nostr Get error: {} {}", domain, resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_names_by_domain(domain: &str, name: Option<&str>) -> Result<Fetched, Error> {
+    let domain = domain.to_lowercase();
+    let nostr_json = fetch_nostr_json(&domain, name).await?;
+    if nostr_json.names.is_empty() {
+        info!("Nostr fetch | {} has no NIP-05 name", domain);
+        return Ok(Fetched::default());
+    }
+
+    let dns_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::DNS,
+        identity: domain.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for (nostr_name, hex_pubkey) in nostr_json.names.iter() {
+        // Server may return every name it knows, ignore the others.
+        if name.map_or(false, |name| !name.eq_ignore_ascii_case(nostr_name)) {
+            continue;
+        }
+        let npub = match hex_to_npub(&hex_pubkey.to_lowercase()) {
+            Ok(npub) => npub,
+            Err(err) => {
+                warn!("Nostr fetch | {}@{}: {}", nostr_name, domain, err);
+                continue;
+            }
+        };
+        let identifier = format!("{}@{}", nostr_name.to_lowercase(), domain);
+        let nostr_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Nostr,
+            identity: npub,
+            created_at: None,
+            display_name: Some(identifier.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Nostr,
+            record_id: Some(identifier),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            dns_identity.clone(),
+            nostr_identity,
+            proof,
+        ));
+    }
+
+    if name.is_some() {
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::DNS, domain));
+    }
+    Ok(fetched)
+}
@@ -0,0 +1,32 @@
+use crate::{
+    error::Error,
+    upstream::nostr::{fetch_nostr_json, hex_to_npub, is_nip05_identifier},
+};
+
+#[test]
+fn test_hex_to_npub() {
+    assert_eq!(
+        hex_to_npub("3bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d").unwrap(),
+        "npub180cvv07tjdrrgpa0j7j7tmnyl2yr6yr7l8j4s3evf6u64th6gkwsyjh6w6"
+    );
+    assert!(hex_to_npub("3bf0c63f").is_err());
+    // 64 bytes, but not 64 hex digits.
+    assert!(hex_to_npub(&format!("{}é", "0".repeat(62))).is_err());
+}
+
+#[test]
+fn test_is_nip05_identifier() {
+    assert!(is_nip05_identifier("bob@example.com"));
+    assert!(is_nip05_identifier("_@example.com"));
+    assert!(!is_nip05_identifier(
+        "npub180cvv07tjdrrgpa0j7j7tmnyl2yr6yr7l8j4s3evf6u64th6gkwsyjh6w6"
+    ));
+    assert!(!is_nip05_identifier("@example.com"));
+}
+
+#[tokio::test]
+async fn test_fetch_nostr_json() -> Result<(), Error> {
+    let result = fetch_nostr_json("nostr.com", None).await?;
+    println!("names: {:?}", result.names);
+    Ok(())
+}
@@ -0,0 +1,261 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::Hold,
+        vertex::{CryptoKey, Identity, KeyAlgorithm},
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use http::uri::InvalidUri;
+use hyper::{body::HttpBody as _, Body, Method};
+use sha1::{Digest, Sha1};
+use tracing::info;
+use uuid::Uuid;
+
+/// OpenPGP packet tags. https://www.rfc-editor.org/rfc/rfc4880#section-4.3
+const TAG_PUBLIC_KEY: u8 = 6;
+const TAG_USER_ID: u8 = 13;
+
+/// Primary key info extracted from an OpenPGP certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Certificate {
+    /// V4 fingerprint of the primary key, lowercase hex.
+    pub fingerprint: String,
+    pub created_at: NaiveDateTime,
+    /// All User IDs, e.g. `Alice <alice@example.com>`.
+    pub user_ids: Vec<String>,
+    /// ASCII-armored certificate.
+    pub armored: String,
+}
+
+pub struct OpenPGP {}
+
+#[async_trait]
+impl Fetcher for OpenPGP {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, email) => fetch_key_by_email(email).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Email])
+    }
+}
+
+/// Remove ASCII armor (headers and CRC24 checksum) and decode the base64 body.
+fn dearmor(armored: &str) -> Option<Vec<u8>> {
+    let body: String = armored
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with("-----BEGIN PGP"))
+        .skip(1)
+        // Armor headers end at the first empty line.
+        .skip_while(|line| !line.is_empty())
+        .take_while(|line| !line.starts_with("-----END PGP"))
+        .filter(|line| !line.is_empty() && !line.starts_with('='))
+        .collect();
+    base64::decode(body).ok()
+}
+
+/// Split binary data into `(tag, body)` packets.
+fn parse_packets(mut data: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut packets = vec![];
+    while !data.is_empty() {
+        let header = data[0];
+        if header & 0x80 == 0 {
+            return None;
+        }
+        let (tag, length, header_len) = if header & 0x40 != 0 {
+            // New format
+            let tag = header & 0x3f;
+            match *data.get(1)? {
+                o1 @ 0..=191 => (tag, o1 as usize, 2),
+                o1 @ 192..=223 => (
+                    tag,
+                    ((o1 as usize - 192) << 8) + *data.get(2)? as usize + 192,
+                    3,
+                ),
+                255 => (
+                    tag,
+                    u32::from_be_bytes(data.get(2..6)?.try_into().ok()?) as usize,
+                    6,
+                ),
+                // Partial body length is not used in certificates.
+                _ => return None,
+            }
+        } else {
+            // Old format
+            let tag = (header >> 2) & 0x0f;
+            match header & 0x03 {
+                0 => (tag, *data.get(1)? as usize, 2),
+                1 => (
+                    tag,
+                    u16::from_be_bytes(data.get(1..3)?.try_into().ok()?) as usize,
+                    3,
+                ),
+                2 => (
+                    tag,
+                    u32::from_be_bytes(data.get(1..5)?.try_into().ok()?) as usize,
+                    5,
+                ),
+                _ => return None,
+            }
+        };
+        let body = data.get(header_len..header_len + length)?;
+        packets.push((tag, body));
+        data = &data[header_len + length..];
+    }
+    Some(packets)
+}
+
+/// Parse the primary key and User IDs of an ASCII-armored certificate.
+/// Only V4 keys are supported.
+pub fn parse_certificate(armored: &str) -> Option<Certificate> {
+    let data = dearmor(armored)?;
+    let packets = parse_packets(&data)?;
+    let (_, key) = packets.iter().find(|(tag, _)| *tag == TAG_PUBLIC_KEY)?;
+    if key.first() != Some(&4) {
+        return None;
+    }
+    let created_at = u32::from_be_bytes(key.get(1..5)?.try_into().ok()?);
+
+    // V4 fingerprint: SHA-1 of 0x99, 2-octet body length, and the key packet body.
+    let mut hasher = Sha1::new();
+    hasher.update([0x99]);
+    hasher.update((key.len() as u16).to_be_bytes());
+    hasher.update(key);
+    let fingerprint = hex::encode(hasher.finalize());
+
+    let user_ids = packets
+        .iter()
+        .filter(|(tag, _)| *tag == TAG_USER_ID)
+        .filter_map(|(_, body)| String::from_utf8(body.to_vec()).ok())
+        .collect();
+
+    Some(Certificate {
+        fingerprint,
+        created_at: timestamp_to_naive(created_at as i64, 0),
+        user_ids,
+        armored: armored.to_string(),
+    })
+}
+
+/// Extract email from a User ID like `Alice <alice@example.com>`.
+pub fn user_id_email(user_id: &str) -> Option<String> {
+    let email = match (user_id.rfind('<'), user_id.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &user_id[start + 1..end],
+        _ => user_id.trim(),
+    };
+    if email.contains('@') && !email.contains(' ') {
+        Some(email.to_lowercase())
+    } else {
+        None
+    }
+}
+
+async fn fetch_certificate(email: &str) -> Result<Option<Certificate>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/vks/v1/by-email/{}",
+        C.upstream.openpgp_keyserver.url, email
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("OpenPGP Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("OpenPGP fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("OpenPGP Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let mut body = vec![];
+    while let Some(chunk) = resp.body_mut().data().await {
+        let chunk = chunk.map_err(|err| {
+            Error::ManualHttpClientError(format!("OpenPGP fetch | error: {:?}", err.to_string()))
+        })?;
+        body.extend_from_slice(&chunk);
+    }
+    Ok(parse_certificate(&String::from_utf8_lossy(&body)))
+}
+
+async fn fetch_key_by_email(email: &str) -> Result<Fetched, Error> {
+    let certificate = match fetch_certificate(&email.to_lowercase()).await? {
+        Some(certificate) => certificate,
+        None => {
+            info!("OpenPGP fetch | no key for {}", email);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let crypto_key: CryptoKey = CryptoKey {
+        uuid: Uuid::new_v4(),
+        algorithm: KeyAlgorithm::PGP,
+        fingerprint: certificate.fingerprint.clone(),
+        public_key: Some(certificate.armored.clone()),
+        chain: None,
+        created_at: Some(certificate.created_at),
+        updated_at: naive_now(),
+    };
+    let mut fetched = Fetched::default();
+    // keys.openpgp.org only publishes User IDs whose email address has been verified.
+    for (user_id, email) in certificate
+        .user_ids
+        .iter()
+        .filter_map(|user_id| user_id_email(user_id).map(|email| (user_id, email)))
+    {
+        let email_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Email,
+            identity: email,
+            created_at: None,
+            display_name: Some(user_id.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let hold: Hold = Hold {
+            uuid: Uuid::new_v4(),
+            source: DataSource::OpenPGP,
+            transaction: None,
+            id: certificate.fingerprint.clone(),
+            created_at: Some(certificate.created_at),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::CryptoKeyHold(
+            email_identity,
+            crypto_key.clone(),
+            hold,
+        ));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,48 @@
+use crate::{
+    error::Error,
+    upstream::openpgp::{fetch_certificate, parse_certificate, user_id_email},
+};
+
+/// Build a certificate with a (fake) V4 key packet and one User ID packet.
+fn armored_certificate(user_id: &str) -> String {
+    let key_body: Vec<u8> = vec![4, 0x5f, 0x5e, 0x10, 0x00, 22, 0, 1, 0];
+    let mut data = vec![0xc6, key_body.len() as u8];
+    data.extend(&key_body);
+    // Old format User ID packet
+    data.extend([0xb4, user_id.len() as u8]);
+    data.extend(user_id.as_bytes());
+    format!(
+        "-----BEGIN PGP PUBLIC KEY BLOCK-----\nComment: test\n\n{}\n=AAAA\n-----END PGP PUBLIC KEY BLOCK-----\n",
+        base64::encode(data)
+    )
+}
+
+#[test]
+fn test_parse_certificate() {
+    let certificate = parse_certificate(&armored_certificate("Alice <Alice@Example.com>")).unwrap();
+    assert_eq!(certificate.user_ids, vec!["Alice <Alice@Example.com>"]);
+    assert_eq!(certificate.fingerprint.len(), 40);
+    assert_eq!(certificate.created_at.timestamp(), 0x5f5e1000);
+
+    assert_eq!(parse_certificate("not a certificate"), None);
+}
+
+#[test]
+fn test_user_id_email() {
+    assert_eq!(
+        user_id_email("Alice <Alice@Example.com>"),
+        Some("alice@example.com".into())
+    );
+    assert_eq!(
+        user_id_email("alice@example.com"),
+        Some("alice@example.com".into())
+    );
+    assert_eq!(user_id_email("Alice"), None);
+}
+
+#[tokio::test]
+async fn test_fetch_certificate() -> Result<(), Error> {
+    let certificate = fetch_certificate("info@keys.openpgp.org").await?;
+    println!("certificate: {:?}", certificate);
+    Ok(())
+}
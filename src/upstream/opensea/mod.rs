@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// https://docs.opensea.io/reference/get_account
+#[derive(Deserialize, Debug)]
+pub struct Account {
+    pub address: String,
+    pub username: Option<String>,
+    pub profile_image_url: Option<String>,
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub social_media_accounts: Vec<SocialMediaAccount>,
+    /// `2021-09-29`
+    pub joined_date: Option<String>,
+}
+
+/// Social account verified by OpenSea.
+#[derive(Deserialize, Debug)]
+pub struct SocialMediaAccount {
+    /// `twitter` / `instagram`
+    pub platform: String,
+    pub username: String,
+}
+
+pub struct OpenSea {}
+
+#[async_trait]
+impl Fetcher for OpenSea {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_account_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+fn social_platform(platform: &str) -> Option<Platform> {
+    match platform {
+        "twitter" => Some(Platform::Twitter),
+        "instagram" => Some(Platform::Instagram),
+        _ => None,
+    }
+}
+
+async fn fetch_account(address: &str) -> Result<Option<Account>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/api/v2/accounts/{}", C.upstream.opensea_api.url, address)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("X-API-KEY", C.upstream.opensea_api.token.clone())
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("OpenSea Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("OpenSea fetch | error: {:?}", err.to_string()))
+    })?;
+
+    // Address never connected to OpenSea.
+    if resp.status() == StatusCode::NOT_FOUND || resp.status() == StatusCode::BAD_REQUEST {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("OpenSea Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+async fn fetch_account_by_address(address: &str) -> Result<Fetched, Error> {
+    let account = match fetch_account(address).await? {
+        Some(account) => account,
+        None => {
+            info!("OpenSea fetch | {} has no OpenSea account", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: account.address.to_lowercase(),
+        created_at: account
+            .joined_date
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .and_then(|date| date.and_hms_opt(0, 0, 0)),
+        display_name: account.username.clone().filter(|name| !name.is_empty()),
+        added_at: naive_now(),
+        avatar_url: account.profile_image_url.clone(),
+        profile_url: account
+            .username
+            .as_ref()
+            .map(|name| format!("https://opensea.io/{}", name)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for social in account.social_media_accounts.iter() {
+        let platform = match social_platform(&social.platform) {
+            Some(platform) => platform,
+            None => {
+                debug!(
+                    platform = social.platform,
+                    "OpenSea fetch | Social account skipped"
+                );
+                continue;
+            }
+        };
+        let username = social.username.trim_start_matches('@').to_lowercase();
+        let social_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: username.clone(),
+            created_at: None,
+            display_name: Some(social.username.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::OpenSea,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::Proof(
+            eth_identity.clone(),
+            social_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, username));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,9 @@
+use crate::{error::Error, upstream::opensea::fetch_account};
+
+#[tokio::test]
+async fn test_fetch_account() -> Result<(), Error> {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let account = fetch_account(address).await?;
+    println!("account: {:?}", account);
+    Ok(())
+}
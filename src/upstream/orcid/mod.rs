@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout, scrape::link_to_identity},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::debug;
+use uuid::Uuid;
+
+/// https://info.orcid.org/documentation/api-tutorials/api-tutorial-read-data-on-a-record/
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct PersonResponse {
+    pub name: Option<Name>,
+    pub researcher_urls: Option<ResearcherUrls>,
+    pub external_identifiers: Option<ExternalIdentifiers>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct Name {
+    pub given_names: Option<Value>,
+    pub family_name: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Value {
+    pub value: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ResearcherUrls {
+    #[serde(default)]
+    pub researcher_url: Vec<ResearcherUrl>,
+}
+
+/// Links added by the researcher.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ResearcherUrl {
+    pub put_code: i64,
+    pub url: Option<Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ExternalIdentifiers {
+    #[serde(default)]
+    pub external_identifier: Vec<ExternalIdentifier>,
+}
+
+/// Identifiers added by ORCID member organizations after the researcher
+/// authorized them, so they are verified by the issuing organization.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct ExternalIdentifier {
+    pub put_code: i64,
+    pub external_id_type: String,
+    pub external_id_value: String,
+    pub external_id_url: Option<Value>,
+}
+
+pub struct Orcid {}
+
+#[async_trait]
+impl Fetcher for Orcid {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, orcid) => fetch_person_by_orcid(orcid).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        match target {
+            Target::Identity(Platform::ORCID, orcid) => is_valid_orcid(orcid),
+            _ => false,
+        }
+    }
+}
+
+/// Validate format and ISO 7064 MOD 11-2 check digit of an ORCID iD
+/// like `0000-0002-1825-0097`.
+pub fn is_valid_orcid(orcid: &str) -> bool {
+    let digits: Vec<char> = orcid.chars().filter(|c| *c != '-').collect();
+    if orcid.len() != 19 || digits.len() != 16 {
+        return false;
+    }
+    let mut total: u32 = 0;
+    for c in digits[..15].iter() {
+        match c.to_digit(10) {
+            Some(digit) => total = (total + digit) * 2,
+            None => return false,
+        }
+    }
+    let check = (12 - total % 11) % 11;
+    let expected = if check == 10 {
+        'X'
+    } else {
+        char::from_digit(check, 10).unwrap()
+    };
+    digits[15].to_ascii_uppercase() == expected
+}
+
+async fn fetch_person(orcid: &str) -> Result<PersonResponse, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/v3.0/{}/person", C.upstream.orcid_api.url, orcid)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("Accept", "application/json")
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("ORCID Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("ORCID fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("ORCID Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_person_by_orcid(orcid: &str) -> Result<Fetched, Error> {
+    let orcid = orcid.to_uppercase();
+    let person = fetch_person(&orcid).await?;
+    let display_name = person.name.as_ref().map(|name| {
+        [&name.given_names, &name.family_name]
+            .iter()
+            .filter_map(|value| value.as_ref().map(|value| value.value.clone()))
+            .collect::<Vec<String>>()
+            .join(" ")
+    });
+
+    // `(put_code, url, level)` of every link.
+    let links: Vec<(i64, String, ProofLevel)> = person
+        .external_identifiers
+        .map(|ids| ids.external_identifier)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|id| {
+            id.external_id_url
+                .map(|url| (id.put_code, url.value, ProofLevel::PlatformVerified))
+        })
+        .chain(
+            person
+                .researcher_urls
+                .map(|urls| urls.researcher_url)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|url| {
+                    url.url
+                        .map(|value| (url.put_code, value.value, ProofLevel::SelfClaimed))
+                }),
+        )
+        .collect();
+
+    let orcid_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::ORCID,
+        identity: orcid.clone(),
+        created_at: None,
+        display_name: display_name.filter(|name| !name.is_empty()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://orcid.org/{}", orcid)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for (put_code, link, level) in links.into_iter() {
+        let (platform, identity) = match link_to_identity(&link) {
+            Some(found) => found,
+            None => {
+                debug!("ORCID fetch | unsupported link: {}", link);
+                continue;
+            }
+        };
+        let to: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::ORCID,
+            record_id: Some(put_code.to_string()),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level,
+        };
+        fetched
+            .connections
+            .push(Connection::Proof(orcid_identity.clone(), to, proof));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+
+    Ok(fetched)
+}
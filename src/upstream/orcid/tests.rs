@@ -0,0 +1,20 @@
+use crate::{
+    error::Error,
+    upstream::orcid::{fetch_person, is_valid_orcid},
+};
+
+#[test]
+fn test_is_valid_orcid() {
+    assert!(is_valid_orcid("0000-0002-1825-0097"));
+    assert!(is_valid_orcid("0000-0002-1694-233X"));
+    assert!(!is_valid_orcid("0000-0002-1825-0098"));
+    assert!(!is_valid_orcid("0000000218250097"));
+    assert!(!is_valid_orcid("suji_yan"));
+}
+
+#[tokio::test]
+async fn test_fetch_person() -> Result<(), Error> {
+    let person = fetch_person("0000-0002-1825-0097").await?;
+    println!("person: {:?}", person);
+    Ok(())
+}
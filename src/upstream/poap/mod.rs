@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{edge::Hold, vertex::Identity},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime};
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// https://documentation.poap.tech/reference/getactionsscan-5
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+    pub event: Event,
+    pub token_id: String,
+    pub owner: String,
+    /// `gnosis` / `mainnet`
+    pub chain: String,
+    /// `2022-05-04 10:08:39`
+    pub created: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Event {
+    pub id: i64,
+    pub fancy_id: String,
+    pub name: String,
+    pub image_url: Option<String>,
+    pub start_date: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ErrorResponse {
+    pub message: String,
+}
+
+pub struct Poap {}
+
+#[async_trait]
+impl Fetcher for Poap {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_tokens_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_tokens(address: &str) -> Result<Vec<Token>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/actions/scan/{}", C.upstream.poap_api.url, address)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("X-API-Key", C.upstream.poap_api.token.clone())
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("POAP Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("POAP fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        let body: ErrorResponse = parse_body(&mut resp).await?;
+        return Err(Error::General(
+            format!("POAP Get error: {}", body.message),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_tokens_by_address(address: &str) -> Result<Fetched, Error> {
+    let tokens = fetch_tokens(address).await?;
+    if tokens.is_empty() {
+        info!("POAP fetch | address: {} has no POAP", address);
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    for token in tokens.into_iter() {
+        let event_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::POAP,
+            identity: token.event.id.to_string(),
+            created_at: token
+                .event
+                .start_date
+                .as_deref()
+                .and_then(|date| NaiveDate::parse_from_str(date, "%d-%b-%Y").ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0)),
+            display_name: Some(token.event.name.clone()),
+            added_at: naive_now(),
+            avatar_url: token.event.image_url.clone(),
+            profile_url: Some(format!("https://poap.gallery/event/{}", token.event.id)),
+            updated_at: naive_now(),
+        };
+        let hold: Hold = Hold {
+            uuid: Uuid::new_v4(),
+            source: DataSource::POAP,
+            transaction: None,
+            id: token.token_id.clone(),
+            created_at: NaiveDateTime::parse_from_str(&token.created, "%Y-%m-%d %H:%M:%S").ok(),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched
+            .connections
+            .push(Connection::Hold(eth_identity.clone(), event_identity, hold));
+    }
+
+    // Nothing can be fetched further from an event.
+    Ok(fetched)
+}
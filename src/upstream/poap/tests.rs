@@ -0,0 +1,32 @@
+use crate::{
+    error::Error,
+    graph::{new_db_connection, vertex::Identity},
+    upstream::{
+        fetch_and_import,
+        poap::{fetch_tokens, Poap},
+        Platform, Target,
+    },
+};
+
+#[tokio::test]
+async fn test_fetch_tokens() -> Result<(), Error> {
+    let address = "0x934b510d4c9103e6a87aef13b816fb080286d649";
+    let tokens = fetch_tokens(address).await?;
+    println!("tokens: {:?}", tokens);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_smoke_poap() -> Result<(), Error> {
+    let address = "0x934b510d4c9103e6a87aef13b816fb080286d649";
+    let tokens = fetch_tokens(address).await?;
+    fetch_and_import::<Poap>(&Target::Identity(Platform::Ethereum, address.into())).await?;
+
+    let db = new_db_connection().await?;
+    for token in tokens.iter() {
+        Identity::find_by_platform_identity(&db, &Platform::POAP, &token.event.id.to_string())
+            .await?
+            .expect("Record not found");
+    }
+    Ok(())
+}
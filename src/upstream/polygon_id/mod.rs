@@ -0,0 +1,691 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        create_identity_to_identity_record, db_connection,
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{DataFetcher, DataSource, Platform, Target, TargetProcessedList},
+    util::{
+        canonical::canonicalize,
+        eth::{abi_word, eth_call, keccak256},
+        make_pinned_client, naive_now, parse_body, request_with_timeout, resolve_public_url,
+    },
+};
+use babyjubjub_rs::{decompress_signature, verify, Point};
+use chrono::{DateTime, NaiveDateTime};
+use ff::{Field, PrimeField};
+use http::StatusCode;
+use hyper::{Body, Method};
+use num_bigint::{BigInt, Sign};
+use poseidon_rs::{Fr, Poseidon};
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::info;
+use uuid::Uuid;
+
+/// Proof types of iden3 credentials we are able to verify.
+const PROOF_TYPE_BJJ_SIGNATURE: &str = "BJJSignature2021";
+const PROOF_TYPE_SMT: &str = "Iden3SparseMerkleTreeProof";
+
+/// Core claim is made of 4 index slots and 4 value slots.
+const CLAIM_SLOTS: usize = 8;
+const SLOT_BYTES: usize = 32;
+/// iden3 ID is 2 bytes type + 27 bytes genesis state + 2 bytes checksum.
+const ID_BYTES: usize = 31;
+
+/// Where the subject ID locates in a core claim, stored in header flags.
+/// https://docs.iden3.io/protocol/claims-structure/
+const SUBJECT_FLAG_INDEX: u8 = 0b010;
+const SUBJECT_FLAG_VALUE: u8 = 0b011;
+/// Set in header flags if expiration is stored in `v_0`.
+const EXPIRATION_FLAG: u8 = 0b1000;
+
+/// Status of credentials which can be checked with a revocation tree proof of the issuer.
+const STATUS_TYPE_SMT: &str = "SparseMerkleTreeProof";
+/// Of the State contract, reverts if the issuer never published this state.
+const GET_STATE_INFO: &[u8] = b"getStateInfoByIdAndState(uint256,uint256)";
+/// Of the State contract, latest state of the issuer is the 2nd word of the result.
+const GET_LATEST_STATE_INFO: &[u8] = b"getStateInfoById(uint256)";
+
+/// An iden3 credential issued by a Polygon ID issuer, in W3C format.
+/// Its `credentialSubject` states the linkage between the holder and
+/// another identity.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Iden3Credential {
+    pub id: String,
+    pub issuer: String,
+    pub issuance_date: Option<String>,
+    pub expiration_date: Option<String>,
+    pub credential_subject: CredentialSubject,
+    /// Where to check if it's revoked. Credentials without it can't be revoked.
+    pub credential_status: Option<CredentialStatus>,
+    pub proof: Vec<Iden3Proof>,
+}
+
+/// Linkage in `credential_subject` is not signed as is: the core claim holds hashes of it
+/// (see `slot_of`), `platform` in `i_2` and `identity` in `i_3`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CredentialSubject {
+    /// DID of the holder, i.e. `did:polygonid:polygon:main:2qH7...`
+    pub id: String,
+    pub platform: String,
+    pub identity: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+    /// URL of the revocation status, on one of `status_hosts` in config.
+    pub id: String,
+    #[serde(rename = "type")]
+    pub status_type: String,
+    /// Same as the one in `v_0` of the core claim.
+    pub revocation_nonce: u64,
+}
+
+/// Response of `CredentialStatus::id`.
+#[derive(Deserialize, Debug)]
+struct RevocationStatus {
+    /// State of the issuer the proof is made against.
+    issuer: IssuerState,
+    /// Inclusion (or absence) of the revocation nonce in the revocation tree of the issuer.
+    mtp: MerkleTreeProof,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Iden3Proof {
+    #[serde(rename = "type")]
+    pub proof_type: String,
+    pub issuer_data: IssuerData,
+    /// Hex of the claim signed by / included in the tree of the issuer.
+    pub core_claim: String,
+    /// Hex of compressed BabyJubJub signature. `BJJSignature2021` only.
+    pub signature: Option<String>,
+    /// Inclusion of `core_claim` in issuer's claims tree. `Iden3SparseMerkleTreeProof` only.
+    pub mtp: Option<MerkleTreeProof>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerData {
+    pub id: String,
+    pub state: IssuerState,
+    /// Hex of the claim holding the key which signs `core_claim`. `BJJSignature2021` only.
+    pub auth_core_claim: Option<String>,
+    /// Inclusion of `auth_core_claim` in issuer's claims tree. `BJJSignature2021` only.
+    pub mtp: Option<MerkleTreeProof>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerState {
+    /// Hex of the state (little-endian), published to the State contract by the issuer.
+    #[serde(alias = "state")]
+    pub value: String,
+    /// Hex of claims tree root (little-endian).
+    pub claims_tree_root: String,
+    /// Hex of revocation tree root (little-endian).
+    pub revocation_tree_root: String,
+    /// Hex of roots tree root (little-endian).
+    pub root_of_roots: String,
+}
+
+impl IssuerState {
+    /// Claims tree root, once it's checked to be a part of the state.
+    pub fn claims_root(&self) -> Result<Fr, Error> {
+        self.check_roots()?;
+        fr_from_le_hex(&self.claims_tree_root)
+    }
+
+    /// Revocation tree root, once it's checked to be a part of the state.
+    pub fn revocation_root(&self) -> Result<Fr, Error> {
+        self.check_roots()?;
+        fr_from_le_hex(&self.revocation_tree_root)
+    }
+
+    /// `state = Poseidon(claims_tree_root, revocation_tree_root, root_of_roots)`
+    fn check_roots(&self) -> Result<(), Error> {
+        let state = poseidon(vec![
+            fr_from_le_hex(&self.claims_tree_root)?,
+            fr_from_le_hex(&self.revocation_tree_root)?,
+            fr_from_le_hex(&self.root_of_roots)?,
+        ])?;
+        if state != fr_from_le_hex(&self.value)? {
+            return Err(Error::SignatureValidationError(
+                "PolygonID issuer state is not made of its roots".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MerkleTreeProof {
+    pub existence: bool,
+    /// Decimal strings, from root to leaf.
+    pub siblings: Vec<String>,
+    /// Leaf found on the path of an absent key, if any.
+    pub node_aux: Option<NodeAux>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NodeAux {
+    /// Decimal strings.
+    pub key: String,
+    pub value: String,
+}
+
+/// Core claim of iden3, parsed into field elements.
+#[derive(Debug, Clone)]
+pub struct CoreClaim {
+    slots: Vec<[u8; SLOT_BYTES]>,
+}
+
+impl CoreClaim {
+    pub fn from_hex(claim_hex: &str) -> Result<Self, Error> {
+        let bytes = hex::decode(claim_hex)
+            .map_err(|err| Error::ParamError(format!("PolygonID core claim: {}", err)))?;
+        if bytes.len() != CLAIM_SLOTS * SLOT_BYTES {
+            return Err(Error::ParamError(format!(
+                "PolygonID core claim: expect {} bytes, got {}",
+                CLAIM_SLOTS * SLOT_BYTES,
+                bytes.len()
+            )));
+        }
+        let slots = bytes
+            .chunks(SLOT_BYTES)
+            .map(|chunk| chunk.try_into().unwrap())
+            .collect();
+        Ok(Self { slots })
+    }
+
+    fn elements(&self, range: std::ops::Range<usize>) -> Result<Vec<Fr>, Error> {
+        self.slots[range]
+            .iter()
+            .map(|slot| fr_from_le(slot))
+            .collect()
+    }
+
+    /// `Poseidon(i_0, i_1, i_2, i_3)`, which also decides the leaf position in claims tree.
+    pub fn hash_index(&self) -> Result<Fr, Error> {
+        poseidon(self.elements(0..4)?)
+    }
+
+    /// `Poseidon(v_0, v_1, v_2, v_3)`
+    pub fn hash_value(&self) -> Result<Fr, Error> {
+        poseidon(self.elements(4..8)?)
+    }
+
+    /// The message signed by issuer: `Poseidon(hash_index, hash_value)`.
+    pub fn hash(&self) -> Result<Fr, Error> {
+        poseidon(vec![self.hash_index()?, self.hash_value()?])
+    }
+
+    /// Subject ID (if any) stored in this claim.
+    pub fn subject_id(&self) -> Option<[u8; ID_BYTES]> {
+        // Header flags are right after the 16-byte schema hash in `i_0`.
+        let slot = match self.slots[0][16] & 0b111 {
+            SUBJECT_FLAG_INDEX => &self.slots[1],
+            SUBJECT_FLAG_VALUE => &self.slots[5],
+            _ => return None,
+        };
+        Some(slot[..ID_BYTES].try_into().unwrap())
+    }
+
+    /// Whether this claim links `platform` and `identity` (see `CredentialSubject`).
+    pub fn links(&self, platform: &str, identity: &str) -> bool {
+        self.slots[2] == slot_of(platform) && self.slots[3] == slot_of(identity)
+    }
+
+    /// Revocation nonce in `v_0`.
+    pub fn revocation_nonce(&self) -> u64 {
+        u64::from_le_bytes(self.slots[4][..8].try_into().unwrap())
+    }
+
+    /// Expiration (UNIX timestamp in seconds) in `v_0`, if any.
+    pub fn expiration(&self) -> Option<i64> {
+        if self.slots[0][16] & EXPIRATION_FLAG == 0 {
+            return None;
+        }
+        Some(i64::from_le_bytes(self.slots[4][8..16].try_into().unwrap()))
+    }
+
+    /// BabyJubJub public key stored in an auth claim (`i_2`, `i_3`).
+    fn public_key(&self) -> Result<Point, Error> {
+        Ok(Point {
+            x: fr_from_le(&self.slots[2])?,
+            y: fr_from_le(&self.slots[3])?,
+        })
+    }
+}
+
+impl MerkleTreeProof {
+    /// Check `claim` is a leaf of the sparse merkle tree with `root`.
+    /// Same as `VerifyProof` in iden3/go-merkletree-sql.
+    pub fn verify(&self, claim: &CoreClaim, root: &Fr) -> Result<bool, Error> {
+        Ok(self.existence && self.root(&claim.hash_index()?, &claim.hash_value()?)? == *root)
+    }
+
+    /// Check `key` is absent from the sparse merkle tree with `root`.
+    pub fn verify_absence(&self, key: &Fr, root: &Fr) -> Result<bool, Error> {
+        Ok(!self.existence && self.root(key, &Fr::zero())? == *root)
+    }
+
+    /// Root computed from this proof for the leaf `key => value`, or for the absence of `key`.
+    /// Same as `RootFromProof` in iden3/go-merkletree-sql.
+    fn root(&self, key: &Fr, value: &Fr) -> Result<Fr, Error> {
+        let path = fr_to_le(key);
+        let mut node = match (self.existence, &self.node_aux) {
+            (true, _) => poseidon(vec![*key, *value, Fr::one()])?,
+            (false, None) => Fr::zero(),
+            (false, Some(aux)) => {
+                let aux_key = fr_from_decimal(&aux.key)?;
+                if aux_key == *key {
+                    return Err(Error::SignatureValidationError(
+                        "PolygonID absence proof is about an existing key".into(),
+                    ));
+                }
+                poseidon(vec![aux_key, fr_from_decimal(&aux.value)?, Fr::one()])?
+            }
+        };
+        for (level, sibling) in self.siblings.iter().enumerate().rev() {
+            let sibling = fr_from_decimal(sibling)?;
+            let go_right = path[level / 8] & (1 << (level % 8)) != 0;
+            node = if go_right {
+                poseidon(vec![sibling, node])?
+            } else {
+                poseidon(vec![node, sibling])?
+            };
+        }
+        Ok(node == *root)
+    }
+}
+
+impl Iden3Proof {
+    /// Verify `core_claim` is really issued by `issuer_data`.
+    pub fn verify(&self) -> Result<CoreClaim, Error> {
+        let claim = CoreClaim::from_hex(&self.core_claim)?;
+        let root = self.issuer_data.state.claims_root()?;
+        let valid = match self.proof_type.as_str() {
+            PROOF_TYPE_BJJ_SIGNATURE => {
+                let auth_claim = CoreClaim::from_hex(
+                    self.issuer_data
+                        .auth_core_claim
+                        .as_ref()
+                        .ok_or_else(|| Error::ParamMissing("authCoreClaim".into()))?,
+                )?;
+                let auth_mtp = self
+                    .issuer_data
+                    .mtp
+                    .as_ref()
+                    .ok_or_else(|| Error::ParamMissing("issuerData.mtp".into()))?;
+                auth_mtp.verify(&auth_claim, &root)?
+                    && verify_signature(
+                        &auth_claim,
+                        self.signature
+                            .as_ref()
+                            .ok_or_else(|| Error::ParamMissing("signature".into()))?,
+                        &claim.hash()?,
+                    )?
+            }
+            PROOF_TYPE_SMT => self
+                .mtp
+                .as_ref()
+                .ok_or_else(|| Error::ParamMissing("mtp".into()))?
+                .verify(&claim, &root)?,
+            other => {
+                return Err(Error::ParamError(format!(
+                    "PolygonID proof type {} is not supported",
+                    other
+                )))
+            }
+        };
+        if !valid {
+            return Err(Error::SignatureValidationError(format!(
+                "PolygonID {} from {} is invalid",
+                self.proof_type, self.issuer_data.id
+            )));
+        }
+        Ok(claim)
+    }
+}
+
+impl Iden3Credential {
+    /// Verify this credential, which means:
+    /// issuer is trusted, at least one proof of `core_claim` is valid against a state
+    /// the issuer published on chain, the claim links the holder to the identity
+    /// in `credential_subject`, and it's neither expired nor revoked.
+    pub async fn verify(&self) -> Result<(), Error> {
+        if !C.upstream.polygon_id.trusted_issuers.contains(&self.issuer) {
+            return Err(Error::SignatureValidationError(format!(
+                "PolygonID issuer {} is not trusted",
+                self.issuer
+            )));
+        }
+        let claim = self.verified_claim().await?;
+
+        let now = naive_now();
+        let expired = claim
+            .expiration()
+            .is_some_and(|expiration| expiration <= now.timestamp())
+            || parse_date(&self.expiration_date).is_some_and(|expiration| expiration <= now);
+        if expired {
+            return Err(Error::SignatureValidationError(format!(
+                "PolygonID credential {} is expired",
+                self.id
+            )));
+        }
+        if let Some(status) = &self.credential_status {
+            if status.revocation_nonce != claim.revocation_nonce() {
+                return Err(Error::SignatureValidationError(format!(
+                    "PolygonID credential status of {} is not about its claim",
+                    self.id
+                )));
+            }
+            if is_revoked(&did_to_id(&self.issuer)?, status).await? {
+                return Err(Error::SignatureValidationError(format!(
+                    "PolygonID credential {} is revoked",
+                    self.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Core claim of the first valid proof, which is about `credential_subject`.
+    async fn verified_claim(&self) -> Result<CoreClaim, Error> {
+        let subject = &self.credential_subject;
+        let holder_id = did_to_id(&subject.id)?;
+        let issuer_id = did_to_id(&self.issuer)?;
+        let mut last_err = Error::ParamMissing("proof".into());
+        for proof in self.proof.iter() {
+            if proof.issuer_data.id != self.issuer {
+                continue;
+            }
+            let claim = match proof.verify() {
+                Ok(claim) => claim,
+                Err(err) => {
+                    last_err = err;
+                    continue;
+                }
+            };
+            if claim.subject_id() != Some(holder_id)
+                || !claim.links(&subject.platform, &subject.identity)
+            {
+                last_err = Error::SignatureValidationError(format!(
+                    "PolygonID claim is not about {} => {}: {}",
+                    subject.id, subject.platform, subject.identity
+                ));
+                continue;
+            }
+            let state = fr_from_le_hex(&proof.issuer_data.state.value)?;
+            if !is_state_published(&issuer_id, &state).await? {
+                last_err = Error::SignatureValidationError(format!(
+                    "PolygonID state of {} is not published",
+                    self.issuer
+                ));
+                continue;
+            }
+            return Ok(claim);
+        }
+        Err(last_err)
+    }
+}
+
+/// Whether issuer `id` published `state` to the State contract.
+/// States never published (e.g. genesis states) are not accepted.
+async fn is_state_published(id: &[u8; ID_BYTES], state: &Fr) -> Result<bool, Error> {
+    let config = &C.upstream.polygon_id;
+    if config.rpc_url.is_empty() || config.state_contract.is_empty() {
+        return Err(Error::General(
+            "PolygonID State contract is not configured".into(),
+            StatusCode::SERVICE_UNAVAILABLE,
+        ));
+    }
+    // IDs and states are little-endian integers, ABI words are big-endian.
+    let mut id_be = id.to_vec();
+    id_be.reverse();
+    let (_, state_be) = fr_to_bigint(state).to_bytes_be();
+    let mut call_data = keccak256(GET_STATE_INFO)[..4].to_vec();
+    call_data.extend_from_slice(&abi_word(&id_be));
+    call_data.extend_from_slice(&abi_word(&state_be));
+    Ok(
+        eth_call(&config.rpc_url, &config.state_contract, &call_data)
+            .await?
+            .is_some(),
+    )
+}
+
+/// Latest state issuer `id` published to the State contract.
+async fn latest_state(id: &[u8; ID_BYTES]) -> Result<Fr, Error> {
+    let config = &C.upstream.polygon_id;
+    let mut id_be = id.to_vec();
+    id_be.reverse();
+    let mut call_data = keccak256(GET_LATEST_STATE_INFO)[..4].to_vec();
+    call_data.extend_from_slice(&abi_word(&id_be));
+    let result = eth_call(&config.rpc_url, &config.state_contract, &call_data)
+        .await?
+        .filter(|result| result.len() >= 64)
+        .ok_or_else(|| {
+            Error::SignatureValidationError("PolygonID issuer has no state on chain".into())
+        })?;
+    fr_from_decimal(&BigInt::from_bytes_be(Sign::Plus, &result[32..64]).to_string())
+}
+
+/// Whether the credential with `status` is revoked by issuer `id`.
+/// Status is fetched from the issuer, then checked against its latest state on chain:
+/// the nonce must be absent from the revocation tree of that state to be not revoked.
+async fn is_revoked(id: &[u8; ID_BYTES], status: &CredentialStatus) -> Result<bool, Error> {
+    if status.status_type != STATUS_TYPE_SMT {
+        return Err(Error::ParamError(format!(
+            "PolygonID credential status {} is not supported",
+            status.status_type
+        )));
+    }
+    let uri: http::Uri = status
+        .id
+        .parse()
+        .map_err(|err| Error::ParamError(format!("Uri format Error: {}", err)))?;
+    let allowed = uri.scheme_str() == Some("https")
+        && uri.host().is_some_and(|host| {
+            C.upstream
+                .polygon_id
+                .status_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(host))
+        });
+    if !allowed {
+        return Err(Error::ParamError(format!(
+            "PolygonID credential status {} is not on a host of trusted issuers",
+            status.id
+        )));
+    }
+    let addrs = resolve_public_url(&status.id).await.ok_or_else(|| {
+        Error::ParamError(format!(
+            "PolygonID credential status {} is not public",
+            status.id
+        ))
+    })?;
+    let client = make_pinned_client(addrs);
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("PolygonID Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "PolygonID revocation status | error: {:?}",
+            err.to_string()
+        ))
+    })?;
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("PolygonID revocation status error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    let body: RevocationStatus = parse_body(&mut resp).await?;
+    if fr_from_le_hex(&body.issuer.value)? != latest_state(id).await? {
+        return Err(Error::SignatureValidationError(
+            "PolygonID revocation status is not of the latest state of its issuer".into(),
+        ));
+    }
+    let nonce = fr_from_decimal(&status.revocation_nonce.to_string())?;
+    let revocation_root = body.issuer.revocation_root()?;
+    if body.mtp.verify_absence(&nonce, &revocation_root)? {
+        return Ok(false);
+    }
+    // Taken as is: only the holder loses if the issuer claims it's revoked.
+    if body.mtp.existence {
+        return Ok(true);
+    }
+    Err(Error::SignatureValidationError(
+        "PolygonID revocation status has an invalid proof".into(),
+    ))
+}
+
+/// Slot holding a string in a core claim: keccak256 of it, little-endian,
+/// with the top 3 bits cleared to fit in the field.
+pub fn slot_of(value: &str) -> [u8; SLOT_BYTES] {
+    let mut slot = keccak256(value.as_bytes());
+    slot[SLOT_BYTES - 1] &= 0b0001_1111;
+    slot
+}
+
+fn poseidon(inputs: Vec<Fr>) -> Result<Fr, Error> {
+    Poseidon::new().hash(inputs).map_err(|err| {
+        Error::General(
+            format!("Poseidon error: {}", err),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })
+}
+
+fn fr_from_decimal(decimal: &str) -> Result<Fr, Error> {
+    <Fr as PrimeField>::from_str(decimal)
+        .ok_or_else(|| Error::ParamError(format!("{} is not a field element", decimal)))
+}
+
+fn fr_from_le(bytes: &[u8]) -> Result<Fr, Error> {
+    fr_from_decimal(&BigInt::from_bytes_le(Sign::Plus, bytes).to_string())
+}
+
+fn fr_from_le_hex(le_hex: &str) -> Result<Fr, Error> {
+    let bytes =
+        hex::decode(le_hex).map_err(|err| Error::ParamError(format!("PolygonID hash: {}", err)))?;
+    fr_from_le(&bytes)
+}
+
+fn fr_to_bigint(fr: &Fr) -> BigInt {
+    BigInt::parse_bytes(ff::to_hex(fr).as_bytes(), 16).unwrap()
+}
+
+fn fr_to_le(fr: &Fr) -> Vec<u8> {
+    let (_, mut bytes) = fr_to_bigint(fr).to_bytes_le();
+    bytes.resize(SLOT_BYTES, 0);
+    bytes
+}
+
+/// Signature is produced by the key in `auth_claim` over `message`.
+fn verify_signature(
+    auth_claim: &CoreClaim,
+    signature_hex: &str,
+    message: &Fr,
+) -> Result<bool, Error> {
+    let bytes: [u8; 64] = hex::decode(signature_hex)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| Error::ParamError("PolygonID signature should be 64 bytes".into()))?;
+    let signature = decompress_signature(&bytes)
+        .map_err(|err| Error::ParamError(format!("PolygonID signature: {}", err)))?;
+    Ok(verify(
+        auth_claim.public_key()?,
+        signature,
+        fr_to_bigint(message),
+    ))
+}
+
+/// `did:polygonid:polygon:main:2qH7...` => iden3 ID bytes
+pub fn did_to_id(did: &str) -> Result<[u8; ID_BYTES], Error> {
+    let encoded = did
+        .strip_prefix("did:")
+        .and_then(|rest| rest.rsplit(':').next())
+        .ok_or_else(|| Error::ParamError(format!("{} is not a DID", did)))?;
+    bs58::decode(encoded)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| Error::ParamError(format!("{} is not an iden3 DID", did)))
+}
+
+fn parse_date(date: &Option<String>) -> Option<NaiveDateTime> {
+    date.as_deref()
+        .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+        .map(|dt| dt.naive_utc())
+}
+
+/// Polygon ID is push-based: holders submit the credentials themselves,
+/// so it is not a `Fetcher`.
+pub struct PolygonID {}
+
+impl PolygonID {
+    /// Verify a serialized iden3 credential and save the linkage it claims.
+    pub async fn ingest(serialized: &str) -> Result<TargetProcessedList, Error> {
+        let credential: Iden3Credential = serde_json::from_str(serialized)?;
+        credential.verify().await?;
+
+        let subject = &credential.credential_subject;
+        let platform = Platform::from_str(&subject.platform)?;
+        let db = db_connection().await?;
+        let holder: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::PolygonID,
+            identity: subject.id.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let to: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: canonicalize(&platform, &subject.identity)?,
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::PolygonID,
+            record_id: Some(credential.id.clone()),
+            created_at: parse_date(&credential.issuance_date),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: true,
+            expired_at: parse_date(&credential.expiration_date),
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::Cryptographic,
+        };
+        create_identity_to_identity_record(&db, &holder, &to, &proof).await?;
+        info!(
+            "PolygonID ingest | {} => {}: {}",
+            subject.id, subject.platform, subject.identity
+        );
+
+        Ok(vec![Target::Identity(platform, to.identity)])
+    }
+}
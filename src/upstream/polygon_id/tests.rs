@@ -0,0 +1,141 @@
+use crate::upstream::polygon_id::{
+    did_to_id, is_revoked, slot_of, CoreClaim, CredentialStatus, MerkleTreeProof, NodeAux,
+};
+use ff::{Field, PrimeField};
+use poseidon_rs::{Fr, Poseidon};
+
+const HOLDER_ID: [u8; 31] = [
+    0x0d, 0x12, 0x2c, 0x97, 0x2e, 0x6a, 0x35, 0x60, 0x7b, 0x3a, 0xa5, 0x0f, 0x1d, 0x88, 0x19, 0x4a,
+    0xc3, 0x30, 0x67, 0x3b, 0x6f, 0xbd, 0x4b, 0x2f, 0x81, 0x1e, 0x22, 0x5c, 0x3d, 0x11, 0x02,
+];
+
+/// Claim with subject ID in index slot `i_1`.
+fn claim_about(id: &[u8; 31]) -> CoreClaim {
+    let mut bytes = vec![0u8; 8 * 32];
+    bytes[16] = 0b010;
+    bytes[32..32 + 31].copy_from_slice(id);
+    CoreClaim::from_hex(&hex::encode(bytes)).unwrap()
+}
+
+#[test]
+fn test_core_claim_from_hex() {
+    assert!(CoreClaim::from_hex("00").is_err());
+    assert!(CoreClaim::from_hex("not hex").is_err());
+    assert!(CoreClaim::from_hex(&"00".repeat(8 * 32)).is_ok());
+}
+
+#[test]
+fn test_subject_id() {
+    assert_eq!(claim_about(&HOLDER_ID).subject_id(), Some(HOLDER_ID));
+    // Self claim has no subject.
+    let self_claim = CoreClaim::from_hex(&"00".repeat(8 * 32)).unwrap();
+    assert_eq!(self_claim.subject_id(), None);
+}
+
+#[test]
+fn test_claim_data() {
+    let mut bytes = vec![0u8; 8 * 32];
+    // Subject in `i_1`, with expiration.
+    bytes[16] = 0b1010;
+    bytes[32..32 + 31].copy_from_slice(&HOLDER_ID);
+    bytes[64..96].copy_from_slice(&slot_of("twitter"));
+    bytes[96..128].copy_from_slice(&slot_of("suji_yan"));
+    bytes[128..136].copy_from_slice(&42u64.to_le_bytes());
+    bytes[136..144].copy_from_slice(&1700000000i64.to_le_bytes());
+    let claim = CoreClaim::from_hex(&hex::encode(bytes)).unwrap();
+
+    assert_eq!(claim.subject_id(), Some(HOLDER_ID));
+    assert!(claim.links("twitter", "suji_yan"));
+    assert!(!claim.links("twitter", "someone_else"));
+    assert!(!claim.links("github", "suji_yan"));
+    assert_eq!(claim.revocation_nonce(), 42);
+    assert_eq!(claim.expiration(), Some(1700000000));
+    assert_eq!(claim_about(&HOLDER_ID).expiration(), None);
+}
+
+#[test]
+fn test_did_to_id() {
+    let did = format!(
+        "did:polygonid:polygon:main:{}",
+        bs58::encode(HOLDER_ID).into_string()
+    );
+    assert_eq!(did_to_id(&did).unwrap(), HOLDER_ID);
+    assert!(did_to_id("did:polygonid:polygon:main:abc").is_err());
+    assert!(did_to_id("0x934b510d4c9103e6a87aef13b816fb080286d649").is_err());
+}
+
+#[test]
+fn test_merkle_tree_proof_verify() {
+    let claim = claim_about(&HOLDER_ID);
+    let leaf = Poseidon::new()
+        .hash(vec![
+            claim.hash_index().unwrap(),
+            claim.hash_value().unwrap(),
+            Fr::one(),
+        ])
+        .unwrap();
+
+    // Tree with a single leaf: root is the leaf itself.
+    let proof = MerkleTreeProof {
+        existence: true,
+        siblings: vec![],
+        node_aux: None,
+    };
+    assert!(proof.verify(&claim, &leaf).unwrap());
+
+    let other = claim_about(&[1u8; 31]);
+    assert!(!proof.verify(&other, &leaf).unwrap());
+
+    let non_existence = MerkleTreeProof {
+        existence: false,
+        siblings: vec![],
+        node_aux: None,
+    };
+    assert!(!non_existence.verify(&claim, &leaf).unwrap());
+}
+
+#[test]
+fn test_merkle_tree_proof_verify_absence() {
+    let nonce = Fr::from_str("42").unwrap();
+    // Empty tree.
+    let empty = MerkleTreeProof {
+        existence: false,
+        siblings: vec![],
+        node_aux: None,
+    };
+    assert!(empty.verify_absence(&nonce, &Fr::zero()).unwrap());
+
+    // Tree with a single leaf of another nonce.
+    let other = Fr::from_str("7").unwrap();
+    let leaf = Poseidon::new()
+        .hash(vec![other, Fr::zero(), Fr::one()])
+        .unwrap();
+    let absence = MerkleTreeProof {
+        existence: false,
+        siblings: vec![],
+        node_aux: Some(NodeAux {
+            key: "7".into(),
+            value: "0".into(),
+        }),
+    };
+    assert!(absence.verify_absence(&nonce, &leaf).unwrap());
+    assert!(!absence.verify_absence(&nonce, &Fr::zero()).unwrap());
+    // The nonce in `node_aux` itself is not absent.
+    assert!(absence.verify_absence(&other, &leaf).is_err());
+    assert!(!empty.verify_absence(&nonce, &leaf).unwrap());
+}
+
+#[tokio::test]
+async fn test_is_revoked_of_untrusted_host() {
+    for url in [
+        "https://attacker.example.com/status/42",
+        "http://169.254.169.254/latest/meta-data",
+    ] {
+        let status = CredentialStatus {
+            id: url.into(),
+            status_type: "SparseMerkleTreeProof".into(),
+            revocation_nonce: 42,
+        };
+        assert!(is_revoked(&[0u8; 31], &status).await.is_err(), "{}", url);
+    }
+}
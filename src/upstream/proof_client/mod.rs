@@ -4,9 +4,11 @@ mod tests;
 
 use crate::config::C;
 use crate::error::Error;
-use crate::graph::{edge::Proof, new_db_connection, vertex::Identity};
-use crate::graph::{Edge, Vertex};
-use crate::upstream::{DataSource, Fetcher, Platform, Target, TargetProcessedList};
+use crate::graph::{
+    edge::{Proof, ProofLevel},
+    vertex::Identity,
+};
+use crate::upstream::{Connection, DataSource, Fetched, Fetcher, Platform, Target};
 use crate::util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive};
 
 use async_trait::async_trait;
@@ -39,6 +41,9 @@ pub struct ProofRecord {
     pub last_checked_at: String,
     pub is_valid: bool,
     pub invalid_reason: String,
+    /// Where this proof is published on the target platform
+    /// (e.g. tweet ID, Gist ID). Not provided for every platform.
+    pub proof_location: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -58,16 +63,16 @@ pub struct ProofClient {}
 
 #[async_trait]
 impl Fetcher for ProofClient {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
@@ -78,15 +83,16 @@ impl Fetcher for ProofClient {
             Platform::NextID,
             Platform::Github,
             Platform::Dotbit,
+            Platform::Telegram,
         ])
     }
 }
 
-#[tracing::instrument(level = "trace", fields(platform = %platform, identity = %identity))]
-async fn fetch_connections_by_platform_identity(
+/// Personas with a proof of `identity` on `platform` (`nextid` for a persona itself).
+pub(crate) async fn query(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<ProofQueryResponse, Error> {
     let client = make_client();
 
     let uri: http::Uri = format!(
@@ -118,7 +124,15 @@ async fn fetch_connections_by_platform_identity(
         ));
     }
 
-    let query_result: ProofQueryResponse = parse_body(&mut resp).await?;
+    parse_body(&mut resp).await
+}
+
+#[tracing::instrument(level = "trace", fields(platform = %platform, identity = %identity))]
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let query_result = query(platform, identity).await?;
     if query_result.pagination.total == 0 {
         return Err(Error::NoResult);
     }
@@ -128,9 +142,8 @@ async fn fetch_connections_by_platform_identity(
         return Err(Error::NoResult);
     }
 
-    let mut next_targets: TargetProcessedList = vec![];
+    let mut fetched = Fetched::default();
     // let next_id_identity = proofs.avatar;
-    let db = new_db_connection().await?;
     for id in query_result.ids {
         let ProofPersona { avatar, proofs } = id;
 
@@ -153,7 +166,6 @@ async fn fetch_connections_by_platform_identity(
                 updated_at: naive_now(),
             };
 
-            let from_record = from.create_or_update(&db).await?;
             let to_platform = Platform::from_str(p.platform.as_str()).unwrap_or(Platform::Unknown);
             if to_platform == Platform::Unknown {
                 event!(
@@ -185,25 +197,37 @@ async fn fetch_connections_by_platform_identity(
                 profile_url: None,
                 updated_at: naive_now(),
             };
-            let to_record = to.create_or_update(&db).await?;
 
-            next_targets.push(Target::Identity(to_platform, p.identity));
+            fetched
+                .next_targets
+                .push(Target::Identity(to_platform, p.identity));
 
             let pf: Proof = Proof {
                 uuid: Uuid::new_v4(),
                 source: DataSource::NextID,
-                record_id: None,
+                record_id: p.proof_location.clone(),
                 created_at: Some(timestamp_to_naive(
                     p.created_at.to_string().parse().unwrap(),
                     0,
                 )),
                 updated_at: naive_now(),
                 fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::PlatformVerified,
             };
-            pf.two_way_binding(&db, &from_record, &to_record).await?;
+            fetched
+                .connections
+                .push(Connection::TwoWayBinding(from, to, pf));
         }
     }
-    next_targets.dedup();
-    event!(Level::TRACE, "Next target count: {:?}", next_targets.len());
-    Ok(next_targets)
+    fetched.next_targets.dedup();
+    event!(
+        Level::TRACE,
+        "Next target count: {:?}",
+        fetched.next_targets.len()
+    );
+    Ok(fetched)
 }
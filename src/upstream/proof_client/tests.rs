@@ -1,5 +1,5 @@
 use crate::upstream::Target;
-use crate::{error::Error, upstream::proof_client::ProofClient, upstream::Fetcher};
+use crate::{error::Error, upstream::fetch_and_import, upstream::proof_client::ProofClient};
 use crate::{
     graph::new_db_connection, graph::vertex::Identity, upstream::Platform, util::naive_now,
 };
@@ -10,7 +10,7 @@ async fn test_smoke() -> Result<(), Error> {
         Platform::Ethereum,
         "0x2467ee73bb0c5acdeedf4e6cc5aa685741126872".into(),
     );
-    ProofClient::fetch(&target).await?;
+    fetch_and_import::<ProofClient>(&target).await?;
 
     let db = new_db_connection().await?;
     let found = Identity::find_by_platform_identity(&db, &target.platform()?, &target.identity()?)
@@ -28,7 +28,7 @@ async fn test_multiple_avatars() -> Result<(), Error> {
         Platform::Ethereum,
         "0x1cb1fa7d604e06cd8c596b5b7bcaaf5c5fdefd53".into(),
     );
-    ProofClient::fetch(&target).await?;
+    fetch_and_import::<ProofClient>(&target).await?;
     let db = new_db_connection().await?;
     let found = Identity::find_by_platform_identity(&db, &Platform::Twitter, "lyria_shan0127")
         .await?
@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{naive_now, parse_timestamp, within_timeout},
+};
+use async_trait::async_trait;
+use gql_client::Client;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct SubmissionQueryVars {
+    id: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct SubmissionQueryResponse {
+    submission: Option<Submission>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Submission {
+    /// Registered Ethereum address (lowercased).
+    id: String,
+    name: Option<String>,
+    /// If this submission is currently registered.
+    registered: bool,
+    /// Second-based timestamp.
+    creation_time: String,
+    requests: Vec<SubmissionRequest>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SubmissionRequest {
+    evidence: Vec<Evidence>,
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+struct Evidence {
+    /// IPFS URI of the evidence file, e.g. `/ipfs/Qm.../registration.json`
+    URI: String,
+}
+
+pub struct ProofOfHumanity {}
+
+#[async_trait]
+impl Fetcher for ProofOfHumanity {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_submission_by_address(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum, Platform::ProofOfHumanity])
+    }
+}
+
+async fn get_submission(address: &str) -> Result<Option<Submission>, Error> {
+    const QUERY_BY_ID: &str = r#"
+        query SubmissionById($id: ID!) {
+            submission(id: $id) {
+                id
+                name
+                registered
+                creationTime
+                requests(orderBy: creationTime, orderDirection: asc, first: 1) {
+                    evidence(orderBy: creationTime, orderDirection: asc, first: 1) {
+                        URI
+                    }
+                }
+            }
+        }
+    "#;
+    let client = Client::new(&C.upstream.proof_of_humanity.url);
+    let vars = SubmissionQueryVars {
+        id: address.to_lowercase(),
+    };
+    let response = client.query_with_vars::<SubmissionQueryResponse, _>(QUERY_BY_ID, vars);
+
+    let response = within_timeout("ProofOfHumanity", response).await?;
+    Ok(response.and_then(|r| r.submission))
+}
+
+async fn fetch_submission_by_address(platform: &Platform, address: &str) -> Result<Fetched, Error> {
+    let submission = match get_submission(address).await? {
+        Some(submission) => submission,
+        None => {
+            info!("ProofOfHumanity fetch | {} is not submitted", address);
+            return Ok(Fetched::default());
+        }
+    };
+    if !submission.registered {
+        info!("ProofOfHumanity fetch | {} is not registered", address);
+        return Ok(Fetched::default());
+    }
+
+    let created_at = parse_timestamp(&submission.creation_time).ok();
+    // Registration evidence is the first evidence of the first request.
+    let evidence_uri = submission
+        .requests
+        .first()
+        .and_then(|request| request.evidence.first())
+        .map(|evidence| evidence.URI.clone());
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: submission.id.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let poh_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::ProofOfHumanity,
+        identity: submission.id.clone(),
+        created_at,
+        display_name: submission.name.clone(),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!(
+            "https://app.proofofhumanity.id/profile/{}",
+            submission.id
+        )),
+        updated_at: naive_now(),
+    };
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::ProofOfHumanity,
+        record_id: evidence_uri,
+        created_at,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+
+    let next_targets = match platform {
+        Platform::ProofOfHumanity => vec![Target::Identity(Platform::Ethereum, submission.id)],
+        _ => vec![],
+    };
+    Ok(Fetched {
+        next_targets,
+        connections: vec![Connection::TwoWayBinding(eth_identity, poh_identity, proof)],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,21 @@
+use crate::{
+    error::Error,
+    graph::{new_db_connection, vertex::Identity},
+    upstream::{fetch_and_import, proof_of_humanity::ProofOfHumanity, Platform, Target},
+};
+
+#[tokio::test]
+async fn test_fetch_submission_by_address() -> Result<(), Error> {
+    let target = Target::Identity(
+        Platform::Ethereum,
+        "0x1db3439a222c519ab44bb1144fc28167b4fa6ee6".into(),
+    );
+    fetch_and_import::<ProofOfHumanity>(&target).await?;
+
+    let db = new_db_connection().await?;
+    Identity::find_by_platform_identity(&db, &Platform::ProofOfHumanity, &target.identity()?)
+        .await?
+        .expect("Record not found");
+
+    Ok(())
+}
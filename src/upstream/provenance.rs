@@ -0,0 +1,35 @@
+use serde::Serialize;
+use std::{future::Future, sync::Mutex};
+
+/// One call to an upstream, recorded to tell fresh data from cached data.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchRecord {
+    /// Name of upstream, see `UPSTREAMS`.
+    pub upstream: &'static str,
+    /// Target fetched, e.g. `Identity/twitter/alice`.
+    pub target: String,
+    pub latency_ms: u64,
+    /// Error message if this call failed.
+    pub error: Option<String>,
+}
+
+tokio::task_local! {
+    static RECORDS: Mutex<Vec<FetchRecord>>;
+}
+
+/// Run `future`, and collect every upstream call made in it (in the same task).
+pub async fn collect<F: Future>(future: F) -> (F::Output, Vec<FetchRecord>) {
+    RECORDS
+        .scope(Mutex::new(vec![]), async move {
+            let output = future.await;
+            let records = RECORDS.with(|records| std::mem::take(&mut *records.lock().unwrap()));
+            (output, records)
+        })
+        .await
+}
+
+/// Record an upstream call. No-op if not running inside `collect`.
+pub(crate) fn record(record: FetchRecord) {
+    let _ = RECORDS.try_with(|records| records.lock().unwrap().push(record));
+}
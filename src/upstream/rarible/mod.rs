@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// https://rarible.com/marketplace/api/v4/profiles/{address}
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// Address of this profile.
+    pub id: String,
+    /// Display name.
+    pub name: Option<String>,
+    /// Username, i.e. `https://rarible.com/{short_url}`.
+    pub short_url: Option<String>,
+    pub image: Option<String>,
+    /// Twitter account verified by Rarible (by posting a tweet).
+    pub twitter_username: Option<String>,
+}
+
+pub struct Rarible {}
+
+#[async_trait]
+impl Fetcher for Rarible {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_profile_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_profile(address: &str) -> Result<Option<Profile>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/profiles/{}", C.upstream.rarible_api.url, address)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Rarible Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Rarible fetch | error: {:?}", err.to_string()))
+    })?;
+
+    // Address never connected to Rarible.
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Rarible Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+/// `@Alice` / `alice` => `alice`
+fn normalize_username(username: &str) -> String {
+    username.trim().trim_start_matches('@').to_lowercase()
+}
+
+async fn fetch_profile_by_address(address: &str) -> Result<Fetched, Error> {
+    let profile = match fetch_profile(address).await? {
+        Some(profile) => profile,
+        None => {
+            info!("Rarible fetch | {} has no Rarible profile", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: profile.id.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    if let Some(short_url) = profile
+        .short_url
+        .as_deref()
+        .map(normalize_username)
+        .filter(|short_url| !short_url.is_empty())
+    {
+        let rarible_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Rarible,
+            identity: short_url.clone(),
+            created_at: None,
+            display_name: profile.name.clone().filter(|name| !name.is_empty()),
+            added_at: naive_now(),
+            avatar_url: profile.image.clone(),
+            profile_url: Some(format!("https://rarible.com/{}", short_url)),
+            updated_at: naive_now(),
+        };
+        // Rarible profile is created by signing in with the address.
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Rarible,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            eth_identity.clone(),
+            rarible_identity,
+            proof,
+        ));
+    }
+
+    if let Some(twitter) = profile
+        .twitter_username
+        .as_deref()
+        .map(normalize_username)
+        .filter(|twitter| !twitter.is_empty())
+    {
+        let twitter_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Twitter,
+            identity: twitter.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Rarible,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched
+            .connections
+            .push(Connection::Proof(eth_identity, twitter_identity, proof));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::Twitter, twitter));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,18 @@
+use crate::{
+    error::Error,
+    upstream::rarible::{fetch_profile, normalize_username},
+};
+
+#[test]
+fn test_normalize_username() {
+    assert_eq!(normalize_username("@Rarible"), "rarible");
+    assert_eq!(normalize_username("rarible "), "rarible");
+}
+
+#[tokio::test]
+async fn test_fetch_profile() -> Result<(), Error> {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let profile = fetch_profile(address).await?;
+    println!("profile: {:?}", profile);
+    Ok(())
+}
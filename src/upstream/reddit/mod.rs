@@ -0,0 +1,128 @@
+//! Reddit is not fetched as a standalone upstream (Keybase already finds
+//! Reddit accounts), but proof posts claimed by other upstreams are checked here.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    error::Error,
+    util::{make_client, parse_body, request_with_timeout},
+};
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::debug;
+
+/// Keybase only accepts proofs posted in this subreddit.
+const KEYBASE_PROOFS_SUBREDDIT: &str = "KeybaseProofs";
+
+/// Reddit JSON API returns a `Listing` of the post, then a `Listing` of its comments.
+/// https://www.reddit.com/dev/api#GET_comments_{article}
+#[derive(Deserialize, Debug)]
+pub struct Listing {
+    pub data: ListingData,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListingData {
+    pub children: Vec<Thing>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Thing {
+    /// `t3` for a post (link).
+    pub kind: String,
+    pub data: Post,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Post {
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub subreddit: String,
+    /// Markdown body of a self post.
+    #[serde(default)]
+    pub selftext: String,
+}
+
+/// `https://www.reddit.com/r/KeybaseProofs/comments/abc/title/` => `https://www.reddit.com/r/KeybaseProofs/comments/abc/title/.json`
+fn json_url(proof_url: &str) -> Result<String, Error> {
+    let url: url::Url = proof_url
+        .parse()
+        .map_err(|_| Error::ParamError(format!("Reddit proof url invalid: {}", proof_url)))?;
+    let host = url.host_str().unwrap_or_default();
+    if url.scheme() != "https" || !(host == "reddit.com" || host.ends_with(".reddit.com")) {
+        return Err(Error::ParamError(format!(
+            "Reddit proof url not on reddit.com: {}",
+            proof_url
+        )));
+    }
+    Ok(format!(
+        "https://www.reddit.com{}/.json",
+        url.path().trim_end_matches('/')
+    ))
+}
+
+async fn fetch_post(proof_url: &str) -> Result<Option<Post>, Error> {
+    let client = make_client();
+    let uri: http::Uri = json_url(proof_url)?
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        // Reddit rejects requests with default user agents.
+        .header("User-Agent", "relation_server")
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Reddit Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Reddit fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Reddit Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let listings: Vec<Listing> = parse_body(&mut resp).await?;
+    Ok(listings
+        .into_iter()
+        .next()
+        .and_then(|listing| listing.data.children.into_iter().next())
+        .filter(|thing| thing.kind == "t3")
+        .map(|thing| thing.data))
+}
+
+/// A post proves `signature` if it is posted by `username` in r/KeybaseProofs,
+/// and its body contains the signature (line breaks may be inserted by the poster).
+fn is_proof_post(post: &Post, username: &str, signature: &str) -> bool {
+    let strip = |s: &str| -> String { s.chars().filter(|c| !c.is_whitespace()).collect() };
+    let signature = strip(signature);
+    post.author.eq_ignore_ascii_case(username)
+        && post.subreddit == KEYBASE_PROOFS_SUBREDDIT
+        && !signature.is_empty()
+        && strip(&post.selftext).contains(&signature)
+}
+
+/// Fetch the proof post at `proof_url` and check it is the signed statement of `username`.
+pub(crate) async fn verify_proof_post(
+    proof_url: &str,
+    username: &str,
+    signature: &str,
+) -> Result<bool, Error> {
+    match fetch_post(proof_url).await? {
+        Some(post) => Ok(is_proof_post(&post, username, signature)),
+        None => {
+            debug!("Reddit fetch | proof post {} is deleted", proof_url);
+            Ok(false)
+        }
+    }
+}
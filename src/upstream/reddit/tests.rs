@@ -0,0 +1,35 @@
+use crate::upstream::reddit::{is_proof_post, json_url, Post};
+
+#[test]
+fn test_json_url() {
+    assert_eq!(
+        json_url(
+            "https://www.reddit.com/r/KeybaseProofs/comments/2vbj6o/my_keybase_proof_redditchris/"
+        )
+        .unwrap(),
+        "https://www.reddit.com/r/KeybaseProofs/comments/2vbj6o/my_keybase_proof_redditchris/.json"
+    );
+    assert!(json_url("https://evil.example.com/r/KeybaseProofs/comments/2vbj6o/").is_err());
+    assert!(json_url("https://notreddit.com/r/KeybaseProofs/").is_err());
+}
+
+#[test]
+fn test_is_proof_post() {
+    let post = Post {
+        author: "Chris".to_string(),
+        subreddit: "KeybaseProofs".to_string(),
+        selftext: "### Keybase proof\n\nI hereby claim:\n\n  * I am /u/chris on reddit.\n\n    hKRib2R5hqhkZXRhY2hlZMOp\n    aGFzaF90eXBlCqNrZXnEIwEg\n".to_string(),
+    };
+    let signature = "hKRib2R5hqhkZXRhY2hlZMOpaGFzaF90eXBlCqNrZXnEIwEg";
+    assert!(is_proof_post(&post, "chris", signature));
+    assert!(!is_proof_post(&post, "alice", signature));
+    assert!(!is_proof_post(&post, "chris", "c29tZXRoaW5nIGVsc2U="));
+    assert!(!is_proof_post(
+        &Post {
+            subreddit: "funny".to_string(),
+            ..post
+        },
+        "chris",
+        signature
+    ));
+}
@@ -4,22 +4,19 @@ use crate::{
     config::C,
     error::Error,
     graph::{
-        create_identity_to_contract_record,
-        edge::hold::Hold,
-        new_db_connection,
+        edge::{hold::Hold, Proof, ProofLevel},
         vertex::{contract::Chain, contract::ContractCategory, Contract, Identity},
     },
-    upstream::{DataSource, Fetcher, Platform, Target, TargetProcessedList},
-    util::{make_client, naive_now, parse_body, request_with_timeout},
+    upstream::{Connection, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, paginate, parse_body, request_with_timeout, Page},
 };
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDateTime};
-use futures::future::join_all;
 use http::uri::InvalidUri;
 use hyper::{Body, Method};
 use serde::Deserialize;
 use std::str::FromStr;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 use uuid::Uuid;
 
 use super::DataFetcher;
@@ -77,19 +74,44 @@ pub struct MetaData {
     pub handle: Option<String>,
 }
 
+/// RSS3 profiles API, which aggregates ENS / Lens / Crossbell / Farcaster profiles of an address.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Rss3ProfileResponse {
+    pub total: i64,
+    pub result: Vec<ProfileItem>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProfileItem {
+    pub address: String,
+    pub network: String,
+    /// `ENS Registrar`, `Lens`, `Crossbell`, `Farcaster`, etc.
+    pub platform: String,
+    pub source: String,
+    pub name: Option<String>,
+    pub handle: String,
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub profile_uri: Vec<String>,
+}
+
 const PAGE_LIMIT: i64 = 500;
 pub struct Rss3 {}
 
 #[async_trait]
 impl Fetcher for Rss3 {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
-            Target::Identity(platform, identity) => fetch_nfts_by_account(platform, identity).await,
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::Identity(platform, identity) => {
+                let mut fetched = fetch_nfts_by_account(platform, identity).await?;
+                fetched.extend(fetch_profiles_by_account(identity).await?);
+                Ok(fetched)
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
@@ -98,76 +120,76 @@ impl Fetcher for Rss3 {
     }
 }
 
-async fn fetch_nfts_by_account(
-    _platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let mut cursor = String::from("");
-    let client = make_client();
-    let mut next_targets = Vec::new();
-
-    loop {
-        let uri: http::Uri;
-        if cursor.len() == 0 {
-            uri = format!(
-                "{}/{}?tag=collectible&include_poap=true&refresh=true",
-                C.upstream.rss3_service.url, identity
-            )
-            .parse()
-            .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
-        } else {
-            uri = format!(
-                "{}/{}?tag=collectible&include_poap=true&refresh=true&cursor={}",
-                C.upstream.rss3_service.url, identity, cursor
-            )
-            .parse()
-            .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
-        }
-
-        let req = hyper::Request::builder()
-            .method(Method::GET)
-            .uri(uri)
-            .body(Body::empty())
-            .map_err(|_err| Error::ParamError(format!("Rss3 Build Request Error {}", _err)))?;
+async fn fetch_nfts_by_account(_platform: &Platform, identity: &str) -> Result<Fetched, Error> {
+    let paginated = paginate(String::from(""), |cursor| fetch_nfts_page(identity, cursor)).await?;
+    if paginated.items.is_empty() {
+        info!("Rss3 Response result is empty");
+    }
 
-        let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
-            Error::ManualHttpClientError(format!("Rss3 fetch fetch | error: {:?}", err.to_string()))
-        })?;
+    let mut fetched = Fetched {
+        truncated: paginated.truncated,
+        ..Default::default()
+    };
+    paginated
+        .items
+        .into_iter()
+        .filter(|p| p.owner == identity.to_lowercase())
+        .filter_map(parse_item)
+        .for_each(|(target, connection)| {
+            fetched.next_targets.push(target);
+            fetched.connections.push(connection);
+        });
 
-        let body: Rss3Response = parse_body(&mut resp).await?;
-        if body.total == 0 {
-            info!("Rss3 Response result is empty");
-            break;
-        }
+    Ok(fetched)
+}
 
-        let futures: Vec<_> = body
-            .result
-            .into_iter()
-            .filter(|p| p.owner == identity.to_lowercase())
-            .map(save_item)
-            .collect();
-
-        let targets: TargetProcessedList = join_all(futures)
-            .await
-            .into_iter()
-            .flat_map(|result| result.unwrap_or_default())
-            .collect();
-
-        next_targets.extend(targets);
-        if body.cursor.is_none() || body.total < PAGE_LIMIT {
-            break;
-        } else {
-            cursor = body.cursor.unwrap();
-        }
+async fn fetch_nfts_page(
+    identity: &str,
+    cursor: String,
+) -> Result<Page<ResultItem, String>, Error> {
+    let uri: http::Uri;
+    if cursor.len() == 0 {
+        uri = format!(
+            "{}/{}?tag=collectible&include_poap=true&refresh=true",
+            C.upstream.rss3_service.url, identity
+        )
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+    } else {
+        uri = format!(
+            "{}/{}?tag=collectible&include_poap=true&refresh=true&cursor={}",
+            C.upstream.rss3_service.url, identity, cursor
+        )
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
     }
 
-    Ok(next_targets)
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Rss3 Build Request Error {}", _err)))?;
+
+    let client = make_client();
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Rss3 fetch fetch | error: {:?}", err.to_string()))
+    })?;
+
+    let body: Rss3Response = parse_body(&mut resp).await?;
+    let next = if body.total < PAGE_LIMIT {
+        None
+    } else {
+        body.cursor
+    };
+    Ok(Page {
+        items: body.result,
+        next,
+    })
 }
 
-async fn save_item(p: ResultItem) -> Result<TargetProcessedList, Error> {
+fn parse_item(p: ResultItem) -> Option<(Target, Connection)> {
     let creataed_at = DateTime::parse_from_rfc3339(&p.timestamp).unwrap();
     let created_at_naive = NaiveDateTime::from_timestamp(creataed_at.timestamp(), 0);
-    let db = new_db_connection().await?;
 
     let from: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
@@ -183,7 +205,7 @@ async fn save_item(p: ResultItem) -> Result<TargetProcessedList, Error> {
     };
 
     if p.actions.len() == 0 {
-        return Ok(vec![]);
+        return None;
     }
 
     let found = p
@@ -197,14 +219,14 @@ async fn save_item(p: ResultItem) -> Result<TargetProcessedList, Error> {
         })
         .find(|a| (p.tag == "collectible" && a.tag == "collectible"));
     if found.is_none() {
-        return Ok(vec![]);
+        return None;
     }
     let real_action = found.unwrap();
 
     if real_action.metadata.symbol.is_none()
         || real_action.metadata.symbol.as_ref().unwrap() == &String::from("ENS")
     {
-        return Ok(vec![]);
+        return None;
     }
 
     let mut nft_category =
@@ -218,7 +240,7 @@ async fn save_item(p: ResultItem) -> Result<TargetProcessedList, Error> {
     let chain = Chain::from_str(p.network.as_str()).unwrap_or_default();
     if chain == Chain::Unknown {
         error!("Rss3 Fetch data | Unknown Chain, original data: {:?}", p);
-        return Ok(vec![]);
+        return None;
     }
     let contract_addr = real_action
         .metadata
@@ -246,12 +268,129 @@ async fn save_item(p: ResultItem) -> Result<TargetProcessedList, Error> {
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
     };
-    create_identity_to_contract_record(&db, &from, &to, &hold).await?;
 
-    Ok(vec![Target::NFT(
-        chain,
-        nft_category,
-        contract_addr.clone(),
-        nft_id.clone(),
-    )])
+    Some((
+        Target::NFT(chain, nft_category, contract_addr, nft_id.clone()),
+        Connection::ContractHold(from, to, hold),
+    ))
+}
+
+async fn fetch_profiles_by_account(identity: &str) -> Result<Fetched, Error> {
+    let uri: http::Uri = format!("{}/{}", C.upstream.rss3_service.profile_url, identity)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Rss3 Build Request Error {}", _err)))?;
+    let client = make_client();
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!(
+            "Rss3 fetch profiles | error: {:?}",
+            err.to_string()
+        ))
+    })?;
+
+    let body: Rss3ProfileResponse = parse_body(&mut resp).await?;
+    if body.total == 0 {
+        info!("Rss3 profiles result is empty");
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    for profile in body.result.into_iter() {
+        if profile.address.to_lowercase() != identity.to_lowercase() {
+            continue;
+        }
+        // ENS is not a proof between identities. Let ENS upstreams resolve it.
+        if profile.platform == "ENS Registrar" {
+            fetched.next_targets.push(Target::NFT(
+                ContractCategory::ENS.default_chain().unwrap(),
+                ContractCategory::ENS,
+                ContractCategory::ENS.default_contract_address().unwrap(),
+                profile.handle.to_lowercase(),
+            ));
+            continue;
+        }
+        match profile_platform(&profile.platform) {
+            Some(platform) => fetched
+                .connections
+                .push(profile_connection(platform, &profile)),
+            None => debug!(
+                "Rss3 fetch profiles | unsupported platform {}",
+                profile.platform
+            ),
+        }
+    }
+
+    Ok(fetched)
+}
+
+/// Map RSS3 profile platform names to ours.
+fn profile_platform(platform: &str) -> Option<Platform> {
+    match platform {
+        "Lens" => Some(Platform::Lens),
+        "Crossbell" => Some(Platform::Crossbell),
+        "Farcaster" => Some(Platform::Farcaster),
+        _ => None,
+    }
+}
+
+/// RSS3 only re-publishes what first-party upstreams provide.
+/// A proof is duplicated if a first-party upstream already connects the same two identities.
+/// One given by RSS3 before is not: it is refreshed, so it won't be seen as gone by `scheduler`.
+pub(crate) fn is_duplicated(existing: &[Proof]) -> bool {
+    existing
+        .iter()
+        .any(|proof| proof.source != DataSource::Rss3)
+}
+
+fn profile_connection(platform: Platform, profile: &ProfileItem) -> Connection {
+    let address = profile.address.to_lowercase();
+    let handle = match platform {
+        // Crossbell characters are stored as `handle.csb`.
+        Platform::Crossbell if !profile.handle.ends_with(".csb") => {
+            format!("{}.csb", profile.handle.to_lowercase())
+        }
+        _ => profile.handle.to_lowercase(),
+    };
+    let record_id = Some(format!("{}:{}", platform, handle));
+
+    let from: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address,
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let to: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform,
+        identity: handle,
+        created_at: None,
+        display_name: profile.name.clone(),
+        added_at: naive_now(),
+        avatar_url: profile.profile_uri.first().cloned(),
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::Rss3,
+        record_id,
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+    Connection::Republished(from, to, proof)
 }
@@ -5,7 +5,7 @@ use crate::{
     graph::vertex::{contract::Chain, Contract, Identity},
     upstream::rss3::Rss3,
     upstream::Platform,
-    upstream::{Fetcher, Target},
+    upstream::{fetch_and_import, Target},
 };
 
 #[tokio::test]
@@ -14,7 +14,7 @@ async fn test_smoke_nft_rss3() -> Result<(), Error> {
         Platform::Ethereum,
         "0x934b510d4c9103e6a87aef13b816fb080286d649".to_lowercase(),
     );
-    let _ = Rss3::fetch(&target).await?;
+    let _ = fetch_and_import::<Rss3>(&target).await?;
     let db = new_db_connection().await?;
 
     let owner = Identity::find_by_platform_identity(&db, &Platform::Ethereum, &target.identity()?)
@@ -34,3 +34,27 @@ async fn test_smoke_nft_rss3() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_is_duplicated() {
+    use crate::graph::edge::Proof;
+    use crate::upstream::{rss3::is_duplicated, DataSource};
+
+    let record_id = Some("lens:sujiyan.lens".to_string());
+    assert!(!is_duplicated(&[]));
+
+    let first_party = Proof {
+        source: DataSource::Lens,
+        ..Default::default()
+    };
+    assert!(is_duplicated(&[first_party]));
+
+    let rss3 = Proof {
+        source: DataSource::Rss3,
+        record_id: Some("lens:another.lens".to_string()),
+        ..Default::default()
+    };
+    assert!(!is_duplicated(&[rss3.clone()]));
+    // The same one given by RSS3 before is refreshed instead of skipped.
+    assert!(!is_duplicated(&[Proof { record_id, ..rss3 }]));
+}
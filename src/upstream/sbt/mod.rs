@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::{ConfigSBTContract, C},
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{
+        eth::{abi_word, decode_hex, eth_call},
+        naive_now,
+    },
+};
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// `balanceOf(address)`
+const ERC721_BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+/// `balanceOf(address,uint256)`
+const ERC1155_BALANCE_OF: [u8; 4] = [0x00, 0xfd, 0xd5, 0x8e];
+
+pub struct SBT {}
+
+#[async_trait]
+impl Fetcher for SBT {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_attestations_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        !C.upstream.sbt.contracts.is_empty()
+            && target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+/// ABI-encoded call of `balanceOf` for `schema` (token ID) of an `erc1155` contract,
+/// or the whole `erc721` contract if `schema` is `None`.
+fn balance_of_call(address: &str, schema: Option<&str>) -> Result<Vec<u8>, Error> {
+    let mut call_data = match schema {
+        Some(_) => ERC1155_BALANCE_OF.to_vec(),
+        None => ERC721_BALANCE_OF.to_vec(),
+    };
+    call_data.extend_from_slice(&abi_word(&decode_hex(address)?));
+    if let Some(schema) = schema {
+        let token_id = BigUint::parse_bytes(schema.as_bytes(), 10)
+            .ok_or_else(|| Error::ParamError(format!("SBT token ID invalid: {}", schema)))?;
+        call_data.extend_from_slice(&abi_word(&token_id.to_bytes_be()));
+    }
+    Ok(call_data)
+}
+
+async fn holds(
+    contract: &ConfigSBTContract,
+    address: &str,
+    schema: Option<&str>,
+) -> Result<bool, Error> {
+    let call_data = balance_of_call(address, schema)?;
+    let result = eth_call(&contract.rpc_url, &contract.address, &call_data).await?;
+    Ok(result.is_some_and(|balance| balance.iter().any(|byte| *byte != 0)))
+}
+
+/// Schema UIDs of `contract` held by `address`.
+/// A whole `erc721` contract is regarded as a single schema.
+async fn scan_contract(contract: &ConfigSBTContract, address: &str) -> Result<Vec<String>, Error> {
+    let mut held: Vec<String> = vec![];
+    match contract.standard.as_str() {
+        "erc721" => {
+            if holds(contract, address, None).await? {
+                held.push(contract.address.to_lowercase());
+            }
+        }
+        "erc1155" => {
+            for schema in contract.schemas.iter() {
+                if holds(contract, address, Some(schema)).await? {
+                    held.push(schema.clone());
+                }
+            }
+        }
+        standard => warn!(
+            "SBT fetch | {}: unsupported standard {}",
+            contract.name, standard
+        ),
+    }
+    Ok(held)
+}
+
+async fn fetch_attestations_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    // All SBTs of an address are held by the same SBT identity, like Civic passes.
+    let sbt_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::SBT,
+        identity: address.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    for contract in C.upstream.sbt.contracts.iter() {
+        let held = match scan_contract(contract, &address).await {
+            Ok(held) => held,
+            Err(err) => {
+                warn!("SBT fetch | {}: {}", contract.name, err);
+                continue;
+            }
+        };
+        for schema in held.into_iter() {
+            info!("SBT fetch | {} holds {} {}", address, contract.name, schema);
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::SBT,
+                // Schema UID of this attestation.
+                record_id: Some(format!("{}:{}", contract.name, schema)),
+                created_at: None,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::Cryptographic,
+            };
+            fetched
+                .connections
+                .push(Connection::Proof(eth_identity.clone(), sbt_identity, proof));
+        }
+    }
+
+    // SBTs don't lead to any other identity.
+    Ok(fetched)
+}
@@ -0,0 +1,15 @@
+use crate::upstream::sbt::balance_of_call;
+
+#[test]
+fn test_balance_of_call() {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    assert_eq!(
+        hex::encode(balance_of_call(address, None).unwrap()),
+        "70a08231000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+    );
+    assert_eq!(
+        hex::encode(balance_of_call(address, Some("10000515")).unwrap()),
+        "00fdd58e000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa960450000000000000000000000000000000000000000000000000000000000989883"
+    );
+    assert!(balance_of_call(address, Some("badge")).is_err());
+}
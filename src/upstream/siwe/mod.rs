@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        batch, db_connection,
+        edge::{Proof, ProofLevel, ProofRecord},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Platform, Target, TargetProcessedList},
+    util::{
+        canonical::canonicalize,
+        eth::{decode_hex, eip191_hash, is_signed_by},
+        naive_now,
+    },
+};
+use chrono::{DateTime, Duration, NaiveDateTime};
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::info;
+use uuid::Uuid;
+
+const HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+/// Prefix of resources which bind the signer to another identity,
+/// i.e. `urn:relation:twitter:suji_yan`.
+const BINDING_RESOURCE_PREFIX: &str = "urn:relation:";
+/// Messages without `Expiration Time` expire this long after `Issued At`.
+const MAX_AGE_MINUTES: i64 = 10;
+
+/// Sign-In with Ethereum message.
+/// https://eips.ethereum.org/EIPS/eip-4361
+#[derive(Debug, Clone, Default)]
+pub struct SiweMessage {
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub nonce: String,
+    pub issued_at: NaiveDateTime,
+    pub expiration_time: Option<NaiveDateTime>,
+    pub not_before: Option<NaiveDateTime>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+fn parse_time(value: &str) -> Result<NaiveDateTime, Error> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.naive_utc())
+        .map_err(|err| Error::ParamError(format!("SIWE: {} {}", value, err)))
+}
+
+impl FromStr for SiweMessage {
+    type Err = Error;
+
+    fn from_str(message: &str) -> Result<Self, Self::Err> {
+        let malformed = |reason: &str| Error::ParamError(format!("SIWE: {}", reason));
+        let mut lines = message.lines().peekable();
+        let domain = lines
+            .next()
+            .and_then(|line| line.strip_suffix(HEADER_SUFFIX))
+            .ok_or_else(|| malformed("invalid header"))?;
+        let address = lines
+            .next()
+            .filter(|line| line.starts_with("0x") && line.len() == 42)
+            .ok_or_else(|| malformed("invalid address"))?;
+        if lines.next() != Some("") {
+            return Err(malformed("missing blank line after address"));
+        }
+
+        let mut siwe = SiweMessage {
+            domain: domain.to_string(),
+            address: address.to_lowercase(),
+            ..Default::default()
+        };
+        // Statement is optional, and followed by a blank line.
+        if let Some(line) = lines.next_if(|line| !line.starts_with("URI: ")) {
+            siwe.statement = Some(line.to_string()).filter(|s| !s.is_empty());
+            if !line.is_empty() && lines.next() != Some("") {
+                return Err(malformed("missing blank line after statement"));
+            }
+        }
+
+        let mut issued_at = None;
+        while let Some(line) = lines.next() {
+            if line == "Resources:" {
+                for resource in lines.by_ref() {
+                    let resource = resource
+                        .strip_prefix("- ")
+                        .ok_or_else(|| malformed("invalid resource"))?;
+                    siwe.resources.push(resource.to_string());
+                }
+                break;
+            }
+            let (key, value) = line
+                .split_once(": ")
+                .ok_or_else(|| malformed("invalid field"))?;
+            match key {
+                "URI" => siwe.uri = value.to_string(),
+                "Version" => siwe.version = value.to_string(),
+                "Chain ID" => siwe.chain_id = value.parse()?,
+                "Nonce" => siwe.nonce = value.to_string(),
+                "Issued At" => issued_at = Some(parse_time(value)?),
+                "Expiration Time" => siwe.expiration_time = Some(parse_time(value)?),
+                "Not Before" => siwe.not_before = Some(parse_time(value)?),
+                "Request ID" => siwe.request_id = Some(value.to_string()),
+                _ => return Err(malformed(&format!("unknown field {}", key))),
+            }
+        }
+        siwe.issued_at = issued_at.ok_or_else(|| malformed("missing Issued At"))?;
+        if siwe.uri.is_empty() || siwe.nonce.is_empty() || siwe.version != "1" {
+            return Err(malformed("missing required field"));
+        }
+        Ok(siwe)
+    }
+}
+
+impl SiweMessage {
+    /// Whether this message is valid at `now`: not expired
+    /// (`MAX_AGE_MINUTES` after issued, if `Expiration Time` is not given) and not before `Not Before`.
+    pub fn is_valid_at(&self, now: &NaiveDateTime) -> bool {
+        let expiration_time = self
+            .expiration_time
+            .unwrap_or(self.issued_at + Duration::minutes(MAX_AGE_MINUTES));
+        expiration_time > *now && self.not_before.is_none_or(|nbf| nbf <= *now)
+    }
+
+    /// Identities the signer claims to own, listed in `resources`.
+    /// Taken as they are, `canonicalize` decides the case of each platform.
+    pub fn bindings(&self) -> Vec<(Platform, String)> {
+        self.resources
+            .iter()
+            .filter_map(|resource| resource.strip_prefix(BINDING_RESOURCE_PREFIX))
+            .filter_map(|binding| binding.split_once(':'))
+            .filter_map(|(platform, identity)| {
+                Platform::from_str(platform)
+                    .ok()
+                    .filter(|platform| *platform != Platform::Unknown)
+                    .map(|platform| (platform, identity.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// Request body of self-attestation.
+#[derive(Deserialize, Debug)]
+pub struct SelfAttestationRequest {
+    /// Full SIWE message.
+    pub message: String,
+    /// `0x`-prefixed hex of signature.
+    pub signature: String,
+}
+
+/// Self-attestation is push-based: the owner of an address submits a
+/// signed SIWE message to claim other identities.
+pub struct SelfAttestation {}
+
+impl SelfAttestation {
+    /// Verify a signed SIWE message and save the bindings it claims.
+    /// Returns the bound identities to be fetched next.
+    pub async fn ingest(request: &SelfAttestationRequest) -> Result<TargetProcessedList, Error> {
+        Ok(Self::submit(request)
+            .await?
+            .into_iter()
+            .map(|(target, _)| target)
+            .collect())
+    }
+
+    /// Verify a signed SIWE message and save the bindings it claims.
+    /// They are only claimed by the signer, not proven by the other side: saved as `SelfClaimed`.
+    /// A nonce is accepted once per signer (it's saved as `record_id`), so a message can't be replayed.
+    /// Saved by `batch::insert`, which fails if the proofs are there already, even for concurrent requests.
+    /// Returns the bound identities with proofs saved for them.
+    pub async fn submit(
+        request: &SelfAttestationRequest,
+    ) -> Result<Vec<(Target, ProofRecord)>, Error> {
+        let expected_domain = &C.upstream.siwe.domain;
+        if expected_domain.is_empty() {
+            return Err(Error::ParamError(
+                "SIWE self-attestation is disabled".into(),
+            ));
+        }
+        let siwe: SiweMessage = request.message.parse()?;
+        if &siwe.domain != expected_domain {
+            return Err(Error::ParamError(format!(
+                "SIWE: domain {} is not {}",
+                siwe.domain, expected_domain
+            )));
+        }
+        if !siwe.is_valid_at(&naive_now()) {
+            return Err(Error::ParamError("SIWE: message is expired".into()));
+        }
+        let signature = decode_hex(&request.signature)?;
+        let hash = eip191_hash(request.message.as_bytes());
+        if !is_signed_by(&hash, &signature, &siwe.address).await? {
+            return Err(Error::SignatureValidationError(format!(
+                "SIWE: message is not signed by {}",
+                siwe.address
+            )));
+        }
+
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: siwe.address.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let db = db_connection().await?;
+        if Proof::exists_by_record_id(&db, &DataSource::SelfAttested, &eth_identity, &siwe.nonce)
+            .await?
+        {
+            return Err(Error::ParamError(format!(
+                "SIWE: nonce {} is used already",
+                siwe.nonce
+            )));
+        }
+        let mut connections = vec![Connection::Identity(eth_identity.clone())];
+        let mut targets = vec![];
+        for (platform, identity) in siwe.bindings() {
+            // Rejected here, since `batch::insert` would skip it silently.
+            let identity = canonicalize(&platform, &identity)?;
+            let to: Identity = Identity {
+                uuid: Some(Uuid::new_v4()),
+                platform,
+                identity: identity.clone(),
+                created_at: None,
+                display_name: None,
+                added_at: naive_now(),
+                avatar_url: None,
+                profile_url: None,
+                updated_at: naive_now(),
+            };
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::SelfAttested,
+                record_id: Some(siwe.nonce.clone()),
+                created_at: Some(siwe.issued_at),
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: false,
+                expired_at: None,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::SelfClaimed,
+            };
+            connections.push(Connection::Proof(eth_identity.clone(), to, proof));
+            targets.push(Target::Identity(platform, identity));
+        }
+
+        // All bindings of one message are saved, or none of them.
+        let upserted = batch::insert(&db, &connections).await?;
+        for target in targets.iter() {
+            info!("SIWE self-attestation | {} => {}", siwe.address, target);
+        }
+        Ok(targets
+            .into_iter()
+            .zip(upserted.proofs.into_iter())
+            .collect())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::upstream::{siwe::SiweMessage, Platform};
+use crate::util::{
+    eth::{decode_hex, is_personal_signed_by},
+    timestamp_to_naive,
+};
+
+const MESSAGE: &str = "relation-service.next.id wants you to sign in with your Ethereum account:
+0x2c7536E3605D9C16a7a3D7b1898e529396a65c23
+
+I own these accounts.
+
+URI: https://relation-service.next.id
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2022-11-14T08:00:00Z
+Resources:
+- urn:relation:twitter:suji_yan
+- urn:relation:github:fengshanshan
+- https://example.com/not-a-binding";
+
+const SIGNATURE: &str = "0x9377c312145a5afb911bf9e8c067bcf6094c533603687850df502b61290bbf5e6161c5eec1680520ec037caf3c5f23aa5c68ac4b92ba4bc05db9346077ea9c8c1c";
+
+#[test]
+fn test_parse_siwe_message() {
+    let siwe: SiweMessage = MESSAGE.parse().unwrap();
+    assert_eq!(siwe.domain, "relation-service.next.id");
+    assert_eq!(siwe.address, "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+    assert_eq!(siwe.statement.as_deref(), Some("I own these accounts."));
+    assert_eq!(siwe.chain_id, 1);
+    assert_eq!(siwe.nonce, "32891756");
+    assert_eq!(siwe.issued_at, timestamp_to_naive(1668412800, 0));
+    assert_eq!(siwe.resources.len(), 3);
+    assert_eq!(
+        siwe.bindings(),
+        vec![
+            (Platform::Twitter, "suji_yan".to_string()),
+            (Platform::Github, "fengshanshan".to_string()),
+        ]
+    );
+
+    assert!("Hello world".parse::<SiweMessage>().is_err());
+}
+
+#[test]
+fn test_siwe_without_statement() {
+    let message = MESSAGE.replace("I own these accounts.\n\n", "");
+    let siwe: SiweMessage = message.parse().unwrap();
+    assert_eq!(siwe.statement, None);
+    assert_eq!(siwe.uri, "https://relation-service.next.id");
+}
+
+#[test]
+fn test_siwe_is_valid_at() {
+    let mut siwe: SiweMessage = MESSAGE.parse().unwrap();
+    assert!(siwe.is_valid_at(&timestamp_to_naive(1668412800, 0)));
+    siwe.expiration_time = Some(timestamp_to_naive(1668412900, 0));
+    assert!(!siwe.is_valid_at(&timestamp_to_naive(1668413000, 0)));
+    siwe.not_before = Some(timestamp_to_naive(1668412850, 0));
+    assert!(!siwe.is_valid_at(&timestamp_to_naive(1668412800, 0)));
+
+    // Expires anyway if `Expiration Time` is not given.
+    let siwe: SiweMessage = MESSAGE.parse().unwrap();
+    assert!(siwe.is_valid_at(&timestamp_to_naive(1668412800 + 60, 0)));
+    assert!(!siwe.is_valid_at(&timestamp_to_naive(1668412800 + 3600, 0)));
+}
+
+#[test]
+fn test_siwe_signature() {
+    let signature = decode_hex(SIGNATURE).unwrap();
+    assert!(is_personal_signed_by(
+        MESSAGE,
+        &signature,
+        "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23"
+    ));
+}
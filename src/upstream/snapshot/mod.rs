@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{edge::Participate, vertex::Identity},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{naive_now, timestamp_to_naive, within_timeout},
+};
+use async_trait::async_trait;
+use gql_client::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::info;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+struct QueryVars {
+    address: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QueryResponse {
+    pub follows: Vec<Follow>,
+    pub votes: Vec<Vote>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Follow {
+    pub space: Space,
+    /// Second-based timestamp.
+    pub created: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Vote {
+    pub space: Space,
+    /// Second-based timestamp.
+    pub created: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Space {
+    /// ENS name of this space, e.g. `aave.eth`.
+    pub id: String,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+}
+
+/// How an address takes part in a space.
+#[derive(Debug, Default)]
+pub struct Participation {
+    pub following: bool,
+    pub votes: i64,
+    /// Earliest follow / vote time.
+    pub created_at: Option<i64>,
+}
+
+const QUERY: &str = r#"
+    query FollowsAndVotes($address: String!) {
+        follows(first: 1000, where: { follower: $address }) {
+            space {
+                id
+                name
+                avatar
+            }
+            created
+        }
+        votes(first: 1000, where: { voter: $address }, orderBy: "created", orderDirection: desc) {
+            space {
+                id
+                name
+                avatar
+            }
+            created
+        }
+    }
+"#;
+
+pub struct Snapshot {}
+
+#[async_trait]
+impl Fetcher for Snapshot {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_participations_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+/// Merge follows and votes of an address into participations of each space.
+fn merge_participations(resp: QueryResponse) -> HashMap<String, (Space, Participation)> {
+    let mut result: HashMap<String, (Space, Participation)> = HashMap::new();
+    let records = resp
+        .follows
+        .into_iter()
+        .map(|follow| (follow.space, follow.created, true))
+        .chain(
+            resp.votes
+                .into_iter()
+                .map(|vote| (vote.space, vote.created, false)),
+        );
+    for (space, created, is_follow) in records {
+        let (_, participation) = result
+            .entry(space.id.clone())
+            .or_insert_with(|| (space, Participation::default()));
+        if is_follow {
+            participation.following = true;
+        } else {
+            participation.votes += 1;
+        }
+        participation.created_at = Some(
+            participation
+                .created_at
+                .map_or(created, |earliest| earliest.min(created)),
+        );
+    }
+    result
+}
+
+async fn fetch_follows_and_votes(address: &str) -> Result<Option<QueryResponse>, Error> {
+    let client = Client::new(&C.upstream.snapshot.url);
+    let vars = QueryVars {
+        address: address.to_string(),
+    };
+    let response = client.query_with_vars::<QueryResponse, _>(QUERY, vars);
+
+    within_timeout("Snapshot", response).await
+}
+
+async fn fetch_participations_by_address(address: &str) -> Result<Fetched, Error> {
+    let resp = match fetch_follows_and_votes(address).await? {
+        Some(resp) => resp,
+        None => return Ok(Fetched::default()),
+    };
+    let participations = merge_participations(resp);
+    if participations.is_empty() {
+        info!("Snapshot fetch | {} has no follow or vote", address);
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    for (space_id, (space, participation)) in participations.into_iter() {
+        let space_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Snapshot,
+            identity: space_id.clone(),
+            created_at: None,
+            display_name: space.name.clone(),
+            added_at: naive_now(),
+            avatar_url: space.avatar.clone(),
+            profile_url: Some(format!("https://snapshot.org/#/{}", space_id)),
+            updated_at: naive_now(),
+        };
+        let participate: Participate = Participate {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Snapshot,
+            following: participation.following,
+            votes: participation.votes,
+            created_at: participation.created_at.map(|ts| timestamp_to_naive(ts, 0)),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+        };
+        fetched.connections.push(Connection::Participate(
+            eth_identity.clone(),
+            space_identity,
+            participate,
+        ));
+    }
+
+    Ok(fetched)
+}
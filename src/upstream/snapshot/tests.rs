@@ -0,0 +1,54 @@
+use crate::{
+    error::Error,
+    upstream::snapshot::{
+        fetch_follows_and_votes, merge_participations, Follow, QueryResponse, Space, Vote,
+    },
+};
+
+fn space(id: &str) -> Space {
+    Space {
+        id: id.into(),
+        name: None,
+        avatar: None,
+    }
+}
+
+#[test]
+fn test_merge_participations() {
+    let resp = QueryResponse {
+        follows: vec![Follow {
+            space: space("aave.eth"),
+            created: 300,
+        }],
+        votes: vec![
+            Vote {
+                space: space("aave.eth"),
+                created: 200,
+            },
+            Vote {
+                space: space("ens.eth"),
+                created: 100,
+            },
+            Vote {
+                space: space("aave.eth"),
+                created: 400,
+            },
+        ],
+    };
+    let result = merge_participations(resp);
+    let (_, aave) = result.get("aave.eth").unwrap();
+    assert!(aave.following);
+    assert_eq!(aave.votes, 2);
+    assert_eq!(aave.created_at, Some(200));
+    let (_, ens) = result.get("ens.eth").unwrap();
+    assert!(!ens.following);
+    assert_eq!(ens.votes, 1);
+}
+
+#[tokio::test]
+async fn test_fetch_follows_and_votes() -> Result<(), Error> {
+    let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+    let resp = fetch_follows_and_votes(address).await?;
+    println!("resp: {:?}", resp);
+    Ok(())
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
+    graph::vertex::Identity,
+    upstream::{
+        Connection, DataFetcher, DataSource, DomainResolver, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method, Request};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// https://github.com/Bonfida/sns-sdk#sns-sdk-proxy
+#[derive(Deserialize, Debug)]
+pub struct ProxyResponse<T> {
+    /// `ok` / `error`
+    pub s: String,
+    pub result: Option<T>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DomainItem {
+    /// Public key of the domain account.
+    pub key: String,
+    /// Domain name without `.sol`.
+    pub domain: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FavoriteDomain {
+    /// Public key of the domain account.
+    pub domain: String,
+    /// Domain name without `.sol`.
+    pub reverse: String,
+}
+
+pub struct SolanaNameService {}
+
+#[async_trait]
+impl Fetcher for SolanaNameService {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_connections_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::SNS, Platform::Solana])
+    }
+}
+
+#[async_trait]
+impl DomainResolver for SolanaNameService {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        get_owner(name).await
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        Ok(get_favorite_domain(address)
+            .await?
+            .map(|favorite| with_sol_suffix(&favorite.reverse)))
+    }
+}
+
+async fn fetch_connections_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    match *platform {
+        Platform::Solana => fetch_domains_by_owner(identity).await,
+        Platform::SNS => fetch_owner_by_domain(identity).await,
+        _ => Ok(Fetched::default()),
+    }
+}
+
+/// `bonfida.sol` / `bonfida` => `bonfida.sol`
+fn with_sol_suffix(name: &str) -> String {
+    let name = name.to_lowercase();
+    if name.ends_with(".sol") {
+        name
+    } else {
+        format!("{}.sol", name)
+    }
+}
+
+async fn get<T: DeserializeOwned>(path: String) -> Result<Option<T>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}{}", C.upstream.sns_api.url, path)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("SNS Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("SNS fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("SNS fetch error, statusCode: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+    let result: ProxyResponse<T> = parse_body(&mut resp).await?;
+    if result.s != "ok" {
+        // Domain not registered / owner has no (favorite) domain.
+        return Ok(None);
+    }
+    Ok(result.result)
+}
+
+/// Resolve a `.sol` domain into owner public key.
+async fn get_owner(domain: &str) -> Result<Option<String>, Error> {
+    get(format!("/resolve/{}", domain.trim_end_matches(".sol"))).await
+}
+
+/// All `.sol` domains held by given owner.
+async fn get_domains(owner: &str) -> Result<Vec<DomainItem>, Error> {
+    Ok(get(format!("/domains/{}", owner))
+        .await?
+        .unwrap_or_default())
+}
+
+/// Reverse resolve: the primary (favorite) domain set by given owner.
+async fn get_favorite_domain(owner: &str) -> Result<Option<FavoriteDomain>, Error> {
+    get(format!("/favorite-domain/{}", owner)).await
+}
+
+/// Hold / resolve / reverse resolve records of a domain.
+fn domain_connection(owner: &str, domain: &str, is_primary: bool) -> Connection {
+    // Solana public keys are base58 encoded, which is case-sensitive.
+    let solana_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Solana,
+        identity: owner.to_string(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let sns_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::SNS,
+        identity: domain.to_string(),
+        created_at: None,
+        display_name: Some(domain.to_string()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://naming.bonfida.org/domain/{}", domain)),
+        updated_at: naive_now(),
+    };
+    let hold: Hold = Hold {
+        uuid: Uuid::new_v4(),
+        source: DataSource::SNS,
+        transaction: None,
+        id: domain.to_string(),
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+    };
+    let resolve: Resolve = Resolve {
+        uuid: Uuid::new_v4(),
+        source: DataSource::SNS,
+        system: DomainNameSystem::SNS,
+        name: domain.to_string(),
+        resolved_address: Some(solana_identity.identity.clone()),
+        expire_at: None,
+        fetcher: DataFetcher::RelationService,
+        updated_at: naive_now(),
+    };
+
+    Connection::Domain {
+        address: solana_identity,
+        domain: sns_identity,
+        hold,
+        resolve,
+        is_primary,
+    }
+}
+
+async fn fetch_domains_by_owner(owner: &str) -> Result<Fetched, Error> {
+    let domains = get_domains(owner).await?;
+    if domains.is_empty() {
+        info!("SNS fetch | {} has no domain", owner);
+        return Ok(Fetched::default());
+    }
+    let primary = SolanaNameService {}.reverse_resolve(owner).await?;
+
+    let mut fetched = Fetched::default();
+    for item in domains.iter() {
+        let domain = with_sol_suffix(&item.domain);
+        let is_primary = primary.as_ref() == Some(&domain);
+        fetched
+            .connections
+            .push(domain_connection(owner, &domain, is_primary));
+    }
+
+    Ok(fetched)
+}
+
+async fn fetch_owner_by_domain(domain: &str) -> Result<Fetched, Error> {
+    let domain = with_sol_suffix(domain);
+    let sns = SolanaNameService {};
+    let owner = match sns.resolve(&domain).await? {
+        Some(owner) => owner,
+        None => {
+            warn!("SNS fetch | {} is not registered", domain);
+            return Ok(Fetched::default());
+        }
+    };
+    let is_primary = get_favorite_domain(&owner)
+        .await?
+        .map_or(false, |favorite| {
+            with_sol_suffix(&favorite.reverse) == domain
+        });
+
+    Ok(Fetched {
+        connections: vec![domain_connection(&owner, &domain, is_primary)],
+        next_targets: vec![Target::Identity(Platform::Solana, owner)],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,27 @@
+use crate::{
+    error::Error,
+    upstream::sns::{get_domains, get_favorite_domain, get_owner, with_sol_suffix},
+};
+
+#[test]
+fn test_with_sol_suffix() {
+    assert_eq!(with_sol_suffix("Bonfida"), "bonfida.sol");
+    assert_eq!(with_sol_suffix("bonfida.sol"), "bonfida.sol");
+}
+
+#[tokio::test]
+async fn test_get_owner() -> Result<(), Error> {
+    let owner = get_owner("bonfida.sol").await?;
+    println!("owner: {:?}", owner);
+    assert!(owner.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_domains() -> Result<(), Error> {
+    let owner = "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA";
+    let domains = get_domains(owner).await?;
+    let favorite = get_favorite_domain(owner).await?;
+    println!("domains: {:?}, favorite: {:?}", domains, favorite);
+    Ok(())
+}
@@ -5,10 +5,9 @@ use crate::{
     error::Error,
     graph::edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
     graph::vertex::Identity,
-    graph::{
-        create_domain_resolve_record, create_identity_to_identity_hold_record, new_db_connection,
+    upstream::{
+        Connection, DataFetcher, DataSource, DomainResolver, Fetched, Fetcher, Platform, Target,
     },
-    upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList},
     util::{make_client, naive_now, parse_body, request_with_timeout},
 };
 
@@ -38,124 +37,139 @@ pub struct ReverseResolveResponse {
     pub name: Option<String>,
 }
 
+/// Top-level domains managed by SPACE ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceIdTld {
+    /// `.bnb` on BNB Chain
+    Bnb,
+    /// `.arb` on Arbitrum One
+    Arb,
+}
+
+impl SpaceIdTld {
+    pub const ALL: [SpaceIdTld; 2] = [SpaceIdTld::Bnb, SpaceIdTld::Arb];
+
+    /// `tld` param of SPACE ID API.
+    fn api_tld(&self) -> &'static str {
+        match self {
+            SpaceIdTld::Bnb => "bnb",
+            SpaceIdTld::Arb => "arb1",
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            SpaceIdTld::Bnb => ".bnb",
+            SpaceIdTld::Arb => ".arb",
+        }
+    }
+
+    /// Which TLD given domain belongs to.
+    pub fn from_domain(domain: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tld| {
+            domain
+                .strip_suffix(tld.suffix())
+                .map_or(false, |name| !name.is_empty())
+        })
+    }
+}
+
+#[async_trait]
+impl DomainResolver for SpaceIdTld {
+    async fn resolve(&self, name: &str) -> Result<Option<String>, Error> {
+        match get_address(*self, name).await {
+            Ok(address) => Ok(Some(address)),
+            Err(Error::NoResult) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn reverse_resolve(&self, address: &str) -> Result<Option<String>, Error> {
+        get_name(*self, address).await
+    }
+}
+
 pub struct SpaceId {}
 
 #[async_trait]
 impl Fetcher for SpaceId {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
     fn can_fetch(target: &Target) -> bool {
-        target.in_platform_supported(vec![Platform::SpaceId, Platform::Ethereum])
+        match target {
+            Target::Identity(Platform::SpaceId, domain) => {
+                SpaceIdTld::from_domain(&domain.to_lowercase()).is_some()
+            }
+            _ => target.in_platform_supported(vec![Platform::Ethereum]),
+        }
     }
 }
 
 async fn fetch_connections_by_platform_identity(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     match *platform {
         Platform::Ethereum => fetch_domain_by_address(platform, identity).await,
         Platform::SpaceId => fetch_address_by_domain(platform, identity).await,
-        _ => Ok(vec![]),
+        _ => Ok(Fetched::default()),
     }
 }
-async fn fetch_domain_by_address(
-    _platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
-    let name = get_name(identity).await?;
-    if name.is_none() {
-        // name=null, address does not have a valid primary name
-        return Ok(vec![]);
+
+async fn fetch_domain_by_address(_platform: &Platform, identity: &str) -> Result<Fetched, Error> {
+    let mut fetched = Fetched::default();
+    for tld in SpaceIdTld::ALL {
+        // name=null, address does not have a valid primary name under this TLD
+        if let Some(name) = tld.reverse_resolve(identity).await? {
+            fetched
+                .connections
+                .push(domain_connection(identity, &name, true));
+            fetched
+                .next_targets
+                .push(Target::Identity(Platform::SpaceId, name));
+        }
     }
 
-    let eth_identity: Identity = Identity {
-        uuid: Some(Uuid::new_v4()),
-        platform: Platform::Ethereum,
-        identity: identity.to_lowercase(),
-        created_at: None,
-        display_name: None,
-        added_at: naive_now(),
-        avatar_url: None,
-        profile_url: None,
-        updated_at: naive_now(),
-    };
+    Ok(fetched)
+}
 
-    let sid_identity: Identity = Identity {
-        uuid: Some(Uuid::new_v4()),
-        platform: Platform::SpaceId,
-        identity: name.clone().unwrap(),
-        created_at: None,
-        display_name: None,
-        added_at: naive_now(),
-        avatar_url: None,
-        profile_url: None,
-        updated_at: naive_now(),
+async fn fetch_address_by_domain(_platform: &Platform, identity: &str) -> Result<Fetched, Error> {
+    let domain = identity.to_lowercase();
+    let tld = match SpaceIdTld::from_domain(&domain) {
+        Some(tld) => tld,
+        None => return Ok(Fetched::default()),
     };
-
-    let hold: Hold = Hold {
-        uuid: Uuid::new_v4(),
-        source: DataSource::SpaceId,
-        transaction: None,
-        id: "".to_string(),
-        created_at: None,
-        updated_at: naive_now(),
-        fetcher: DataFetcher::RelationService,
+    let address = match tld.resolve(&domain).await? {
+        Some(address) => address.to_lowercase(),
+        None => return Ok(Fetched::default()),
     };
 
-    let resolve: Resolve = Resolve {
-        uuid: Uuid::new_v4(),
-        source: DataSource::SpaceId,
-        system: DomainNameSystem::SpaceId,
-        name: name.clone().unwrap(),
-        fetcher: DataFetcher::RelationService,
-        updated_at: naive_now(),
-    };
-    let reverse: Resolve = Resolve {
-        uuid: Uuid::new_v4(),
-        source: DataSource::SpaceId,
-        system: DomainNameSystem::SpaceId,
-        name: name.clone().unwrap(),
-        fetcher: DataFetcher::RelationService,
-        updated_at: naive_now(),
-    };
+    // lookup reverse resolve name
+    let is_primary = tld.reverse_resolve(&address).await?.as_ref() == Some(&domain);
 
-    // hold record
-    create_identity_to_identity_hold_record(&db, &eth_identity, &sid_identity, &hold).await?;
-    // 'regular' resolution involves mapping from a name to an address.
-    create_domain_resolve_record(&db, &sid_identity, &eth_identity, &resolve).await?;
-    // 'reverse' resolution maps from an address back to a name.
-    create_domain_resolve_record(&db, &eth_identity, &sid_identity, &reverse).await?;
-
-    return Ok(vec![Target::Identity(
-        Platform::SpaceId,
-        name.clone().unwrap(),
-    )]);
+    Ok(Fetched {
+        connections: vec![domain_connection(&address, &domain, is_primary)],
+        next_targets: vec![Target::Identity(Platform::Ethereum, address)],
+        ..Default::default()
+    })
 }
 
-async fn fetch_address_by_domain(
-    _platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
-    let address = get_address(identity).await?;
-
+fn domain_connection(address: &str, domain: &str, is_primary: bool) -> Connection {
     let eth_identity: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::Ethereum,
-        identity: address.clone().to_lowercase(),
+        identity: address.to_lowercase(),
         created_at: None,
         display_name: None,
         added_at: naive_now(),
@@ -167,7 +181,7 @@ async fn fetch_address_by_domain(
     let sid_identity: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::SpaceId,
-        identity: identity.to_string(),
+        identity: domain.to_string(),
         created_at: None,
         display_name: None,
         added_at: naive_now(),
@@ -189,42 +203,29 @@ async fn fetch_address_by_domain(
         uuid: Uuid::new_v4(),
         source: DataSource::SpaceId,
         system: DomainNameSystem::SpaceId,
-        name: identity.to_string(),
+        name: domain.to_string(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: None,
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
 
-    // hold record
-    create_identity_to_identity_hold_record(&db, &eth_identity, &sid_identity, &hold).await?;
-    // 'regular' resolution involves mapping from a name to an address.
-    create_domain_resolve_record(&db, &sid_identity, &eth_identity, &resolve).await?;
-
-    // lookup reverse resolve name
-    if let Some(domain) = get_name(&address).await? {
-        // 'reverse' resolution maps from an address back to a name.
-        let reverse: Resolve = Resolve {
-            uuid: Uuid::new_v4(),
-            source: DataSource::SpaceId,
-            system: DomainNameSystem::SpaceId,
-            name: domain,
-            fetcher: DataFetcher::RelationService,
-            updated_at: naive_now(),
-        };
-        create_domain_resolve_record(&db, &eth_identity, &sid_identity, &reverse).await?;
+    Connection::Domain {
+        address: eth_identity,
+        domain: sid_identity,
+        hold,
+        resolve,
+        is_primary,
     }
-
-    return Ok(vec![Target::Identity(
-        Platform::Ethereum,
-        address.clone().to_lowercase(),
-    )]);
 }
 
 /// Resolve Names: https://docs.space.id/developer-guide/web3-name-sdk/sid-api#resolve-names
-async fn get_address(domain: &str) -> Result<String, Error> {
+async fn get_address(tld: SpaceIdTld, domain: &str) -> Result<String, Error> {
     let client = make_client();
     let uri: http::Uri = format!(
-        "{}/v1/getAddress?tld=bnb&domain={}",
+        "{}/v1/getAddress?tld={}&domain={}",
         C.upstream.spaceid_api.url.clone(),
+        tld.api_tld(),
         domain
     )
     .parse()
@@ -271,11 +272,12 @@ async fn get_address(domain: &str) -> Result<String, Error> {
 }
 
 /// Reverse Resolve Names: https://docs.space.id/developer-guide/web3-name-sdk/sid-api#reverse-resolve-names
-async fn get_name(address: &str) -> Result<Option<String>, Error> {
+async fn get_name(tld: SpaceIdTld, address: &str) -> Result<Option<String>, Error> {
     let client = make_client();
     let uri: http::Uri = format!(
-        "{}/v1/getName?tld=bnb&address={}",
+        "{}/v1/getName?tld={}&address={}",
         C.upstream.spaceid_api.url.clone(),
+        tld.api_tld(),
         address
     )
     .parse()
@@ -310,5 +312,5 @@ async fn get_name(address: &str) -> Result<Option<String>, Error> {
             return Err(Error::General(err_message, resp.status()));
         }
     };
-    Ok(result.name)
+    Ok(result.name.map(|name| name.to_lowercase()))
 }
@@ -1,13 +1,27 @@
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
-    use crate::upstream::space_id::{get_address, get_name};
+    use crate::upstream::space_id::{get_address, get_name, SpaceIdTld};
+
+    #[test]
+    fn test_tld_from_domain() {
+        assert_eq!(
+            SpaceIdTld::from_domain("sujiyan.bnb"),
+            Some(SpaceIdTld::Bnb)
+        );
+        assert_eq!(
+            SpaceIdTld::from_domain("sujiyan.arb"),
+            Some(SpaceIdTld::Arb)
+        );
+        assert_eq!(SpaceIdTld::from_domain(".bnb"), None);
+        assert_eq!(SpaceIdTld::from_domain("sujiyan.eth"), None);
+    }
 
     #[tokio::test]
     async fn test_get_address() -> Result<(), Error> {
         // let domain = "nopayable.bnb";
         let domain = "sujiyan.bnb";
-        let address = get_address(&domain).await?;
+        let address = get_address(SpaceIdTld::Bnb, &domain).await?;
         println!("address: {:?}", address.to_lowercase());
         Ok(())
     }
@@ -16,7 +30,9 @@ mod tests {
     async fn test_get_name() -> Result<(), Error> {
         // 0xB86fF7E3F4E6186DfD25cFF40605441D0c0481c4
         let address = "0x934b510d4c9103e6a87aef13b816fb080286d649";
-        let name = get_name(&address).await?;
+        let name = get_name(SpaceIdTld::Bnb, &address).await?;
+        println!("name: {:?}", name);
+        let name = get_name(SpaceIdTld::Arb, &address).await?;
         println!("name: {:?}", name);
         Ok(())
     }
@@ -5,13 +5,18 @@ mod tests;
 use crate::config::C;
 use crate::error::Error;
 use crate::graph::edge::ProofRecord;
-use crate::graph::{edge::Proof, new_db_connection, vertex::Identity};
+use crate::graph::{
+    db_connection,
+    edge::{Proof, ProofLevel},
+    vertex::Identity,
+};
 use crate::graph::{Edge, Vertex};
-use crate::upstream::{DataSource, Fetcher, Platform, TargetProcessedList};
+use crate::upstream::{import::import, Connection, DataSource, Fetched, Fetcher, Platform};
 use crate::util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive};
 use aragog::query::{Comparison, Filter, QueryResult};
-use aragog::{DatabaseConnection, DatabaseRecord, EdgeRecord, Record};
+use aragog::{AqlQuery, DatabaseAccess, DatabaseConnection, DatabaseRecord, EdgeRecord, Record};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use http::StatusCode;
 use hyper::{Body, Method};
 use serde::Deserialize;
@@ -20,8 +25,6 @@ use tracing::{debug, info};
 
 use uuid::Uuid;
 
-use futures::future::join_all;
-
 use super::{DataFetcher, Target};
 
 #[derive(Deserialize, Debug)]
@@ -51,12 +54,38 @@ pub struct ErrorResponse {
 
 pub struct SybilList {}
 
-async fn save_item(
-    db: &DatabaseConnection,
+/// `created_at` of the latest SybilList proof saved in database (if any).
+async fn latest_record_created_at(db: &DatabaseConnection) -> Result<Option<NaiveDateTime>, Error> {
+    let aql = AqlQuery::new(
+        r"FOR p IN @@proofs
+        FILTER p.source == @source
+        SORT p.created_at DESC
+        LIMIT 1
+        RETURN p.created_at",
+    )
+    .bind_var("@proofs", Proof::COLLECTION_NAME)
+    .bind_var("source", DataSource::SybilList.to_string())
+    .batch_size(1)
+    .count(false);
+    let result: Vec<Option<NaiveDateTime>> = db.database().aql_query(aql).await?;
+
+    Ok(result.into_iter().next().flatten())
+}
+
+/// Parse one record of sybil list.
+/// Records which are not newer than `since` are skipped.
+fn parse_item(
     eth_wallet_address: String,
     value: Value,
-) -> Option<(Platform, String)> {
+    since: Option<NaiveDateTime>,
+) -> Option<Connection> {
     let item: VerifiedItem = serde_json::from_value(value).ok()?;
+    // Negative (malformed) timestamps are skipped.
+    let create_ms_time: u32 = (item.twitter.timestamp % 1000).try_into().ok()?;
+    let created_at = timestamp_to_naive(item.twitter.timestamp / 1000, create_ms_time); // millisecond
+    if since.map_or(false, |since| created_at <= since) {
+        return None;
+    }
 
     let from: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
@@ -70,7 +99,6 @@ async fn save_item(
         profile_url: None,
         updated_at: naive_now(),
     };
-    let from_record = from.create_or_update(db).await.ok()?;
 
     let to: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
@@ -83,28 +111,26 @@ async fn save_item(
         profile_url: None,
         updated_at: naive_now(),
     };
-    let to_record = to.create_or_update(db).await.ok()?;
 
-    let create_ms_time: u32 = (item.twitter.timestamp % 1000).try_into().unwrap();
     let pf: Proof = Proof {
         uuid: Uuid::new_v4(),
         source: DataSource::SybilList,
         record_id: Some(item.twitter.tweet_id),
-        created_at: Some(timestamp_to_naive(
-            item.twitter.timestamp / 1000,
-            create_ms_time,
-        )), // millisecond
+        created_at: Some(created_at),
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
     };
 
-    pf.two_way_binding(db, &from_record, &to_record)
-        .await
-        .ok()?;
-    Some((Platform::Twitter, item.twitter.handle.clone()))
+    Some(Connection::TwoWayBinding(from, to, pf))
 }
 
 /// Trigger a refetch from github.
+/// Only records newer than the latest one in database will be imported.
 pub async fn prefetch() -> Result<(), Error> {
     let client = make_client();
     let uri: http::Uri = (C.upstream.sybil_service.url).parse().unwrap();
@@ -131,33 +157,37 @@ pub async fn prefetch() -> Result<(), Error> {
     let body: Map<String, Value> = parse_body(&mut resp).await?;
 
     // parse
-    let db = new_db_connection().await?;
-    let futures: Vec<_> = body
+    let db = db_connection().await?;
+    let since = latest_record_created_at(&db).await?;
+    let total = body.len();
+    let connections: Vec<Connection> = body
         .into_iter()
-        .map(|(eth_wallet_address, value)| save_item(&db, eth_wallet_address, value))
+        .filter_map(|(eth_wallet_address, value)| parse_item(eth_wallet_address, value, since))
         .collect();
-    let _ = join_all(futures).await;
+    let saved = connections.len();
+    import(&db, &connections).await?;
+    info!(total, saved, ?since, "Sybil list: prefetch completed");
     Ok(())
 }
 
 #[async_trait]
 impl Fetcher for SybilList {
     /// Only search sybil list in local database, no download process should occur.
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         let platform = target.platform()?;
         let identity = target.identity()?;
-        let db = new_db_connection().await?;
+        let db = db_connection().await?;
         let found = Identity::find_by_platform_identity(&db, &platform, &identity).await?;
         if found.is_none() {
             info!(
                 "Sybil list: {} not found in local sybil list record",
                 target,
             );
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match platform {
@@ -173,15 +203,18 @@ impl Fetcher for SybilList {
 
                 if result.len() == 0 {
                     debug!("No sybil list record found for {}", identity);
-                    Ok(vec![])
+                    Ok(Fetched::default())
                 } else {
                     let found: ProofRecord = result.first().unwrap().clone().into();
                     let next_target: DatabaseRecord<Identity> = found.record.to_record(&db).await?;
 
-                    Ok(vec![Target::Identity(
-                        next_target.platform,
-                        next_target.identity.clone(),
-                    )])
+                    Ok(Fetched {
+                        next_targets: vec![Target::Identity(
+                            next_target.platform,
+                            next_target.identity.clone(),
+                        )],
+                        ..Default::default()
+                    })
                 }
             }
             Platform::Twitter => {
@@ -194,16 +227,19 @@ impl Fetcher for SybilList {
 
                 if result.len() == 0 {
                     debug!("No sybil list record found for {}", identity);
-                    Ok(vec![])
+                    Ok(Fetched::default())
                 } else {
                     let found: ProofRecord = result.first().unwrap().clone().into();
                     let next_target: DatabaseRecord<Identity> =
                         found.record.from_record(&db).await?;
 
-                    Ok(vec![Target::Identity(
-                        next_target.platform,
-                        next_target.identity.clone(),
-                    )])
+                    Ok(Fetched {
+                        next_targets: vec![Target::Identity(
+                            next_target.platform,
+                            next_target.identity.clone(),
+                        )],
+                        ..Default::default()
+                    })
                 }
             }
             _ => Err(Error::General(
@@ -2,7 +2,7 @@ use crate::{
     error::Error,
     graph::{new_db_connection, vertex::Identity},
     upstream::{
-        sybil_list::{prefetch, SybilList},
+        sybil_list::{parse_item, prefetch, SybilList},
         Target,
     },
     upstream::{Fetcher, Platform},
@@ -29,8 +29,20 @@ async fn test_get_sybil_result() -> Result<(), Error> {
     .expect("Record not found");
     assert_eq!(
         Target::Identity(Platform::Twitter, "MonetSupply".into()),
-        *fetched.first().unwrap()
+        *fetched.next_targets.first().unwrap()
     );
 
     Ok(())
 }
+
+#[test]
+fn test_parse_item() {
+    let item = |timestamp: i64| {
+        serde_json::json!({
+            "twitter": { "timestamp": timestamp, "tweetID": "1", "handle": "MonetSupply" }
+        })
+    };
+    let address = "0x4306D8e8AC2a9C893Ac1cd137a0Cd6966Fa6B6Ff".to_string();
+    assert!(parse_item(address.clone(), item(1668412800123), None).is_some());
+    assert!(parse_item(address, item(-1), None).is_none());
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout, timestamp_to_naive},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+/// Bindings collected by the configured Telegram verification bot.
+/// The bot asks a Telegram user to sign a challenge with their wallet,
+/// and only publishes bindings whose signature is valid.
+#[derive(Deserialize, Debug)]
+pub struct BindingsResponse {
+    #[serde(default)]
+    pub bindings: Vec<Binding>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Binding {
+    pub user_id: i64,
+    pub username: String,
+    pub address: String,
+    pub signature: String,
+    /// Unix timestamp in seconds.
+    pub created_at: i64,
+    pub is_valid: bool,
+}
+
+pub struct Telegram {}
+
+#[async_trait]
+impl Fetcher for Telegram {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(platform, identity) => {
+                fetch_bindings_by_platform_identity(platform, identity).await
+            }
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        // Verification bot is optional. Next.ID proofs are handled by `ProofClient`.
+        !C.upstream.telegram_bot.url.is_empty()
+            && target.in_platform_supported(vec![Platform::Telegram, Platform::Ethereum])
+    }
+}
+
+/// Telegram usernames are case-insensitive and often written with a leading `@`.
+pub fn normalize_username(username: &str) -> String {
+    username.trim().trim_start_matches('@').to_lowercase()
+}
+
+async fn fetch_bindings(platform: &Platform, identity: &str) -> Result<Vec<Binding>, Error> {
+    let client = make_client();
+    let query = match platform {
+        Platform::Telegram => format!("username={}", normalize_username(identity)),
+        _ => format!("address={}", identity.to_lowercase()),
+    };
+    let uri: http::Uri = format!("{}/bindings?{}", C.upstream.telegram_bot.url, query)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header(
+            "Authorization",
+            format!("Bearer {}", C.upstream.telegram_bot.token),
+        )
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Telegram Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Telegram fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Telegram Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: BindingsResponse = parse_body(&mut resp).await?;
+    Ok(body.bindings)
+}
+
+async fn fetch_bindings_by_platform_identity(
+    platform: &Platform,
+    identity: &str,
+) -> Result<Fetched, Error> {
+    let bindings = fetch_bindings(platform, identity).await?;
+    if bindings.is_empty() {
+        info!("Telegram fetch | {}: {} has no binding", platform, identity);
+        return Ok(Fetched::default());
+    }
+
+    let mut fetched = Fetched::default();
+    for binding in bindings.into_iter() {
+        if !binding.is_valid {
+            debug!("Telegram fetch | invalid binding: {:?}", binding);
+            continue;
+        }
+        let username = normalize_username(&binding.username);
+        let address = binding.address.to_lowercase();
+        let created_at = Some(timestamp_to_naive(binding.created_at, 0));
+
+        let telegram_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Telegram,
+            identity: username.clone(),
+            created_at: None,
+            display_name: Some(binding.username.trim_start_matches('@').to_string()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: Some(format!("https://t.me/{}", username)),
+            updated_at: naive_now(),
+        };
+        let eth_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Ethereum,
+            identity: address.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Telegram,
+            record_id: Some(binding.signature.clone()),
+            created_at,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            telegram_identity,
+            eth_identity,
+            proof,
+        ));
+
+        match platform {
+            Platform::Telegram => fetched
+                .next_targets
+                .push(Target::Identity(Platform::Ethereum, address)),
+            _ => fetched
+                .next_targets
+                .push(Target::Identity(Platform::Telegram, username)),
+        }
+    }
+
+    Ok(fetched)
+}
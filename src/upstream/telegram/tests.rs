@@ -0,0 +1,14 @@
+use crate::upstream::{telegram::normalize_username, Platform};
+use std::str::FromStr;
+
+#[test]
+fn test_normalize_username() {
+    assert_eq!(normalize_username("@Suji_Yan"), "suji_yan");
+    assert_eq!(normalize_username(" suji_yan "), "suji_yan");
+}
+
+#[test]
+fn test_platform_from_str() {
+    // Next.ID proofs and Keybase nametags use this name.
+    assert_eq!(Platform::from_str("telegram").unwrap(), Platform::Telegram);
+}
@@ -1,10 +1,20 @@
+use crate::config::C;
 use crate::error::Error;
-use crate::upstream::{fetch_all, fetch_one, Platform, Target};
+use crate::graph::{new_db_connection, vertex::Identity};
+use crate::upstream::breaker::{Breaker, CircuitState};
+use crate::upstream::{
+    fetch_all, fetch_one, import::import, next_round, provenance, upstreams_of, Connection,
+    DataSource, Platform, Target, UPSTREAMS,
+};
+use fake::{Fake, Faker};
+use http::StatusCode;
+use std::{collections::HashSet, time::Duration};
+use strum::IntoEnumIterator;
 
 #[tokio::test]
 async fn test_fetch_one_result() -> Result<(), Error> {
     let result = fetch_one(&Target::Identity(Platform::Twitter, "yeiwb".into())).await?;
-    assert_ne!(result.len(), 0);
+    assert_ne!(result.fetched.next_targets.len(), 0);
 
     Ok(())
 }
@@ -15,3 +25,174 @@ async fn test_fetch_all() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_upstreams_fetchers_for() {
+    let names: Vec<_> = UPSTREAMS
+        .fetchers_for(&Target::Identity(Platform::Twitter, "yeiwb".into()))
+        .into_iter()
+        .map(|upstream| upstream.name)
+        .collect();
+    assert!(names.contains(&"keybase"));
+    assert!(!names.contains(&"dotbit"));
+    assert!(UPSTREAMS.upstreams().len() >= names.len());
+}
+
+#[test]
+fn test_upstreams_of() {
+    let names: HashSet<_> = UPSTREAMS
+        .upstreams()
+        .iter()
+        .map(|upstream| upstream.name)
+        .collect();
+    for source in DataSource::iter() {
+        for name in upstreams_of(&source) {
+            assert!(
+                names.contains(name),
+                "{} owned by unknown upstream {}",
+                source,
+                name
+            );
+        }
+    }
+    assert!(upstreams_of(&DataSource::SybilList).is_empty());
+}
+
+#[tokio::test]
+async fn test_upstreams_fetch_all_partial() {
+    // Some upstreams may fail, the rest of them are returned anyway.
+    let target = Target::Identity(Platform::Twitter, "yeiwb".into());
+    let outcome = UPSTREAMS.fetch_all(&target).await;
+    let names: Vec<_> = UPSTREAMS
+        .fetchers_for(&target)
+        .into_iter()
+        .map(|upstream| upstream.name)
+        .collect();
+    assert!(outcome
+        .errors
+        .iter()
+        .all(|err| names.contains(&err.upstream.as_str()) && err.target == target.to_string()));
+}
+
+#[tokio::test]
+async fn test_upstreams_fetch_all_within() {
+    // Upstreams still running after timeout are reported as failed, not waited for.
+    let target = Target::Identity(Platform::Twitter, "yeiwb".into());
+    let outcome = UPSTREAMS
+        .fetch_all_within(&target, Duration::from_millis(1))
+        .await;
+    assert!(!outcome.errors.is_empty());
+    assert!(outcome
+        .errors
+        .iter()
+        .all(|err| err.error.starts_with("Timeout")));
+}
+
+#[test]
+fn test_breaker() {
+    let failure: Result<(), Error> = Err(Error::General("Down".into(), StatusCode::BAD_GATEWAY));
+    let breaker = Breaker::default();
+    for _ in 0..C.breaker.failures {
+        assert!(breaker.allow());
+        breaker.record("test", &failure);
+    }
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert!(!breaker.allow());
+    assert_eq!(breaker.trips(), 1);
+
+    // A success in between starts counting again.
+    let breaker = Breaker::default();
+    for _ in 1..C.breaker.failures {
+        breaker.record("test", &failure);
+    }
+    breaker.record("test", &Ok(()));
+    breaker.record("test", &failure);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    // Not found, or errors of the request itself, are not failures.
+    let breaker = Breaker::default();
+    for _ in 0..C.breaker.failures {
+        breaker.record::<()>("test", &Err(Error::UpstreamNotFound("yeiwb".into())));
+        breaker.record::<()>("test", &Err(Error::ParamError("yeiwb".into())));
+        breaker.record::<()>("test", &Err(Error::InvalidIdentityFormat("yeiwb".into())));
+        breaker.record::<()>(
+            "test",
+            &Err(Error::General("Bad".into(), StatusCode::BAD_REQUEST)),
+        );
+    }
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    // Timeouts are.
+    let breaker = Breaker::default();
+    for _ in 0..C.breaker.failures {
+        breaker.record::<()>(
+            "test",
+            &Err(Error::General(
+                "Timeout".into(),
+                StatusCode::REQUEST_TIMEOUT,
+            )),
+        );
+    }
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+#[test]
+fn test_next_round() {
+    let twitter = Target::Identity(Platform::Twitter, "yeiwb".into());
+    let github = Target::Identity(Platform::Github, "yeiwb".into());
+    let keybase = Target::Identity(Platform::Keybase, "yeiwb".into());
+    let processed = HashSet::from([twitter.clone()]);
+    let up_next = vec![twitter, github.clone(), github.clone(), keybase.clone()];
+
+    assert_eq!(
+        next_round(up_next.clone(), &processed, 10),
+        vec![github.clone(), keybase]
+    );
+    assert_eq!(next_round(up_next, &processed, 1), vec![github]);
+}
+
+#[tokio::test]
+async fn test_provenance_collect() {
+    let target = Target::Identity(Platform::Twitter, "yeiwb".into());
+    let keybase = UPSTREAMS
+        .upstreams()
+        .iter()
+        .find(|upstream| upstream.name == "keybase")
+        .unwrap();
+
+    let (_, records) = provenance::collect(keybase.fetch(&target)).await;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].upstream, "keybase");
+    assert_eq!(records[0].target, target.to_string());
+
+    // Calls out of `collect` are not recorded anywhere.
+    let _ = keybase.fetch(&target).await;
+    let (_, records) = provenance::collect(async {}).await;
+    assert!(records.is_empty());
+}
+
+#[tokio::test]
+async fn test_import_skip_malformed() -> Result<(), Error> {
+    let db = new_db_connection().await?;
+    let valid = Identity {
+        platform: Platform::Twitter,
+        identity: format!("import_{}", uuid::Uuid::new_v4()),
+        ..Faker.fake()
+    };
+    let invalid = Identity {
+        platform: Platform::Ethereum,
+        identity: "not an address".into(),
+        ..Faker.fake()
+    };
+    let connections = vec![
+        Connection::Identity(valid.clone()),
+        Connection::Identity(invalid.clone()),
+        Connection::Proof(valid.clone(), invalid, Faker.fake()),
+    ];
+
+    import(&db, &connections).await?;
+    // Only the malformed identity and its connections are skipped.
+    let found = Identity::find_by_platform_identity(&db, &valid.platform, &valid.identity).await?;
+    assert!(found.is_some());
+    Ok(())
+}
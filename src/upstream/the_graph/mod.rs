@@ -5,19 +5,15 @@ use crate::{
     config::C,
     error::Error,
     graph::{
-        create_identity_to_contract_record,
         edge::{hold::Hold, resolve::DomainNameSystem, Resolve},
-        new_db_connection,
         vertex::{
             contract::{Chain, ContractCategory},
-            Contract, ContractRecord, Identity,
+            Contract, Identity,
         },
-        Edge, Vertex,
     },
-    upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList},
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
     util::{naive_now, parse_timestamp},
 };
-use aragog::DatabaseConnection;
 use async_trait::async_trait;
 use gql_client::Client;
 use serde::{Deserialize, Serialize};
@@ -43,6 +39,9 @@ struct Domain {
     /// Creation timestamp (in secods)
     #[serde(rename = "createdAt")]
     created_at: String,
+    /// Registration expiry timestamp (in seconds), `null` for subdomains.
+    #[serde(default, rename = "expiryDate")]
+    expiry_date: Option<String>,
     /// ETH event logs for this ENS.
     events: Vec<DomainEvent>,
     /// Reverse resolve record set on this ENS.
@@ -76,6 +75,7 @@ const QUERY_BY_ENS: &str = r#"
             domains(where: { name: $target }) {
                 name
                 createdAt
+                expiryDate
                 events(first: 1) {
                     transactionID
                 }
@@ -91,6 +91,7 @@ const QUERY_BY_ENS: &str = r#"
               domain {
                 name
                 createdAt
+                expiryDate
                 events(first: 1) {
                     transactionID
                 }
@@ -113,6 +114,7 @@ const QUERY_BY_WALLET: &str = r#"
             domains(where: { owner: $target }) {
                 name
                 createdAt
+                expiryDate
                 events(first: 1) {
                     transactionID
                 }
@@ -128,6 +130,7 @@ const QUERY_BY_WALLET: &str = r#"
               domain {
                 name
                 createdAt
+                expiryDate
                 events(first: 1) {
                     transactionID
                 }
@@ -149,9 +152,9 @@ pub struct TheGraph {}
 
 #[async_trait]
 impl Fetcher for TheGraph {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         perform_fetch(target).await
@@ -167,7 +170,7 @@ impl Fetcher for TheGraph {
 /// See also: https://github.com/ensdomains/ens-subgraph/issues/25
 /// Consider deploy a self-hosted reverse lookup service like:
 /// https://github.com/fafrd/ens-reverse-lookup
-async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+async fn perform_fetch(target: &Target) -> Result<Fetched, Error> {
     let query: String;
     let target_var: String;
     match target {
@@ -187,7 +190,7 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
     let resp = client.query_with_vars::<QueryResponse, QueryVars>(&query, vars);
 
     let data: Option<QueryResponse> =
-        match tokio::time::timeout(std::time::Duration::from_secs(5), resp).await {
+        match tokio::time::timeout(std::time::Duration::from_secs(C.http.timeout), resp).await {
             Ok(resp) => match resp {
                 Ok(resp) => resp,
                 Err(err) => {
@@ -196,14 +199,17 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
                 }
             },
             Err(_) => {
-                warn!(?target, "TheGraph: Timeout: no response in 5 seconds.");
+                warn!(
+                    ?target,
+                    "TheGraph: Timeout: no response in {} seconds.", C.http.timeout
+                );
                 None
             }
         };
 
     if data.is_none() {
         info!(?target, "TheGraph: No result");
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
     let res = data.unwrap();
     debug!(?target, wrapped = res.wrapped_domains.len(), domains = res.domains.len(), "Records found.");
@@ -226,23 +232,18 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
 
     if merged_domains.is_empty() {
         info!(?target, "TheGraph: No result");
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
-    let db = new_db_connection().await?;
-    let mut next_targets: TargetProcessedList = vec![];
+    let mut fetched = Fetched::default();
 
     for domain in merged_domains.into_iter() {
-        // Create own record
-        let contract_record = create_or_update_own(&db, &domain).await?;
-
         // Deal with resolve target.
-        let resolved_address = domain.resolved_address.map(|r| r.id);
-        match resolved_address.clone() {
+        let resolved_address = domain.resolved_address.as_ref().map(|r| r.id.clone());
+        let resolved = match resolved_address.clone() {
             Some(address) => {
                 if address != "0x0000000000000000000000000000000000000000".to_string() {
-                    // Create resolve record
                     debug!(?target, address, domain = domain.name, "TheGraph: Resolved");
-                    let resolve_target = Identity {
+                    Some(Identity {
                         uuid: Some(Uuid::new_v4()),
                         platform: Platform::Ethereum,
                         identity: address.clone(),
@@ -252,33 +253,25 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
                         avatar_url: None,
                         profile_url: None,
                         updated_at: naive_now(),
-                    }
-                    .create_or_update(&db)
-                    .await?;
-                    let resolve = Resolve {
-                        uuid: Uuid::new_v4(),
-                        source: DataSource::TheGraph,
-                        system: DomainNameSystem::ENS,
-                        name: domain.name.clone(),
-                        fetcher: DataFetcher::RelationService,
-                        updated_at: naive_now(),
-                    };
-
-                    // 'reverse' resolution
-                    resolve
-                        .connect(&db, &resolve_target, &contract_record)
-                        .await?;
+                    })
+                } else {
+                    None
                 }
             }
             None => {
                 // Resolve record not existed anymore. Maybe deleted by user.
                 // TODO: Should find existed connection and delete it.
+                None
             }
-        }
+        };
+        // Create own and resolve record
+        fetched
+            .connections
+            .push(domain_connection(&domain, resolved));
 
         // Append up_next
         match target {
-            Target::Identity(_, _) => next_targets.push(Target::NFT(
+            Target::Identity(_, _) => fetched.next_targets.push(Target::NFT(
                 Chain::Ethereum,
                 ContractCategory::ENS,
                 ContractCategory::ENS.default_contract_address().unwrap(),
@@ -286,9 +279,11 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
             )),
             Target::NFT(_, _, _, _) => {
                 let owner_address = domain.owner.id.clone();
-                next_targets.push(Target::Identity(Platform::Ethereum, owner_address.clone()));
+                fetched
+                    .next_targets
+                    .push(Target::Identity(Platform::Ethereum, owner_address.clone()));
                 if resolved_address.is_some() && resolved_address != Some(owner_address) {
-                    next_targets.push(Target::Identity(
+                    fetched.next_targets.push(Target::Identity(
                         Platform::Ethereum,
                         resolved_address.unwrap(),
                     ));
@@ -296,14 +291,12 @@ async fn perform_fetch(target: &Target) -> Result<TargetProcessedList, Error> {
             }
         }
     }
-    Ok(next_targets)
+    Ok(fetched)
 }
 
 /// Focus on `Hold` record.
-async fn create_or_update_own(
-    db: &DatabaseConnection,
-    domain: &Domain,
-) -> Result<ContractRecord, Error> {
+/// `resolved` is the address this domain is resolving to.
+fn domain_connection(domain: &Domain, resolved: Option<Identity>) -> Connection {
     let creation_tx = domain
         .events
         .first() // TODO: really?
@@ -337,20 +330,60 @@ async fn create_or_update_own(
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
     };
-    let (owner_record, contract_record, _hold_record) =
-        create_identity_to_contract_record(db, &owner, &conrtract, &ownership).await?;
-
     let resolve = Resolve {
         uuid: Uuid::new_v4(),
         source: DataSource::TheGraph,
         system: DomainNameSystem::ENS,
         name: domain.name.clone(),
+        resolved_address: domain.resolved_address.as_ref().map(|r| r.id.clone()),
+        expire_at: domain
+            .expiry_date
+            .as_ref()
+            .and_then(|expiry| parse_timestamp(expiry).ok()),
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
-    // As the same time record 'regular' resolution
-    resolve
-        .connect(&db, &contract_record, &owner_record)
-        .await?;
-    Ok(contract_record)
+    Connection::ContractDomain {
+        owner,
+        contract: conrtract,
+        hold: ownership,
+        resolve,
+        resolved,
+    }
+}
+
+/// Find the address which an ENS name is resolving to.
+pub(crate) async fn resolve_ens_name(name: &str) -> Result<Option<String>, Error> {
+    let client = Client::new(&C.upstream.the_graph.ens);
+    let vars = QueryVars {
+        target: name.to_string(),
+    };
+    let resp = client.query_with_vars::<QueryResponse, QueryVars>(QUERY_BY_ENS, vars);
+
+    let data: Option<QueryResponse> =
+        match tokio::time::timeout(std::time::Duration::from_secs(C.http.timeout), resp).await {
+            Ok(resp) => match resp {
+                Ok(resp) => resp,
+                Err(err) => {
+                    warn!(name, ?err, "TheGraph: Failed to resolve");
+                    None
+                }
+            },
+            Err(_) => {
+                warn!(
+                    name,
+                    "TheGraph: Timeout: no response in {} seconds.", C.http.timeout
+                );
+                None
+            }
+        };
+
+    Ok(data.and_then(|res| {
+        res.wrapped_domains
+            .into_iter()
+            .map(|wd| wd.domain)
+            .chain(res.domains.into_iter())
+            .find_map(|domain| domain.resolved_address)
+            .map(|account| account.id)
+    }))
 }
@@ -9,7 +9,7 @@ use crate::{
         vertex::Identity,
         vertex::{contract::ContractCategory, Contract},
     },
-    upstream::{the_graph::TheGraph, DataFetcher, DataSource, Fetcher, Platform, Target},
+    upstream::{fetch_and_import, the_graph::TheGraph, DataFetcher, DataSource, Platform, Target},
     util::parse_timestamp,
 };
 
@@ -22,7 +22,7 @@ async fn test_find_ens_by_wallet() -> Result<(), Error> {
         Platform::Ethereum,
         "0xd8da6bf26964af9d7eed9e03e53415d37aa96045".into(),
     );
-    let targets = TheGraph::fetch(&target).await?;
+    let targets = fetch_and_import::<TheGraph>(&target).await?;
     println!("targets {:?}", targets);
 
     Identity::find_by_platform_identity(&db, &Platform::Ethereum, &target.identity()?)
@@ -65,7 +65,7 @@ async fn test_find_wallet_by_ens() -> Result<(), Error> {
         ContractCategory::ENS.default_contract_address().unwrap(),
         "vitalik.eth".into(),
     );
-    let address_targets = TheGraph::fetch(&target).await?;
+    let address_targets = fetch_and_import::<TheGraph>(&target).await?;
     println!("targets {:?}", address_targets);
     assert!(!address_targets.is_empty());
     assert_eq!(
@@ -116,7 +116,9 @@ async fn test_wrapped_ens_find_by_wallet() -> Result<(), Error> {
     let target = Target::Identity(Platform::Ethereum, owner);
 
     let log = span!(Level::TRACE, "test_wrapped_domains");
-    let address_targets = TheGraph::fetch(&target).instrument(log).await?;
+    let address_targets = fetch_and_import::<TheGraph>(&target)
+        .instrument(log)
+        .await?;
     let _wrapped_ens = address_targets.iter().find(|t| t.nft_id().unwrap() == "nykma.eth").unwrap();
 
     Ok(())
@@ -134,7 +136,7 @@ async fn test_wrapped_ens_find_by_ens() -> Result<(), Error> {
         "nykma.eth".into(),
     );
     let log = span!(Level::TRACE, "test_wrapped_domains");
-    let address_targets = TheGraph::fetch(&ens).instrument(log).await?;
+    let address_targets = fetch_and_import::<TheGraph>(&ens).instrument(log).await?;
     let _wrapped_ens = address_targets.iter().find(|t| t.identity().unwrap() == owner).unwrap();
 
     Ok(())
@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::{contract::ContractCategory, Identity},
+    },
+    upstream::{
+        keybase::lookup, Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target,
+    },
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// https://developer.twitter.com/en/docs/twitter-api/users/lookup/api-reference/get-users-by-username-username
+#[derive(Deserialize, Debug)]
+pub struct Response<T> {
+    pub data: Option<T>,
+    #[serde(default)]
+    pub errors: Vec<ResponseError>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ResponseError {
+    pub detail: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+    pub username: String,
+    pub description: Option<String>,
+    pub profile_image_url: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Tweet {
+    pub id: String,
+    pub text: String,
+    pub created_at: Option<String>,
+}
+
+pub struct Twitter {}
+
+#[async_trait]
+impl Fetcher for Twitter {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, handle) => fetch_proofs_by_handle(handle).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Twitter])
+    }
+}
+
+/// Find Keybase username in a Keybase proof tweet, e.g.
+/// `Verifying myself: I am alice on Keybase.io. 2M6hS0... / https://keybase.io/alice/sigs/2M6hS0...`
+/// Next.ID proof tweets are not handled here: they are collected by `ProofClient`
+/// with tweet ID as `record_id` already.
+fn parse_keybase_proof(text: &str) -> Option<String> {
+    const PREFIX: &str = "Verifying myself: I am ";
+    const SUFFIX: &str = " on Keybase.io.";
+    let start = text.find(PREFIX)? + PREFIX.len();
+    let end = text[start..].find(SUFFIX)?;
+    let username = &text[start..start + end];
+    if username.is_empty() || username.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(username.to_lowercase())
+}
+
+/// Find ENS names (`something.eth`) in user bio.
+pub(crate) fn parse_ens_names(bio: &str) -> Vec<String> {
+    bio.split(|c: char| !(c.is_alphanumeric() || c == '.' || c == '-'))
+        .map(|word| word.trim_matches('.').to_lowercase())
+        .filter(|word| word.len() > ".eth".len() && word.ends_with(".eth"))
+        .collect()
+}
+
+async fn get<T: DeserializeOwned>(path: String) -> Result<T, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}{}", C.upstream.twitter_api.url, path)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header(
+            "Authorization",
+            format!("Bearer {}", C.upstream.twitter_api.token),
+        )
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Twitter Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Twitter fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Twitter Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    parse_body(&mut resp).await
+}
+
+async fn fetch_user(handle: &str) -> Result<Option<User>, Error> {
+    let resp: Response<User> = get(format!(
+        "/2/users/by/username/{}?user.fields=description,profile_image_url,created_at",
+        handle
+    ))
+    .await?;
+    Ok(resp.data)
+}
+
+async fn fetch_tweets(user_id: &str) -> Result<Vec<Tweet>, Error> {
+    let resp: Response<Vec<Tweet>> = get(format!(
+        "/2/users/{}/tweets?max_results=100&exclude=retweets,replies&tweet.fields=created_at",
+        user_id
+    ))
+    .await?;
+    Ok(resp.data.unwrap_or_default())
+}
+
+async fn fetch_proofs_by_handle(handle: &str) -> Result<Fetched, Error> {
+    let handle = handle.trim_start_matches('@').to_lowercase();
+    let user = match fetch_user(&handle).await? {
+        Some(user) => user,
+        None => {
+            info!("Twitter fetch | user {} not found", handle);
+            return Ok(Fetched::default());
+        }
+    };
+    let mut fetched = Fetched::default();
+
+    // ENS in bio is a self-claim only. Let ENS upstreams resolve and verify it.
+    let bio = user.description.clone().unwrap_or_default();
+    for name in parse_ens_names(&bio) {
+        fetched.next_targets.push(Target::NFT(
+            ContractCategory::ENS.default_chain().unwrap(),
+            ContractCategory::ENS,
+            ContractCategory::ENS.default_contract_address().unwrap(),
+            name,
+        ));
+    }
+
+    let tweets = fetch_tweets(&user.id).await?;
+    let twitter_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Twitter,
+        identity: handle.clone(),
+        created_at: user
+            .created_at
+            .as_deref()
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+            .map(|dt| dt.naive_utc()),
+        display_name: Some(user.name.clone()),
+        added_at: naive_now(),
+        avatar_url: user.profile_image_url.clone(),
+        profile_url: Some(format!("https://twitter.com/{}", user.username)),
+        updated_at: naive_now(),
+    };
+    for tweet in tweets.iter() {
+        let keybase_username = match parse_keybase_proof(&tweet.text) {
+            Some(username) => username,
+            None => continue,
+        };
+        // Keybase identity is stored as Keybase user ID.
+        let keybase_user = match lookup(&format!("usernames={}", keybase_username)).await {
+            Ok(person_info) => person_info,
+            Err(err) => {
+                warn!("Twitter fetch | Keybase user {}: {}", keybase_username, err);
+                continue;
+            }
+        };
+        let keybase_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Keybase,
+            identity: keybase_user.id.clone(),
+            created_at: None,
+            display_name: Some(keybase_user.basics.username.clone()),
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: Some(format!("https://keybase.io/{}", keybase_username)),
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Twitter,
+            record_id: Some(tweet.id.clone()),
+            created_at: tweet
+                .created_at
+                .as_deref()
+                .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+                .map(|dt| dt.naive_utc()),
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            twitter_identity.clone(),
+            keybase_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::Keybase, keybase_user.id));
+    }
+
+    Ok(fetched)
+}
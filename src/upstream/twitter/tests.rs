@@ -0,0 +1,26 @@
+use crate::{
+    error::Error,
+    upstream::twitter::{fetch_user, parse_ens_names, parse_keybase_proof},
+};
+
+#[test]
+fn test_parse_keybase_proof() {
+    let text = "Verifying myself: I am Alice on Keybase.io. 2M6hS0ab3VKw / https://keybase.io/alice/sigs/2M6hS0ab3VKw";
+    assert_eq!(parse_keybase_proof(text), Some("alice".into()));
+    assert_eq!(parse_keybase_proof("gm"), None);
+}
+
+#[test]
+fn test_parse_ens_names() {
+    assert_eq!(
+        parse_ens_names("builder. vitalik.eth | sub.name.eth, not.ethereum"),
+        vec!["vitalik.eth".to_string(), "sub.name.eth".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_user() -> Result<(), Error> {
+    let user = fetch_user("suji_yan").await?.expect("User not found");
+    assert_eq!(user.username.to_lowercase(), "suji_yan");
+    Ok(())
+}
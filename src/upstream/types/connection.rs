@@ -0,0 +1,139 @@
+use crate::graph::{
+    edge::{Annotation, Hold, Participate, Proof, Resolve},
+    vertex::{Contract, CryptoKey, Identity},
+    GraphStats,
+};
+
+use super::target::TargetProcessedList;
+use serde::Serialize;
+
+/// A relation found by upstream, to be saved by `upstream::import`.
+#[derive(Debug, Clone)]
+pub enum Connection {
+    /// Identity found without any relation, only its info will be updated.
+    Identity(Identity),
+    /// `Proof(from, to, proof)`: `from` proves `to`.
+    Proof(Identity, Identity, Proof),
+    /// `Republished(from, to, proof)`: `from` proves `to`, re-published by RSS3.
+    /// Skipped if the two identities are already connected by a first-party upstream.
+    Republished(Identity, Identity, Proof),
+    /// `TwoWayBinding(from, to, proof)`: `from` and `to` prove each other.
+    TwoWayBinding(Identity, Identity, Proof),
+    /// `Hold(from, to, hold)`: `from` holds identity `to`.
+    Hold(Identity, Identity, Hold),
+    /// `ContractHold(from, to, hold)`: `from` holds an NFT / token in contract `to`.
+    ContractHold(Identity, Contract, Hold),
+    /// `CryptoKeyHold(from, to, hold)`: `from` holds public key `to`.
+    CryptoKeyHold(Identity, CryptoKey, Hold),
+    /// `Resolve(from, to, resolve)`: domain `from` resolves to `to`.
+    Resolve(Identity, Identity, Resolve),
+    /// Records of a domain held by an address, see `graph::create_domain_records`.
+    Domain {
+        address: Identity,
+        domain: Identity,
+        hold: Hold,
+        resolve: Resolve,
+        is_primary: bool,
+    },
+    /// A domain held as an NFT in `contract` (e.g. ENS):
+    /// `Hold` from `owner` to `contract`, 'regular' `Resolve` from `contract` to `owner`,
+    /// and 'reverse' `Resolve` from `resolved` to `contract`.
+    ContractDomain {
+        owner: Identity,
+        contract: Contract,
+        hold: Hold,
+        resolve: Resolve,
+        resolved: Option<Identity>,
+    },
+    /// `Participate(from, to, participate)`
+    Participate(Identity, Identity, Participate),
+    /// `Annotation(from, to, annotation)`
+    Annotation(Identity, Identity, Annotation),
+}
+
+/// What an upstream found for a target.
+#[derive(Debug, Clone, Default)]
+pub struct Fetched {
+    /// Targets to fetch in next round.
+    pub next_targets: TargetProcessedList,
+    /// Relations to save.
+    pub connections: Vec<Connection>,
+    /// Some results are left out (e.g. pages beyond `crawler.max_pages`),
+    /// so what's missing here may still be there in upstream.
+    pub truncated: bool,
+}
+
+impl Fetched {
+    /// Merge `other` into this one.
+    pub fn extend(&mut self, other: Fetched) {
+        self.next_targets.extend(other.next_targets);
+        self.connections.extend(other.connections);
+        self.truncated |= other.truncated;
+    }
+}
+
+/// An upstream failed to fetch a target.
+#[derive(Debug, Clone, Serialize, async_graphql::SimpleObject)]
+pub struct FetchError {
+    /// Name of upstream, see `UPSTREAMS`.
+    pub upstream: String,
+    /// Target failed to fetch, e.g. `Identity/twitter/alice`.
+    pub target: String,
+    /// Error message.
+    pub error: String,
+}
+
+/// What all upstreams found for a target: connections found by the succeeded ones,
+/// along with errors of the failed ones.
+#[derive(Debug, Clone, Default)]
+pub struct FetchOutcome {
+    pub fetched: Fetched,
+    pub errors: Vec<FetchError>,
+    /// Names of upstreams which ran and succeeded.
+    pub succeeded: Vec<&'static str>,
+    /// Names of upstreams which ran and succeeded, but left some results out.
+    pub truncated: Vec<&'static str>,
+    /// What's newly created when `fetched` is saved.
+    pub created: GraphStats,
+}
+
+/// What a crawl (see `upstream::fetch_all`) brings in, in all rounds.
+#[derive(Debug, Clone, Default)]
+pub struct Crawled {
+    /// What's newly created.
+    pub created: GraphStats,
+    /// Upstreams failed. What's found by others is saved anyway.
+    pub errors: Vec<FetchError>,
+}
+
+impl FetchOutcome {
+    /// Whether every upstream succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Whether upstream `name` ran and succeeded.
+    pub fn succeeded(&self, name: &str) -> bool {
+        self.succeeded.contains(&name)
+    }
+
+    /// Whether upstream `name` ran and succeeded, but left some results out.
+    pub fn truncated(&self, name: &str) -> bool {
+        self.truncated.contains(&name)
+    }
+
+    /// Whether upstream `name` ran and failed.
+    pub fn failed(&self, name: &str) -> bool {
+        self.errors.iter().any(|err| err.upstream == name)
+    }
+}
+
+impl FromIterator<Fetched> for Fetched {
+    fn from_iter<I: IntoIterator<Item = Fetched>>(iter: I) -> Self {
+        let mut fetched = Fetched::default();
+        for other in iter {
+            fetched.extend(other);
+        }
+        fetched
+    }
+}
@@ -97,6 +97,239 @@ pub enum DataSource {
     #[graphql(name = "space_id")]
     SpaceId,
 
+    /// https://docs.passport.gitcoin.co/building-with-passport/scorer-api
+    #[strum(serialize = "gitcoin_passport")]
+    #[serde(rename = "gitcoin_passport")]
+    #[graphql(name = "gitcoin_passport")]
+    GitcoinPassport,
+
+    /// https://github.com/Proof-Of-Humanity/proof-of-humanity-subgraph
+    #[strum(serialize = "proof_of_humanity")]
+    #[serde(rename = "proof_of_humanity")]
+    #[graphql(name = "proof_of_humanity")]
+    ProofOfHumanity,
+
+    /// BrightID node API (https://dev.brightid.org/docs/node-api/)
+    #[strum(serialize = "brightid")]
+    #[serde(rename = "brightid")]
+    #[graphql(name = "brightid")]
+    BrightID,
+
+    /// Nostr NIP-05 (https://github.com/nostr-protocol/nips/blob/master/05.md)
+    #[strum(serialize = "nostr")]
+    #[serde(rename = "nostr")]
+    #[graphql(name = "nostr")]
+    Nostr,
+
+    /// Mastodon `rel=me` verified profile links (https://docs.joinmastodon.org/user/profile/#verification)
+    #[strum(serialize = "mastodon")]
+    #[serde(rename = "mastodon")]
+    #[graphql(name = "mastodon")]
+    Mastodon,
+
+    /// Twitter API v2 (https://developer.twitter.com/en/docs/twitter-api)
+    #[strum(serialize = "twitter")]
+    #[serde(rename = "twitter")]
+    #[graphql(name = "twitter")]
+    Twitter,
+
+    /// https://documentation.poap.tech
+    #[strum(serialize = "poap")]
+    #[serde(rename = "poap")]
+    #[graphql(name = "poap")]
+    POAP,
+
+    /// https://docs.snapshot.org/graphql-api
+    #[strum(serialize = "snapshot")]
+    #[serde(rename = "snapshot")]
+    #[graphql(name = "snapshot")]
+    Snapshot,
+
+    /// https://docs.opensea.io/reference/api-overview
+    #[strum(serialize = "opensea")]
+    #[serde(rename = "opensea")]
+    #[graphql(name = "opensea")]
+    OpenSea,
+
+    /// https://sns.id (via Bonfida SNS SDK proxy)
+    #[strum(serialize = "sns")]
+    #[serde(rename = "sns")]
+    #[graphql(name = "sns")]
+    SNS,
+
+    /// https://indexer.crossbell.io/docs
+    #[strum(serialize = "crossbell")]
+    #[serde(rename = "crossbell")]
+    #[graphql(name = "crossbell")]
+    Crossbell,
+
+    /// https://y.at
+    #[strum(serialize = "yat")]
+    #[serde(rename = "yat")]
+    #[graphql(name = "yat")]
+    Yat,
+
+    /// Telegram verification bot
+    #[strum(serialize = "telegram")]
+    #[serde(rename = "telegram")]
+    #[graphql(name = "telegram")]
+    Telegram,
+
+    /// Discord OAuth attestation service
+    #[strum(serialize = "discord")]
+    #[serde(rename = "discord")]
+    #[graphql(name = "discord")]
+    Discord,
+
+    /// https://keys.openpgp.org
+    #[strum(serialize = "openpgp")]
+    #[serde(rename = "openpgp")]
+    #[graphql(name = "openpgp")]
+    OpenPGP,
+
+    /// https://orcid.org
+    #[strum(serialize = "orcid")]
+    #[serde(rename = "orcid")]
+    #[graphql(name = "orcid")]
+    ORCID,
+
+    /// https://mirror.xyz
+    #[strum(serialize = "mirror")]
+    #[serde(rename = "mirror")]
+    #[graphql(name = "mirror")]
+    Mirror,
+
+    /// https://galxe.com
+    #[strum(serialize = "galxe")]
+    #[serde(rename = "galxe")]
+    #[graphql(name = "galxe")]
+    Galxe,
+
+    /// https://link3.to
+    #[strum(serialize = "link3")]
+    #[serde(rename = "link3")]
+    #[graphql(name = "link3")]
+    Link3,
+
+    /// https://worldcoin.org/world-id
+    #[strum(serialize = "worldid")]
+    #[serde(rename = "worldid")]
+    #[graphql(name = "worldid")]
+    WorldID,
+
+    /// https://www.civic.com/
+    #[strum(serialize = "civic")]
+    #[serde(rename = "civic")]
+    #[graphql(name = "civic")]
+    Civic,
+
+    /// Verifiable claims issued by Polygon ID issuers, verified by us.
+    /// https://polygon.technology/polygon-id
+    #[strum(serialize = "polygonid")]
+    #[serde(rename = "polygonid")]
+    #[graphql(name = "polygonid")]
+    PolygonID,
+
+    /// W3C Verifiable Credentials issued by trusted issuers, verified by us.
+    /// https://www.w3.org/TR/vc-data-model/
+    #[strum(serialize = "verifiable_credential")]
+    #[serde(rename = "verifiable_credential")]
+    #[graphql(name = "verifiable_credential")]
+    VerifiableCredential,
+
+    /// Bindings claimed by the owner of an address in a signed SIWE message.
+    /// https://eips.ethereum.org/EIPS/eip-4361
+    #[strum(serialize = "self_attested")]
+    #[serde(rename = "self_attested")]
+    #[graphql(name = "self_attested")]
+    SelfAttested,
+
+    /// https://www.aptosnames.com
+    #[strum(serialize = "aptos_names")]
+    #[serde(rename = "aptos_names")]
+    #[graphql(name = "aptos_names")]
+    AptosNames,
+
+    /// https://avvy.domains
+    #[strum(serialize = "avvy")]
+    #[serde(rename = "avvy")]
+    #[graphql(name = "avvy")]
+    Avvy,
+
+    /// https://icns.xyz
+    #[strum(serialize = "icns")]
+    #[serde(rename = "icns")]
+    #[graphql(name = "icns")]
+    ICNS,
+
+    /// https://www.stargaze.zone/names
+    #[strum(serialize = "stargaze_names")]
+    #[serde(rename = "stargaze_names")]
+    #[graphql(name = "stargaze_names")]
+    StargazeNames,
+
+    /// https://debank.com
+    #[strum(serialize = "debank")]
+    #[serde(rename = "debank")]
+    #[graphql(name = "debank")]
+    DeBank,
+
+    /// https://etherscan.io/labelcloud
+    #[strum(serialize = "etherscan")]
+    #[serde(rename = "etherscan")]
+    #[graphql(name = "etherscan")]
+    Etherscan,
+
+    /// https://rarible.com
+    #[strum(serialize = "rarible")]
+    #[serde(rename = "rarible")]
+    #[graphql(name = "rarible")]
+    Rarible,
+
+    /// https://zora.co
+    #[strum(serialize = "zora")]
+    #[serde(rename = "zora")]
+    #[graphql(name = "zora")]
+    Zora,
+
+    /// Claims published on a website itself (`rel="me"` links).
+    #[strum(serialize = "website")]
+    #[serde(rename = "website")]
+    #[graphql(name = "website")]
+    Website,
+
+    /// 3PID bindings of a Matrix identity server (e.g. Sydent).
+    /// https://spec.matrix.org/latest/identity-service-api/
+    #[strum(serialize = "matrix")]
+    #[serde(rename = "matrix")]
+    #[graphql(name = "matrix")]
+    Matrix,
+
+    /// https://github.com/HackerNews/API
+    #[strum(serialize = "hackernews")]
+    #[serde(rename = "hackernews")]
+    #[graphql(name = "hackernews")]
+    HackerNews,
+
+    /// Soulbound tokens held in configured contracts, e.g. https://docs.sismo.io
+    #[strum(serialize = "sbt")]
+    #[serde(rename = "sbt")]
+    #[graphql(name = "sbt")]
+    SBT,
+
+    /// Ethereum Attestation Service
+    /// https://attest.sh
+    #[strum(serialize = "eas")]
+    #[serde(rename = "eas")]
+    #[graphql(name = "eas")]
+    EAS,
+
+    /// Operator-defined JSON API, see `[[upstream.generic]]` in config.
+    #[strum(serialize = "generic")]
+    #[serde(rename = "generic")]
+    #[graphql(name = "generic")]
+    Generic,
+
     /// Unknown
     #[strum(serialize = "unknown")]
     #[serde(rename = "unknown")]
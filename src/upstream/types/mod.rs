@@ -1,3 +1,4 @@
+pub(crate) mod connection;
 pub(crate) mod data_fetcher;
 pub(crate) mod data_source;
 pub(crate) mod platform;
@@ -5,6 +6,7 @@ pub(crate) mod target;
 
 use serde::{Deserialize, Serialize};
 
+pub use connection::{Connection, Crawled, FetchError, FetchOutcome, Fetched};
 pub use data_fetcher::DataFetcher;
 pub use data_source::DataSource;
 pub use platform::Platform;
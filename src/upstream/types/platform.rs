@@ -97,6 +97,237 @@ pub enum Platform {
     #[graphql(name = "space_id")]
     SpaceId,
 
+    /// Discord. Identity is the snowflake user ID, username goes to `display_name`.
+    #[strum(serialize = "discord")]
+    #[serde(rename = "discord")]
+    #[graphql(name = "discord")]
+    Discord,
+
+    /// Google
+    #[strum(serialize = "google")]
+    #[serde(rename = "google")]
+    #[graphql(name = "google")]
+    Google,
+
+    /// Proof of Humanity registration. Identity is the registered Ethereum address.
+    #[strum(serialize = "proof_of_humanity")]
+    #[serde(rename = "proof_of_humanity")]
+    #[graphql(name = "proof_of_humanity")]
+    ProofOfHumanity,
+
+    /// BrightID sybil-resistance verification. Identity is the most recent context ID linked to a BrightID user.
+    #[strum(serialize = "brightid")]
+    #[serde(rename = "brightid")]
+    #[graphql(name = "brightid")]
+    BrightID,
+
+    /// Nostr. Identity is NIP-19 `npub` encoded public key.
+    #[strum(serialize = "nostr")]
+    #[serde(rename = "nostr")]
+    #[graphql(name = "nostr")]
+    Nostr,
+
+    /// Mastodon and other ActivityPub servers. Identity is `user@domain`.
+    #[strum(serialize = "mastodon")]
+    #[serde(rename = "mastodon")]
+    #[graphql(name = "mastodon")]
+    Mastodon,
+
+    /// POAP event. Identity is event ID. Attendees hold it.
+    #[strum(serialize = "poap")]
+    #[serde(rename = "poap")]
+    #[graphql(name = "poap")]
+    POAP,
+
+    /// Snapshot space. Identity is the ENS name of the space.
+    #[strum(serialize = "snapshot")]
+    #[serde(rename = "snapshot")]
+    #[graphql(name = "snapshot")]
+    Snapshot,
+
+    /// Instagram
+    #[strum(serialize = "instagram")]
+    #[serde(rename = "instagram")]
+    #[graphql(name = "instagram")]
+    Instagram,
+
+    /// Solana. Identity is base58 encoded public key.
+    #[strum(serialize = "solana")]
+    #[serde(rename = "solana")]
+    #[graphql(name = "solana")]
+    Solana,
+
+    /// Solana Name Service (`.sol` domain).
+    #[strum(serialize = "sns")]
+    #[serde(rename = "sns")]
+    #[graphql(name = "sns")]
+    SNS,
+
+    /// Crossbell character. Identity is `handle.csb`.
+    #[strum(serialize = "crossbell")]
+    #[serde(rename = "crossbell")]
+    #[graphql(name = "crossbell")]
+    Crossbell,
+
+    /// CyberConnect ccProfile. Identity is the profile handle (`name.cyber`).
+    #[strum(serialize = "cyberconnect")]
+    #[serde(rename = "cyberconnect")]
+    #[graphql(name = "cyberconnect")]
+    CyberConnect,
+
+    /// Yat emoji ID. Identity is the emoji string itself.
+    #[strum(serialize = "yat")]
+    #[serde(rename = "yat")]
+    #[graphql(name = "yat")]
+    Yat,
+
+    /// Telegram. Identity is the username without leading `@`.
+    #[strum(serialize = "telegram")]
+    #[serde(rename = "telegram")]
+    #[graphql(name = "telegram")]
+    Telegram,
+
+    /// Email address. Identity is the lowercased address.
+    #[strum(serialize = "email")]
+    #[serde(rename = "email")]
+    #[graphql(name = "email")]
+    Email,
+
+    /// ORCID researcher. Identity is the ORCID iD (`0000-0002-1825-0097`).
+    #[strum(serialize = "orcid")]
+    #[serde(rename = "orcid")]
+    #[graphql(name = "orcid")]
+    ORCID,
+
+    /// Mirror.xyz publication. Identity is its ENS name, or `{label}` of `{label}.mirror.xyz`.
+    #[strum(serialize = "mirror")]
+    #[serde(rename = "mirror")]
+    #[graphql(name = "mirror")]
+    Mirror,
+
+    /// Galxe (Project Galaxy). Identity is the Galxe ID.
+    #[strum(serialize = "galxe")]
+    #[serde(rename = "galxe")]
+    #[graphql(name = "galxe")]
+    Galxe,
+
+    /// World ID. Identity is the nullifier hash of an Orb-verified human.
+    #[strum(serialize = "worldid")]
+    #[serde(rename = "worldid")]
+    #[graphql(name = "worldid")]
+    WorldID,
+
+    /// Civic Pass holder. Identity is the address holding the pass.
+    #[strum(serialize = "civic")]
+    #[serde(rename = "civic")]
+    #[graphql(name = "civic")]
+    Civic,
+
+    /// Polygon ID. Identity is the DID of holder (`did:polygonid:...`).
+    #[strum(serialize = "polygonid")]
+    #[serde(rename = "polygonid")]
+    #[graphql(name = "polygonid")]
+    PolygonID,
+
+    /// Aptos. Identity is the full-length lowercase account address.
+    #[strum(serialize = "aptos")]
+    #[serde(rename = "aptos")]
+    #[graphql(name = "aptos")]
+    Aptos,
+
+    /// Aptos Names (`.apt` name).
+    #[strum(serialize = "aptos_names")]
+    #[serde(rename = "aptos_names")]
+    #[graphql(name = "aptos_names")]
+    AptosNames,
+
+    /// Avvy Domains (`.avax` name on Avalanche).
+    #[strum(serialize = "avvy")]
+    #[serde(rename = "avvy")]
+    #[graphql(name = "avvy")]
+    Avvy,
+
+    /// Cosmos ecosystem. Identity is the lowercase bech32 address, whose prefix tells the chain.
+    #[strum(serialize = "cosmos")]
+    #[serde(rename = "cosmos")]
+    #[graphql(name = "cosmos")]
+    Cosmos,
+
+    /// Interchain Name Service (`{name}.{bech32 prefix}`).
+    #[strum(serialize = "icns")]
+    #[serde(rename = "icns")]
+    #[graphql(name = "icns")]
+    ICNS,
+
+    /// Stargaze Names (`.stars` name).
+    #[strum(serialize = "stargaze_names")]
+    #[serde(rename = "stargaze_names")]
+    #[graphql(name = "stargaze_names")]
+    StargazeNames,
+
+    /// DeBank Web3 ID. Identity is the ID without `@` prefix.
+    #[strum(serialize = "debank")]
+    #[serde(rename = "debank")]
+    #[graphql(name = "debank")]
+    DeBank,
+
+    /// Etherscan public name tag. Identity is the lowercased name tag.
+    #[strum(serialize = "etherscan")]
+    #[serde(rename = "etherscan")]
+    #[graphql(name = "etherscan")]
+    Etherscan,
+
+    /// Rarible profile. Identity is the profile short URL (username).
+    #[strum(serialize = "rarible")]
+    #[serde(rename = "rarible")]
+    #[graphql(name = "rarible")]
+    Rarible,
+
+    /// Zora profile. Identity is the profile handle.
+    #[strum(serialize = "zora")]
+    #[serde(rename = "zora")]
+    #[graphql(name = "zora")]
+    Zora,
+
+    /// Personal website, including Tor onion services.
+    /// Identity is the URL without scheme, e.g. `example.com/~alice`.
+    /// `generic_web_site` is the name of this platform in Keybase proofs.
+    #[strum(to_string = "website", serialize = "generic_web_site")]
+    #[serde(rename = "website")]
+    #[graphql(name = "website")]
+    Website,
+
+    /// Phone number. Identity is the MSISDN (E.164 digits without `+`).
+    #[strum(serialize = "phone")]
+    #[serde(rename = "phone")]
+    #[graphql(name = "phone")]
+    Phone,
+
+    /// Matrix user. Identity is the lowercased MXID, e.g. `@alice:matrix.org`.
+    #[strum(serialize = "matrix")]
+    #[serde(rename = "matrix")]
+    #[graphql(name = "matrix")]
+    Matrix,
+
+    /// Hacker News user. Identity is the case-sensitive username.
+    #[strum(serialize = "hackernews")]
+    #[serde(rename = "hackernews")]
+    #[graphql(name = "hackernews")]
+    HackerNews,
+
+    /// Holder of soulbound tokens (e.g. Sismo badges). Identity is the holder address.
+    #[strum(serialize = "sbt")]
+    #[serde(rename = "sbt")]
+    #[graphql(name = "sbt")]
+    SBT,
+
+    /// Gitcoin Passport stamp. Identity is the hashed account ID of the stamp,
+    /// which reveals nothing about the account on the stamp provider.
+    #[strum(serialize = "gitcoin_passport")]
+    #[serde(rename = "gitcoin_passport")]
+    #[graphql(name = "gitcoin_passport")]
+    GitcoinPassport,
+
     /// Unknown
     #[strum(serialize = "unknown")]
     #[serde(rename = "unknown")]
@@ -2,17 +2,12 @@ mod tests;
 
 use crate::config::C;
 use crate::error::Error;
-use crate::graph::edge::Edge;
 use crate::graph::edge::Resolve;
 use crate::graph::edge::{hold::Hold, resolve::DomainNameSystem};
-use crate::graph::vertex::IdentityRecord;
-use crate::graph::vertex::Vertex;
-use crate::graph::{new_db_connection, vertex::Identity};
-use crate::upstream::{DataFetcher, DataSource, Fetcher, Platform, Target, TargetProcessedList};
-use crate::util::{make_client, naive_now, parse_body, request_with_timeout};
-use aragog::DatabaseConnection;
+use crate::graph::vertex::Identity;
+use crate::upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target};
+use crate::util::{make_client, naive_now, paginate, parse_body, request_with_timeout, Page};
 use async_trait::async_trait;
-use futures::future::join_all;
 use http::uri::InvalidUri;
 use hyper::{Body, Method};
 use serde::Deserialize;
@@ -96,35 +91,60 @@ pub struct Records {
 
 const UNKNOWN_OWNER: &str = "0x0000000000000000000000000000000000000000";
 
+/// TLDs which can be resolved by UnstoppableDomains resolution API.
+/// https://docs.unstoppabledomains.com/resolution/supported-tlds/
+const SUPPORTED_TLDS: [&str; 9] = [
+    "crypto",
+    "nft",
+    "wallet",
+    "x",
+    "bitcoin",
+    "dao",
+    "888",
+    "zil",
+    "blockchain",
+];
+
+/// Judge if given domain is managed by UnstoppableDomains.
+fn is_supported_domain(domain: &str) -> bool {
+    match domain.rsplit_once('.') {
+        Some((name, tld)) => !name.is_empty() && SUPPORTED_TLDS.contains(&tld),
+        None => false,
+    }
+}
+
 pub struct UnstoppableDomains {}
 #[async_trait]
 impl Fetcher for UnstoppableDomains {
-    async fn fetch(target: &Target) -> Result<TargetProcessedList, Error> {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
         if !Self::can_fetch(target) {
-            return Ok(vec![]);
+            return Ok(Fetched::default());
         }
 
         match target {
             Target::Identity(platform, identity) => {
                 fetch_connections_by_platform_identity(platform, identity).await
             }
-            Target::NFT(_, _, _, _) => todo!(),
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
         }
     }
 
     fn can_fetch(target: &Target) -> bool {
-        target.in_platform_supported(vec![Platform::UnstoppableDomains, Platform::Ethereum])
+        match target {
+            Target::Identity(Platform::UnstoppableDomains, domain) => is_supported_domain(domain),
+            _ => target.in_platform_supported(vec![Platform::Ethereum]),
+        }
     }
 }
 
 async fn fetch_connections_by_platform_identity(
     platform: &Platform,
     identity: &str,
-) -> Result<TargetProcessedList, Error> {
+) -> Result<Fetched, Error> {
     match *platform {
         Platform::Ethereum => fetch_domains_by_account(platform, identity).await,
         Platform::UnstoppableDomains => fetch_account_by_domain(platform, identity).await,
-        _ => Ok(vec![]),
+        _ => Ok(Fetched::default()),
     }
 }
 
@@ -224,11 +244,7 @@ async fn fetch_reverse(owner: &str) -> Result<ReverseResponse, Error> {
     Ok(result)
 }
 
-async fn save_domain(
-    db: &DatabaseConnection,
-    eth_record: &IdentityRecord,
-    item: Item,
-) -> Result<TargetProcessedList, Error> {
+fn parse_domain(eth_identity: &Identity, item: Item, fetched: &mut Fetched) {
     let identity: Identity = Identity {
         uuid: Some(Uuid::new_v4()),
         platform: Platform::UnstoppableDomains,
@@ -249,77 +265,65 @@ async fn save_domain(
         updated_at: naive_now(),
         fetcher: DataFetcher::RelationService,
     };
-    let domain_record = identity.create_or_update(&db).await?;
-    hold.connect(db, eth_record, &domain_record).await?;
 
     let resolve: Resolve = Resolve {
         uuid: Uuid::new_v4(),
         source: DataSource::UnstoppableDomains,
         system: DomainNameSystem::UnstoppableDomains,
         name: item.id.clone(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: None,
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
 
     // 'regular' resolution involves mapping from a name to an address.
-    resolve.connect(db, &domain_record, eth_record).await?;
-
+    // 'reverse' resolution maps from an address back to a name, if reverse = true.
+    fetched.connections.push(Connection::Domain {
+        address: eth_identity.clone(),
+        domain: identity,
+        hold,
+        resolve,
+        is_primary: item.attributes.meta.reverse,
+    });
     if item.attributes.meta.reverse {
-        // reverse = true
-        // 'reverse' resolution maps from an address back to a name.
-        resolve.connect(db, eth_record, &domain_record).await?;
-        return Ok(vec![Target::Identity(
+        fetched.next_targets.push(Target::Identity(
             Platform::UnstoppableDomains,
-            item.attributes.meta.domain.clone(),
-        )]);
+            item.attributes.meta.domain,
+        ));
     }
-    Ok(vec![])
 }
 
-async fn fetch_domains_by_account(
-    _platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let mut cnt: u32 = 0;
-    let mut next = String::from("");
-    let mut next_targets: Vec<Target> = Vec::new();
-    while cnt < u32::MAX {
-        let result = fetch_domain(identity, &next).await?;
-        let db = new_db_connection().await?;
-        cnt += result.data.len() as u32;
-
-        let eth_identity: Identity = Identity {
-            uuid: Some(Uuid::new_v4()),
-            platform: Platform::Ethereum,
-            identity: identity.to_string().to_lowercase().clone(),
-            created_at: None,
-            display_name: None,
-            added_at: naive_now(),
-            avatar_url: None,
-            profile_url: None,
-            updated_at: naive_now(),
-        };
-        let eth_record = eth_identity.create_or_update(&db).await?;
-        let futures: Vec<_> = result
-            .data
-            .into_iter()
-            .map(|item| save_domain(&db, &eth_record, item))
-            .collect();
-
-        let targets: TargetProcessedList = join_all(futures)
-            .await
-            .into_iter()
-            .flat_map(|result| result.unwrap_or_default())
-            .collect();
-
-        next_targets.extend(targets);
-        if result.meta.has_more {
-            next = result.meta.next;
-        } else {
-            break;
-        }
+async fn fetch_domains_by_account(_platform: &Platform, identity: &str) -> Result<Fetched, Error> {
+    let paginated = paginate(String::from(""), |next| fetch_domains_page(identity, next)).await?;
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: identity.to_string().to_lowercase().clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let mut fetched = Fetched {
+        truncated: paginated.truncated,
+        ..Default::default()
+    };
+    for item in paginated.items.into_iter() {
+        parse_domain(&eth_identity, item, &mut fetched);
     }
-    Ok(next_targets)
+    Ok(fetched)
+}
+
+async fn fetch_domains_page(owners: &str, page: String) -> Result<Page<Item, String>, Error> {
+    let result = fetch_domain(owners, &page).await?;
+    Ok(Page {
+        items: result.data,
+        next: result.meta.has_more.then_some(result.meta.next),
+    })
 }
 
 async fn fetch_owner(domains: &str) -> Result<DomainResponse, Error> {
@@ -359,14 +363,10 @@ async fn fetch_owner(domains: &str) -> Result<DomainResponse, Error> {
     Ok(result)
 }
 
-async fn fetch_account_by_domain(
-    _platform: &Platform,
-    identity: &str,
-) -> Result<TargetProcessedList, Error> {
-    let db = new_db_connection().await?;
+async fn fetch_account_by_domain(_platform: &Platform, identity: &str) -> Result<Fetched, Error> {
     let result = fetch_owner(identity).await?;
     if result.meta.owner.is_none() {
-        return Ok(vec![]);
+        return Ok(Fetched::default());
     }
 
     if result.meta.owner.clone().unwrap().to_lowercase() == UNKNOWN_OWNER {
@@ -408,30 +408,31 @@ async fn fetch_account_by_domain(
         fetcher: DataFetcher::RelationService,
     };
 
-    let eth_record = eth_identity.create_or_update(&db).await?;
-    let domain_record = identity.create_or_update(&db).await?;
-    hold.connect(&db, &eth_record, &domain_record).await?;
-
     let resolve: Resolve = Resolve {
         uuid: Uuid::new_v4(),
         source: DataSource::UnstoppableDomains,
         system: DomainNameSystem::UnstoppableDomains,
         name: result.meta.domain.clone(),
+        resolved_address: Some(eth_identity.identity.clone()),
+        expire_at: None,
         fetcher: DataFetcher::RelationService,
         updated_at: naive_now(),
     };
 
-    // 'regular' resolution involves mapping from a name to an address.
-    resolve.connect(&db, &domain_record, &eth_record).await?;
-
-    if result.meta.reverse {
-        // reverse = true
-        // 'reverse' resolution maps from an address back to a name.
-        resolve.connect(&db, &eth_record, &domain_record).await?;
-    }
-
-    Ok(vec![Target::Identity(
-        Platform::Ethereum,
-        result.meta.owner.clone().unwrap().to_lowercase(),
-    )])
+    Ok(Fetched {
+        // 'regular' resolution involves mapping from a name to an address.
+        // 'reverse' resolution maps from an address back to a name, if reverse = true.
+        connections: vec![Connection::Domain {
+            address: eth_identity,
+            domain: identity,
+            hold,
+            resolve,
+            is_primary: result.meta.reverse,
+        }],
+        next_targets: vec![Target::Identity(
+            Platform::Ethereum,
+            result.meta.owner.clone().unwrap().to_lowercase(),
+        )],
+        ..Default::default()
+    })
 }
@@ -4,10 +4,10 @@ use crate::{
     graph::vertex::{contract::Chain, Contract, Identity},
     upstream::unstoppable::UnstoppableDomains,
     upstream::Platform,
-    upstream::{Fetcher, Target},
+    upstream::{fetch_and_import, Target},
 };
 
-use super::fetch_domain;
+use super::{fetch_domain, is_supported_domain};
 
 #[tokio::test]
 async fn test_fetch_domains_by_account() -> Result<(), Error> {
@@ -15,7 +15,7 @@ async fn test_fetch_domains_by_account() -> Result<(), Error> {
         Platform::Ethereum,
         "0xCbCca6e22d90b8d2B829852a8D551e8410f40956".to_lowercase(),
     );
-    let _ = UnstoppableDomains::fetch(&target).await?;
+    let _ = fetch_and_import::<UnstoppableDomains>(&target).await?;
     let db = new_db_connection().await?;
     let found =
         Identity::find_by_platform_identity(&db, &Platform::UnstoppableDomains, "0xzella.crypto")
@@ -37,7 +37,7 @@ async fn test_fetch_domain() -> Result<(), Error> {
 #[tokio::test]
 async fn test_fetch_account_by_domain() -> Result<(), Error> {
     let target = Target::Identity(Platform::UnstoppableDomains, String::from("88888888.888"));
-    let _ = UnstoppableDomains::fetch(&target).await?;
+    let _ = fetch_and_import::<UnstoppableDomains>(&target).await?;
     let db = new_db_connection().await?;
     let found = Identity::find_by_platform_identity(
         &db,
@@ -49,3 +49,12 @@ async fn test_fetch_account_by_domain() -> Result<(), Error> {
     print!("found: {:?}", found);
     Ok(())
 }
+
+#[test]
+fn test_is_supported_domain() {
+    assert!(is_supported_domain("0xzella.crypto"));
+    assert!(is_supported_domain("88888888.888"));
+    assert!(!is_supported_domain("vitalik.eth"));
+    assert!(!is_supported_domain(".crypto"));
+    assert!(!is_supported_domain("crypto"));
+}
@@ -0,0 +1,376 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        create_identity_to_identity_record, db_connection,
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{DataFetcher, DataSource, Platform, Target, TargetProcessedList},
+    util::{canonical, naive_now, timestamp_to_naive},
+};
+use chrono::{DateTime, NaiveDateTime};
+use ed25519_dalek::{PublicKey as Ed25519PublicKey, Signature as Ed25519Signature, Verifier};
+use k256::ecdsa::{Signature as Secp256k1Signature, VerifyingKey as Secp256k1PublicKey};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Multicodec prefixes (varint) of public keys in `did:key`.
+/// https://w3c-ccg.github.io/did-method-key/
+const MULTICODEC_ED25519_PUB: [u8; 2] = [0xed, 0x01];
+const MULTICODEC_SECP256K1_PUB: [u8; 2] = [0xe7, 0x01];
+
+/// https://www.w3.org/TR/vc-di-eddsa/#eddsa-jcs-2022
+const CRYPTOSUITE_EDDSA_JCS: &str = "eddsa-jcs-2022";
+
+/// Public key of a credential issuer.
+#[derive(Debug)]
+pub enum IssuerKey {
+    Ed25519(Ed25519PublicKey),
+    Secp256k1(Secp256k1PublicKey),
+}
+
+impl IssuerKey {
+    /// Resolve public key from a `did:key` DID (fragment is ignored).
+    /// Other DID methods need a network resolver, which is not supported.
+    pub fn from_did(did: &str) -> Result<Self, Error> {
+        let did = did.split('#').next().unwrap_or_default();
+        let encoded = did
+            .strip_prefix("did:key:z")
+            .ok_or_else(|| Error::ParamError(format!("VC: {} is not a did:key", did)))?;
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|err| Error::ParamError(format!("VC: {} {}", did, err)))?;
+        let (codec, key) = bytes.split_at(2.min(bytes.len()));
+        match codec {
+            c if c == MULTICODEC_ED25519_PUB => Ed25519PublicKey::from_bytes(key)
+                .map(IssuerKey::Ed25519)
+                .map_err(|err| Error::ParamError(format!("VC: {} {}", did, err))),
+            c if c == MULTICODEC_SECP256K1_PUB => Secp256k1PublicKey::from_sec1_bytes(key)
+                .map(IssuerKey::Secp256k1)
+                .map_err(|err| Error::ParamError(format!("VC: {} {}", did, err))),
+            _ => Err(Error::ParamError(format!(
+                "VC: key type of {} is not supported",
+                did
+            ))),
+        }
+    }
+
+    /// Verify `signature` of `message`.
+    /// secp256k1 signatures are ECDSA over SHA-256, as in JWS `ES256K`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        match self {
+            IssuerKey::Ed25519(public_key) => Ed25519Signature::try_from(signature)
+                .is_ok_and(|sig| public_key.verify(message, &sig).is_ok()),
+            IssuerKey::Secp256k1(public_key) => Secp256k1Signature::try_from(signature)
+                .map(|sig| sig.normalize_s().unwrap_or(sig))
+                .is_ok_and(|sig| public_key.verify(message, &sig).is_ok()),
+        }
+    }
+}
+
+/// Subject of a credential, with the identity it claims to be linked to.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CredentialSubject {
+    /// DID of the subject, i.e. `did:pkh:eip155:1:0x...`
+    pub id: Option<String>,
+    pub platform: Option<String>,
+    pub identity: Option<String>,
+}
+
+/// A verified credential, no matter how it is encoded.
+#[derive(Debug, Clone)]
+pub struct Credential {
+    pub id: Option<String>,
+    pub issuer: String,
+    pub issuance_date: Option<NaiveDateTime>,
+    pub expiration_date: Option<NaiveDateTime>,
+    pub subjects: Vec<CredentialSubject>,
+}
+
+impl Credential {
+    /// Parse and verify a credential encoded as JWT or JSON-LD with embedded proof.
+    pub fn verify(serialized: &str) -> Result<Self, Error> {
+        let serialized = serialized.trim();
+        let credential = if serialized.starts_with('{') {
+            verify_json_ld(&serde_json::from_str(serialized)?)?
+        } else {
+            verify_jwt(serialized)?
+        };
+        if !C.upstream.vc.trusted_issuers.contains(&credential.issuer) {
+            return Err(Error::SignatureValidationError(format!(
+                "VC issuer {} is not trusted",
+                credential.issuer
+            )));
+        }
+        credential.check_validity(naive_now())?;
+        Ok(credential)
+    }
+
+    /// Credentials are accepted only from `issuance_date` (JWT `nbf`) until `expiration_date`.
+    fn check_validity(&self, now: NaiveDateTime) -> Result<(), Error> {
+        if self.issuance_date.is_some_and(|issuance| issuance > now) {
+            return Err(Error::ParamError("VC is not yet valid".into()));
+        }
+        if self
+            .expiration_date
+            .is_some_and(|expiration| expiration < now)
+        {
+            return Err(Error::ParamError("VC is expired".into()));
+        }
+        Ok(())
+    }
+}
+
+/// `credentialSubject` could be an object or an array of objects.
+fn parse_subjects(value: &Value) -> Result<Vec<CredentialSubject>, Error> {
+    let subjects = match value {
+        Value::Array(_) => value.clone(),
+        _ => json!([value]),
+    };
+    Ok(serde_json::from_value(subjects)?)
+}
+
+/// `issuer` could be a string or an object with `id`.
+fn parse_issuer(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .or_else(|| value["id"].as_str())
+        .map(String::from)
+}
+
+fn parse_date(value: &Value) -> Option<NaiveDateTime> {
+    value
+        .as_str()
+        .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+        .map(|dt| dt.naive_utc())
+}
+
+fn base64url_decode(encoded: &str) -> Result<Vec<u8>, Error> {
+    base64::decode_config(encoded, base64::URL_SAFE_NO_PAD)
+        .map_err(|err| Error::ParamError(format!("VC: {}", err)))
+}
+
+/// JWT encoded credential.
+/// https://www.w3.org/TR/vc-data-model/#jwt-encoding
+fn verify_jwt(jwt: &str) -> Result<Credential, Error> {
+    let parts: Vec<&str> = jwt.split('.').collect();
+    if parts.len() != 3 {
+        return Err(Error::ParamError("VC: malformed JWT".into()));
+    }
+    let header: Value = serde_json::from_slice(&base64url_decode(parts[0])?)?;
+    let payload: Value = serde_json::from_slice(&base64url_decode(parts[1])?)?;
+    let signature = base64url_decode(parts[2])?;
+
+    let issuer = parse_issuer(&payload["iss"])
+        .or_else(|| parse_issuer(&payload["vc"]["issuer"]))
+        .ok_or_else(|| Error::ParamMissing("iss".into()))?;
+    let key = IssuerKey::from_did(&issuer)?;
+    let alg_matches = matches!(
+        (header["alg"].as_str(), &key),
+        (Some("EdDSA"), IssuerKey::Ed25519(_)) | (Some("ES256K"), IssuerKey::Secp256k1(_))
+    );
+    let signing_input = format!("{}.{}", parts[0], parts[1]);
+    if !alg_matches || !key.verify(signing_input.as_bytes(), &signature) {
+        return Err(Error::SignatureValidationError(format!(
+            "VC JWT signature of {} is invalid",
+            issuer
+        )));
+    }
+
+    let vc = &payload["vc"];
+    let mut subjects = parse_subjects(&vc["credentialSubject"])?;
+    // `sub` stands for `credentialSubject.id`.
+    if let Some(sub) = payload["sub"].as_str() {
+        subjects
+            .iter_mut()
+            .filter(|subject| subject.id.is_none())
+            .for_each(|subject| subject.id = Some(sub.to_string()));
+    }
+    Ok(Credential {
+        id: payload["jti"]
+            .as_str()
+            .or(vc["id"].as_str())
+            .map(String::from),
+        issuer,
+        issuance_date: payload["nbf"]
+            .as_i64()
+            .map(|nbf| timestamp_to_naive(nbf, 0))
+            .or_else(|| parse_date(&vc["issuanceDate"])),
+        expiration_date: payload["exp"]
+            .as_i64()
+            .map(|exp| timestamp_to_naive(exp, 0))
+            .or_else(|| parse_date(&vc["expirationDate"])),
+        subjects,
+    })
+}
+
+/// JSON-LD credential secured by a Data Integrity proof.
+/// Only `eddsa-jcs-2022` is supported, since other cryptosuites require
+/// RDF canonicalization.
+fn verify_json_ld(document: &Value) -> Result<Credential, Error> {
+    let issuer =
+        parse_issuer(&document["issuer"]).ok_or_else(|| Error::ParamMissing("issuer".into()))?;
+    let proof = &document["proof"];
+    if proof["cryptosuite"].as_str() != Some(CRYPTOSUITE_EDDSA_JCS) {
+        return Err(Error::ParamError(format!(
+            "VC: cryptosuite {} is not supported",
+            proof["cryptosuite"]
+        )));
+    }
+    let verification_method = proof["verificationMethod"]
+        .as_str()
+        .ok_or_else(|| Error::ParamMissing("verificationMethod".into()))?;
+    if verification_method.split('#').next() != Some(issuer.as_str()) {
+        return Err(Error::SignatureValidationError(format!(
+            "VC: {} is not a key of {}",
+            verification_method, issuer
+        )));
+    }
+    let signature = proof["proofValue"]
+        .as_str()
+        .and_then(|value| value.strip_prefix('z'))
+        .and_then(|value| bs58::decode(value).into_vec().ok())
+        .ok_or_else(|| Error::ParamMissing("proofValue".into()))?;
+
+    // https://www.w3.org/TR/vc-di-eddsa/#verify-proof-eddsa-jcs-2022
+    let mut unsecured = document.clone();
+    unsecured.as_object_mut().unwrap().remove("proof");
+    let mut proof_config = proof.clone();
+    proof_config.as_object_mut().unwrap().remove("proofValue");
+    if !document["@context"].is_null() {
+        proof_config["@context"] = document["@context"].clone();
+    }
+    let mut hash_data = Sha256::digest(canonicalize(&proof_config).as_bytes()).to_vec();
+    hash_data.extend(Sha256::digest(canonicalize(&unsecured).as_bytes()));
+
+    let key = IssuerKey::from_did(verification_method)?;
+    if !key.verify(&hash_data, &signature) {
+        return Err(Error::SignatureValidationError(format!(
+            "VC proof of {} is invalid",
+            issuer
+        )));
+    }
+
+    Ok(Credential {
+        id: document["id"].as_str().map(String::from),
+        issuer,
+        issuance_date: parse_date(&document["issuanceDate"])
+            .or_else(|| parse_date(&document["validFrom"])),
+        expiration_date: parse_date(&document["expirationDate"])
+            .or_else(|| parse_date(&document["validUntil"])),
+        subjects: parse_subjects(&document["credentialSubject"])?,
+    })
+}
+
+/// JSON Canonicalization Scheme (RFC 8785): keys sorted, no whitespace.
+pub fn canonicalize(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort_by(|a, b| a.encode_utf16().cmp(b.encode_utf16()));
+            let members: Vec<String> = keys
+                .into_iter()
+                .map(|key| format!("{}:{}", Value::from(key.as_str()), canonicalize(&map[key])))
+                .collect();
+            format!("{{{}}}", members.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(canonicalize).collect();
+            format!("[{}]", items.join(","))
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Identity represented by the DID of a credential subject.
+pub fn did_to_identity(did: &str) -> Option<(Platform, String)> {
+    let parts: Vec<&str> = did.split(':').collect();
+    match parts.as_slice() {
+        ["did", "pkh", "eip155", _chain_id, address] => {
+            Some((Platform::Ethereum, address.to_lowercase()))
+        }
+        ["did", "pkh", "solana", _chain_id, address] => {
+            Some((Platform::Solana, address.to_string()))
+        }
+        ["did", "ethr", address] if address.starts_with("0x") => {
+            Some((Platform::Ethereum, address.to_lowercase()))
+        }
+        ["did", "polygonid", ..] => Some((Platform::PolygonID, did.to_string())),
+        _ => None,
+    }
+}
+
+fn new_identity(platform: Platform, identity: String) -> Identity {
+    Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform,
+        identity,
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    }
+}
+
+/// Verifiable Credentials are push-based: holders submit them to us.
+pub struct VerifiableCredential {}
+
+impl VerifiableCredential {
+    /// Verify a serialized credential and save linkages in its `credentialSubject`s.
+    pub async fn ingest(serialized: &str) -> Result<TargetProcessedList, Error> {
+        let credential = Credential::verify(serialized)?;
+        let db = db_connection().await?;
+        let mut next_targets: TargetProcessedList = vec![];
+        for subject in credential.subjects.iter() {
+            let (from, (platform, identity)) = match (
+                subject.id.as_deref().and_then(did_to_identity),
+                &subject.platform,
+                &subject.identity,
+            ) {
+                (Some(from), Some(platform), Some(identity)) => {
+                    let platform = Platform::from_str(platform)?;
+                    let identity = canonical::canonicalize(&platform, identity)?;
+                    (from, (platform, identity))
+                }
+                _ => {
+                    warn!("VC ingest | subject {:?} has no linkage", subject.id);
+                    continue;
+                }
+            };
+            let from_identity = new_identity(from.0, from.1.clone());
+            let to_identity = new_identity(platform, identity.clone());
+            let proof: Proof = Proof {
+                uuid: Uuid::new_v4(),
+                source: DataSource::VerifiableCredential,
+                record_id: credential.id.clone(),
+                created_at: credential.issuance_date,
+                updated_at: naive_now(),
+                fetcher: DataFetcher::RelationService,
+                verified: true,
+                expired_at: credential.expiration_date,
+                invalid_since: None,
+                invalidation_reason: None,
+                level: ProofLevel::Cryptographic,
+            };
+            create_identity_to_identity_record(&db, &from_identity, &to_identity, &proof).await?;
+            info!(
+                "VC ingest | {} => {}: {} issued by {}",
+                from.1, platform, identity, credential.issuer
+            );
+            next_targets.push(Target::Identity(from.0, from.1));
+            next_targets.push(Target::Identity(platform, identity));
+        }
+
+        Ok(next_targets)
+    }
+}
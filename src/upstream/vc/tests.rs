@@ -0,0 +1,159 @@
+use crate::{
+    upstream::{
+        vc::{canonicalize, did_to_identity, verify_json_ld, verify_jwt, Credential, IssuerKey},
+        Platform,
+    },
+    util::timestamp_to_naive,
+};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+fn keypair() -> Keypair {
+    let secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn did_key(keypair: &Keypair) -> String {
+    let mut bytes = vec![0xed, 0x01];
+    bytes.extend(keypair.public.as_bytes());
+    format!("did:key:z{}", bs58::encode(bytes).into_string())
+}
+
+fn base64url(data: &[u8]) -> String {
+    base64::encode_config(data, base64::URL_SAFE_NO_PAD)
+}
+
+#[test]
+fn test_canonicalize() {
+    let value = json!({"b": [1, "x", null], "a": {"d": true, "c": 1.5}});
+    assert_eq!(
+        canonicalize(&value),
+        r#"{"a":{"c":1.5,"d":true},"b":[1,"x",null]}"#
+    );
+}
+
+#[test]
+fn test_did_to_identity() {
+    assert_eq!(
+        did_to_identity("did:pkh:eip155:1:0x934B510D4C9103E6a87AEf13b816fb080286D649"),
+        Some((
+            Platform::Ethereum,
+            "0x934b510d4c9103e6a87aef13b816fb080286d649".into()
+        ))
+    );
+    assert_eq!(
+        did_to_identity("did:ethr:0x934b510d4c9103e6a87aef13b816fb080286d649"),
+        Some((
+            Platform::Ethereum,
+            "0x934b510d4c9103e6a87aef13b816fb080286d649".into()
+        ))
+    );
+    assert_eq!(did_to_identity("did:web:example.com"), None);
+}
+
+#[test]
+fn test_issuer_key_from_did() {
+    let keypair = keypair();
+    let did = did_key(&keypair);
+    assert!(matches!(
+        IssuerKey::from_did(&format!("{}#key-1", did)),
+        Ok(IssuerKey::Ed25519(_))
+    ));
+    assert!(IssuerKey::from_did("did:web:example.com").is_err());
+}
+
+#[test]
+fn test_verify_jwt() {
+    let keypair = keypair();
+    let issuer = did_key(&keypair);
+    let header = base64url(br#"{"alg":"EdDSA","typ":"JWT"}"#);
+    let payload = base64url(
+        json!({
+            "iss": issuer,
+            "sub": "did:pkh:eip155:1:0x934b510d4c9103e6a87aef13b816fb080286d649",
+            "jti": "urn:uuid:3978344f-8596-4c3a-a978-8fcaba3903c5",
+            "nbf": 1668413200,
+            "vc": {
+                "type": ["VerifiableCredential"],
+                "credentialSubject": {"platform": "twitter", "identity": "suji_yan"}
+            }
+        })
+        .to_string()
+        .as_bytes(),
+    );
+    let signing_input = format!("{}.{}", header, payload);
+    let signature = base64url(&keypair.sign(signing_input.as_bytes()).to_bytes());
+
+    let credential = verify_jwt(&format!("{}.{}", signing_input, signature)).unwrap();
+    assert_eq!(credential.issuer, issuer);
+    assert_eq!(
+        credential.subjects[0].id.as_deref(),
+        Some("did:pkh:eip155:1:0x934b510d4c9103e6a87aef13b816fb080286d649")
+    );
+    assert_eq!(credential.subjects[0].identity.as_deref(), Some("suji_yan"));
+
+    let tampered = format!("{}.{}.{}", header, base64url(b"{}"), signature);
+    assert!(verify_jwt(&tampered).is_err());
+}
+
+#[test]
+fn test_verify_json_ld() {
+    let keypair = keypair();
+    let issuer = did_key(&keypair);
+    let mut document = json!({
+        "@context": ["https://www.w3.org/2018/credentials/v1"],
+        "id": "urn:uuid:3978344f-8596-4c3a-a978-8fcaba3903c5",
+        "type": ["VerifiableCredential"],
+        "issuer": issuer,
+        "issuanceDate": "2022-11-14T08:00:00Z",
+        "credentialSubject": {
+            "id": "did:pkh:eip155:1:0x934b510d4c9103e6a87aef13b816fb080286d649",
+            "platform": "github",
+            "identity": "fengshanshan"
+        }
+    });
+    let mut proof = json!({
+        "type": "DataIntegrityProof",
+        "cryptosuite": "eddsa-jcs-2022",
+        "verificationMethod": format!("{}#key-1", issuer),
+        "proofPurpose": "assertionMethod",
+        "@context": document["@context"].clone(),
+    });
+    let mut hash_data = Sha256::digest(canonicalize(&proof).as_bytes()).to_vec();
+    hash_data.extend(Sha256::digest(canonicalize(&document).as_bytes()));
+    proof["proofValue"] = json!(format!(
+        "z{}",
+        bs58::encode(keypair.sign(&hash_data).to_bytes()).into_string()
+    ));
+    document["proof"] = proof;
+
+    let credential = verify_json_ld(&document).unwrap();
+    assert_eq!(credential.issuer, issuer);
+    assert_eq!(credential.subjects[0].platform.as_deref(), Some("github"));
+
+    document["credentialSubject"]["identity"] = json!("someone_else");
+    assert!(verify_json_ld(&document).is_err());
+}
+
+#[test]
+fn test_check_validity() {
+    let credential = Credential {
+        id: None,
+        issuer: "did:web:example.com".into(),
+        issuance_date: Some(timestamp_to_naive(1668413200, 0)),
+        expiration_date: Some(timestamp_to_naive(1699949200, 0)),
+        subjects: vec![],
+    };
+    assert!(credential
+        .check_validity(timestamp_to_naive(1680000000, 0))
+        .is_ok());
+    // Before `nbf`.
+    assert!(credential
+        .check_validity(timestamp_to_naive(1668413199, 0))
+        .is_err());
+    assert!(credential
+        .check_validity(timestamp_to_naive(1699949201, 0))
+        .is_err());
+}
@@ -0,0 +1,331 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{
+        keybase::{lookup, PersonInfo, ProofItem, PROOF_STATE_OK},
+        proof_client::{query, ProofRecord},
+        Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target,
+    },
+    util::{
+        make_pinned_client, naive_now, resolve_public_url,
+        scrape::{extract_rel_me, fetch_html_with, link_to_identity},
+        PinnedClient,
+    },
+};
+use async_trait::async_trait;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Where Keybase looks for the signed statement of a website.
+/// https://book.keybase.io/docs/server#proofs-for-websites
+const KEYBASE_TXT_PATHS: [&str; 2] = ["/.well-known/keybase.txt", "/keybase.txt"];
+/// Holds the public key of the NextID persona who owns this website,
+/// the same as `nextid=` TXT record of a domain.
+const NEXTID_PATH: &str = "/.well-known/nextid";
+
+pub struct Website {}
+
+#[async_trait]
+impl Fetcher for Website {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, site) => fetch_claims_by_site(site).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Website])
+    }
+}
+
+/// `https://Example.com/~alice/` => `example.com/~alice`
+pub(crate) fn normalize_site(site: &str) -> String {
+    let site = site
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/');
+    match site.split_once('/') {
+        Some((host, path)) => format!("{}/{}", host.to_lowercase(), path),
+        None => site.to_lowercase(),
+    }
+}
+
+fn host_of(site: &str) -> &str {
+    site.split('/').next().unwrap_or_default()
+}
+
+/// URL to crawl `site` with.
+/// Onion services are reached through `onion_gateway` (e.g. `onion.ly`), skipped if not configured.
+fn site_url(site: &str) -> Option<String> {
+    let host = host_of(site);
+    if !host.ends_with(".onion") {
+        return Some(format!("https://{}", site));
+    }
+    let gateway = &C.upstream.website.onion_gateway;
+    if gateway.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "https://{}.{}{}",
+        host,
+        gateway,
+        &site[host.len()..]
+    ))
+}
+
+/// Keybase username in a `keybase.txt`, e.g. `... My keybase username is [alice](https://keybase.io/alice).`
+fn parse_keybase_txt(txt: &str) -> Option<String> {
+    let start = txt.find("https://keybase.io/")? + "https://keybase.io/".len();
+    let username: String = txt[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    if username.is_empty() {
+        None
+    } else {
+        Some(username.to_lowercase())
+    }
+}
+
+/// NextID persona public key in `.well-known/nextid`.
+fn parse_nextid(txt: &str) -> Option<String> {
+    let persona = txt.trim().to_lowercase();
+    if persona.starts_with("0x") && persona.len() > 2 {
+        Some(persona)
+    } else {
+        None
+    }
+}
+
+/// Fetch a file of `site`, `None` if it is unreachable.
+async fn fetch_file(client: &PinnedClient, base_url: &str, path: &str) -> Option<String> {
+    fetch_html_with(client, &format!("{}{}", base_url, path))
+        .await
+        .ok()
+}
+
+async fn fetch_claims_by_site(site: &str) -> Result<Fetched, Error> {
+    let site = normalize_site(site);
+    let base_url = match site_url(&site) {
+        Some(base_url) => base_url,
+        None => {
+            info!("Website fetch | {} skipped, no onion gateway", site);
+            return Ok(Fetched::default());
+        }
+    };
+    let client = match resolve_public_url(&base_url).await {
+        Some(addrs) => make_pinned_client(addrs),
+        None => {
+            warn!("Website fetch | {} skipped, not a public host", site);
+            return Ok(Fetched::default());
+        }
+    };
+    let path = &site[host_of(&site).len()..];
+    let origin = &base_url[..base_url.len() - path.len()];
+
+    let site_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Website,
+        identity: site.clone(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://{}", site)),
+        updated_at: naive_now(),
+    };
+    let mut fetched = Fetched::default();
+
+    // `rel="me"` only states what the owner of this page claims.
+    let html = fetch_file(&client, &base_url, "").await.unwrap_or_default();
+    for link in extract_rel_me(&html).into_iter() {
+        let (platform, identity) = match link_to_identity(&link) {
+            Some((Platform::DNS, domain)) if domain == host_of(&site) => continue,
+            Some(claimed) => claimed,
+            None => continue,
+        };
+        let claimed_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: Some(link.clone()),
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Website,
+            record_id: Some(link),
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::Proof(
+            site_identity.clone(),
+            claimed_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+
+    // Files under the site root are controlled by the owner of the whole host,
+    // but they only bind it to an account which also claims this host.
+    let host = host_of(&site).split(':').next().unwrap_or_default();
+    let mut keybase_txt = None;
+    for path in KEYBASE_TXT_PATHS {
+        if let Some(txt) = fetch_file(&client, origin, path).await {
+            if let Some(username) = parse_keybase_txt(&txt) {
+                keybase_txt = Some((username, format!("{}{}", origin, path)));
+                break;
+            }
+        }
+    }
+    if let Some((username, url)) = keybase_txt {
+        match lookup(&format!("usernames={}", username)).await {
+            Ok(person_info) => {
+                let keybase_identity: Identity = Identity {
+                    uuid: Some(Uuid::new_v4()),
+                    platform: Platform::Keybase,
+                    identity: person_info.id.clone(),
+                    created_at: None,
+                    display_name: Some(person_info.basics.username.clone()),
+                    added_at: naive_now(),
+                    avatar_url: None,
+                    profile_url: None,
+                    updated_at: naive_now(),
+                };
+                let confirmed = keybase_proof_of(&person_info, host)
+                    .map(|proof| (DataSource::Keybase, Some(proof.proof_id.clone())));
+                fetched
+                    .connections
+                    .push(claim(&site_identity, keybase_identity, url, confirmed));
+                fetched
+                    .next_targets
+                    .push(Target::Identity(Platform::Keybase, person_info.id));
+            }
+            Err(err) => warn!("Website fetch | Keybase user {}: {}", username, err),
+        }
+    }
+
+    if let Some(persona) = fetch_file(&client, origin, NEXTID_PATH)
+        .await
+        .as_deref()
+        .and_then(parse_nextid)
+    {
+        let nextid_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::NextID,
+            identity: persona.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let confirmed = match nextid_proof_of(&persona, host).await {
+            Ok(found) => found.map(|proof| (DataSource::NextID, proof.proof_location)),
+            Err(err) => {
+                warn!("Website fetch | NextID persona {}: {}", persona, err);
+                None
+            }
+        };
+        fetched.connections.push(claim(
+            &site_identity,
+            nextid_identity,
+            format!("{}{}", origin, NEXTID_PATH),
+            confirmed,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(Platform::NextID, persona));
+    }
+
+    if fetched.next_targets.is_empty() {
+        info!("Website fetch | {} has no identity claim", site);
+    }
+    Ok(fetched)
+}
+
+/// `site` claims `account` in file `url`. If `account` claims the host of `site` as well
+/// (`confirmed` by `source`, with its `record_id`), they are bound to each other.
+/// Otherwise it is only a one-way claim of `site`.
+fn claim(
+    site: &Identity,
+    account: Identity,
+    url: String,
+    confirmed: Option<(DataSource, Option<String>)>,
+) -> Connection {
+    let proof = |source, record_id, level| Proof {
+        uuid: Uuid::new_v4(),
+        source,
+        record_id,
+        created_at: None,
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level,
+    };
+    match confirmed {
+        Some((source, record_id)) => Connection::TwoWayBinding(
+            site.clone(),
+            account,
+            proof(source, record_id, ProofLevel::PlatformVerified),
+        ),
+        None => Connection::Proof(
+            site.clone(),
+            account,
+            proof(DataSource::Website, Some(url), ProofLevel::SelfClaimed),
+        ),
+    }
+}
+
+/// Keybase proof of `person` for website or domain `host`, if it is in place.
+fn keybase_proof_of<'a>(person: &'a PersonInfo, host: &str) -> Option<&'a ProofItem> {
+    person.proofs_summary.all.iter().find(|p| {
+        p.state == PROOF_STATE_OK
+            && (p.proof_type == "generic_web_site" || p.proof_type == "dns")
+            && p.nametag.eq_ignore_ascii_case(host)
+    })
+}
+
+/// Valid DNS proof of NextID `persona` for `host`.
+async fn nextid_proof_of(persona: &str, host: &str) -> Result<Option<ProofRecord>, Error> {
+    Ok(query(&Platform::NextID, persona)
+        .await?
+        .ids
+        .into_iter()
+        .filter(|id| id.avatar.eq_ignore_ascii_case(persona))
+        .flat_map(|id| id.proofs)
+        .find(|p| {
+            p.is_valid
+                && p.platform == Platform::DNS.to_string()
+                && p.identity.eq_ignore_ascii_case(host)
+        }))
+}
@@ -0,0 +1,99 @@
+use crate::{
+    error::Error,
+    graph::{edge::ProofLevel, vertex::Identity},
+    upstream::{
+        website::{
+            claim, fetch_claims_by_site, normalize_site, parse_keybase_txt, parse_nextid, site_url,
+        },
+        Connection, DataSource, Platform,
+    },
+};
+use fake::{Fake, Faker};
+use std::str::FromStr;
+
+#[test]
+fn test_normalize_site() {
+    assert_eq!(normalize_site("https://Example.com/"), "example.com");
+    assert_eq!(
+        normalize_site("http://example.com/~Alice/"),
+        "example.com/~Alice"
+    );
+    assert_eq!(normalize_site("example.com"), "example.com");
+}
+
+#[tokio::test]
+async fn test_fetch_claims_by_private_site() -> Result<(), Error> {
+    let fetched = fetch_claims_by_site("http://127.0.0.1:8529/").await?;
+    assert!(fetched.connections.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_site_url() {
+    assert_eq!(
+        site_url("example.com/~alice"),
+        Some("https://example.com/~alice".to_string())
+    );
+}
+
+#[test]
+fn test_keybase_platform() {
+    assert_eq!(
+        Platform::from_str("generic_web_site").unwrap(),
+        Platform::Website
+    );
+    assert_eq!(Platform::Website.to_string(), "website");
+}
+
+#[test]
+fn test_parse_keybase_txt() {
+    let txt = "==================================================================
+https://keybase.io/chris
+--------------------------------------------------------------------
+
+I hereby claim:
+
+  * I am an admin of https://chriscoyne.com
+  * I am chris (https://keybase.io/chris) on keybase.";
+    assert_eq!(parse_keybase_txt(txt), Some("chris".to_string()));
+    assert_eq!(parse_keybase_txt("hello"), None);
+}
+
+#[test]
+fn test_parse_nextid() {
+    assert_eq!(
+        parse_nextid("0x02D7C5E01BEDF1C993F40EC302D9BF162620DAEA93A7155CD9A8019AE3A2C2A476\n"),
+        Some("0x02d7c5e01bedf1c993f40ec302d9bf162620daea93a7155cd9a8019ae3a2c2a476".to_string())
+    );
+    assert_eq!(parse_nextid("<html></html>"), None);
+}
+
+#[test]
+fn test_claim() {
+    let site: Identity = Faker.fake();
+    let account: Identity = Faker.fake();
+    let url = "https://example.com/keybase.txt".to_string();
+    match claim(&site, account.clone(), url.clone(), None) {
+        Connection::Proof(_, _, proof) => {
+            assert_eq!(proof.source, DataSource::Website);
+            assert_eq!(proof.record_id, Some(url.clone()));
+            assert_eq!(proof.level, ProofLevel::SelfClaimed);
+        }
+        _ => panic!("A claim not confirmed by the other side should be one-way"),
+    }
+    let confirmed = Some((DataSource::Keybase, Some("proof_id".to_string())));
+    match claim(&site, account, url, confirmed) {
+        Connection::TwoWayBinding(_, _, proof) => {
+            assert_eq!(proof.source, DataSource::Keybase);
+            assert_eq!(proof.level, ProofLevel::PlatformVerified);
+        }
+        _ => panic!("A claim confirmed by the other side should be a binding"),
+    }
+}
+
+#[tokio::test]
+async fn test_fetch_claims_by_site() -> Result<(), Error> {
+    let fetched = fetch_claims_by_site("chriscoyne.com").await?;
+    println!("fetched: {:?}", fetched);
+    Ok(())
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use chrono::DateTime;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// Verification of an address, recorded by the configured World ID app
+/// after verifying the zero-knowledge proof submitted by its holder.
+#[derive(Deserialize, Debug)]
+pub struct Verification {
+    pub verified: bool,
+    /// `orb` or `device`.
+    pub verification_level: String,
+    /// Unique per human per app action. Same human always gets the same one.
+    pub nullifier_hash: String,
+    pub created_at: Option<String>,
+}
+
+/// Only Orb verification proves personhood.
+const VERIFICATION_LEVEL_ORB: &str = "orb";
+
+pub struct WorldID {}
+
+#[async_trait]
+impl Fetcher for WorldID {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_verification_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        // Only available when a World ID app is configured.
+        !C.upstream.world_id.url.is_empty()
+            && target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+impl Verification {
+    pub fn is_orb_verified(&self) -> bool {
+        self.verified && self.verification_level == VERIFICATION_LEVEL_ORB
+    }
+}
+
+async fn fetch_verification(address: &str) -> Result<Option<Verification>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!(
+        "{}/v1/verifications/{}/{}",
+        C.upstream.world_id.url, C.upstream.world_id.app_id, address
+    )
+    .parse()
+    .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("WorldID Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("WorldID fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("WorldID Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+async fn fetch_verification_by_address(address: &str) -> Result<Fetched, Error> {
+    let address = address.to_lowercase();
+    let verification = match fetch_verification(&address).await? {
+        Some(verification) if verification.is_orb_verified() => verification,
+        _ => {
+            info!("WorldID fetch | {} is not orb verified", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: address,
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    // Addresses verified by the same human share one WorldID identity.
+    let world_id_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::WorldID,
+        identity: verification.nullifier_hash.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+    let proof: Proof = Proof {
+        uuid: Uuid::new_v4(),
+        source: DataSource::WorldID,
+        record_id: Some(verification.nullifier_hash.to_lowercase()),
+        created_at: verification
+            .created_at
+            .as_deref()
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+            .map(|dt| dt.naive_utc()),
+        updated_at: naive_now(),
+        fetcher: DataFetcher::RelationService,
+        verified: false,
+        expired_at: None,
+        invalid_since: None,
+        invalidation_reason: None,
+        level: ProofLevel::PlatformVerified,
+    };
+    // Nullifier hash cannot be resolved back to other addresses.
+    Ok(Fetched {
+        connections: vec![Connection::Proof(eth_identity, world_id_identity, proof)],
+        ..Default::default()
+    })
+}
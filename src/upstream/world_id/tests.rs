@@ -0,0 +1,14 @@
+use crate::upstream::world_id::Verification;
+
+#[test]
+fn test_is_orb_verified() {
+    let verification = |verified: bool, level: &str| Verification {
+        verified,
+        verification_level: level.into(),
+        nullifier_hash: "0x2bf8406809dcefb1486dadc96c0a897db9bab002053054cf64272db512c6fbd8".into(),
+        created_at: None,
+    };
+    assert!(verification(true, "orb").is_orb_verified());
+    assert!(!verification(true, "device").is_orb_verified());
+    assert!(!verification(false, "orb").is_orb_verified());
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use url::form_urlencoded::byte_serialize;
+use uuid::Uuid;
+
+/// Record tags of an emoji ID. https://api-docs.y.at
+const TAG_ETHEREUM: &str = "0x1004";
+const TAG_WEBSITE: &str = "0x4001";
+
+#[derive(Deserialize, Debug)]
+pub struct EmojiIdResponse {
+    pub status: bool,
+    #[serde(default)]
+    pub result: Vec<EmojiIdRecord>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EmojiIdRecord {
+    pub tag: String,
+    pub data: String,
+}
+
+pub struct Yat {}
+
+#[async_trait]
+impl Fetcher for Yat {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, yat) => fetch_records_by_yat(yat).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Yat])
+    }
+}
+
+/// Turn a Yat record into the identity it links to.
+/// Websites are recorded as the DNS identity of their host.
+fn record_to_identity(record: &EmojiIdRecord) -> Option<(Platform, String)> {
+    match record.tag.as_str() {
+        TAG_ETHEREUM if record.data.starts_with("0x") => {
+            Some((Platform::Ethereum, record.data.to_lowercase()))
+        }
+        TAG_WEBSITE => {
+            let url = url::Url::parse(&record.data).ok()?;
+            let host = url.host_str()?.trim_start_matches("www.");
+            Some((Platform::DNS, host.to_lowercase()))
+        }
+        _ => None,
+    }
+}
+
+async fn fetch_records(yat: &str) -> Result<Vec<EmojiIdRecord>, Error> {
+    let client = make_client();
+    let yat: String = byte_serialize(yat.as_bytes()).collect();
+    let uri: http::Uri = format!("{}/emoji_id/{}", C.upstream.yat_api.url, yat)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Yat Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Yat fetch | error: {:?}", err.to_string()))
+    })?;
+
+    if resp.status() == http::StatusCode::NOT_FOUND {
+        return Ok(vec![]);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Yat Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: EmojiIdResponse = parse_body(&mut resp).await?;
+    if !body.status {
+        return Ok(vec![]);
+    }
+    Ok(body.result)
+}
+
+async fn fetch_records_by_yat(yat: &str) -> Result<Fetched, Error> {
+    let records = fetch_records(yat).await?;
+    if records.is_empty() {
+        info!("Yat fetch | {} has no record", yat);
+        return Ok(Fetched::default());
+    }
+
+    let yat_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Yat,
+        identity: yat.to_string(),
+        created_at: None,
+        display_name: Some(yat.to_string()),
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: Some(format!("https://y.at/{}", yat)),
+        updated_at: naive_now(),
+    };
+
+    let mut fetched = Fetched::default();
+    for (platform, identity) in records.iter().filter_map(record_to_identity) {
+        let to: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: identity.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        // Records are set by the Yat owner only, so this is a one-way proof.
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Yat,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched
+            .connections
+            .push(Connection::Proof(yat_identity.clone(), to, proof));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, identity));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,40 @@
+use crate::{
+    error::Error,
+    upstream::{
+        yat::{fetch_records, record_to_identity, EmojiIdRecord},
+        Platform,
+    },
+};
+
+#[test]
+fn test_record_to_identity() {
+    let record = |tag: &str, data: &str| EmojiIdRecord {
+        tag: tag.into(),
+        data: data.into(),
+    };
+    assert_eq!(
+        record_to_identity(&record(
+            "0x1004",
+            "0x934B510D4C9103E6a87AEf13b816fb080286D649"
+        )),
+        Some((
+            Platform::Ethereum,
+            "0x934b510d4c9103e6a87aef13b816fb080286d649".into()
+        ))
+    );
+    assert_eq!(
+        record_to_identity(&record("0x4001", "https://www.example.com/about")),
+        Some((Platform::DNS, "example.com".into()))
+    );
+    assert_eq!(
+        record_to_identity(&record("0x1001", "48edfHu7V9Z84YzzMa6fUueoELZ9ZRXq9")),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_records() -> Result<(), Error> {
+    let records = fetch_records("🦊🦊🦊").await?;
+    println!("records: {:?}", records);
+    Ok(())
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        edge::{Proof, ProofLevel},
+        vertex::Identity,
+    },
+    upstream::{Connection, DataFetcher, DataSource, Fetched, Fetcher, Platform, Target},
+    util::{make_client, naive_now, parse_body, request_with_timeout},
+};
+use async_trait::async_trait;
+use http::{uri::InvalidUri, StatusCode};
+use hyper::{Body, Method};
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+/// https://zora.co/api/profiles/{address}
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub address: String,
+    /// `https://zora.co/@{handle}`
+    pub handle: Option<String>,
+    pub display_name: Option<String>,
+    pub avatar: Option<String>,
+    pub social_accounts: Option<SocialAccounts>,
+}
+
+/// Accounts linked by the owner on Zora.
+#[derive(Deserialize, Debug, Default)]
+pub struct SocialAccounts {
+    pub twitter: Option<SocialAccount>,
+    pub farcaster: Option<SocialAccount>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SocialAccount {
+    pub username: String,
+}
+
+pub struct Zora {}
+
+#[async_trait]
+impl Fetcher for Zora {
+    async fn fetch(target: &Target) -> Result<Fetched, Error> {
+        if !Self::can_fetch(target) {
+            return Ok(Fetched::default());
+        }
+
+        match target {
+            Target::Identity(_, address) => fetch_profile_by_address(address).await,
+            Target::NFT(_, _, _, _) => Ok(Fetched::default()),
+        }
+    }
+
+    fn can_fetch(target: &Target) -> bool {
+        target.in_platform_supported(vec![Platform::Ethereum])
+    }
+}
+
+async fn fetch_profile(address: &str) -> Result<Option<Profile>, Error> {
+    let client = make_client();
+    let uri: http::Uri = format!("{}/profiles/{}", C.upstream.zora_api.url, address)
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Zora Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Zora fetch | error: {:?}", err.to_string()))
+    })?;
+
+    // Address never created a Zora profile.
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Zora Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    Ok(Some(parse_body(&mut resp).await?))
+}
+
+/// `@Alice` / `alice` => `alice`
+fn normalize_username(username: &str) -> String {
+    username.trim().trim_start_matches('@').to_lowercase()
+}
+
+async fn fetch_profile_by_address(address: &str) -> Result<Fetched, Error> {
+    let profile = match fetch_profile(address).await? {
+        Some(profile) => profile,
+        None => {
+            info!("Zora fetch | {} has no Zora profile", address);
+            return Ok(Fetched::default());
+        }
+    };
+
+    let mut fetched = Fetched::default();
+    let eth_identity: Identity = Identity {
+        uuid: Some(Uuid::new_v4()),
+        platform: Platform::Ethereum,
+        identity: profile.address.to_lowercase(),
+        created_at: None,
+        display_name: None,
+        added_at: naive_now(),
+        avatar_url: None,
+        profile_url: None,
+        updated_at: naive_now(),
+    };
+
+    if let Some(handle) = profile
+        .handle
+        .as_deref()
+        .map(normalize_username)
+        .filter(|handle| !handle.is_empty())
+    {
+        let zora_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform: Platform::Zora,
+            identity: handle.clone(),
+            created_at: None,
+            display_name: profile.display_name.clone().filter(|name| !name.is_empty()),
+            added_at: naive_now(),
+            avatar_url: profile.avatar.clone(),
+            profile_url: Some(format!("https://zora.co/@{}", handle)),
+            updated_at: naive_now(),
+        };
+        // Zora profile is created by signing in with the address.
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Zora,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::PlatformVerified,
+        };
+        fetched.connections.push(Connection::TwoWayBinding(
+            eth_identity.clone(),
+            zora_identity,
+            proof,
+        ));
+    }
+
+    let social_accounts = profile.social_accounts.unwrap_or_default();
+    for (platform, account) in [
+        (Platform::Twitter, social_accounts.twitter),
+        (Platform::Farcaster, social_accounts.farcaster),
+    ] {
+        let username = match account
+            .map(|account| normalize_username(&account.username))
+            .filter(|username| !username.is_empty())
+        {
+            Some(username) => username,
+            None => continue,
+        };
+        let social_identity: Identity = Identity {
+            uuid: Some(Uuid::new_v4()),
+            platform,
+            identity: username.clone(),
+            created_at: None,
+            display_name: None,
+            added_at: naive_now(),
+            avatar_url: None,
+            profile_url: None,
+            updated_at: naive_now(),
+        };
+        let proof: Proof = Proof {
+            uuid: Uuid::new_v4(),
+            source: DataSource::Zora,
+            record_id: None,
+            created_at: None,
+            updated_at: naive_now(),
+            fetcher: DataFetcher::RelationService,
+            verified: false,
+            expired_at: None,
+            invalid_since: None,
+            invalidation_reason: None,
+            level: ProofLevel::SelfClaimed,
+        };
+        fetched.connections.push(Connection::Proof(
+            eth_identity.clone(),
+            social_identity,
+            proof,
+        ));
+        fetched
+            .next_targets
+            .push(Target::Identity(platform, username));
+    }
+
+    Ok(fetched)
+}
@@ -0,0 +1,32 @@
+use crate::{
+    error::Error,
+    upstream::zora::{fetch_profile, Profile},
+};
+
+#[test]
+fn test_parse_profile() -> Result<(), Error> {
+    let body = r#"{
+        "address": "0x17cd072cBd45031EFc21Da538c783E0ed3b25DCc",
+        "handle": "jacob",
+        "displayName": "Jacob",
+        "avatar": null,
+        "socialAccounts": {
+            "twitter": { "username": "js_horne" },
+            "farcaster": null
+        }
+    }"#;
+    let profile: Profile = serde_json::from_str(body)?;
+    assert_eq!(profile.handle.as_deref(), Some("jacob"));
+    let social_accounts = profile.social_accounts.unwrap();
+    assert_eq!(social_accounts.twitter.unwrap().username, "js_horne");
+    assert!(social_accounts.farcaster.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_profile() -> Result<(), Error> {
+    let address = "0x17cd072cbd45031efc21da538c783e0ed3b25dcc";
+    let profile = fetch_profile(address).await?;
+    println!("profile: {:?}", profile);
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::{error::Error, util::eth::to_checksum_address};
+
+/// `0x` followed by 40 hex digits, in any case.
+pub fn is_evm_address(address: &str) -> bool {
+    address.len() == 42
+        && address.starts_with("0x")
+        && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Lowercase an EVM address, which is how it is saved as `Identity.identity`.
+/// A mixed-case one should be a valid EIP-55 checksum, to catch typos.
+pub fn normalize_evm_address(address: &str) -> Result<String, Error> {
+    if !is_evm_address(address) {
+        return Err(Error::InvalidIdentityFormat(format!(
+            "{} is not an EVM address",
+            address
+        )));
+    }
+    let digits = &address[2..];
+    let is_mixed_case = digits.chars().any(|c| c.is_ascii_lowercase())
+        && digits.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && to_checksum_address(address) != address {
+        return Err(Error::InvalidIdentityFormat(format!(
+            "{} has an invalid EIP-55 checksum",
+            address
+        )));
+    }
+    Ok(address.to_lowercase())
+}
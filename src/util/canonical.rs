@@ -0,0 +1,104 @@
+use crate::{error::Error, upstream::Platform, util::address::normalize_evm_address};
+use unicode_normalization::UnicodeNormalization;
+
+/// How identities of a platform are written in one way,
+/// so the same account is always saved as the same vertex.
+pub trait Canonicalizer: Send + Sync {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error>;
+}
+
+/// Kept as is, for case-sensitive or opaque identities.
+pub struct Verbatim;
+
+/// EVM address, lowercased (see `normalize_evm_address`).
+pub struct EvmAddress;
+
+/// Case-insensitive username, lowercased without leading `@`.
+pub struct Handle;
+
+/// Case-insensitive identity, lowercased.
+pub struct Lowercase;
+
+/// Name of a name service (ENS alike), NFC-normalized and lowercased.
+pub struct Name;
+
+/// Domain name, lowercased and punycode-encoded.
+pub struct Domain;
+
+/// Fediverse account `user@domain`, lowercased without leading `@`, with domain punycode-encoded.
+pub struct FediverseAccount;
+
+impl Canonicalizer for Verbatim {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        Ok(identity.to_string())
+    }
+}
+
+impl Canonicalizer for EvmAddress {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        normalize_evm_address(identity)
+    }
+}
+
+impl Canonicalizer for Handle {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        Ok(identity.trim_start_matches('@').to_lowercase())
+    }
+}
+
+impl Canonicalizer for Lowercase {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        Ok(identity.to_lowercase())
+    }
+}
+
+impl Canonicalizer for Name {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        Ok(identity.nfc().collect::<String>().to_lowercase())
+    }
+}
+
+impl Canonicalizer for Domain {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        idna::domain_to_ascii(identity.trim_end_matches('.'))
+            .map_err(|_| Error::InvalidIdentityFormat(format!("{} is not a domain name", identity)))
+    }
+}
+
+impl Canonicalizer for FediverseAccount {
+    fn canonicalize(&self, identity: &str) -> Result<String, Error> {
+        let account = identity.trim_start_matches('@');
+        match account.split_once('@') {
+            Some((user, domain)) => Ok(format!(
+                "{}@{}",
+                user.to_lowercase(),
+                Domain.canonicalize(domain)?
+            )),
+            None => Err(Error::InvalidIdentityFormat(format!(
+                "{} is not in user@domain form",
+                identity
+            ))),
+        }
+    }
+}
+
+/// Canonicalizer of identities on `platform`.
+pub fn canonicalizer(platform: &Platform) -> &'static dyn Canonicalizer {
+    use Platform::*;
+    match platform {
+        // Keep in sync with migration `20261016_lowercase_evm_addresses`.
+        Ethereum | ProofOfHumanity => &EvmAddress,
+        Twitter | Github | Reddit | Telegram | Instagram | Keybase | DeBank => &Handle,
+        Email | Etherscan | Matrix | Aptos | Cosmos => &Lowercase,
+        Lens | Dotbit | UnstoppableDomains | SpaceId | SNS | Crossbell | CyberConnect
+        | AptosNames | Avvy | ICNS | StargazeNames | Snapshot => &Name,
+        DNS => &Domain,
+        Mastodon => &FediverseAccount,
+        _ => &Verbatim,
+    }
+}
+
+/// Write `identity` on `platform` in the way it is saved.
+pub fn canonicalize(platform: &Platform, identity: &str) -> Result<String, Error> {
+    canonicalizer(platform).canonicalize(identity)
+}
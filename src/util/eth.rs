@@ -0,0 +1,197 @@
+//! Helpers for verifying Ethereum signatures.
+
+use crate::{
+    config::C,
+    error::Error,
+    util::{make_client, parse_body, request_with_timeout},
+};
+use http::uri::InvalidUri;
+use hyper::{Body, Method};
+use k256::{
+    ecdsa::recoverable::{Id as RecoveryId, Signature as RecoverableSignature},
+    ecdsa::Signature,
+    elliptic_curve::sec1::ToEncodedPoint,
+};
+use serde::Deserialize;
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+use tracing::debug;
+
+/// Selector of `isValidSignature(bytes32,bytes)`, which is also the magic value
+/// returned by a contract wallet when the signature is valid.
+/// https://eips.ethereum.org/EIPS/eip-1271
+const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// `keccak256(data)`
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Hash of a personal message (EIP-191, version `0x45`), as `personal_sign` does.
+pub fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    data.extend_from_slice(message);
+    keccak256(&data)
+}
+
+/// Hash of typed structured data (EIP-712), given its domain separator and struct hash.
+pub fn eip712_hash(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut data = vec![0x19, 0x01];
+    data.extend_from_slice(domain_separator);
+    data.extend_from_slice(struct_hash);
+    keccak256(&data)
+}
+
+/// Left-pad `data` into a 32-byte ABI word.
+pub fn abi_word(data: &[u8]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[32 - data.len()..].copy_from_slice(data);
+    word
+}
+
+/// Decode a `0x`-prefixed hex string.
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|err| Error::ParamError(format!("Hex parse error: {}", err)))
+}
+
+/// EIP-55 mixed-case checksum encoding of an address.
+/// https://eips.ethereum.org/EIPS/eip-55
+pub fn to_checksum_address(address: &str) -> String {
+    let address = address.trim_start_matches("0x").to_lowercase();
+    let hash = hex::encode(keccak256(address.as_bytes()));
+    let checksummed: String = address
+        .chars()
+        .zip(hash.chars())
+        .map(|(c, h)| {
+            if c.is_ascii_alphabetic() && h.to_digit(16).is_some_and(|h| h >= 8) {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+/// Recover signer address (lowercase, `0x`-prefixed) of a 65-byte `r || s || v` signature on `hash`.
+pub fn recover_address(hash: &[u8; 32], signature: &[u8]) -> Result<String, Error> {
+    if signature.len() != 65 {
+        return Err(Error::SignatureValidationError(format!(
+            "Ethereum signature should be 65 bytes, got {}",
+            signature.len()
+        )));
+    }
+    // `v` is either 0 / 1 or 27 / 28.
+    let v = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => {
+            return Err(Error::SignatureValidationError(format!(
+                "Ethereum signature has an invalid v: {}",
+                v
+            )))
+        }
+    };
+    let invalid = |err: k256::ecdsa::Error| Error::SignatureValidationError(err.to_string());
+    let signature = Signature::try_from(&signature[..64]).map_err(invalid)?;
+    let recovery_id = RecoveryId::new(v).map_err(invalid)?;
+    let public_key = RecoverableSignature::new(&signature, recovery_id)
+        .map_err(invalid)?
+        .recover_verifying_key_from_digest_bytes(hash.into())
+        .map_err(invalid)?;
+    let point = public_key.to_encoded_point(false);
+    // Address is the last 20 bytes of `keccak256(uncompressed public key without 0x04 prefix)`.
+    Ok(format!(
+        "0x{}",
+        hex::encode(&keccak256(&point.as_bytes()[1..])[12..])
+    ))
+}
+
+/// Whether `signature` of personal message `message` is signed by `address` (EOA only).
+pub fn is_personal_signed_by(message: &str, signature: &[u8], address: &str) -> bool {
+    recover_address(&eip191_hash(message.as_bytes()), signature)
+        .is_ok_and(|signer| signer.eq_ignore_ascii_case(address))
+}
+
+/// Whether `signature` of `hash` is signed by `address`.
+/// Falls back to EIP-1271 `isValidSignature` of `address` when it is not
+/// the ECDSA signer, so contract wallets are supported as long as an RPC
+/// is configured.
+pub async fn is_signed_by(hash: &[u8; 32], signature: &[u8], address: &str) -> Result<bool, Error> {
+    if recover_address(hash, signature).is_ok_and(|signer| signer.eq_ignore_ascii_case(address)) {
+        return Ok(true);
+    }
+    if C.upstream.ethereum_rpc.url.is_empty() {
+        return Ok(false);
+    }
+    is_valid_contract_signature(address, hash, signature).await
+}
+
+#[derive(Deserialize, Debug)]
+struct JsonRpcResponse {
+    result: Option<String>,
+    error: Option<serde_json::Value>,
+}
+
+/// Call `isValidSignature(bytes32 hash, bytes signature)` of contract `address`.
+async fn is_valid_contract_signature(
+    address: &str,
+    hash: &[u8; 32],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    // selector || hash || offset of `signature` || length || `signature` padded to 32 bytes
+    let mut call_data = EIP1271_MAGIC_VALUE.to_vec();
+    call_data.extend_from_slice(hash);
+    call_data.extend_from_slice(&abi_word(&[0x40]));
+    call_data.extend_from_slice(&abi_word(&(signature.len() as u64).to_be_bytes()));
+    call_data.extend_from_slice(signature);
+    call_data.resize(call_data.len() + (32 - signature.len() % 32) % 32, 0);
+
+    match eth_call(&C.upstream.ethereum_rpc.url, address, &call_data).await? {
+        Some(result) => Ok(result.len() >= 4 && result[..4] == EIP1271_MAGIC_VALUE),
+        // Reverted: not a contract wallet, or it rejects the signature.
+        None => Ok(false),
+    }
+}
+
+/// `eth_call` `call_data` on contract `to` at the latest block.
+/// Returns `None` if the call reverted.
+pub async fn eth_call(rpc_url: &str, to: &str, call_data: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+    let client = make_client();
+    let uri: http::Uri = rpc_url
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_call",
+        "params": [
+            {"to": to, "data": format!("0x{}", hex::encode(call_data))},
+            "latest"
+        ],
+    });
+    let req = hyper::Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .map_err(|_err| Error::ParamError(format!("Ethereum RPC Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(&client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Ethereum RPC | error: {:?}", err.to_string()))
+    })?;
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Ethereum RPC error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let body: JsonRpcResponse = parse_body(&mut resp).await?;
+    if let Some(error) = body.error {
+        debug!("Ethereum RPC | eth_call to {} reverted: {}", to, error);
+        return Ok(None);
+    }
+    Ok(Some(decode_hex(&body.result.unwrap_or_default())?))
+}
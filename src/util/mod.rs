@@ -1,16 +1,47 @@
+pub mod address;
+pub mod canonical;
+pub mod eth;
+pub mod scrape;
+pub mod single_flight;
 #[cfg(test)]
 mod tests;
 
-use std::{collections::HashSet, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    hash::Hash,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
 
-use crate::error::Error;
+use crate::{config::C, error::Error};
 use chrono::NaiveDateTime;
-use http::Response;
-use hyper::{body::HttpBody as _, client::HttpConnector, Body, Client, Request};
+use futures::future::{ready, Ready};
+use http::{Response, StatusCode};
+use hyper::{
+    body::HttpBody as _,
+    client::{
+        connect::{dns::Name, Connect},
+        HttpConnector,
+    },
+    service::Service,
+    Body, Client, Request,
+};
 use hyper_tls::HttpsConnector;
+use rand::Rng;
 use serde::Deserialize;
+use tokio::{
+    net::lookup_host,
+    sync::{OwnedSemaphorePermit, Semaphore},
+};
+use tracing::warn;
 
-const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+lazy_static! {
+    /// Concurrency cap of each upstream host.
+    static ref HOST_PERMITS: Mutex<HashMap<String, Arc<Semaphore>>> = Mutex::new(HashMap::new());
+}
 
 /// Returns current UNIX timestamp (unit: second).
 pub fn timestamp() -> i64 {
@@ -34,33 +65,226 @@ pub fn timestamp_to_naive(ts: i64, ms: u32) -> NaiveDateTime {
 }
 
 pub fn make_client() -> Client<HttpsConnector<HttpConnector>> {
-    let https = HttpsConnector::new();
-    // let mut http = HttpConnector::new();
-    // http.set_connect_timeout(Some(std::time::Duration::from_secs(5)));
-    // let https = HttpsConnector::new_with_connector(http);
+    let mut http = HttpConnector::new();
+    http.set_connect_timeout(Some(Duration::from_secs(C.http.timeout)));
+    http.enforce_http(false);
+    let https = HttpsConnector::new_with_connector(http);
 
     Client::builder().build::<_, hyper::Body>(https)
 }
 
-pub async fn request_with_timeout(
-    client: &Client<HttpsConnector<HttpConnector>>,
-    req: Request<Body>,
-) -> Result<Response<Body>, Error> {
-    match tokio::time::timeout(DEFAULT_TIMEOUT, client.request(req)).await {
-        Ok(resp) => match resp {
-            Ok(resp) => Ok(resp),
-            Err(err) => Err(Error::General(
-                format!("error: {:?}", err),
-                lambda_http::http::StatusCode::BAD_REQUEST,
-            )),
+/// Whether `ip` is reachable from the public internet.
+pub fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                || a == 0
+                // Carrier-grade NAT, 100.64.0.0/10
+                || (a == 100 && (b & 0xc0) == 64))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(&IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // Unique local, fc00::/7
+                    || (first & 0xfe00) == 0xfc00
+                    // Link-local, fe80::/10
+                    || (first & 0xffc0) == 0xfe80)
+            }
         },
+    }
+}
+
+/// Addresses of the host of `url`, if it's a domain name resolved to public addresses only.
+/// For URLs given by users, this keeps us from requesting internal services (SSRF).
+/// Connect to these addresses only (see `make_pinned_client`), since resolving it again
+/// may give a private one (DNS rebinding).
+pub async fn resolve_public_url(url: &str) -> Option<Vec<SocketAddr>> {
+    let uri: http::Uri = url.parse().ok()?;
+    let host = uri.host()?.trim_end_matches('.').to_lowercase();
+    if host.starts_with('[')
+        || host.parse::<IpAddr>().is_ok()
+        || host == "localhost"
+        || host.ends_with(".localhost")
+    {
+        return None;
+    }
+    let addrs: Vec<SocketAddr> = lookup_host((host.as_str(), uri.port_u16().unwrap_or(443)))
+        .await
+        .ok()?
+        .collect();
+    if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(&addr.ip())) {
+        return None;
+    }
+    Some(addrs)
+}
+
+/// HTTP client connecting to given addresses only, see `make_pinned_client`.
+pub type PinnedClient = Client<HttpsConnector<HttpConnector<PinnedResolver>>>;
+
+/// Same as `make_client`, but whatever the host is, it connects to one of `addrs`.
+/// Use it to connect to addresses checked beforehand: resolving the host again
+/// may give another address by then (DNS rebinding).
+pub fn make_pinned_client(addrs: Vec<SocketAddr>) -> PinnedClient {
+    let mut http = HttpConnector::new_with_resolver(PinnedResolver(addrs));
+    http.set_connect_timeout(Some(Duration::from_secs(C.http.timeout)));
+    http.enforce_http(false);
+    let https = HttpsConnector::new_with_connector(http);
+
+    Client::builder().build::<_, hyper::Body>(https)
+}
+
+/// Resolves every host to the same addresses (port is taken from the URI anyway).
+#[derive(Clone)]
+pub struct PinnedResolver(Vec<SocketAddr>);
+
+impl Service<Name> for PinnedResolver {
+    type Response = std::vec::IntoIter<SocketAddr>;
+    type Error = std::io::Error;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Name) -> Self::Future {
+        ready(Ok(self.0.clone().into_iter()))
+    }
+}
+
+/// Wait for `request` to upstream `name` (e.g. a GraphQL query) at most `http.timeout` seconds.
+/// Both failure and timeout are errors, so they won't be taken as "nothing found".
+pub async fn within_timeout<T, E: std::fmt::Debug>(
+    name: &str,
+    request: impl Future<Output = Result<T, E>>,
+) -> Result<T, Error> {
+    match tokio::time::timeout(Duration::from_secs(C.http.timeout), request).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(err)) => Err(Error::ManualHttpClientError(format!(
+            "{} fetch | error: {:?}",
+            name, err
+        ))),
         Err(_) => Err(Error::General(
-            format!("Timeout: no response in {:?}.", DEFAULT_TIMEOUT),
-            lambda_http::http::StatusCode::REQUEST_TIMEOUT,
+            format!(
+                "{} fetch | Timeout: no response in {} seconds.",
+                name, C.http.timeout
+            ),
+            StatusCode::REQUEST_TIMEOUT,
         )),
     }
 }
 
+/// Send `req` with timeout of `http.timeout` for each attempt.
+/// Timeout, network error, 429 and 5xx are retried `http.retries` times with exponential backoff.
+/// Requests sent to the same host at the same time are capped by `http.max_per_host`
+/// (each attempt takes a permit, released while waiting to retry).
+/// Still being 429 after all retries gives `Error::UpstreamRateLimited`.
+pub async fn request_with_timeout<T>(
+    client: &Client<T>,
+    req: Request<Body>,
+) -> Result<Response<Body>, Error>
+where
+    T: Connect + Clone + Send + Sync + 'static,
+{
+    let timeout = Duration::from_secs(C.http.timeout);
+    let host = req.uri().host().unwrap_or_default().to_string();
+
+    // Body is buffered to be sent again on retry.
+    let (parts, body) = req.into_parts();
+    let body = hyper::body::to_bytes(body)
+        .await
+        .map_err(|err| Error::General(format!("error: {:?}", err), StatusCode::BAD_REQUEST))?;
+
+    let mut attempt: u32 = 0;
+    loop {
+        let mut req = Request::new(Body::from(body.clone()));
+        *req.method_mut() = parts.method.clone();
+        *req.uri_mut() = parts.uri.clone();
+        *req.version_mut() = parts.version;
+        *req.headers_mut() = parts.headers.clone();
+
+        attempt += 1;
+        let permit = host_permit(&host).await;
+        let result = tokio::time::timeout(timeout, client.request(req)).await;
+        let reason = match &result {
+            Ok(Ok(resp)) if !is_retryable(resp.status()) => None,
+            Ok(Ok(resp)) => Some(resp.status().to_string()),
+            Ok(Err(err)) => Some(err.to_string()),
+            Err(_) => Some("timeout".to_string()),
+        };
+        match reason {
+            Some(reason) if attempt <= C.http.retries => {
+                let delay = backoff_delay(attempt, C.http.backoff, C.http.max_backoff);
+                warn!(
+                    host,
+                    attempt,
+                    reason,
+                    delay_ms = delay.as_millis() as u64,
+                    "HTTP request failed, retrying"
+                );
+                drop(permit);
+                tokio::time::sleep(delay).await;
+            }
+            _ => {
+                return match result {
+                    Ok(Ok(resp)) if resp.status() == StatusCode::TOO_MANY_REQUESTS => {
+                        Err(Error::UpstreamRateLimited(host))
+                    }
+                    Ok(Ok(resp)) => Ok(resp),
+                    Ok(Err(err)) => Err(Error::General(
+                        format!("error: {:?}", err),
+                        StatusCode::BAD_GATEWAY,
+                    )),
+                    Err(_) => Err(Error::General(
+                        format!("Timeout: no response in {:?}.", timeout),
+                        StatusCode::REQUEST_TIMEOUT,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Whether a request responded with `status` is worth retrying.
+pub(crate) fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Time to wait before `attempt`-th retry (starts from 1):
+/// `backoff` doubled on every retry and capped by `max_backoff` (both in milliseconds),
+/// then randomized into its upper half to spread retries of concurrent requests.
+pub(crate) fn backoff_delay(attempt: u32, backoff: u64, max_backoff: u64) -> Duration {
+    let delay = backoff
+        .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+        .min(max_backoff);
+    Duration::from_millis(rand::thread_rng().gen_range(delay / 2..=delay))
+}
+
+async fn host_permit(host: &str) -> OwnedSemaphorePermit {
+    let semaphore = HOST_PERMITS
+        .lock()
+        .unwrap()
+        .entry(host.to_string())
+        .or_insert_with(|| Arc::new(Semaphore::new(C.http.max_per_host.max(1))))
+        .clone();
+    semaphore
+        .acquire_owned()
+        .await
+        .expect("Host semaphore should never be closed")
+}
+
+/// Deserialize the JSON body of `resp`.
+/// An upstream responding 404 has nothing of it, which gives `Error::UpstreamNotFound`.
 pub async fn parse_body<T>(resp: &mut Response<Body>) -> Result<T, Error>
 where
     T: for<'de> Deserialize<'de>,
@@ -71,10 +295,77 @@ where
         body_bytes.append(&mut chunk_bytes);
     }
     let body = std::str::from_utf8(&body_bytes).unwrap();
+    if resp.status() == StatusCode::NOT_FOUND {
+        return Err(Error::UpstreamNotFound(body.to_string()));
+    }
 
     Ok(serde_json::from_str(body)?)
 }
 
+/// One page of a paginated upstream API.
+#[derive(Debug)]
+pub struct Page<T, P> {
+    pub items: Vec<T>,
+    /// Where the next page starts, `None` if this is the last page.
+    pub next: Option<P>,
+}
+
+impl<T> Page<T, usize> {
+    /// Page of an offset-based API starting at `offset`.
+    /// A page shorter than `limit` is the last one.
+    pub fn offset(items: Vec<T>, offset: usize, limit: usize) -> Self {
+        let next = if items.len() < limit {
+            None
+        } else {
+            Some(offset + items.len())
+        };
+        Self { items, next }
+    }
+}
+
+/// Items of all pages fetched by `paginate`.
+#[derive(Debug)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    /// Stopped at `crawler.max_pages` before the last page, see `Fetched::truncated`.
+    pub truncated: bool,
+}
+
+/// Fetch all pages of a paginated API and merge their items.
+/// `fetch_page` is called with `start` first, then with `next` of the previous page,
+/// which can be a cursor, an offset or a page number.
+/// Stops at the last page, an empty page, or after `crawler.max_pages` pages.
+/// Waits `crawler.page_interval` between two pages to respect rate limits of upstreams.
+pub async fn paginate<T, P, F, Fut>(start: P, mut fetch_page: F) -> Result<Paginated<T>, Error>
+where
+    F: FnMut(P) -> Fut,
+    Fut: Future<Output = Result<Page<T, P>, Error>>,
+{
+    let interval = Duration::from_millis(C.crawler.page_interval);
+    let mut items: Vec<T> = vec![];
+    let mut next = Some(start);
+    let mut pages: usize = 0;
+    let mut truncated = false;
+    while let Some(position) = next.take() {
+        if pages >= C.crawler.max_pages {
+            warn!("Pagination stopped after {} pages", pages);
+            truncated = true;
+            break;
+        }
+        if pages > 0 && !interval.is_zero() {
+            tokio::time::sleep(interval).await;
+        }
+        let page = fetch_page(position).await?;
+        pages += 1;
+        if page.items.is_empty() {
+            break;
+        }
+        items.extend(page.items);
+        next = page.next;
+    }
+    Ok(Paginated { items, truncated })
+}
+
 pub(crate) fn hashset_append<T>(set: &mut HashSet<T>, items: Vec<T>)
 where
     T: Eq + Clone + Hash,
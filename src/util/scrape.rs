@@ -0,0 +1,158 @@
+//! Helpers for upstreams which scrape web pages instead of calling an API.
+
+use crate::{
+    error::Error,
+    upstream::Platform,
+    util::{make_client, request_with_timeout},
+};
+use http::uri::InvalidUri;
+use hyper::{body::HttpBody as _, client::connect::Connect, Body, Client, Method};
+use serde_json::Value;
+
+/// GET a web page and return its HTML.
+pub async fn fetch_html(url: &str) -> Result<String, Error> {
+    fetch_html_with(&make_client(), url).await
+}
+
+/// Same as `fetch_html`, using `client` (e.g. a `PinnedClient`).
+pub async fn fetch_html_with<T>(client: &Client<T>, url: &str) -> Result<String, Error>
+where
+    T: Connect + Clone + Send + Sync + 'static,
+{
+    let uri: http::Uri = url
+        .parse()
+        .map_err(|_err: InvalidUri| Error::ParamError(format!("Uri format Error {}", _err)))?;
+    let req = hyper::Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("Accept", "text/html")
+        .body(Body::empty())
+        .map_err(|_err| Error::ParamError(format!("Scrape Build Request Error {}", _err)))?;
+
+    let mut resp = request_with_timeout(client, req).await.map_err(|err| {
+        Error::ManualHttpClientError(format!("Scrape fetch | error: {:?}", err.to_string()))
+    })?;
+    if !resp.status().is_success() {
+        return Err(Error::General(
+            format!("Scrape Get error: {}", resp.status()),
+            resp.status(),
+        ));
+    }
+
+    let mut body: Vec<u8> = vec![];
+    while let Some(chunk) = resp.body_mut().data().await {
+        let chunk = chunk.map_err(|err| {
+            Error::ManualHttpClientError(format!("Scrape fetch | error: {:?}", err.to_string()))
+        })?;
+        body.extend_from_slice(&chunk);
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Extract `href` of the first link in an HTML fragment.
+pub fn extract_href(html: &str) -> Option<String> {
+    let start = html.find("href=\"")? + "href=\"".len();
+    let end = html[start..].find('"')?;
+    Some(html[start..start + end].to_string())
+}
+
+/// `href` of every `<a>` / `<link>` tag whose `rel` contains `me`.
+/// https://microformats.org/wiki/rel-me
+pub fn extract_rel_me(html: &str) -> Vec<String> {
+    let mut links = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let tag = match rest.find('>') {
+            Some(tag_end) => &rest[..tag_end],
+            None => break,
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        if !name.eq_ignore_ascii_case("a") && !name.eq_ignore_ascii_case("link") {
+            continue;
+        }
+        let is_me = ["rel=\"", "rel='"].iter().any(|attr| {
+            tag.find(attr).is_some_and(|rel_start| {
+                let value = &tag[rel_start + attr.len()..];
+                let quote = &attr[attr.len() - 1..];
+                value
+                    .split(quote)
+                    .next()
+                    .is_some_and(|rel| rel.split_whitespace().any(|token| token == "me"))
+            })
+        });
+        if is_me {
+            if let Some(href) = extract_href(tag) {
+                links.push(href);
+            }
+        }
+    }
+    links
+}
+
+/// Content of every `<script>` whose opening tag contains `marker`.
+fn extract_scripts<'a>(html: &'a str, marker: &str) -> Vec<&'a str> {
+    let mut scripts = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<script") {
+        rest = &rest[start..];
+        let tag_end = match rest.find('>') {
+            Some(tag_end) => tag_end,
+            None => break,
+        };
+        let tag = &rest[..tag_end];
+        let content_end = match rest[tag_end..].find("</script>") {
+            Some(content_end) => tag_end + content_end,
+            None => break,
+        };
+        if tag.contains(marker) {
+            scripts.push(rest[tag_end + 1..content_end].trim());
+        }
+        rest = &rest[content_end..];
+    }
+    scripts
+}
+
+/// Parse every JSON-LD (`<script type="application/ld+json">`) block of a page.
+pub fn extract_json_ld(html: &str) -> Vec<Value> {
+    extract_scripts(html, "application/ld+json")
+        .into_iter()
+        .filter_map(|script| serde_json::from_str(script).ok())
+        .collect()
+}
+
+/// Parse the `__NEXT_DATA__` block of a Next.js page.
+pub fn extract_next_data(html: &str) -> Option<Value> {
+    extract_scripts(html, "__NEXT_DATA__")
+        .into_iter()
+        .find_map(|script| serde_json::from_str(script).ok())
+}
+
+/// Find the first value of `key` in a JSON tree, depth-first.
+pub fn find_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => map
+            .get(key)
+            .or_else(|| map.values().find_map(|v| find_key(v, key))),
+        Value::Array(array) => array.iter().find_map(|v| find_key(v, key)),
+        _ => None,
+    }
+}
+
+/// Which identity a link points to.
+/// Links not to a known platform are regarded as personal domains.
+pub fn link_to_identity(link: &str) -> Option<(Platform, String)> {
+    let url: url::Url = link.parse().ok()?;
+    let host = url.host_str()?.trim_start_matches("www.").to_lowercase();
+    let first_segment = url
+        .path_segments()
+        .and_then(|mut segments| segments.next())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.to_lowercase());
+    match host.as_str() {
+        "github.com" => Some((Platform::Github, first_segment?)),
+        "twitter.com" | "x.com" => Some((Platform::Twitter, first_segment?)),
+        "keybase.io" => Some((Platform::Keybase, first_segment?)),
+        _ => Some((Platform::DNS, host)),
+    }
+}
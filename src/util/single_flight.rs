@@ -0,0 +1,64 @@
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::{collections::HashMap, future::Future, hash::Hash, sync::Mutex};
+
+/// Deduplicate concurrent work: runs with the same key at the same time share one run,
+/// and all of them get (a clone of) its result.
+pub struct SingleFlight<K, V> {
+    running: Mutex<HashMap<K, Shared<BoxFuture<'static, V>>>>,
+}
+
+impl<K, V> Default for SingleFlight<K, V> {
+    fn default() -> Self {
+        Self {
+            running: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> SingleFlight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone + Send + Sync + 'static,
+{
+    /// Run `work` for `key`, or wait for the one already running for it.
+    pub async fn run<F, Fut>(&self, key: K, work: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V> + Send + 'static,
+    {
+        let (shared, _leader) = {
+            let mut running = self.running.lock().unwrap();
+            match running.get(&key) {
+                Some(shared) => (shared.clone(), None),
+                None => {
+                    let shared = work().boxed().shared();
+                    running.insert(key.clone(), shared.clone());
+                    (shared, Some(Leader { flight: self, key }))
+                }
+            }
+        };
+        shared.await
+    }
+
+    /// How many runs are going on.
+    pub fn len(&self) -> usize {
+        self.running.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Held by the caller who started a run, which ends that run (finished or cancelled) when dropped.
+/// No one else can start one for the same key before that, so it only removes its own run.
+struct Leader<'a, K: Eq + Hash, V> {
+    flight: &'a SingleFlight<K, V>,
+    key: K,
+}
+
+impl<K: Eq + Hash, V> Drop for Leader<'_, K, V> {
+    fn drop(&mut self) {
+        self.flight.running.lock().unwrap().remove(&self.key);
+    }
+}
@@ -1 +1,283 @@
+use crate::{
+    config::C,
+    error::Error,
+    upstream::Platform,
+    util::address::normalize_evm_address,
+    util::canonical::canonicalize,
+    util::eth,
+    util::scrape::{
+        extract_href, extract_json_ld, extract_next_data, extract_rel_me, find_key,
+        link_to_identity,
+    },
+    util::single_flight::SingleFlight,
+    util::{
+        backoff_delay, is_public_ip, is_retryable, paginate, parse_body, resolve_public_url, Page,
+    },
+};
 
+#[test]
+fn test_link_to_identity() {
+    let html = r#"<a href="https://github.com/Gargron" target="_blank" rel="nofollow noopener noreferrer me"><span class="invisible">https://</span>github.com/Gargron</a>"#;
+    let link = extract_href(html).unwrap();
+    assert_eq!(
+        link_to_identity(&link),
+        Some((Platform::Github, "gargron".into()))
+    );
+    assert_eq!(
+        link_to_identity("https://www.example.com/about"),
+        Some((Platform::DNS, "example.com".into()))
+    );
+}
+
+#[test]
+fn test_extract_rel_me() {
+    let html = r#"<html><head>
+<link rel="me" href="https://github.com/alice">
+<link rel="stylesheet" href="/style.css">
+</head><body>
+<a href="https://twitter.com/alice" rel='nofollow me'>Twitter</a>
+<a href="https://example.com/merch" rel="noopener">Shop</a>
+</body></html>"#;
+    assert_eq!(
+        extract_rel_me(html),
+        vec!["https://github.com/alice", "https://twitter.com/alice"]
+    );
+}
+
+#[test]
+fn test_extract_scripts() {
+    let html = r#"<html><head>
+<script src="/app.js"></script>
+<script type="application/ld+json">{"@type": "Person", "name": "Alice"}</script>
+<script id="__NEXT_DATA__" type="application/json">{"props": {"pageProps": {"profile": {"handle": "alice"}}}}</script>
+</head></html>"#;
+    let json_ld = extract_json_ld(html);
+    assert_eq!(json_ld.len(), 1);
+    assert_eq!(json_ld[0]["name"], "Alice");
+
+    let next_data = extract_next_data(html).unwrap();
+    assert_eq!(find_key(&next_data, "handle").unwrap(), "alice");
+    assert_eq!(find_key(&next_data, "missing"), None);
+}
+
+#[test]
+fn test_recover_address() {
+    // https://web3js.readthedocs.io/en/v1.8.0/web3-eth-accounts.html#sign
+    let hash = eth::eip191_hash(b"Some data");
+    assert_eq!(
+        hex::encode(hash),
+        "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655"
+    );
+    let signature = eth::decode_hex("0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c").unwrap();
+    assert_eq!(
+        eth::recover_address(&hash, &signature).unwrap(),
+        "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23"
+    );
+    assert!(eth::is_personal_signed_by(
+        "Some data",
+        &signature,
+        "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+    ));
+    assert!(!eth::is_personal_signed_by(
+        "Other data",
+        &signature,
+        "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+    ));
+    assert!(eth::recover_address(&hash, &signature[..64]).is_err());
+}
+
+#[test]
+fn test_to_checksum_address() {
+    // https://eips.ethereum.org/EIPS/eip-55#test-cases
+    assert_eq!(
+        eth::to_checksum_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    );
+    assert_eq!(
+        eth::to_checksum_address("0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359"),
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+    );
+}
+
+#[test]
+fn test_normalize_evm_address() {
+    let lowercase = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+    assert_eq!(normalize_evm_address(lowercase).unwrap(), lowercase);
+    assert_eq!(
+        normalize_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap(),
+        lowercase
+    );
+    assert_eq!(
+        normalize_evm_address("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED").unwrap(),
+        lowercase
+    );
+    // Mixed-case with a wrong checksum.
+    assert!(normalize_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_err());
+    assert!(normalize_evm_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea").is_err());
+    assert!(normalize_evm_address("vitalik.eth").is_err());
+}
+
+#[test]
+fn test_canonicalize() {
+    let cases = [
+        (Platform::Twitter, "@Suji_Yan", "suji_yan"),
+        (
+            Platform::Ethereum,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ),
+        // Decomposed `é` is composed.
+        (Platform::SpaceId, "Cafe\u{301}.bnb", "caf\u{e9}.bnb"),
+        (Platform::DNS, "Bücher.example.", "xn--bcher-kva.example"),
+        (
+            Platform::Mastodon,
+            "@Alice@Mastodon.Social",
+            "alice@mastodon.social",
+        ),
+        // Case-sensitive ones are kept as is.
+        (Platform::HackerNews, "PG", "PG"),
+    ];
+    for (platform, identity, expected) in cases {
+        assert_eq!(canonicalize(&platform, identity).unwrap(), expected);
+    }
+    assert!(canonicalize(&Platform::Mastodon, "alice").is_err());
+}
+
+#[tokio::test]
+async fn test_single_flight() {
+    let flight: SingleFlight<&str, usize> = SingleFlight::default();
+    let calls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let run = || {
+        let calls = calls.clone();
+        flight.run("yeiwb", move || async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+        })
+    };
+
+    let results = futures::future::join_all((0..10).map(|_| run())).await;
+    assert!(results.iter().all(|result| *result == 1));
+    assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert!(flight.is_empty());
+
+    // Finished runs are not shared with later ones.
+    assert_eq!(run().await, 2);
+}
+
+#[test]
+fn test_page_offset() {
+    let page = Page::offset(vec![1, 2, 3], 6, 3);
+    assert_eq!(page.next, Some(9));
+    let page = Page::offset(vec![1, 2], 9, 3);
+    assert_eq!(page.next, None);
+}
+
+#[tokio::test]
+async fn test_paginate() -> Result<(), Error> {
+    let all: Vec<usize> = (0..25).collect();
+    let result = paginate(0, |offset: usize| {
+        let items: Vec<usize> = all.iter().skip(offset).take(10).copied().collect();
+        async move { Ok(Page::offset(items, offset, 10)) }
+    })
+    .await?;
+    assert_eq!(result.items, all);
+    assert!(!result.truncated);
+
+    let pages = ["", "b", "c"];
+    let result = paginate(0, |page: usize| async move {
+        Ok(Page {
+            items: vec![pages[page]],
+            next: (page + 1 < pages.len()).then_some(page + 1),
+        })
+    })
+    .await?;
+    assert_eq!(result.items, pages);
+    assert!(!result.truncated);
+
+    let result = paginate(0, |page: usize| async move {
+        Ok(Page {
+            items: vec![page],
+            next: Some(page + 1),
+        })
+    })
+    .await?;
+    assert_eq!(result.items.len(), C.crawler.max_pages);
+    assert!(result.truncated);
+    Ok(())
+}
+
+#[test]
+fn test_is_retryable() {
+    use http::StatusCode;
+
+    assert!(is_retryable(StatusCode::TOO_MANY_REQUESTS));
+    assert!(is_retryable(StatusCode::BAD_GATEWAY));
+    assert!(!is_retryable(StatusCode::NOT_FOUND));
+    assert!(!is_retryable(StatusCode::OK));
+}
+
+#[test]
+fn test_backoff_delay() {
+    use std::time::Duration;
+
+    for _ in 0..10 {
+        let first = backoff_delay(1, 200, 5000);
+        assert!(first >= Duration::from_millis(100) && first <= Duration::from_millis(200));
+        let third = backoff_delay(3, 200, 5000);
+        assert!(third >= Duration::from_millis(400) && third <= Duration::from_millis(800));
+        let capped = backoff_delay(20, 200, 5000);
+        assert!(capped >= Duration::from_millis(2500) && capped <= Duration::from_millis(5000));
+    }
+}
+
+#[test]
+fn test_is_public_ip() {
+    assert!(is_public_ip(&"93.184.216.34".parse().unwrap()));
+    assert!(is_public_ip(&"2606:2800:220:1::".parse().unwrap()));
+    for ip in [
+        "127.0.0.1",
+        "10.0.0.1",
+        "172.16.0.1",
+        "192.168.1.1",
+        "169.254.169.254",
+        "100.64.0.1",
+        "0.0.0.0",
+        "::1",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:127.0.0.1",
+    ] {
+        assert!(!is_public_ip(&ip.parse().unwrap()), "{}", ip);
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_public_url() {
+    for url in [
+        "https://127.0.0.1",
+        "https://169.254.169.254/latest/meta-data",
+        "https://[::1]:8080",
+        "https://localhost",
+        "https://api.localhost",
+        "https://alice@127.0.0.1",
+    ] {
+        assert!(resolve_public_url(url).await.is_none(), "{}", url);
+    }
+}
+
+#[tokio::test]
+async fn test_parse_body_not_found() {
+    use http::StatusCode;
+    use hyper::{Body, Response};
+
+    let mut resp = Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Body::from(r#"{"error": "no such user"}"#))
+        .unwrap();
+    let result: Result<serde_json::Value, Error> = parse_body(&mut resp).await;
+    assert!(matches!(result, Err(Error::UpstreamNotFound(_))));
+
+    let mut resp = Response::new(Body::from(r#"{"name": "alice"}"#));
+    let body: serde_json::Value = parse_body(&mut resp).await.unwrap();
+    assert_eq!(body["name"], "alice");
+}
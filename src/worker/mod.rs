@@ -0,0 +1,105 @@
+pub mod scheduler;
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    config::C,
+    upstream::{fetch_all, Target},
+};
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::{event, Instrument, Level, Span};
+
+lazy_static! {
+    /// Refresh jobs of this process, consumed by workers spawned in `start`.
+    static ref QUEUE: Queue = Queue::new();
+}
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// A target to refresh, and the span it's enqueued in (so its logs carry the same `request_id`).
+type Job = (Target, Span);
+
+struct Queue {
+    sender: UnboundedSender<Job>,
+    receiver: Arc<tokio::sync::Mutex<UnboundedReceiver<Job>>>,
+    /// Targets waiting in queue or being refreshed.
+    pending: Mutex<HashSet<Target>>,
+}
+
+impl Queue {
+    fn new() -> Self {
+        let (sender, receiver) = unbounded_channel();
+        Self {
+            sender,
+            receiver: Arc::new(tokio::sync::Mutex::new(receiver)),
+            pending: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+/// Spawn `[worker] concurrency` workers consuming refresh jobs in the background.
+/// Calling this more than once does nothing.
+pub fn start() {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let concurrency = C.worker.concurrency.max(1);
+    for worker in 0..concurrency {
+        tokio::spawn(run(worker));
+    }
+    event!(Level::INFO, concurrency, "Refresh workers started.");
+}
+
+/// Refresh `target` in the background.
+/// Returns `false` if a job of the same target is already queued or running,
+/// or workers are not started in this process (e.g. in `lambda`), which leaves it outdated.
+pub fn enqueue(target: Target) -> bool {
+    if !STARTED.load(Ordering::SeqCst) {
+        event!(Level::DEBUG, ?target, "Workers not started. Skipped.");
+        return false;
+    }
+    push(target)
+}
+
+fn push(target: Target) -> bool {
+    if !QUEUE.pending.lock().unwrap().insert(target.clone()) {
+        event!(Level::DEBUG, ?target, "Already queued. Skipped.");
+        return false;
+    }
+    if let Err(err) = QUEUE.sender.send((target, Span::current())) {
+        // Receiver lives in `QUEUE` forever, so this should never happen.
+        QUEUE.pending.lock().unwrap().remove(&err.0 .0);
+        return false;
+    }
+    true
+}
+
+/// Refresh jobs waiting in queue or running.
+pub fn queue_depth() -> usize {
+    QUEUE.pending.lock().unwrap().len()
+}
+
+async fn run(worker: usize) {
+    loop {
+        let (target, span) = match QUEUE.receiver.lock().await.recv().await {
+            Some(job) => job,
+            None => break,
+        };
+        async {
+            event!(Level::DEBUG, worker, ?target, "Refreshing.");
+            if let Err(err) = fetch_all(target.clone()).await {
+                event!(Level::WARN, worker, ?target, %err, "Failed to refresh");
+            }
+        }
+        .instrument(span)
+        .await;
+        QUEUE.pending.lock().unwrap().remove(&target);
+    }
+}
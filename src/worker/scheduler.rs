@@ -0,0 +1,132 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    config::C,
+    error::Error,
+    graph::{
+        db_connection,
+        edge::{InvalidationReason, Proof, StaleProof},
+        Edge,
+    },
+    upstream::{refetch, upstreams_of, DataSource, FetchOutcome, Fetched, Target},
+    util::naive_now,
+};
+use strum::IntoEnumIterator;
+use tracing::{event, Level};
+
+/// Proofs of these sources are saved by prefetch or ingest endpoints instead of fetchers,
+/// so re-fetching will never find them again.
+const NOT_REFETCHABLE: [DataSource; 4] = [
+    DataSource::SybilList,
+    DataSource::SelfAttested,
+    DataSource::VerifiableCredential,
+    DataSource::PolygonID,
+];
+
+/// Scan stale proofs every `[scheduler] interval` seconds in the background.
+pub fn start() {
+    if C.scheduler.interval == 0 {
+        return;
+    }
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(Duration::from_secs(C.scheduler.interval));
+        loop {
+            interval.tick().await;
+            if let Err(err) = scan().await {
+                event!(Level::WARN, %err, "Failed to scan stale proofs");
+            }
+        }
+    });
+    event!(
+        Level::INFO,
+        interval = C.scheduler.interval,
+        "Stale proof scheduler started."
+    );
+}
+
+/// Re-fetch identities on both ends of every stale proof.
+/// A proof not found again is invalidated only if an upstream saving its source (see `upstreams_of`)
+/// ran and succeeded, and none of them failed or left results out:
+/// an empty result of a failed, truncated or skipped upstream proves nothing.
+/// A proof neither found again nor invalidated is marked checked (see `Proof::mark_checked`).
+/// Sources with any of their upstreams disabled are skipped.
+pub async fn scan() -> Result<(), Error> {
+    let db = db_connection().await?;
+    let started = naive_now();
+    // Result of re-fetching this target during this scan (without connections found),
+    // `None` if it failed before any upstream ran.
+    let mut refetched: HashMap<Target, Option<FetchOutcome>> = HashMap::new();
+    let (mut refreshed, mut invalidated) = (0, 0);
+
+    for source in DataSource::iter().filter(|source| !NOT_REFETCHABLE.contains(source)) {
+        let staleness = C.scheduler.staleness_of(&source.to_string());
+        if staleness == 0 {
+            continue;
+        }
+        let owners = upstreams_of(&source);
+        if owners.is_empty() {
+            continue;
+        }
+        if let Some(disabled) = owners.iter().find(|name| !C.upstream.is_enabled(name)) {
+            event!(Level::DEBUG, %source, upstream = disabled, "Upstream disabled. Skipped.");
+            continue;
+        }
+        let before = started - chrono::Duration::seconds(staleness as i64);
+        let stale = Proof::find_stale(&db, &source, before, C.scheduler.batch).await?;
+        for StaleProof { record, from, to } in stale {
+            let mut outcomes = vec![];
+            for identity in [from, to] {
+                let target = Target::Identity(identity.platform, identity.identity);
+                if !refetched.contains_key(&target) {
+                    let outcome = match refetch(&target).await {
+                        Ok(outcome) => Some(FetchOutcome {
+                            fetched: Fetched::default(),
+                            ..outcome
+                        }),
+                        Err(err) => {
+                            event!(Level::WARN, %target, %err, "Failed to refetch");
+                            None
+                        }
+                    };
+                    refetched.insert(target.clone(), outcome);
+                }
+                outcomes.push(refetched[&target].as_ref());
+            }
+            let confirmed = outcomes.iter().all(Option::is_some) && {
+                let outcomes: Vec<&FetchOutcome> = outcomes.into_iter().flatten().collect();
+                owners
+                    .iter()
+                    .any(|name| outcomes.iter().any(|outcome| outcome.succeeded(name)))
+                    && !owners.iter().any(|name| {
+                        outcomes
+                            .iter()
+                            .any(|outcome| outcome.failed(name) || outcome.truncated(name))
+                    })
+            };
+
+            match Proof::find_by_uuid(&db, &record.uuid).await? {
+                Some(found) if found.updated_at < started => {
+                    if confirmed {
+                        event!(Level::DEBUG, uuid = %found.uuid, %source, "Not found in upstream. Invalidated.");
+                        Proof::invalidate(&db, found, InvalidationReason::Gone).await?;
+                        invalidated += 1;
+                    } else {
+                        // Not picked again until it's stale again, so it won't starve the others.
+                        Proof::mark_checked(&db, &found).await?;
+                    }
+                }
+                Some(_) => refreshed += 1,
+                None => {}
+            }
+        }
+    }
+
+    event!(
+        Level::INFO,
+        refetched = refetched.len(),
+        refreshed,
+        invalidated,
+        "Stale proofs scanned."
+    );
+    Ok(())
+}
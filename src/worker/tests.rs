@@ -0,0 +1,62 @@
+use crate::{
+    graph::{edge::Proof, new_db_connection, vertex::Identity, Edge},
+    upstream::{import::import, Connection, DataSource, Platform, Target},
+    util::naive_now,
+    worker::{enqueue, push},
+};
+use fake::{Fake, Faker};
+
+#[test]
+fn test_enqueue_not_started() {
+    let target = Target::Identity(Platform::Twitter, "worker_test_enqueue_not_started".into());
+    assert!(!enqueue(target));
+}
+
+#[test]
+fn test_push_dedup() {
+    let target = Target::Identity(Platform::Twitter, "worker_test_push_dedup".into());
+    assert!(push(target.clone()));
+    assert!(!push(target));
+}
+
+#[tokio::test]
+async fn test_republished_refreshed() {
+    let db = new_db_connection().await.unwrap();
+    let from: Identity = Faker.fake();
+    let to: Identity = Faker.fake();
+    let republished = || Proof {
+        source: DataSource::Rss3,
+        record_id: Some(format!("twitter:{}", to.identity)),
+        ..Faker.fake()
+    };
+    // Fetched long ago.
+    let first = Proof {
+        updated_at: naive_now() - chrono::Duration::days(30),
+        ..republished()
+    };
+    import(
+        &db,
+        &[Connection::Republished(
+            from.clone(),
+            to.clone(),
+            first.clone(),
+        )],
+    )
+    .await
+    .unwrap();
+
+    // Scheduler invalidates proofs not refreshed since it started.
+    let started = naive_now();
+    import(
+        &db,
+        &[Connection::Republished(from, to.clone(), republished())],
+    )
+    .await
+    .unwrap();
+    let refreshed = Proof::find_by_uuid(&db, &first.uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(refreshed.updated_at >= started);
+    assert!(refreshed.invalid_since.is_none());
+}